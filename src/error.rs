@@ -33,3 +33,54 @@ impl fmt::Display for GlyphTriangulationError {
         write!(f, "The glyph outline could not be triangulated.")
     }
 }
+
+/// An error that can occur if the vertex indices of a mesh
+/// exceed the range of the requested [crate::IndexType].
+#[derive(Debug)]
+pub struct IndexOverflowError;
+
+impl MeshTextError for IndexOverflowError {}
+
+impl Error for IndexOverflowError {}
+
+impl fmt::Display for IndexOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The mesh has too many vertices for the requested index type."
+        )
+    }
+}
+
+/// An error that can occur if text is laid out along a path
+/// that has fewer than two distinct points.
+#[derive(Debug)]
+pub struct InvalidPathError;
+
+impl MeshTextError for InvalidPathError {}
+
+impl Error for InvalidPathError {}
+
+impl fmt::Display for InvalidPathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The path has fewer than two distinct points.")
+    }
+}
+
+/// An error that can occur if three-dimensional text is requested
+/// with a two-dimensional [crate::GlamVertex] type.
+#[derive(Debug)]
+pub struct VertexDimensionError;
+
+impl MeshTextError for VertexDimensionError {}
+
+impl Error for VertexDimensionError {}
+
+impl fmt::Display for VertexDimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The vertex type can not hold three-dimensional vertices."
+        )
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+
+use crate::{util::write_stored_zip, IndexedMeshText};
+
+/// The content types of the parts of a 3MF package.
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+
+/// The relationship that marks the 3D model as the start part of a 3MF package.
+const RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+/// The unit of the coordinates of a 3MF model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ModelUnit {
+    /// One unit is a micrometer.
+    Micron,
+
+    /// One unit is a millimeter, which is the default of most slicers.
+    #[default]
+    Millimeter,
+
+    /// One unit is a centimeter.
+    Centimeter,
+
+    /// One unit is an inch.
+    Inch,
+
+    /// One unit is a foot.
+    Foot,
+
+    /// One unit is a meter.
+    Meter,
+}
+
+impl ModelUnit {
+    /// Gets the name of the unit in a 3MF model.
+    fn name(&self) -> &'static str {
+        match self {
+            ModelUnit::Micron => "micron",
+            ModelUnit::Millimeter => "millimeter",
+            ModelUnit::Centimeter => "centimeter",
+            ModelUnit::Inch => "inch",
+            ModelUnit::Foot => "foot",
+            ModelUnit::Meter => "meter",
+        }
+    }
+}
+
+/// Writes an [IndexedMeshText] as a 3MF package.
+///
+/// Slicers expect closed meshes, which means only three-dimensional
+/// meshes are suitable for 3D printing. The vertices of two-dimensional
+/// meshes are placed in the XY-plane.
+///
+/// Arguments:
+///
+/// * `mesh`: The mesh that is exported.
+/// * `unit`: The [ModelUnit] of the coordinates of the mesh.
+/// * `writer`: The writer to which the package is written.
+///
+/// Returns:
+///
+/// An [io::Error] if the package could not be written.
+///
+/// # Example
+///
+/// ```rust
+/// use meshtext::{
+///     export::{write_3mf, ModelUnit},
+///     IndexedMeshText, MeshGenerator, TextSection,
+/// };
+///
+/// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+/// let mut generator = MeshGenerator::new(font_data);
+///
+/// // Make the text 10mm high.
+/// let transform = [10.0, 0.0, 0.0, 0.0,
+///                  0.0, 10.0, 0.0, 0.0,
+///                  0.0, 0.0, 2.0, 0.0,
+///                  0.0, 0.0, 0.0, 1.0];
+/// let mesh: IndexedMeshText = generator
+///     .generate_section("Hello World!", false, Some(&transform))
+///     .expect("Failed to generate mesh.");
+///
+/// let mut package = Vec::new();
+/// write_3mf(&mesh, ModelUnit::Millimeter, &mut package).expect("Failed to write 3MF.");
+/// ```
+pub fn write_3mf<W: Write>(mesh: &IndexedMeshText, unit: ModelUnit, writer: W) -> io::Result<()> {
+    write_package(&[(mesh, None)], unit, writer)
+}
+
+/// Writes multiple colored [IndexedMeshText]s as a single 3MF package.
+///
+/// Each mesh becomes a separate object with its own color, which
+/// allows e.g. printing each glyph of a text in a different color.
+///
+/// Arguments:
+///
+/// * `meshes`: The meshes that are exported together with their
+///   linear RGB color with components in the range `0.0..=1.0`.
+/// * `unit`: The [ModelUnit] of the coordinates of the meshes.
+/// * `writer`: The writer to which the package is written.
+///
+/// Returns:
+///
+/// An [io::Error] if the package could not be written.
+pub fn write_3mf_colored<W: Write>(
+    meshes: &[(&IndexedMeshText, [f32; 3])],
+    unit: ModelUnit,
+    writer: W,
+) -> io::Result<()> {
+    let meshes: Vec<_> = meshes.iter().map(|(m, c)| (*m, Some(*c))).collect();
+    write_package(&meshes, unit, writer)
+}
+
+/// Writes the parts of a 3MF package.
+///
+/// Arguments:
+///
+/// * `meshes`: The meshes that are exported together with their optional color.
+/// * `unit`: The [ModelUnit] of the coordinates of the meshes.
+/// * `writer`: The writer to which the package is written.
+///
+/// Returns:
+///
+/// An [io::Error] if the package could not be written.
+fn write_package<W: Write>(
+    meshes: &[(&IndexedMeshText, Option<[f32; 3]>)],
+    unit: ModelUnit,
+    writer: W,
+) -> io::Result<()> {
+    let model = model(meshes, unit);
+    write_stored_zip(
+        writer,
+        &[
+            ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+            ("_rels/.rels", RELATIONSHIPS.as_bytes()),
+            ("3D/3dmodel.model", model.as_bytes()),
+        ],
+        1,
+    )
+}
+
+/// Generates the 3D model part of a 3MF package.
+///
+/// Arguments:
+///
+/// * `meshes`: The meshes that are exported together with their optional color.
+/// * `unit`: The [ModelUnit] of the coordinates of the meshes.
+///
+/// Returns:
+///
+/// The XML of the model.
+fn model(meshes: &[(&IndexedMeshText, Option<[f32; 3]>)], unit: ModelUnit) -> String {
+    let mut model = String::new();
+    let _ = writeln!(model, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        model,
+        r#"<model unit="{}" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">"#,
+        unit.name()
+    );
+    let _ = writeln!(model, "  <resources>");
+
+    // The materials use the id `1`, so the objects start at `2`.
+    let colored = meshes.iter().any(|(_, color)| color.is_some());
+    if colored {
+        let _ = writeln!(model, r#"    <basematerials id="1">"#);
+        for (i, (_, color)) in meshes.iter().enumerate() {
+            let [r, g, b] = color
+                .unwrap_or([1f32; 3])
+                .map(|c| (c.clamp(0f32, 1f32) * 255f32).round() as u8);
+            let _ = writeln!(
+                model,
+                "      <base name=\"Part {}\" displaycolor=\"#{r:02X}{g:02X}{b:02X}\"/>",
+                i + 1
+            );
+        }
+        let _ = writeln!(model, "    </basematerials>");
+    }
+
+    for (i, (mesh, _)) in meshes.iter().enumerate() {
+        match colored {
+            true => {
+                let _ = writeln!(
+                    model,
+                    r#"    <object id="{}" type="model" pid="1" pindex="{i}">"#,
+                    i + 2
+                );
+            }
+            false => {
+                let _ = writeln!(model, r#"    <object id="{}" type="model">"#, i + 2);
+            }
+        }
+        let _ = writeln!(model, "      <mesh>");
+        let _ = writeln!(model, "        <vertices>");
+        let components = if mesh.two_dimensional { 2 } else { 3 };
+        for v in mesh.vertices.chunks_exact(components) {
+            let z = v.get(2).copied().unwrap_or(0f32);
+            let _ = writeln!(
+                model,
+                r#"          <vertex x="{}" y="{}" z="{z}"/>"#,
+                v[0], v[1]
+            );
+        }
+        let _ = writeln!(model, "        </vertices>");
+        let _ = writeln!(model, "        <triangles>");
+        for t in mesh.indices.chunks_exact(3) {
+            let _ = writeln!(
+                model,
+                r#"          <triangle v1="{}" v2="{}" v3="{}"/>"#,
+                t[0], t[1], t[2]
+            );
+        }
+        let _ = writeln!(model, "        </triangles>");
+        let _ = writeln!(model, "      </mesh>");
+        let _ = writeln!(model, "    </object>");
+    }
+
+    let _ = writeln!(model, "  </resources>");
+    let _ = writeln!(model, "  <build>");
+    for i in 0..meshes.len() {
+        let _ = writeln!(model, r#"    <item objectid="{}"/>"#, i + 2);
+    }
+    let _ = writeln!(model, "  </build>");
+    let _ = writeln!(model, "</model>");
+
+    model
+}
@@ -0,0 +1,143 @@
+use std::{
+    fmt::{Display, Write as _},
+    io::{self, Write},
+};
+
+use glam::Vec3A;
+
+use crate::{util::write_stored_zip, IndexedMeshText, TriangleMesh};
+
+/// The alignment of the files within an USDZ package in bytes.
+const USDZ_ALIGNMENT: usize = 64;
+
+/// Writes an [IndexedMeshText] as an USDZ package.
+///
+/// The package contains a single `Mesh` with flat normals, which is bound
+/// to an `UsdPreviewSurface` material with the given base color. This can
+/// be viewed directly by AR Quick Look on iOS.
+///
+/// The mesh is placed in a Y-up scene with one unit per meter.
+///
+/// Arguments:
+///
+/// * `mesh`: The mesh that is exported.
+/// * `color`: The base color of the mesh as linear RGB components in the range `0.0..=1.0`.
+/// * `writer`: The writer to which the package is written.
+///
+/// Returns:
+///
+/// An [io::Error] if the package could not be written.
+///
+/// # Example
+///
+/// ```rust
+/// use meshtext::{export::write_usdz, IndexedMeshText, MeshGenerator, TextSection};
+///
+/// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+/// let mut generator = MeshGenerator::new(font_data);
+///
+/// let mesh: IndexedMeshText = generator
+///     .generate_section("Hello World!", false, None)
+///     .expect("Failed to generate mesh.");
+///
+/// let mut package = Vec::new();
+/// write_usdz(&mesh, [0.9, 0.1, 0.1], &mut package).expect("Failed to write USDZ.");
+/// ```
+pub fn write_usdz<W: Write>(mesh: &IndexedMeshText, color: [f32; 3], writer: W) -> io::Result<()> {
+    let stage = usda_stage(mesh, color);
+    write_stored_zip(writer, &[("text.usda", stage.as_bytes())], USDZ_ALIGNMENT)
+}
+
+/// Generates the USD stage of a mesh in the ASCII format.
+///
+/// Arguments:
+///
+/// * `mesh`: The mesh that is exported.
+/// * `color`: The base color of the mesh.
+///
+/// Returns:
+///
+/// The content of the `.usda` file.
+fn usda_stage(mesh: &IndexedMeshText, color: [f32; 3]) -> String {
+    let points: Vec<Vec3A> = (0..mesh.vertex_count())
+        .map(|i| mesh.vertex(i).into())
+        .collect();
+
+    // Each corner of a triangle gets the normal of the triangle.
+    let mut normals = Vec::with_capacity(mesh.indices.len());
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| points[triangle[i] as usize]);
+        let normal = (b - a).cross(c - a).normalize_or_zero();
+        normals.extend_from_slice(&[normal; 3]);
+    }
+
+    let vector = |v: &Vec3A| format!("({}, {}, {})", v.x, v.y, v.z);
+
+    let mut stage = String::new();
+    let _ = write!(
+        stage,
+        r#"#usda 1.0
+(
+    defaultPrim = "Text"
+    metersPerUnit = 1
+    upAxis = "Y"
+)
+
+def Xform "Text"
+{{
+    def Mesh "Mesh" (
+        prepend apiSchemas = ["MaterialBindingAPI"]
+    )
+    {{
+        float3[] extent = [{}, {}]
+        int[] faceVertexCounts = [{}]
+        int[] faceVertexIndices = [{}]
+        rel material:binding = </Text/Material>
+        normal3f[] normals = [{}] (
+            interpolation = "faceVarying"
+        )
+        point3f[] points = [{}]
+        uniform token subdivisionScheme = "none"
+    }}
+
+    def Material "Material"
+    {{
+        token outputs:surface.connect = </Text/Material/Surface.outputs:surface>
+
+        def Shader "Surface"
+        {{
+            uniform token info:id = "UsdPreviewSurface"
+            color3f inputs:diffuseColor = ({}, {}, {})
+            float inputs:metallic = 0
+            float inputs:roughness = 0.5
+            token outputs:surface
+        }}
+    }}
+}}
+"#,
+        vector(&mesh.bbox.min),
+        vector(&mesh.bbox.max),
+        join((0..mesh.indices.len() / 3).map(|_| 3)),
+        join(mesh.indices.iter()),
+        join(normals.iter().map(vector)),
+        join(points.iter().map(vector)),
+        color[0],
+        color[1],
+        color[2],
+    );
+
+    stage
+}
+
+/// Joins values into a comma separated list.
+///
+/// Arguments:
+///
+/// * `values`: The values that are joined.
+///
+/// Returns:
+///
+/// The values separated by commas.
+fn join<T: Display>(values: impl Iterator<Item = T>) -> String {
+    values.map(|v| v.to_string()).collect::<Vec<_>>().join(", ")
+}
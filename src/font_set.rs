@@ -0,0 +1,384 @@
+use std::ops::RangeInclusive;
+
+use glam::{Mat3, Mat4, Vec3, Vec4};
+
+use crate::{
+    error::MeshTextError,
+    util::{is_combining_mark, is_variation_selector},
+    BoundingBox, FontFace, IndexType, IndexedMeshText, MeshGenerator, Script, TextSection,
+};
+
+/// The zero-width joiner, which keeps the joined characters in the same face.
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// A [FontSet] combines multiple fonts, so that each character
+/// of a text section is generated with a font that supports it.
+///
+/// Characters are routed to a face by the following rules:
+///
+/// 1. The first route whose range contains the character,
+///    if its face supports the character.
+/// 2. The face of the [Script] of the character, if it supports the character.
+/// 3. The first face in the order in which the faces were added,
+///    that supports the character.
+/// 4. The fallback face, which is the primary face by default.
+///
+/// Whitespace, combining marks, variation selectors and zero-width
+/// joiners stay in the face of the preceding character. Characters
+/// without a [Script] (e.g. digits and punctuation) also stay in
+/// that face, unless it does not support them or they are routed.
+pub struct FontSet<T>
+where
+    T: FontFace,
+{
+    /// The [MeshGenerator] of each face, starting with the primary face.
+    faces: Vec<MeshGenerator<T>>,
+
+    /// The face that is used for characters that no face supports.
+    fallback: usize,
+
+    /// The ranges of characters that are routed to a certain face.
+    routes: Vec<(RangeInclusive<char>, usize)>,
+
+    /// The scripts that are routed to a certain face.
+    scripts: Vec<(Script, usize)>,
+}
+
+impl<T> FontSet<T>
+where
+    T: FontFace,
+{
+    /// Creates a new [FontSet].
+    ///
+    /// Arguments:
+    ///
+    /// * `primary`: The [MeshGenerator] of the primary face, which
+    ///   is used for all characters that no other face supports.
+    ///
+    /// Returns:
+    ///
+    /// The new [FontSet] with a single face.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{FontSet, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let font_set = FontSet::new(MeshGenerator::new(font_data));
+    /// assert_eq!(font_set.face_count(), 1);
+    /// ```
+    pub fn new(primary: MeshGenerator<T>) -> Self {
+        Self {
+            faces: vec![primary],
+            fallback: 0,
+            routes: Vec::new(),
+            scripts: Vec::new(),
+        }
+    }
+
+    /// Adds another face to this [FontSet].
+    ///
+    /// Arguments:
+    ///
+    /// * `generator`: The [MeshGenerator] of the face.
+    ///
+    /// Returns:
+    ///
+    /// The index of the added face.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{FontSet, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut font_set = FontSet::new(MeshGenerator::new(font_data));
+    ///
+    /// let index = font_set.add_face(MeshGenerator::new(font_data));
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn add_face(&mut self, generator: MeshGenerator<T>) -> usize {
+        self.faces.push(generator);
+        self.faces.len() - 1
+    }
+
+    /// Routes a range of characters to a certain face.
+    ///
+    /// Routes are checked in the order in which they were added.
+    /// Routes to faces that do not exist are ignored.
+    ///
+    /// Arguments:
+    ///
+    /// * `range`: The characters that are routed.
+    /// * `face`: The index of the face.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{FontSet, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut font_set = FontSet::new(MeshGenerator::new(font_data));
+    /// let index = font_set.add_face(MeshGenerator::new(font_data));
+    ///
+    /// // Use the second face for digits.
+    /// font_set.add_route('0'..='9', index);
+    /// assert_eq!(font_set.face_of_char('5'), index);
+    /// assert_eq!(font_set.face_of_char('a'), 0);
+    /// ```
+    pub fn add_route(&mut self, range: RangeInclusive<char>, face: usize) {
+        self.routes.push((range, face));
+    }
+
+    /// Routes all characters of a [Script] to a certain face.
+    ///
+    /// Routes of ranges take precedence over routes of scripts.
+    /// Routes to faces that do not exist are ignored.
+    ///
+    /// Arguments:
+    ///
+    /// * `script`: The [Script] that is routed.
+    /// * `face`: The index of the face.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{FontSet, MeshGenerator, Script};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut font_set = FontSet::new(MeshGenerator::new(font_data));
+    /// let index = font_set.add_face(MeshGenerator::new(font_data));
+    ///
+    /// font_set.add_script(Script::Latin, index);
+    /// assert_eq!(font_set.face_of_char('a'), index);
+    /// ```
+    pub fn add_script(&mut self, script: Script, face: usize) {
+        self.scripts.push((script, face));
+    }
+
+    /// Sets the face that is used for characters that no face supports.
+    ///
+    /// Arguments:
+    ///
+    /// * `face`: The index of the face, which is `0` (the primary face) by default.
+    ///   If there is no face with this index, the primary face is used.
+    pub fn set_fallback_face(&mut self, face: usize) {
+        self.fallback = face;
+    }
+
+    /// Removes all routes of ranges and scripts of this [FontSet].
+    pub fn clear_routes(&mut self) {
+        self.routes.clear();
+        self.scripts.clear();
+    }
+
+    /// Gets the number of faces of this [FontSet].
+    ///
+    /// Returns:
+    ///
+    /// The number of faces including the primary face.
+    pub fn face_count(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// Gets the [MeshGenerator] of a face.
+    ///
+    /// Arguments:
+    ///
+    /// * `index`: The index of the face.
+    ///
+    /// Returns:
+    ///
+    /// The [MeshGenerator] or `None` if there is no face with the given index.
+    pub fn face(&self, index: usize) -> Option<&MeshGenerator<T>> {
+        self.faces.get(index)
+    }
+
+    /// Gets the [MeshGenerator] of a face, e.g. to change its settings.
+    ///
+    /// Arguments:
+    ///
+    /// * `index`: The index of the face.
+    ///
+    /// Returns:
+    ///
+    /// The [MeshGenerator] or `None` if there is no face with the given index.
+    pub fn face_mut(&mut self, index: usize) -> Option<&mut MeshGenerator<T>> {
+        self.faces.get_mut(index)
+    }
+
+    /// Finds the face that is used for a certain character.
+    ///
+    /// Arguments:
+    ///
+    /// * `character`: The character in question.
+    ///
+    /// Returns:
+    ///
+    /// The index of the face.
+    pub fn face_of_char(&self, character: char) -> usize {
+        let script = Script::of_char(character);
+        self.routed_face(character)
+            .or_else(|| {
+                self.scripts
+                    .iter()
+                    .filter(|(s, _)| Some(*s) == script)
+                    .map(|(_, face)| *face)
+                    .find(|face| self.supports(*face, character))
+            })
+            .or_else(|| (0..self.faces.len()).find(|face| self.supports(*face, character)))
+            .unwrap_or(match self.fallback < self.faces.len() {
+                true => self.fallback,
+                false => 0,
+            })
+    }
+
+    /// Finds the face of the first route whose range contains a character.
+    ///
+    /// Arguments:
+    ///
+    /// * `character`: The character in question.
+    ///
+    /// Returns:
+    ///
+    /// The index of the face or `None` if no route with
+    /// a face that supports the character matches.
+    fn routed_face(&self, character: char) -> Option<usize> {
+        self.routes
+            .iter()
+            .filter(|(range, _)| range.contains(&character))
+            .map(|(_, face)| *face)
+            .find(|face| self.supports(*face, character))
+    }
+
+    /// Checks wether a face supports a character.
+    ///
+    /// Arguments:
+    ///
+    /// * `face`: The index of the face.
+    /// * `character`: The character in question.
+    ///
+    /// Returns:
+    ///
+    /// `true` if the face exists and has a glyph for the character.
+    fn supports(&self, face: usize, character: char) -> bool {
+        self.faces
+            .get(face)
+            .is_some_and(|generator| generator.has_glyph(character))
+    }
+
+    /// Splits a text into runs of characters that use the same face.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that is split.
+    ///
+    /// Returns:
+    ///
+    /// The index of the face and the text of each run.
+    fn runs<'a>(&self, text: &'a str) -> Vec<(usize, &'a str)> {
+        let mut runs: Vec<(usize, &'a str)> = Vec::new();
+        let mut start = 0;
+        let mut current = None;
+        for (i, c) in text.char_indices() {
+            let joins_previous = c.is_whitespace()
+                || is_combining_mark(c)
+                || is_variation_selector(c)
+                || c == ZERO_WIDTH_JOINER;
+            let face = match current {
+                Some(face) if joins_previous => face,
+                Some(face)
+                    if Script::of_char(c).is_none()
+                        && self.supports(face, c)
+                        && self.routed_face(c).is_none() =>
+                {
+                    face
+                }
+                _ => self.face_of_char(c),
+            };
+
+            if let Some(previous) = current.filter(|previous| *previous != face) {
+                runs.push((previous, &text[start..i]));
+                start = i;
+            }
+            current = Some(face);
+        }
+        if let Some(face) = current {
+            runs.push((face, &text[start..]));
+        }
+
+        runs
+    }
+
+    /// Generates the runs of a text section and merges them into a single mesh.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section that will be transformed into a mesh.
+    /// * `flat`: Wether the mesh is flat or has an extent of `1` unit in the z-axis.
+    /// * `two_dimensional`: Wether a two-dimensional mesh is generated.
+    /// * `transform`: The optional homogenous 4x4 transformation matrix that will be
+    ///   applied to each vertex of the mesh.
+    ///
+    /// Returns:
+    ///
+    /// The merged mesh or an [MeshTextError] if anything went wrong in the process.
+    fn generate_runs<I: IndexType>(
+        &mut self,
+        text: &str,
+        flat: bool,
+        two_dimensional: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<IndexedMeshText<I>, Box<dyn MeshTextError>> {
+        let mut mesh = IndexedMeshText {
+            advance: 0f32,
+            bbox: BoundingBox::default(),
+            indices: Vec::new(),
+            two_dimensional,
+            vertices: Vec::new(),
+        };
+
+        // Adjacent runs use different faces, so there is no kerning across them.
+        for (face, run) in self.runs(text) {
+            self.faces[face].append_section(&mut mesh, "", run, flat, transform)?;
+        }
+
+        Ok(mesh)
+    }
+}
+
+impl<T, I> TextSection<IndexedMeshText<I>> for FontSet<T>
+where
+    T: FontFace,
+    I: IndexType,
+{
+    fn generate_section(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<IndexedMeshText<I>, Box<dyn MeshTextError>> {
+        self.generate_runs(text, flat, false, transform)
+    }
+
+    fn generate_section_2d(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<IndexedMeshText<I>, Box<dyn MeshTextError>> {
+        let transform = transform.map(|value| {
+            let transform = Mat3::from_cols_array(value);
+            let column = |c: Vec3| Vec4::new(c.x, c.y, 0f32, c.z);
+            Mat4::from_cols(
+                column(transform.x_axis),
+                column(transform.y_axis),
+                Vec4::Z,
+                column(transform.z_axis),
+            )
+            .to_cols_array()
+        });
+
+        self.generate_runs(text, true, true, transform.as_ref())
+    }
+}
@@ -17,64 +17,337 @@
 /// while using this crate.
 pub mod error;
 
+/// Contains functions that write generated meshes to common file formats.
+#[cfg(any(feature = "3mf", feature = "usdz"))]
+pub mod export {
+    #[cfg(feature = "3mf")]
+    mod three_mf;
+    #[cfg(feature = "3mf")]
+    pub use three_mf::*;
+
+    #[cfg(feature = "usdz")]
+    mod usdz;
+    #[cfg(feature = "usdz")]
+    pub use usdz::*;
+}
+
 // Re-export the faces.
 #[cfg(not(feature = "owned"))]
 pub use ttf_parser::Face;
 #[cfg(feature = "owned")]
 pub use owned_ttf_parser::OwnedFace;
+pub use ttf_parser::{GlyphId, RasterImageFormat};
+
+mod font_set;
+pub use font_set::FontSet;
 
 mod mesh_generator;
 pub use mesh_generator::MeshGenerator;
 
 mod types {
+    mod advance_fallback;
+    pub use advance_fallback::AdvanceFallback;
+
+    mod anchor;
+    pub use anchor::Anchor;
+
+    mod bend;
+    pub use bend::Bend;
+
     mod bounding_box;
     pub use bounding_box::BoundingBox;
 
+    mod cache_policy;
+    pub use cache_policy::CachePolicy;
+
     mod cache_type;
     pub use cache_type::CacheType;
 
+    mod cached_mesh;
+    pub use cached_mesh::CachedMesh;
+
+    mod caret;
+    pub use caret::Caret;
+
+    mod coverage_mask;
+    pub use coverage_mask::CoverageMask;
+
+    mod dash_pattern;
+    pub use dash_pattern::DashPattern;
+
+    mod decoration_metrics;
+    pub use decoration_metrics::DecorationMetrics;
+
+    mod distance_field;
+    pub use distance_field::DistanceField;
+
+    mod extrusion_settings;
+    pub use extrusion_settings::ExtrusionSettings;
+
+    #[cfg(feature = "shaping")]
+    mod font_feature;
+    #[cfg(feature = "shaping")]
+    pub use font_feature::FontFeature;
+
+    #[cfg(feature = "system-fonts")]
+    mod font_query;
+    #[cfg(feature = "system-fonts")]
+    pub use font_query::FontQuery;
+
+    mod generation_stats;
+    pub use generation_stats::GenerationStats;
+
+    mod generation_warning;
+    pub use generation_warning::GenerationWarning;
+
+    mod glyph_cache;
+    pub use glyph_cache::{CachedGlyph, GlyphCache};
+
+    mod glyph_draw;
+    pub use glyph_draw::GlyphDraw;
+
+    mod glyph_draw_list;
+    pub use glyph_draw_list::GlyphDrawList;
+
+    mod glyph_image;
+    pub use glyph_image::GlyphImage;
+
+    mod glyph_key;
+    pub(crate) use glyph_key::GlyphKey;
+
+    mod glyph_node;
+    pub use glyph_node::GlyphNode;
+
     mod glyph_outline;
     pub(crate) use glyph_outline::GlyphOutline;
 
+    mod glyph_source;
+    pub use glyph_source::GlyphSource;
+
+    mod glyph_scene;
+    pub use glyph_scene::GlyphScene;
+
+    mod heightmap;
+    pub use heightmap::Heightmap;
+
     mod indexed_mesh_text;
     pub use indexed_mesh_text::*;
 
+    mod indexed_mesh_text_glam;
+    pub use indexed_mesh_text_glam::IndexedMeshTextGlam;
+
+    mod interleaved_mesh_text;
+    pub use interleaved_mesh_text::*;
+
+    mod line_metrics;
+    pub use line_metrics::LineMetrics;
+
+    mod mesh_part;
+    pub use mesh_part::MeshPart;
+
     mod mesh_text;
     pub use mesh_text::*;
 
+    mod mesh_text_glam;
+    pub use mesh_text_glam::MeshTextGlam;
+
+    mod metrics_source;
+    pub use metrics_source::MetricsSource;
+
+    mod mirror_axis;
+    pub use mirror_axis::MirrorAxis;
+
+    mod outline_key;
+    pub(crate) use outline_key::OutlineKey;
+
+    mod paragraph;
+    pub use paragraph::Paragraph;
+
+    mod partitioned_mesh_text;
+    pub use partitioned_mesh_text::*;
+
+    mod positioned_glyph;
+    pub(crate) use positioned_glyph::PositionedGlyph;
+
     mod quality_settings;
     pub use quality_settings::QualitySettings;
 
+    mod scale_reference;
+    pub use scale_reference::ScaleReference;
+
+    mod section_layout;
+    pub use section_layout::SectionLayout;
+
+    #[cfg(feature = "shaping")]
+    mod shaping_settings;
+    #[cfg(feature = "shaping")]
+    pub use shaping_settings::ShapingSettings;
+
+    mod script;
+    pub use script::Script;
+
+    mod seam;
+    pub use seam::Seam;
+
+    mod side_wall_mapping;
+    pub use side_wall_mapping::SideWallMapping;
+
+    mod tab_settings;
+    pub use tab_settings::{TabSettings, TabSize};
+
+    mod text_alignment;
+    pub use text_alignment::TextAlignment;
+
+    mod triangle_provenance;
+    pub use triangle_provenance::TriangleProvenance;
+
+    mod uv_projection;
+    pub use uv_projection::UvProjection;
+
+    mod vertex_attribute;
+    pub use vertex_attribute::*;
+
+    mod vertex_coloring;
+    pub use vertex_coloring::VertexColoring;
+
+    mod vertex_layout;
+    pub use vertex_layout::VertexLayout;
+
+    mod whitespace_mode;
+    pub use whitespace_mode::WhitespaceMode;
+
     mod traits {
         mod font_face;
         pub(crate) use font_face::*;
 
+        mod glam_vertex;
+        pub use glam_vertex::*;
+
         mod glyph;
         pub use glyph::*;
 
+        mod glyph_provider;
+        pub use glyph_provider::*;
+
+        mod index_type;
+        pub use index_type::*;
+
+        mod mesh_post_processor;
+        pub use mesh_post_processor::*;
+
+        mod outline_processor;
+        pub use outline_processor::*;
+
         mod text_section;
         pub use text_section::*;
+
+        mod triangle_mesh;
+        pub use triangle_mesh::*;
     }
     pub use traits::*;
 }
 pub use types::*;
 
 pub(crate) mod util {
+    #[cfg(any(feature = "kurbo", feature = "lyon"))]
+    mod bezier_paths;
+    #[cfg(any(feature = "kurbo", feature = "lyon"))]
+    pub(crate) use bezier_paths::*;
+
+    mod carets;
+    pub(crate) use carets::*;
+
+    mod combining_marks;
+    pub(crate) use combining_marks::*;
+
+    mod convex_hull;
+    pub(crate) use convex_hull::*;
+
+    mod coverage;
+    pub(crate) use coverage::*;
+
+    mod distance_field;
+    pub(crate) use distance_field::*;
+
+    mod fallback_mesh;
+    pub(crate) use fallback_mesh::*;
+
+    #[cfg(feature = "geo")]
+    mod geo_polygons;
+    #[cfg(feature = "geo")]
+    pub(crate) use geo_polygons::*;
+
     mod glam_conversions;
     pub(crate) use glam_conversions::*;
 
+    mod interleave;
+    pub(crate) use interleave::*;
+
+    mod kerning;
+    pub(crate) use kerning::*;
+
+    mod lightmap;
+    pub(crate) use lightmap::*;
+
+    #[cfg(not(feature = "shaping"))]
+    mod ligatures;
+    #[cfg(not(feature = "shaping"))]
+    pub(crate) use ligatures::*;
+
+    mod line_breaking;
+    pub(crate) use line_breaking::*;
+
+    mod lru_cache;
+    pub(crate) use lru_cache::LruCache;
+
     mod mesh_to_flat_2d;
     pub(crate) use mesh_to_flat_2d::*;
 
+    mod monospace;
+    pub(crate) use monospace::*;
+
     mod outline_builder;
     pub(crate) use outline_builder::GlyphOutlineBuilder;
 
+    mod outline_repair;
+    pub(crate) use outline_repair::*;
+
+    mod partition;
+    pub(crate) use partition::*;
+
+    mod polyline;
+    pub(crate) use polyline::*;
+
+    mod projection;
+    pub(crate) use projection::*;
+
     mod raster_to_mesh;
     pub(crate) use raster_to_mesh::*;
 
+    #[cfg(feature = "shaping")]
+    mod shaping;
+    #[cfg(feature = "shaping")]
+    pub(crate) use shaping::*;
+
+    mod stroke;
+    pub(crate) use stroke::*;
+
+    #[cfg(feature = "system-fonts")]
+    mod system_fonts;
+    #[cfg(feature = "system-fonts")]
+    pub(crate) use system_fonts::*;
+
     mod text_mesh;
     pub(crate) use text_mesh::*;
 
     mod triangulation;
     pub(crate) use triangulation::*;
+
+    mod vertical_metrics;
+    pub(crate) use vertical_metrics::*;
+
+    #[cfg(any(feature = "3mf", feature = "usdz"))]
+    mod zip;
+    #[cfg(any(feature = "3mf", feature = "usdz"))]
+    pub(crate) use zip::*;
 }
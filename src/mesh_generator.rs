@@ -1,69 +1,298 @@
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    ops::Range,
+    time::{Duration, Instant},
+};
 
-use glam::{Mat3, Mat4, Vec2, Vec3, Vec3A};
-use ttf_parser::GlyphId;
+use glam::{Mat3, Mat4, Vec2, Vec3, Vec3A, Vec4};
+use ttf_parser::{GlyphId, OutlineBuilder};
 
 use crate::{
-    error::MeshTextError,
+    error::{IndexOverflowError, InvalidPathError, MeshTextError, VertexDimensionError},
     util::{
-        mesh_to_flat_2d, mesh_to_indexed_flat_2d, raster_to_mesh, raster_to_mesh_indexed,
-        text_mesh_from_data, text_mesh_from_data_2d, text_mesh_from_data_indexed,
-        text_mesh_from_data_indexed_2d, GlyphOutlineBuilder,
+        apply_whitespace_mode, caret_positions, classify_triangle, convert_fallback_mesh,
+        convex_hull_2d, extrude_convex_hull, glam_vecs_to_raw, glam_vecs_to_raw_2d,
+        interleaved_mesh_from_data, interleaved_mesh_from_data_2d, is_collapsible_whitespace,
+        mesh_to_flat_2d, mesh_to_indexed_flat_2d, multi_channel_distance_field,
+        partitioned_mesh_from_data, partitioned_mesh_from_data_2d, polyline_frames,
+        polyline_transform, quantize_points, quantize_points_2d, raster_to_mesh,
+        raster_to_mesh_indexed, raw_to_glam_vecs, remove_degenerate_triangles,
+        remove_degenerate_triangles_indexed, repair_outline, stroke_contours, text_mesh_from_data,
+        text_mesh_from_data_indexed, text_mesh_from_data_indexed_2d, transform_points,
+        transform_points_2d, transform_vertices, wrap_line, FromIndexedMeshData, FromMeshData,
+        GlyphOutlineBuilder, LruCache, TriangulationScratch,
     },
-    BoundingBox, CacheType, FontFace, Glyph, IndexedMeshText, MeshText, QualitySettings,
-    TextSection,
+    AdvanceFallback, Bend, BoundingBox, CachePolicy, CacheType, CachedGlyph, CachedMesh, Caret,
+    DashPattern, DecorationMetrics, DistanceField, ExtrusionSettings, FontFace, GenerationStats,
+    GenerationWarning, GlamVertex, Glyph, GlyphCache, GlyphDraw, GlyphDrawList, GlyphImage,
+    GlyphKey, GlyphNode, GlyphOutline, GlyphProvider, GlyphScene, Heightmap, IndexType,
+    IndexedMeshText, IndexedMeshTextGlam, InterleavedMeshText, LineMetrics, MeshPostProcessor,
+    MeshText, MeshTextGlam, MetricsSource, OutlineKey, OutlineProcessor, Paragraph,
+    PartitionedMeshText, PositionedGlyph, QualitySettings, ScaleReference, SectionLayout,
+    TabSettings, TabSize, TextAlignment, TextSection, TriangleProvenance, VertexLayout,
+    WhitespaceMode,
 };
 
+#[cfg(feature = "geo")]
+use crate::util::contours_to_polygons;
+#[cfg(feature = "hyphenation")]
+use crate::util::dictionary_hyphenation_points;
+#[cfg(feature = "kurbo")]
+use crate::util::BezPathBuilder;
+#[cfg(feature = "lyon")]
+use crate::util::LyonPathBuilder;
+#[cfg(not(feature = "shaping"))]
+use crate::util::{is_combining_mark, is_variation_selector, SOFT_HYPHEN, ZERO_WIDTH_JOINER};
+#[cfg(feature = "shaping")]
+use crate::{util::shape_text, ShapingSettings};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "rayon")]
+use std::collections::HashSet;
+
 type Mesh = (Vec<Vec3A>, BoundingBox);
 type Mesh2D = (Vec<Vec2>, BoundingBox);
 
-type IndexedMesh = (Vec<u32>, Vec<Vec3A>, BoundingBox);
-type IndexedMesh2D = (Vec<u32>, Vec<Vec2>, BoundingBox);
+/// The indices, vertices and bounding box of a mesh, whose
+/// indices are [u32] unless another [IndexType] is requested.
+type IndexedMesh<I = u32> = (Vec<I>, Vec<Vec3A>, BoundingBox);
+type IndexedMesh2D<I = u32> = (Vec<I>, Vec<Vec2>, BoundingBox);
+
+/// The mesh of a text section together with the range of vertices of each glyph.
+type SectionMesh = (IndexedMesh, Vec<Range<usize>>);
+
+/// The two-dimensional mesh of a text section together with the range of vertices of each glyph.
+type SectionMesh2D = (IndexedMesh2D, Vec<Range<usize>>);
+
+type GlyphPoints = (Vec<Vec2>, (f32, f32), f32);
+
+/// Computes the extrusion depth of a character from its index and the character itself.
+type DepthCallback = Box<dyn Fn(usize, char) -> f32 + Send + Sync>;
+
+/// Computes the displacement of the front cap at a point of the untransformed text.
+type DisplacementCallback = Box<dyn Fn(f32, f32) -> f32 + Send + Sync>;
+
+/// Reads the current time, which decides when cached glyphs expire.
+type ClockCallback = Box<dyn Fn() -> Instant + Send + Sync>;
+
+/// The cached glyph, wether it is flat and the quality it was generated with.
+type CacheKey = (GlyphKey, bool, QualitySettings);
+
+/// The text of a generated indexed section, wether it is flat and two-dimensional
+/// and the bits of its transformation.
+type SectionKey = (String, bool, bool, [u32; 16]);
+
+/// The end of the indices and vertices of a glyph within the buffers of its section
+/// together with its bounding box, which is `None` if the glyph has no vertices.
+type GlyphSpan = (usize, usize, Option<BoundingBox>);
+
+/// The bounding box of a generated indexed section together with the [GlyphSpan]s of its glyphs.
+type SectionRecord = (BoundingBox, Vec<GlyphSpan>);
+
+/// The vertices and indices of a triangulated outline.
+type IndexedTriangulation = (Vec<Vec3A>, Vec<u32>);
+
+/// The content of a triangulated outline together with its triangulation.
+type CachedTriangulation<M> = (Box<[u32]>, M);
+
+/// An uncached glyph together with its outline, center and bounding box, the number of
+/// discarded degenerate and non-finite contours and the duration of outlining it.
+#[cfg(feature = "rayon")]
+type PreparedGlyph = (
+    GlyphKey,
+    Option<(GlyphOutline, Vec2, BoundingBox)>,
+    (usize, usize),
+    Duration,
+);
+
+/// Triangulates a glyph outline into a mesh of the given type.
+#[cfg(feature = "rayon")]
+type Triangulator<M> = fn(
+    &GlyphOutline,
+    bool,
+    &ExtrusionSettings,
+    Vec2,
+    &mut TriangulationScratch,
+) -> Result<M, Box<dyn MeshTextError>>;
+
+/// The character that is inserted when a word is broken across two lines.
+const HYPHEN: char = '-';
+
+/// Line heights below this fraction of the units per em are considered broken.
+const MIN_HEIGHT_RATIO: f32 = 0.1;
+
+/// The number of generated sections whose glyphs can be reused when they are updated.
+const SECTION_RECORDS: usize = 16;
 
 /// A [MeshGenerator] handles rasterizing individual glyphs.
 ///
 /// Each [MeshGenerator] will handle exactly one font. This means
 /// if you need support for multiple fonts, you will need to create
-/// multiple instances (one per font) of this generator.
+/// multiple instances (one per font) of this generator, which can
+/// be combined in a [crate::FontSet].
 pub struct MeshGenerator<T>
 where
     T: FontFace,
 {
-    /// Cached non-indexed glyphs are stored in this [HashMap].
+    /// Controls the advance of glyphs without a horizontal advance.
+    pub(super) advance_fallback: AdvanceFallback,
+
+    /// Cached non-indexed glyphs are stored in this [LruCache].
     ///
-    /// The key is the character itself, however because each
-    /// character can have a 2D and a 3D variant, in the 3D
-    /// variant each character is prefixed with an `_`.
+    /// The key is the id of the glyph, however because each
+    /// glyph can have a 2D and a 3D variant, the key also
+    /// contains wether the glyph is flat. Glyphs generated with
+    /// different [QualitySettings] are kept apart as well.
     #[allow(unused)]
-    pub(super) cache: HashMap<String, Mesh>,
+    pub(super) cache: LruCache<CacheKey, Mesh>,
+
+    /// Reads the time at which cached glyphs are used,
+    /// which is [Instant::now] if there is no clock.
+    pub(super) cache_clock: Option<ClockCallback>,
+
+    /// The [CachePolicy] of the cache that handles non-indexed meshes.
+    pub(super) cache_policy: CachePolicy,
+
+    /// The duration after which glyphs that have not been used
+    /// are evicted from the internal caches.
+    pub(super) cache_ttl: Option<Duration>,
+
+    /// Controls wether contours that the font does not close
+    /// are closed by connecting their last point to the first one.
+    pub(super) close_contours: bool,
+
+    /// The mesh and advance that replace characters missing from the font.
+    pub(super) fallback_mesh: Option<(IndexedMesh, bool, f32)>,
 
     /// The current [FontFace].
     pub(super) font: T,
 
-    /// Cached indexed glyphs are stored in this [HashMap].
+    /// The [GlyphProvider] that is consulted before the font.
+    pub(super) glyph_provider: Option<Box<dyn GlyphProvider>>,
+
+    /// Cached indexed glyphs are stored in this [LruCache].
     ///
-    /// The key is the character itself, however because each
-    /// character can have a 2D and a 3D variant, in the 3D
-    /// variant each character is prefixed with an `_`.
+    /// The key is the id of the glyph, however because each
+    /// glyph can have a 2D and a 3D variant, the key also
+    /// contains wether the glyph is flat. Glyphs generated with
+    /// different [QualitySettings] are kept apart as well.
     #[allow(unused)]
-    pub(super) indexed_cache: HashMap<String, IndexedMesh>,
+    pub(super) indexed_cache: LruCache<CacheKey, IndexedMesh>,
+
+    /// The [CachePolicy] of the cache that handles indexed meshes.
+    pub(super) indexed_cache_policy: CachePolicy,
+
+    /// The indexed triangulations of glyph outlines, which are shared by
+    /// all glyphs with identical outlines (e.g. repeated components).
+    /// They are stored under the hash of their [OutlineKey] next to the content of the outline.
+    pub(super) indexed_outline_cache: LruCache<u64, CachedTriangulation<IndexedTriangulation>>,
+
+    /// The maximum length of a contour segment in font units,
+    /// above which segments are split evenly.
+    pub(super) max_segment_length: u32,
+
+    /// The table from which the vertical metrics of the font are read.
+    pub(super) metrics_source: MetricsSource,
+
+    /// The minimum area of a contour in square font units,
+    /// below which contours are dropped before triangulation.
+    pub(super) min_contour_area: u32,
+
+    /// The builder that receives the outline of each freshly generated glyph,
+    /// whose buffers are reused between glyphs.
+    pub(super) outline_builder: GlyphOutlineBuilder,
+
+    /// The non-indexed triangulations of glyph outlines, which are shared by
+    /// all glyphs with identical outlines (e.g. repeated components).
+    /// They are stored under the hash of their [OutlineKey] next to the content of the outline.
+    pub(super) outline_cache: LruCache<u64, CachedTriangulation<Vec<Vec3A>>>,
+
+    /// The [MeshPostProcessor]s applied to each freshly generated glyph.
+    pub(super) post_processors: Vec<Box<dyn MeshPostProcessor>>,
+
+    /// The processors that adjust glyph outlines before triangulation.
+    pub(super) outline_processors: Vec<Box<dyn OutlineProcessor>>,
 
     /// Quality settings for generating the text meshes.
     pub(super) quality: QualitySettings,
 
-    /// Controls wether the generator will automatically
-    /// cache glyphs.
-    #[allow(unused)]
-    pub(super) use_cache: bool,
+    /// The recently generated indexed sections, which locate the glyphs that are
+    /// reused when a section is updated. They are removed whenever the meshes
+    /// of the glyphs change, so that outdated glyphs are never reused.
+    pub(super) section_records: LruCache<SectionKey, SectionRecord>,
+
+    /// The spacing of the grid to which the output vertices are snapped
+    /// relative to the size of one em.
+    pub(super) quantization: Option<f32>,
+
+    /// The statistics of the generated glyphs, which
+    /// are only collected if this is not `None`.
+    pub(super) stats: Option<GenerationStats>,
+
+    /// The layout of a text section that is used by the next mesh
+    /// generated for exactly this text, so that it is not laid out twice.
+    pub(super) prepared_layout: Option<(String, Vec<PositionedGlyph>)>,
+
+    /// The font prepared for shaping, which is kept between text
+    /// sections if the font data outlives the generator.
+    #[cfg(feature = "shaping")]
+    pub(super) shaper: Option<rustybuzz::Face<'static>>,
+
+    /// Controls how text sections are shaped.
+    #[cfg(feature = "shaping")]
+    pub(super) shaping: ShapingSettings,
+
+    /// Wraps text sections around a curved surface.
+    pub(super) bend: Option<Bend>,
+
+    /// Computes the extrusion depth of each character of a text section.
+    pub(super) depth_for: Option<DepthCallback>,
+
+    /// The extrusion depth at the start and at the end of each text section.
+    pub(super) depth_gradient: Option<(f32, f32)>,
+
+    /// Offsets the front cap of text sections along the z-axis.
+    pub(super) displacement: Option<DisplacementCallback>,
+
+    /// Controls the shape of three-dimensional glyphs.
+    pub(super) extrusion: ExtrusionSettings,
+
+    /// Controls where tabs move the following text.
+    pub(super) tabs: TabSettings,
+
+    /// The buffers used while triangulating glyph outlines,
+    /// which are reused between glyphs.
+    pub(super) triangulation_scratch: TriangulationScratch,
+
+    /// Controls wether glyph outlines are repaired, so that
+    /// three-dimensional glyphs form closed solids.
+    pub(super) watertight: bool,
+
+    /// The problems of the font that were worked around since
+    /// the warnings were taken the last time.
+    pub(super) warnings: Vec<GenerationWarning>,
+
+    /// The layout of the vertices of an [InterleavedMeshText].
+    pub(super) vertex_layout: VertexLayout,
 }
 
 #[cfg(not(feature = "owned"))]
 mod borrowed_mesh_generator {
-    use std::collections::HashMap;
-
     use ttf_parser::GlyphId;
 
-    use crate::{FontFace, MeshGenerator, QualitySettings};
+    #[cfg(not(feature = "shaping"))]
+    use crate::util::find_ligature;
+    #[cfg(feature = "shaping")]
+    use crate::ShapingSettings;
+    use crate::{
+        util::{
+            average_advance_of_face, find_kerning, is_monospaced_face, vertical_metrics_of_face,
+            GlyphOutlineBuilder, LruCache, TriangulationScratch,
+        },
+        AdvanceFallback, CachePolicy, ExtrusionSettings, FontFace, MeshGenerator, MetricsSource,
+        QualitySettings, TabSettings, VertexLayout,
+    };
 
     impl FontFace for ttf_parser::Face<'_> {
         /// Computes glyph's horizontal advance.
@@ -77,6 +306,24 @@ mod borrowed_mesh_generator {
             ttf_parser::Face::glyph_hor_advance(self, glyph_id)
         }
 
+        /// Gets the average horizontal advance of all glyphs.
+        ///
+        /// Returns:
+        ///
+        /// The average advance or `None` if the font does not specify it.
+        fn average_advance(&self) -> Option<u16> {
+            average_advance_of_face(self)
+        }
+
+        /// Gets a glyph's tight bounding box without outlining it.
+        ///
+        /// Returns:
+        ///
+        /// The bounding box or `None` when the glyph has no outline.
+        fn glyph_bounding_box(&self, glyph_id: GlyphId) -> Option<ttf_parser::Rect> {
+            ttf_parser::Face::glyph_bounding_box(self, glyph_id)
+        }
+
         /// Resolves a Glyph ID for a code point.
         ///
         /// All subtable formats except Mixed Coverage (8) are supported.
@@ -90,6 +337,45 @@ mod borrowed_mesh_generator {
             ttf_parser::Face::glyph_index(self, code_point)
         }
 
+        /// Resolves a Glyph ID for a glyph name.
+        ///
+        /// Uses the `post` and `CFF` tables as sources.
+        ///
+        /// Returns:
+        ///
+        /// The [GlyphId] or `None` when no glyph has the given name.
+        fn glyph_index_by_name(&self, name: &str) -> Option<GlyphId> {
+            ttf_parser::Face::glyph_index_by_name(self, name)
+        }
+
+        /// Resolves a variation of a Glyph ID from two code points.
+        ///
+        /// Uses the format 14 subtable of the `cmap` table.
+        ///
+        /// Returns:
+        ///
+        /// The [GlyphId] or `None` when the font has no such variation.
+        fn glyph_variation_index(&self, code_point: char, variation: char) -> Option<GlyphId> {
+            ttf_parser::Face::glyph_variation_index(self, code_point, variation)
+        }
+
+        /// Finds the longest ligature that starts a sequence of glyphs.
+        ///
+        /// Uses the ligature substitutions of the `GSUB` table.
+        ///
+        /// Arguments:
+        ///
+        /// * `glyphs`: The glyphs of the sequence and wether each glyph is a joiner,
+        ///   which may be skipped if the ligature does not list it as a component.
+        ///
+        /// Returns:
+        ///
+        /// The ligature and the number of glyphs it replaces or `None` if no ligature matches.
+        #[cfg(not(feature = "shaping"))]
+        fn ligature(&self, glyphs: &[(GlyphId, bool)]) -> Option<(GlyphId, usize)> {
+            find_ligature(self, glyphs)
+        }
+
         /// Computes the face's height.
         ///
         /// This method is affected by variation axes.
@@ -101,6 +387,223 @@ mod borrowed_mesh_generator {
             ttf_parser::Face::height(self)
         }
 
+        /// Gets the number of font units per em.
+        ///
+        /// Returns:
+        ///
+        /// The units per em, which are guaranteed to be in the range `16..=16384`.
+        fn units_per_em(&self) -> u16 {
+            ttf_parser::Face::units_per_em(self)
+        }
+
+        /// Gets the face's horizontal ascender.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The distance from the baseline to the top of the line.
+        fn ascender(&self) -> i16 {
+            ttf_parser::Face::ascender(self)
+        }
+
+        /// Gets the face's horizontal descender.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The distance from the baseline to the bottom of the line, which is usually negative.
+        fn descender(&self) -> i16 {
+            ttf_parser::Face::descender(self)
+        }
+
+        /// Gets the face's horizontal line gap.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The additional space between two lines.
+        fn line_gap(&self) -> i16 {
+            ttf_parser::Face::line_gap(self)
+        }
+
+        /// Gets the face's vertical ascender.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The vertical ascender or `None` if the font has no `vhea` table.
+        fn vertical_ascender(&self) -> Option<i16> {
+            ttf_parser::Face::vertical_ascender(self)
+        }
+
+        /// Gets the face's vertical descender.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The vertical descender or `None` if the font has no `vhea` table.
+        fn vertical_descender(&self) -> Option<i16> {
+            ttf_parser::Face::vertical_descender(self)
+        }
+
+        /// Gets the face's vertical line gap.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The vertical line gap or `None` if the font has no `vhea` table.
+        fn vertical_line_gap(&self) -> Option<i16> {
+            ttf_parser::Face::vertical_line_gap(self)
+        }
+
+        /// Computes glyph's vertical advance.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The vertical advance of the glyph or `None` if the font has no vertical metrics.
+        fn glyph_ver_advance(&self, glyph_id: GlyphId) -> Option<u16> {
+            ttf_parser::Face::glyph_ver_advance(self, glyph_id)
+        }
+
+        /// Computes glyph's vertical side bearing.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The top side bearing of the glyph or `None` if the font has no vertical metrics.
+        fn glyph_ver_side_bearing(&self, glyph_id: GlyphId) -> Option<i16> {
+            ttf_parser::Face::glyph_ver_side_bearing(self, glyph_id)
+        }
+
+        /// Gets the y-coordinate of the glyph's vertical origin.
+        ///
+        /// Only fonts with a `VORG` table (usually CFF fonts) specify the vertical origin.
+        ///
+        /// Returns:
+        ///
+        /// The vertical origin of the glyph or `None` if it is not specified.
+        fn vertical_origin(&self, glyph_id: GlyphId) -> Option<i16> {
+            ttf_parser::Face::glyph_y_origin(self, glyph_id)
+        }
+
+        /// Gets the italic angle of the face.
+        ///
+        /// The angle is given in counter-clockwise degrees from the vertical,
+        /// which means it is negative for glyphs leaning to the right.
+        ///
+        /// Returns:
+        ///
+        /// The italic angle or `None` if the font has no `post` table.
+        fn italic_angle(&self) -> Option<f32> {
+            ttf_parser::Face::italic_angle(self)
+        }
+
+        /// Gets the underline metrics of the face.
+        ///
+        /// Returns:
+        ///
+        /// The position and thickness of the underline or
+        /// `None` if the font has no `post` table.
+        fn underline_metrics(&self) -> Option<ttf_parser::LineMetrics> {
+            ttf_parser::Face::underline_metrics(self)
+        }
+
+        /// Gets the kerning between two glyphs.
+        ///
+        /// Arguments:
+        ///
+        /// * `left`: The glyph on the left side.
+        /// * `right`: The glyph on the right side.
+        ///
+        /// Returns:
+        ///
+        /// The adjustment of the advance of the left glyph
+        /// or `None` if the font has no kerning for this pair.
+        fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i16> {
+            find_kerning(self, left, right)
+        }
+
+        /// Gets the raster image of a glyph, e.g. of a color emoji.
+        ///
+        /// Arguments:
+        ///
+        /// * `glyph_id`: The id of the glyph.
+        /// * `pixels_per_em`: The preferred size of the image.
+        ///
+        /// Returns:
+        ///
+        /// The encoded image of the closest strike or `None` if the glyph has no raster image.
+        fn raster_image(
+            &self,
+            glyph_id: GlyphId,
+            pixels_per_em: u16,
+        ) -> Option<ttf_parser::RasterGlyphImage<'_>> {
+            ttf_parser::Face::glyph_raster_image(self, glyph_id, pixels_per_em)
+        }
+
+        /// Gets an entry of the `name` table of the face.
+        ///
+        /// Only entries with a Unicode encoding are considered.
+        ///
+        /// Arguments:
+        ///
+        /// * `name_id`: The ID of the name, e.g. [ttf_parser::name_id::FAMILY].
+        ///
+        /// Returns:
+        ///
+        /// The name or `None` if the font has no such entry.
+        fn name(&self, name_id: u16) -> Option<String> {
+            ttf_parser::Face::names(self)
+                .into_iter()
+                .filter(|n| n.name_id == name_id)
+                .find_map(|n| n.to_string())
+        }
+
+        /// Checks wether all glyphs of the face have the same advance.
+        ///
+        /// Uses the `isFixedPitch` flag of the `post` table and the
+        /// PANOSE classification of the `OS/2` table.
+        ///
+        /// Returns:
+        ///
+        /// `true` if the face is monospaced.
+        fn is_monospaced(&self) -> bool {
+            is_monospaced_face(self)
+        }
+
+        /// Reads the vertical metrics of the face from the given source.
+        ///
+        /// Arguments:
+        ///
+        /// * `source`: The table from which the metrics are read.
+        ///
+        /// Returns:
+        ///
+        /// The ascender, descender and line gap or `None`
+        /// if the face does not contain the required table.
+        fn vertical_metrics(&self, source: MetricsSource) -> Option<(i16, i16, i16)> {
+            vertical_metrics_of_face(self, source)
+        }
+
+        /// Gets the underlying [ttf_parser::Face].
+        ///
+        /// Returns:
+        ///
+        /// The parsed font that is used for shaping.
+        #[cfg(feature = "shaping")]
+        fn as_face(&self) -> &ttf_parser::Face<'_> {
+            self
+        }
+
         /// Outlines a glyph and returns its tight bounding box.
         ///
         /// **Warning**: since `ttf-parser` is a pull parser,
@@ -171,13 +674,63 @@ mod borrowed_mesh_generator {
         pub fn new(font: &'static [u8]) -> Self {
             let face =
                 ttf_parser::Face::parse(font, 0).expect("Failed to generate font from data.");
+            Self::with_face(face, QualitySettings::default(), CachePolicy::Unlimited)
+        }
+
+        /// Creates a new [MeshGenerator] for an already parsed font.
+        ///
+        /// Arguments:
+        ///
+        /// * `face`: The face of the font that will be used for rasterizing.
+        /// * `quality`: The [QualitySettings] that should be used.
+        /// * `policy`: The [CachePolicy] of the glyph caches.
+        fn with_face(
+            face: ttf_parser::Face<'static>,
+            quality: QualitySettings,
+            policy: CachePolicy,
+        ) -> Self {
+            #[cfg(feature = "shaping")]
+            let shaper = Some(rustybuzz::Face::from_face(face.clone()));
 
             Self {
-                cache: HashMap::new(),
+                advance_fallback: AdvanceFallback::default(),
+                cache: LruCache::new(),
+                cache_clock: None,
+                cache_policy: policy,
+                cache_ttl: None,
+                close_contours: true,
+                bend: None,
+                depth_for: None,
+                depth_gradient: None,
+                displacement: None,
+                extrusion: ExtrusionSettings::default(),
+                fallback_mesh: None,
                 font: face,
-                indexed_cache: HashMap::new(),
-                quality: QualitySettings::default(),
-                use_cache: true,
+                glyph_provider: None,
+                indexed_cache: LruCache::new(),
+                indexed_cache_policy: policy,
+                indexed_outline_cache: LruCache::new(),
+                max_segment_length: 0,
+                metrics_source: MetricsSource::default(),
+                min_contour_area: 0,
+                outline_builder: GlyphOutlineBuilder::default(),
+                outline_cache: LruCache::new(),
+                post_processors: Vec::new(),
+                prepared_layout: None,
+                outline_processors: Vec::new(),
+                quality,
+                quantization: None,
+                section_records: LruCache::new(),
+                stats: None,
+                #[cfg(feature = "shaping")]
+                shaper,
+                #[cfg(feature = "shaping")]
+                shaping: ShapingSettings::default(),
+                tabs: TabSettings::default(),
+                triangulation_scratch: TriangulationScratch::default(),
+                vertex_layout: VertexLayout::default(),
+                warnings: Vec::new(),
+                watertight: false,
             }
         }
 
@@ -190,14 +743,7 @@ mod borrowed_mesh_generator {
         pub fn new_with_quality(font: &'static [u8], quality: QualitySettings) -> Self {
             let face =
                 ttf_parser::Face::parse(font, 0).expect("Failed to generate font from data.");
-
-            Self {
-                cache: HashMap::new(),
-                font: face,
-                indexed_cache: HashMap::new(),
-                quality,
-                use_cache: true,
-            }
+            Self::with_face(face, quality, CachePolicy::Unlimited)
         }
 
         /// Creates a new [MeshGenerator] with custom quality settings and no caching.
@@ -209,22 +755,27 @@ mod borrowed_mesh_generator {
         pub fn new_without_cache(font: &'static [u8], quality: QualitySettings) -> Self {
             let face =
                 ttf_parser::Face::parse(font, 0).expect("Failed to generate font from data.");
-
-            Self {
-                cache: HashMap::new(),
-                font: face,
-                indexed_cache: HashMap::new(),
-                quality,
-                use_cache: false,
-            }
+            Self::with_face(face, quality, CachePolicy::Disabled)
         }
     }
 }
 
 #[cfg(feature = "owned")]
 mod owned_mesh_generator {
-    use crate::{FontFace, MeshGenerator, QualitySettings};
-    use std::collections::HashMap;
+    #[cfg(not(feature = "shaping"))]
+    use crate::util::find_ligature;
+    #[cfg(feature = "shaping")]
+    use crate::ShapingSettings;
+    #[cfg(feature = "system-fonts")]
+    use crate::{util::load_system_font, FontQuery};
+    use crate::{
+        util::{
+            average_advance_of_face, find_kerning, is_monospaced_face, vertical_metrics_of_face,
+            GlyphOutlineBuilder, LruCache, TriangulationScratch,
+        },
+        AdvanceFallback, CachePolicy, ExtrusionSettings, FontFace, MeshGenerator, MetricsSource,
+        QualitySettings, TabSettings, VertexLayout,
+    };
 
     use owned_ttf_parser::{AsFaceRef, OwnedFace};
 
@@ -240,6 +791,27 @@ mod owned_mesh_generator {
             self.as_face_ref().glyph_hor_advance(glyph_id)
         }
 
+        /// Gets the average horizontal advance of all glyphs.
+        ///
+        /// Returns:
+        ///
+        /// The average advance or `None` if the font does not specify it.
+        fn average_advance(&self) -> Option<u16> {
+            average_advance_of_face(self.as_face_ref())
+        }
+
+        /// Gets a glyph's tight bounding box without outlining it.
+        ///
+        /// Returns:
+        ///
+        /// The bounding box or `None` when the glyph has no outline.
+        fn glyph_bounding_box(
+            &self,
+            glyph_id: owned_ttf_parser::GlyphId,
+        ) -> Option<owned_ttf_parser::Rect> {
+            self.as_face_ref().glyph_bounding_box(glyph_id)
+        }
+
         /// Resolves a Glyph ID for a code point.
         ///
         /// All subtable formats except Mixed Coverage (8) are supported.
@@ -253,32 +825,302 @@ mod owned_mesh_generator {
             self.as_face_ref().glyph_index(code_point)
         }
 
-        /// Computes the face's height.
+        /// Resolves a Glyph ID for a glyph name.
         ///
-        /// This method is affected by variation axes.
+        /// Uses the `post` and `CFF` tables as sources.
         ///
         /// Returns:
         ///
-        /// The line height.
-        fn height(&self) -> i16 {
-            self.as_face_ref().height()
+        /// The [GlyphId] or `None` when no glyph has the given name.
+        fn glyph_index_by_name(&self, name: &str) -> Option<owned_ttf_parser::GlyphId> {
+            self.as_face_ref().glyph_index_by_name(name)
         }
 
-        /// Outlines a glyph and returns its tight bounding box.
+        /// Resolves a variation of a Glyph ID from two code points.
         ///
-        /// **Warning**: since `ttf-parser` is a pull parser,
-        /// `OutlineBuilder` will emit segments even when outline is partially malformed.
-        /// You must check `outline_glyph()` result before using
-        /// `OutlineBuilder`'s output.
+        /// Uses the format 14 subtable of the `cmap` table.
         ///
-        /// `gvar`, `glyf`, `CFF` and `CFF2` tables are supported.
-        /// And they will be accesses in this specific order.
+        /// Returns:
         ///
-        /// This method is affected by variation axes.
+        /// The [GlyphId] or `None` when the font has no such variation.
+        fn glyph_variation_index(
+            &self,
+            code_point: char,
+            variation: char,
+        ) -> Option<owned_ttf_parser::GlyphId> {
+            self.as_face_ref()
+                .glyph_variation_index(code_point, variation)
+        }
+
+        /// Finds the longest ligature that starts a sequence of glyphs.
         ///
-        /// Returns `None` when glyph has no outline or on error.
+        /// Uses the ligature substitutions of the `GSUB` table.
         ///
-        /// # Example
+        /// Arguments:
+        ///
+        /// * `glyphs`: The glyphs of the sequence and wether each glyph is a joiner,
+        ///   which may be skipped if the ligature does not list it as a component.
+        ///
+        /// Returns:
+        ///
+        /// The ligature and the number of glyphs it replaces or `None` if no ligature matches.
+        #[cfg(not(feature = "shaping"))]
+        fn ligature(
+            &self,
+            glyphs: &[(owned_ttf_parser::GlyphId, bool)],
+        ) -> Option<(owned_ttf_parser::GlyphId, usize)> {
+            find_ligature(self.as_face_ref(), glyphs)
+        }
+
+        /// Computes the face's height.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The line height.
+        fn height(&self) -> i16 {
+            self.as_face_ref().height()
+        }
+
+        /// Gets the number of font units per em.
+        ///
+        /// Returns:
+        ///
+        /// The units per em, which are guaranteed to be in the range `16..=16384`.
+        fn units_per_em(&self) -> u16 {
+            self.as_face_ref().units_per_em()
+        }
+
+        /// Gets the face's horizontal ascender.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The distance from the baseline to the top of the line.
+        fn ascender(&self) -> i16 {
+            self.as_face_ref().ascender()
+        }
+
+        /// Gets the face's horizontal descender.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The distance from the baseline to the bottom of the line, which is usually negative.
+        fn descender(&self) -> i16 {
+            self.as_face_ref().descender()
+        }
+
+        /// Gets the face's horizontal line gap.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The additional space between two lines.
+        fn line_gap(&self) -> i16 {
+            self.as_face_ref().line_gap()
+        }
+
+        /// Gets the face's vertical ascender.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The vertical ascender or `None` if the font has no `vhea` table.
+        fn vertical_ascender(&self) -> Option<i16> {
+            self.as_face_ref().vertical_ascender()
+        }
+
+        /// Gets the face's vertical descender.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The vertical descender or `None` if the font has no `vhea` table.
+        fn vertical_descender(&self) -> Option<i16> {
+            self.as_face_ref().vertical_descender()
+        }
+
+        /// Gets the face's vertical line gap.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The vertical line gap or `None` if the font has no `vhea` table.
+        fn vertical_line_gap(&self) -> Option<i16> {
+            self.as_face_ref().vertical_line_gap()
+        }
+
+        /// Computes glyph's vertical advance.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The vertical advance of the glyph or `None` if the font has no vertical metrics.
+        fn glyph_ver_advance(&self, glyph_id: owned_ttf_parser::GlyphId) -> Option<u16> {
+            self.as_face_ref().glyph_ver_advance(glyph_id)
+        }
+
+        /// Computes glyph's vertical side bearing.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns:
+        ///
+        /// The top side bearing of the glyph or `None` if the font has no vertical metrics.
+        fn glyph_ver_side_bearing(&self, glyph_id: owned_ttf_parser::GlyphId) -> Option<i16> {
+            self.as_face_ref().glyph_ver_side_bearing(glyph_id)
+        }
+
+        /// Gets the y-coordinate of the glyph's vertical origin.
+        ///
+        /// Only fonts with a `VORG` table (usually CFF fonts) specify the vertical origin.
+        ///
+        /// Returns:
+        ///
+        /// The vertical origin of the glyph or `None` if it is not specified.
+        fn vertical_origin(&self, glyph_id: owned_ttf_parser::GlyphId) -> Option<i16> {
+            self.as_face_ref().glyph_y_origin(glyph_id)
+        }
+
+        /// Gets the italic angle of the face.
+        ///
+        /// The angle is given in counter-clockwise degrees from the vertical,
+        /// which means it is negative for glyphs leaning to the right.
+        ///
+        /// Returns:
+        ///
+        /// The italic angle or `None` if the font has no `post` table.
+        fn italic_angle(&self) -> Option<f32> {
+            self.as_face_ref().italic_angle()
+        }
+
+        /// Gets the underline metrics of the face.
+        ///
+        /// Returns:
+        ///
+        /// The position and thickness of the underline or
+        /// `None` if the font has no `post` table.
+        fn underline_metrics(&self) -> Option<owned_ttf_parser::LineMetrics> {
+            self.as_face_ref().underline_metrics()
+        }
+
+        /// Gets the kerning between two glyphs.
+        ///
+        /// Arguments:
+        ///
+        /// * `left`: The glyph on the left side.
+        /// * `right`: The glyph on the right side.
+        ///
+        /// Returns:
+        ///
+        /// The adjustment of the advance of the left glyph
+        /// or `None` if the font has no kerning for this pair.
+        fn kerning(
+            &self,
+            left: owned_ttf_parser::GlyphId,
+            right: owned_ttf_parser::GlyphId,
+        ) -> Option<i16> {
+            find_kerning(self.as_face_ref(), left, right)
+        }
+
+        /// Gets the raster image of a glyph, e.g. of a color emoji.
+        ///
+        /// Arguments:
+        ///
+        /// * `glyph_id`: The id of the glyph.
+        /// * `pixels_per_em`: The preferred size of the image.
+        ///
+        /// Returns:
+        ///
+        /// The encoded image of the closest strike or `None` if the glyph has no raster image.
+        fn raster_image(
+            &self,
+            glyph_id: owned_ttf_parser::GlyphId,
+            pixels_per_em: u16,
+        ) -> Option<owned_ttf_parser::RasterGlyphImage<'_>> {
+            self.as_face_ref()
+                .glyph_raster_image(glyph_id, pixels_per_em)
+        }
+
+        /// Gets an entry of the `name` table of the face.
+        ///
+        /// Only entries with a Unicode encoding are considered.
+        ///
+        /// Arguments:
+        ///
+        /// * `name_id`: The ID of the name, e.g. [ttf_parser::name_id::FAMILY].
+        ///
+        /// Returns:
+        ///
+        /// The name or `None` if the font has no such entry.
+        fn name(&self, name_id: u16) -> Option<String> {
+            self.as_face_ref()
+                .names()
+                .into_iter()
+                .filter(|n| n.name_id == name_id)
+                .find_map(|n| n.to_string())
+        }
+
+        /// Checks wether all glyphs of the face have the same advance.
+        ///
+        /// Uses the `isFixedPitch` flag of the `post` table and the
+        /// PANOSE classification of the `OS/2` table.
+        ///
+        /// Returns:
+        ///
+        /// `true` if the face is monospaced.
+        fn is_monospaced(&self) -> bool {
+            is_monospaced_face(self.as_face_ref())
+        }
+
+        /// Reads the vertical metrics of the face from the given source.
+        ///
+        /// Arguments:
+        ///
+        /// * `source`: The table from which the metrics are read.
+        ///
+        /// Returns:
+        ///
+        /// The ascender, descender and line gap or `None`
+        /// if the face does not contain the required table.
+        fn vertical_metrics(&self, source: MetricsSource) -> Option<(i16, i16, i16)> {
+            vertical_metrics_of_face(self.as_face_ref(), source)
+        }
+
+        /// Gets the underlying [ttf_parser::Face].
+        ///
+        /// Returns:
+        ///
+        /// The parsed font that is used for shaping.
+        #[cfg(feature = "shaping")]
+        fn as_face(&self) -> &owned_ttf_parser::Face<'_> {
+            self.as_face_ref()
+        }
+
+        /// Outlines a glyph and returns its tight bounding box.
+        ///
+        /// **Warning**: since `ttf-parser` is a pull parser,
+        /// `OutlineBuilder` will emit segments even when outline is partially malformed.
+        /// You must check `outline_glyph()` result before using
+        /// `OutlineBuilder`'s output.
+        ///
+        /// `gvar`, `glyf`, `CFF` and `CFF2` tables are supported.
+        /// And they will be accesses in this specific order.
+        ///
+        /// This method is affected by variation axes.
+        ///
+        /// Returns `None` when glyph has no outline or on error.
+        ///
+        /// # Example
         ///
         /// ```
         /// use std::fmt::Write;
@@ -333,13 +1175,56 @@ mod owned_mesh_generator {
         /// * `font`: The font that will be used for rasterizing.
         pub fn new(font: Vec<u8>) -> Self {
             let face = OwnedFace::from_vec(font, 0).expect("Failed to generate font from data.");
+            Self::with_face(face, QualitySettings::default(), CachePolicy::Unlimited)
+        }
 
+        /// Creates a new [MeshGenerator] for an already parsed font.
+        ///
+        /// Arguments:
+        ///
+        /// * `face`: The face of the font that will be used for rasterizing.
+        /// * `quality`: The [QualitySettings] that should be used.
+        /// * `policy`: The [CachePolicy] of the glyph caches.
+        fn with_face(face: OwnedFace, quality: QualitySettings, policy: CachePolicy) -> Self {
             Self {
-                cache: HashMap::new(),
+                advance_fallback: AdvanceFallback::default(),
+                cache: LruCache::new(),
+                cache_clock: None,
+                cache_policy: policy,
+                cache_ttl: None,
+                close_contours: true,
+                bend: None,
+                depth_for: None,
+                depth_gradient: None,
+                displacement: None,
+                extrusion: ExtrusionSettings::default(),
+                fallback_mesh: None,
                 font: face,
-                indexed_cache: HashMap::new(),
-                quality: QualitySettings::default(),
-                use_cache: true,
+                glyph_provider: None,
+                indexed_cache: LruCache::new(),
+                indexed_cache_policy: policy,
+                indexed_outline_cache: LruCache::new(),
+                max_segment_length: 0,
+                metrics_source: MetricsSource::default(),
+                min_contour_area: 0,
+                outline_builder: GlyphOutlineBuilder::default(),
+                outline_cache: LruCache::new(),
+                post_processors: Vec::new(),
+                prepared_layout: None,
+                outline_processors: Vec::new(),
+                quality,
+                quantization: None,
+                section_records: LruCache::new(),
+                stats: None,
+                #[cfg(feature = "shaping")]
+                shaper: None,
+                #[cfg(feature = "shaping")]
+                shaping: ShapingSettings::default(),
+                tabs: TabSettings::default(),
+                triangulation_scratch: TriangulationScratch::default(),
+                vertex_layout: VertexLayout::default(),
+                warnings: Vec::new(),
+                watertight: false,
             }
         }
 
@@ -351,14 +1236,7 @@ mod owned_mesh_generator {
         /// * `quality`: The [QualitySettings] that should be used.
         pub fn new_with_quality(font: Vec<u8>, quality: QualitySettings) -> Self {
             let face = OwnedFace::from_vec(font, 0).expect("Failed to generate font from data.");
-
-            Self {
-                cache: HashMap::new(),
-                font: face,
-                indexed_cache: HashMap::new(),
-                quality,
-                use_cache: true,
-            }
+            Self::with_face(face, quality, CachePolicy::Unlimited)
         }
 
         /// Creates a new [MeshGenerator] with custom quality settings and no caching.
@@ -369,14 +1247,44 @@ mod owned_mesh_generator {
         /// * `quality`: The [QualitySettings] that should be used.
         pub fn new_without_cache(font: Vec<u8>, quality: QualitySettings) -> Self {
             let face = OwnedFace::from_vec(font, 0).expect("Failed to generate font from data.");
+            Self::with_face(face, quality, CachePolicy::Disabled)
+        }
 
-            Self {
-                cache: HashMap::new(),
-                font: face,
-                indexed_cache: HashMap::new(),
-                quality,
-                use_cache: false,
-            }
+        /// Creates a new [MeshGenerator] for a font that is installed on the system.
+        ///
+        /// The generator owns the loaded data of the font, which is why the
+        /// `system-fonts` feature enables the `owned` feature.
+        /// Font collections are parsed at the index of the requested face.
+        ///
+        /// Arguments:
+        ///
+        /// * `query`: The [FontQuery] that describes the font.
+        ///
+        /// Returns:
+        ///
+        /// The new [MeshGenerator] or `None` if no installed font matches the query.
+        ///
+        /// # Example
+        ///
+        /// ```rust,no_run
+        /// use meshtext::{FontQuery, IndexedMeshText, MeshGenerator, TextSection};
+        ///
+        /// let mut generator = MeshGenerator::from_system_font(&FontQuery::new("sans-serif"))
+        ///     .expect("Failed to find a sans-serif font.");
+        ///
+        /// let result: IndexedMeshText = generator
+        ///     .generate_section("Hello World!", false, None)
+        ///     .expect("Failed to generate mesh.");
+        /// ```
+        #[cfg(feature = "system-fonts")]
+        pub fn from_system_font(query: &FontQuery) -> Option<Self> {
+            let (data, index) = load_system_font(query)?;
+            let face = OwnedFace::from_vec(data, index).ok()?;
+            Some(Self::with_face(
+                face,
+                QualitySettings::default(),
+                CachePolicy::Unlimited,
+            ))
         }
     }
 }
@@ -385,13 +1293,11 @@ impl<T> MeshGenerator<T>
 where
     T: FontFace,
 {
-    /// Removes all stored glyphs from the internal cache.
+    /// Removes all stored glyphs from the internal caches.
     ///
     /// Normally it should not be necessary to do this manually unless your program
     /// cached so many glyphs, that memory consumption becomes an issue.
     ///
-    /// This function does nothing if the current [MeshGenerator] does not have a cache.
-    ///
     /// # Example
     ///
     /// ```rust
@@ -403,880 +1309,6213 @@ where
     /// generator.clear_cache();
     /// ```
     pub fn clear_cache(&mut self) {
-        if self.use_cache {
-            self.cache.clear();
-        }
+        self.clear_cached_meshes();
+        self.indexed_outline_cache.clear();
+        self.outline_cache.clear();
     }
 
-    /// Fills the internal cache of a [MeshGenerator] with the given characters.
+    /// Removes a single glyph from the internal caches.
+    ///
+    /// The glyph is removed from both caches regardless of its dimensions and
+    /// [QualitySettings], so that it will be generated again the next time it is
+    /// requested. This is useful after the outline of the glyph changed.
     ///
     /// Arguments:
     ///
-    /// * `glyphs`: The glyphs that will be precached. Each character should appear exactly once.
-    /// * `flat`: Wether the flat or three-dimensional variant of the characters should be preloaded.
-    /// If both variants should be precached this function must be called twice with this parameter set
-    /// to `true` and `false`.
-    /// * `cache`: An optional value that controls which cache will be filled. `None` means both caches will be filled.
+    /// * `glyph`: The character whose meshes are removed.
     ///
-    /// Returns:
+    /// # Example
     ///
-    /// A [Result] indicating if the operation was successful.
+    /// ```rust
+    /// use meshtext::{CacheType, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator
+    ///     .precache_glyphs("ab", true, None)
+    ///     .expect("Failed to precache glyphs.");
+    ///
+    /// generator.evict_glyph('a');
+    /// assert!(generator.peek_glyph('a', true, CacheType::Normal).is_none());
+    /// assert!(generator.peek_glyph('b', true, CacheType::Normal).is_some());
+    /// ```
+    pub fn evict_glyph(&mut self, glyph: char) {
+        let glyph = self.glyph_key_of_char(glyph);
+        self.cache.retain(|(key, _, _), _| *key != glyph);
+        self.indexed_cache.retain(|(key, _, _), _| *key != glyph);
+        self.section_records.clear();
+    }
+
+    /// Removes the glyphs of a text from the internal caches.
+    ///
+    /// See [MeshGenerator::evict_glyph] for details.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The characters whose meshes are removed.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use meshtext::MeshGenerator;
+    /// use meshtext::{CacheType, MeshGenerator};
     ///
     /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
     /// let mut generator = MeshGenerator::new(font_data);
+    /// generator
+    ///     .precache_glyphs("abc", false, None)
+    ///     .expect("Failed to precache glyphs.");
     ///
-    /// let common = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789".to_string();
-    ///
-    /// // Precache both flat and three-dimensional glyphs both for indexed and non-indexed meshes.
-    /// generator.precache_glyphs(&common, false, None);
-    /// generator.precache_glyphs(&common, true, None);
+    /// generator.evict_glyphs("ab");
+    /// assert_eq!(generator.export_cache().indexed.len(), 1);
     /// ```
-    pub fn precache_glyphs(
-        &mut self,
-        glyphs: &str,
-        flat: bool,
-        cache: Option<CacheType>,
-    ) -> Result<(), Box<dyn MeshTextError>> {
-        if let Some(cache_type) = cache {
-            match cache_type {
-                CacheType::Normal => {
-                    for c in glyphs.chars() {
-                        self.generate_glyph(c, flat, None)?;
-                    }
-                }
-                CacheType::Indexed => {
-                    for c in glyphs.chars() {
-                        self.generate_glyph_indexed(c, flat, None)?;
-                    }
-                }
-            }
-        } else {
-            // If no type is set explicitely, both variants will be precached.
-            for c in glyphs.chars() {
-                self.generate_glyph(c, flat, None)?;
-            }
-            for c in glyphs.chars() {
-                self.generate_glyph_indexed(c, flat, None)?;
-            }
-        }
-
-        Ok(())
+    pub fn evict_glyphs(&mut self, glyphs: &str) {
+        let glyphs: Vec<GlyphKey> = glyphs.chars().map(|c| self.glyph_key_of_char(c)).collect();
+        self.cache.retain(|(key, _, _), _| !glyphs.contains(key));
+        self.indexed_cache
+            .retain(|(key, _, _), _| !glyphs.contains(key));
+        self.section_records.clear();
     }
 
-    /// Generates the [MeshText] of a single character with a custom transformation.
+    /// Gets the [CachePolicy] of one of the internal caches.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be converted to a mesh.
-    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
-    /// to generate a mesh with a depth of `1.0` units.
-    /// * `transform`: The 4x4 homogenous transformation matrix in column
-    /// major order that will be applied to this text.
+    /// * `cache_type`: The [CacheType] of the cache.
     ///
     /// Returns:
     ///
-    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    fn generate_glyph(
-        &mut self,
-        glyph: char,
+    /// The current [CachePolicy] of the cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{CachePolicy, CacheType, MeshGenerator, QualitySettings};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new_without_cache(font_data, QualitySettings::default());
+    ///
+    /// assert_eq!(generator.cache_policy(CacheType::Indexed), CachePolicy::Disabled);
+    /// ```
+    pub fn cache_policy(&self, cache_type: CacheType) -> CachePolicy {
+        match cache_type {
+            CacheType::Normal => self.cache_policy,
+            CacheType::Indexed => self.indexed_cache_policy,
+        }
+    }
+
+    /// Sets the [CachePolicy] of one of the internal caches.
+    ///
+    /// Each cache can be enabled, disabled or limited in size independently,
+    /// e.g. if only indexed meshes are ever requested. Glyphs that exceed the
+    /// new policy are removed from the cache.
+    ///
+    /// Arguments:
+    ///
+    /// * `cache_type`: The [CacheType] of the cache.
+    /// * `policy`: The new [CachePolicy] of the cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{CachePolicy, CacheType, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_cache_policy(CacheType::Normal, CachePolicy::Disabled);
+    /// generator.set_cache_policy(CacheType::Indexed, CachePolicy::Limited(256));
+    /// ```
+    pub fn set_cache_policy(&mut self, cache_type: CacheType, policy: CachePolicy) {
+        match cache_type {
+            CacheType::Normal => self.cache_policy = policy,
+            CacheType::Indexed => self.indexed_cache_policy = policy,
+        }
+
+        match policy {
+            CachePolicy::Disabled => match cache_type {
+                CacheType::Normal => {
+                    self.cache.clear();
+                    self.outline_cache.clear();
+                }
+                CacheType::Indexed => {
+                    self.indexed_cache.clear();
+                    self.indexed_outline_cache.clear();
+                }
+            },
+            CachePolicy::Unlimited => (),
+            CachePolicy::Limited(capacity) => match cache_type {
+                CacheType::Normal => {
+                    self.cache.trim(capacity);
+                    self.outline_cache.trim(capacity);
+                }
+                CacheType::Indexed => {
+                    self.indexed_cache.trim(capacity);
+                    self.indexed_outline_cache.trim(capacity);
+                }
+            },
+        }
+    }
+
+    /// Sets the duration after which glyphs that have not been used are
+    /// evicted from the internal caches.
+    ///
+    /// Expired glyphs are evicted during the next call that generates a mesh,
+    /// which keeps the memory bounded if many different glyphs are only shown
+    /// for a short time. By default glyphs never expire.
+    ///
+    /// Arguments:
+    ///
+    /// * `ttl`: The duration after which unused glyphs expire or [None]
+    ///   in order to keep them until they are removed manually.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_cache_ttl(Some(Duration::from_secs(60)));
+    /// ```
+    pub fn set_cache_ttl(&mut self, ttl: Option<Duration>) {
+        self.cache_ttl = ttl;
+
+        // Glyphs that are already cached count as used right now.
+        if let Some(now) = self.cache_now() {
+            self.cache.mark_all_used(now);
+            self.indexed_cache.mark_all_used(now);
+            self.outline_cache.mark_all_used(now);
+            self.indexed_outline_cache.mark_all_used(now);
+        }
+    }
+
+    /// Sets the clock that decides when cached glyphs expire.
+    ///
+    /// By default the time to live of the internal caches is measured with
+    /// [Instant::now]. A custom clock allows to expire glyphs based on a
+    /// different time, e.g. the time of a simulation that can be paused.
+    ///
+    /// Arguments:
+    ///
+    /// * `clock`: The callback that returns the current time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// use meshtext::{CacheType, Glyph, IndexedMeshText, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let time = Arc::new(Mutex::new(Instant::now()));
+    /// let clock = time.clone();
+    /// generator.set_cache_clock(move || *clock.lock().unwrap());
+    /// generator.set_cache_ttl(Some(Duration::from_secs(60)));
+    ///
+    /// let _: IndexedMeshText = generator
+    ///     .generate_glyph('a', true, None)
+    ///     .expect("Failed to generate glyph.");
+    /// *time.lock().unwrap() += Duration::from_secs(61);
+    /// let _: IndexedMeshText = generator
+    ///     .generate_glyph('b', true, None)
+    ///     .expect("Failed to generate glyph.");
+    /// assert!(generator.peek_glyph('a', true, CacheType::Indexed).is_none());
+    /// ```
+    pub fn set_cache_clock(&mut self, clock: impl Fn() -> Instant + Send + Sync + 'static) {
+        self.cache_clock = Some(Box::new(clock));
+    }
+
+    /// Gets the family name of the font.
+    ///
+    /// The typographic family name is preferred, since it groups all
+    /// styles of a font, e.g. when a font has more than four weights.
+    ///
+    /// Returns:
+    ///
+    /// The family name or `None` if the font does not specify it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// assert_eq!(generator.family_name().as_deref(), Some("Fira Mono"));
+    /// ```
+    pub fn family_name(&self) -> Option<String> {
+        self.font
+            .name(ttf_parser::name_id::TYPOGRAPHIC_FAMILY)
+            .or_else(|| self.font.name(ttf_parser::name_id::FAMILY))
+    }
+
+    /// Gets the subfamily name of the font, which describes its style.
+    ///
+    /// The typographic subfamily name is preferred over the legacy one.
+    ///
+    /// Returns:
+    ///
+    /// The subfamily name (e.g. `"Bold Italic"`) or `None` if the font does not specify it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// assert_eq!(generator.subfamily_name().as_deref(), Some("Regular"));
+    /// ```
+    pub fn subfamily_name(&self) -> Option<String> {
+        self.font
+            .name(ttf_parser::name_id::TYPOGRAPHIC_SUBFAMILY)
+            .or_else(|| self.font.name(ttf_parser::name_id::SUBFAMILY))
+    }
+
+    /// Gets the version of the font as specified in its `name` table.
+    ///
+    /// Returns:
+    ///
+    /// The version string (e.g. `"Version 1.000"`) or `None` if the font does not specify it.
+    pub fn version(&self) -> Option<String> {
+        self.font.name(ttf_parser::name_id::VERSION)
+    }
+
+    /// Gets the suggested placement of an underline.
+    ///
+    /// Returns:
+    ///
+    /// The [DecorationMetrics] of the underline relative to the font size
+    /// or `None` if the font does not specify them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let underline = generator.underline_metrics().expect("Font has no underline metrics.");
+    /// assert!(underline.position < 0f32);
+    /// assert!(underline.thickness > 0f32);
+    /// ```
+    pub fn underline_metrics(&self) -> Option<DecorationMetrics> {
+        let metrics = self.font.underline_metrics()?;
+        let scale = self.font_scale();
+
+        Some(DecorationMetrics {
+            position: metrics.position as f32 / scale,
+            thickness: metrics.thickness as f32 / scale,
+        })
+    }
+
+    /// Checks wether all glyphs of the font have the same advance.
+    ///
+    /// This is only a hint of the font. Monospaced fonts may still contain glyphs
+    /// that span several cells, so text is always laid out using the advance of
+    /// each glyph.
+    ///
+    /// Returns:
+    ///
+    /// `true` if the font is monospaced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// assert!(generator.is_monospaced());
+    /// ```
+    pub fn is_monospaced(&self) -> bool {
+        self.font.is_monospaced()
+    }
+
+    /// Gets the font metric by which all glyph coordinates are normalized.
+    ///
+    /// Usually this is the line height of the font. Some fonts (e.g. icon fonts)
+    /// report a line height that is not positive or far smaller than their
+    /// glyphs, in which case the units per em are used instead.
+    ///
+    /// Returns:
+    ///
+    /// The [ScaleReference] used by this generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{MeshGenerator, ScaleReference};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// assert_eq!(generator.scale_reference(), ScaleReference::Height);
+    /// ```
+    pub fn scale_reference(&self) -> ScaleReference {
+        let (ascender, descender, _) = self.vertical_metrics();
+        let height = ascender - descender;
+        let units_per_em = self.font.units_per_em() as f32;
+
+        if height < units_per_em * MIN_HEIGHT_RATIO {
+            ScaleReference::UnitsPerEm
+        } else {
+            ScaleReference::Height
+        }
+    }
+
+    /// Gets the number of font units per em.
+    ///
+    /// Returns:
+    ///
+    /// The units per em of the font.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// assert_eq!(generator.units_per_em(), 1000);
+    /// ```
+    pub fn units_per_em(&self) -> u16 {
+        self.font.units_per_em()
+    }
+
+    /// Converts a length in font units to the normalized coordinates of the generated meshes.
+    ///
+    /// Arguments:
+    ///
+    /// * `units`: The length in font units.
+    ///
+    /// Returns:
+    ///
+    /// The length relative to the [ScaleReference] of the font.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let normalized = generator.font_units_to_normalized(500f32);
+    /// assert!((generator.normalized_to_font_units(normalized) - 500f32).abs() < 1e-3);
+    /// ```
+    pub fn font_units_to_normalized(&self, units: f32) -> f32 {
+        units / self.font_scale()
+    }
+
+    /// Converts a length in the normalized coordinates of the generated meshes to font units.
+    ///
+    /// Arguments:
+    ///
+    /// * `normalized`: The length relative to the [ScaleReference] of the font.
+    ///
+    /// Returns:
+    ///
+    /// The length in font units.
+    pub fn normalized_to_font_units(&self, normalized: f32) -> f32 {
+        normalized * self.font_scale()
+    }
+
+    /// Computes the scale that sizes the generated meshes to a font size.
+    ///
+    /// Typography tools usually specify the font size as the size of one em,
+    /// e.g. in points or world units, while the generated meshes are normalized
+    /// by the [ScaleReference] of the font.
+    ///
+    /// Arguments:
+    ///
+    /// * `em_size`: The size of one em in the target unit.
+    ///
+    /// Returns:
+    ///
+    /// The factor by which the generated meshes are scaled, so that one em
+    /// spans `em_size` target units.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{Glyph, MeshGenerator, MeshText};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // Render text at 12 points, where one unit is one point.
+    /// let scale = generator.em_scale(12f32);
+    /// let glyph: MeshText = generator
+    ///     .generate_glyph('a', true, Some(&[
+    ///         scale, 0.0, 0.0, 0.0,
+    ///         0.0, scale, 0.0, 0.0,
+    ///         0.0, 0.0, 1.0, 0.0,
+    ///         0.0, 0.0, 0.0, 1.0,
+    ///     ]))
+    ///     .expect("Failed to generate glyph.");
+    ///
+    /// // Fira Mono has an advance of 600 units for 1000 units per em.
+    /// assert!((glyph.advance * scale - 7.2).abs() < 1e-3);
+    /// ```
+    pub fn em_scale(&self, em_size: f32) -> f32 {
+        em_size * self.font_scale() / self.font.units_per_em() as f32
+    }
+
+    /// Sets the [QualitySettings] used for generating glyphs.
+    ///
+    /// Glyphs that were cached with different settings remain in
+    /// the internal caches, but are only used again once the
+    /// previous settings are restored.
+    ///
+    /// Arguments:
+    ///
+    /// * `quality`: The new [QualitySettings].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{MeshGenerator, QualitySettings};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_quality(QualitySettings {
+    ///     quad_interpolation_steps: 10,
+    ///     cubic_interpolation_steps: 6,
+    /// });
+    /// ```
+    pub fn set_quality(&mut self, quality: QualitySettings) {
+        if self.quality != quality {
+            self.quality = quality;
+            self.section_records.clear();
+        }
+    }
+
+    /// Sets the [ExtrusionSettings] used for generating three-dimensional glyphs.
+    ///
+    /// Glyphs that were cached with different settings are removed
+    /// from the internal caches.
+    ///
+    /// Arguments:
+    ///
+    /// * `extrusion`: The new [ExtrusionSettings].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{ExtrusionSettings, IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // Shrink the back of each glyph to half of its size.
+    /// generator.set_extrusion_settings(ExtrusionSettings {
+    ///     back_cap_scale: 0.5,
+    /// });
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Pyramid", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// ```
+    pub fn set_extrusion_settings(&mut self, extrusion: ExtrusionSettings) {
+        if self.extrusion != extrusion {
+            self.extrusion = extrusion;
+            self.clear_cached_meshes();
+        }
+    }
+
+    /// Sets the [AdvanceFallback] used for glyphs for which the font
+    /// does not specify a horizontal advance.
+    ///
+    /// Arguments:
+    ///
+    /// * `fallback`: The new [AdvanceFallback].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{AdvanceFallback, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_advance_fallback(AdvanceFallback::GlyphWidth { side_bearing: 0.05 });
+    /// ```
+    pub fn set_advance_fallback(&mut self, fallback: AdvanceFallback) {
+        self.advance_fallback = fallback;
+    }
+
+    /// Sets the [MetricsSource] from which the vertical metrics of the font are read.
+    ///
+    /// Since the line height normalizes all glyph coordinates, this changes
+    /// the size of the generated text. Choosing the same source as another
+    /// renderer makes the text sizes match. All cached glyphs are removed.
+    ///
+    /// Arguments:
+    ///
+    /// * `source`: The new [MetricsSource].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{MeshGenerator, MetricsSource};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_metrics_source(MetricsSource::Hhea);
+    /// ```
+    pub fn set_metrics_source(&mut self, source: MetricsSource) {
+        if self.metrics_source != source {
+            self.metrics_source = source;
+            self.clear_cache();
+        }
+    }
+
+    /// Controls wether the vertices of generated meshes are snapped to a grid.
+    ///
+    /// Quantized coordinates can be deduplicated and compressed more efficiently
+    /// and yield stable hashes of meshes. The vertices are snapped after the
+    /// transformation is applied, so the grid is aligned with the output.
+    /// This applies to all generated meshes, including interleaved, partitioned
+    /// and appended meshes, paragraphs and outlines. Triangles that lose their
+    /// area are removed. Meshes that are merged with [IndexedMeshText::merge]
+    /// can be snapped again with [IndexedMeshText::quantize].
+    ///
+    /// Arguments:
+    ///
+    /// * `step`: The spacing of the grid relative to the size of one em
+    ///   (e.g. `1.0 / 1024.0`) or `None` to disable quantization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.set_quantization(Some(1f32 / 1024f32));
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Grid", true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// ```
+    pub fn set_quantization(&mut self, step: Option<f32>) {
+        if self.quantization != step {
+            self.quantization = step;
+            self.section_records.clear();
+        }
+    }
+
+    /// Sets the [TabSettings] used for laying out text sections.
+    ///
+    /// Arguments:
+    ///
+    /// * `tabs`: The new [TabSettings].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{MeshGenerator, TabSettings, TabSize};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // Align the columns at fixed positions and continue every two units.
+    /// generator.set_tab_settings(TabSettings {
+    ///     tab_size: TabSize::Advance(2.0),
+    ///     stops: vec![5.0, 8.0],
+    /// });
+    ///
+    /// let layout = generator.section_layout("a\tb");
+    /// assert_eq!(layout.carets[2].position.x, 5.0);
+    /// ```
+    pub fn set_tab_settings(&mut self, tabs: TabSettings) {
+        self.tabs = tabs;
+    }
+
+    /// Sets the [ShapingSettings] used for laying out text sections.
+    ///
+    /// Arguments:
+    ///
+    /// * `shaping`: The new [ShapingSettings].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{FontFeature, MeshGenerator, ShapingSettings};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // Draw each letter of "fi" separately.
+    /// generator.set_shaping_settings(ShapingSettings {
+    ///     ligatures: false,
+    ///     ..Default::default()
+    /// });
+    ///
+    /// // Use small capitals and tabular figures.
+    /// generator.set_shaping_settings(ShapingSettings {
+    ///     features: vec![FontFeature::enable(b"smcp"), FontFeature::enable(b"tnum")],
+    ///     ..Default::default()
+    /// });
+    ///
+    /// // Shape the following sections as Turkish text.
+    /// generator.set_shaping_settings(ShapingSettings {
+    ///     script: Some(*b"Latn"),
+    ///     language: Some("tr".to_string()),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    #[cfg(feature = "shaping")]
+    pub fn set_shaping_settings(&mut self, shaping: ShapingSettings) {
+        self.shaping = shaping;
+    }
+
+    /// Sets the [VertexLayout] used for generating an [InterleavedMeshText].
+    ///
+    /// Arguments:
+    ///
+    /// * `layout`: The attributes each vertex should consist of.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{
+    ///     InterleavedMeshText, MeshGenerator, TextSection, VertexAttribute, VertexLayout,
+    /// };
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let mut layout = VertexLayout::new(&[VertexAttribute::Position, VertexAttribute::Color]);
+    /// layout.color = [1f32, 0f32, 0f32, 1f32];
+    /// generator.set_vertex_layout(layout);
+    ///
+    /// let result: InterleavedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert_eq!(result.stride, 28);
+    /// ```
+    pub fn set_vertex_layout(&mut self, layout: VertexLayout) {
+        self.vertex_layout = layout;
+    }
+
+    /// Controls wether glyphs are generated as watertight meshes.
+    ///
+    /// In this mode the outline of each glyph is rebuilt from the area it covers
+    /// before it is triangulated. Overlapping contours (e.g. of composite glyphs
+    /// or variable fonts) are merged, degenerate contours are removed and all
+    /// contours are oriented consistently. Three-dimensional indexed glyphs then
+    /// form closed, manifold solids without internal faces, which is what slicers
+    /// expect for 3D printing.
+    ///
+    /// Glyphs that were cached with a different setting are removed
+    /// from the internal caches.
+    ///
+    /// Arguments:
+    ///
+    /// * `watertight`: Wether glyph outlines are repaired.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.set_watertight(true);
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Solid", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// ```
+    pub fn set_watertight(&mut self, watertight: bool) {
+        if self.watertight != watertight {
+            self.watertight = watertight;
+            self.clear_cached_meshes();
+        }
+    }
+
+    /// Controls wether open contours are closed automatically.
+    ///
+    /// Some fonts omit the segment that connects the last point of a contour
+    /// back to the first one or never close a contour at all. By default such
+    /// contours are closed, otherwise their last point is dropped and contours
+    /// that remain open fail to triangulate.
+    ///
+    /// Glyphs that were cached with a different setting are removed
+    /// from the internal caches.
+    ///
+    /// Arguments:
+    ///
+    /// * `close_contours`: Wether open contours are closed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.set_close_contours(false);
+    /// ```
+    pub fn set_close_contours(&mut self, close_contours: bool) {
+        if self.close_contours != close_contours {
+            self.close_contours = close_contours;
+            self.clear_cached_meshes();
+        }
+    }
+
+    /// Sets the minimum area of a contour in square font units.
+    ///
+    /// Smaller contours (e.g. tiny decorative specks of ornamental fonts)
+    /// are dropped before triangulation, which also applies to holes.
+    /// A value of `0` keeps all contours, which is the default.
+    /// Higher values result in lower polygon count.
+    ///
+    /// Glyphs that were cached with a different setting are removed
+    /// from the internal caches.
+    ///
+    /// Arguments:
+    ///
+    /// * `area`: The minimum area of a contour in square font units.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.set_min_contour_area(400);
+    /// ```
+    pub fn set_min_contour_area(&mut self, area: u32) {
+        if self.min_contour_area != area {
+            self.min_contour_area = area;
+            self.clear_cached_meshes();
+        }
+    }
+
+    /// Sets the maximum length of a contour segment in font units.
+    ///
+    /// Longer segments are split evenly after the curves are interpolated,
+    /// so that the side walls of three-dimensional glyphs consist of quads of
+    /// a similar size instead of single huge quads along long straight edges,
+    /// which improves vertex lighting and deformations. A value of `0` keeps
+    /// all segments, which is the default. Lower values result in higher polygon count.
+    ///
+    /// Glyphs that were cached with a different setting are removed
+    /// from the internal caches.
+    ///
+    /// Arguments:
+    ///
+    /// * `length`: The maximum length of a segment in font units.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.set_max_segment_length(50);
+    /// ```
+    pub fn set_max_segment_length(&mut self, length: u32) {
+        if self.max_segment_length != length {
+            self.max_segment_length = length;
+            self.clear_cached_meshes();
+        }
+    }
+
+    /// Sets a callback that controls the extrusion depth of each character.
+    ///
+    /// The callback is consulted whenever a three-dimensional text section is
+    /// generated, which allows baking effects like progressively deeper characters
+    /// or emphasized words into a single mesh. The depth is relative to the default
+    /// depth of `1.0` units and the glyphs remain centered about the XY-plane.
+    /// Flat meshes are not affected.
+    ///
+    /// Arguments:
+    ///
+    /// * `depth_for`: The callback that receives the index of a character within
+    ///   the text section counted in characters and the character itself and returns
+    ///   its depth. In paragraphs the index refers to the text of the paragraph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // Each character is deeper than the previous one.
+    /// generator.set_depth_for(|index, _| 1.0 + index as f32 * 0.5);
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Stairs", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert_eq!(result.bbox.max.z, 1.75);
+    /// ```
+    pub fn set_depth_for(
+        &mut self,
+        depth_for: impl Fn(usize, char) -> f32 + Send + Sync + 'static,
+    ) {
+        self.depth_for = Some(Box::new(depth_for));
+    }
+
+    /// Removes the callback that controls the extrusion depth of each character,
+    /// so that all glyphs have the default depth again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.clear_depth_for();
+    /// ```
+    pub fn clear_depth_for(&mut self) {
+        self.depth_for = None;
+    }
+
+    /// Linearly interpolates the extrusion depth across the width of each text section.
+    ///
+    /// This produces wedge-shaped three-dimensional text without the need for a
+    /// per-glyph callback. The depths are relative to the default depth of `1.0`
+    /// units and are multiplied with the depths of the callback set with
+    /// [MeshGenerator::set_depth_for()]. The text remains centered about the XY-plane.
+    /// In paragraphs the depth is interpolated across the width of the paragraph.
+    ///
+    /// Arguments:
+    ///
+    /// * `start`: The depth at the start of the text section.
+    /// * `end`: The depth at the end of the text section.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_depth_gradient(0.5, 2.0);
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Wedge", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert!(result.bbox.max.z > 0.5 && result.bbox.max.z <= 1.0);
+    /// ```
+    pub fn set_depth_gradient(&mut self, start: f32, end: f32) {
+        self.depth_gradient = Some((start, end));
+    }
+
+    /// Removes the depth gradient set with [MeshGenerator::set_depth_gradient()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_depth_gradient(1.0, 3.0);
+    /// generator.clear_depth_gradient();
+    /// ```
+    pub fn clear_depth_gradient(&mut self) {
+        self.depth_gradient = None;
+    }
+
+    /// Sets a callback that displaces the front cap of text sections along the z-axis.
+    ///
+    /// The displacement is applied after the layout, which allows generating embossed
+    /// or warped text surfaces. For three-dimensional meshes only the vertices of the
+    /// front cap (including the front edges of the side walls) are displaced, flat
+    /// meshes are displaced entirely. Displacements should stay within the depth of
+    /// the glyphs, so that the front cap remains in front of the back cap.
+    ///
+    /// Arguments:
+    ///
+    /// * `displacement`: The callback that receives a point `(x, y)` in the coordinate
+    ///   system of the untransformed text section and returns the offset along the z-axis.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // A gentle wave across the text.
+    /// generator.set_displacement(|x, _| 0.1 * x.sin());
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Wave", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert!(result.bbox.max.z > 0.5);
+    /// assert_eq!(result.bbox.min.z, -0.5);
+    /// ```
+    pub fn set_displacement(
+        &mut self,
+        displacement: impl Fn(f32, f32) -> f32 + Send + Sync + 'static,
+    ) {
+        self.displacement = Some(Box::new(displacement));
+    }
+
+    /// Displaces the front cap of text sections along the z-axis using a [Heightmap].
+    ///
+    /// This is a shorthand for [MeshGenerator::set_displacement()] with a callback
+    /// that samples the given heightmap.
+    ///
+    /// Arguments:
+    ///
+    /// * `heightmap`: The [Heightmap] that is sampled at each vertex of the front cap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec2;
+    /// use meshtext::{Heightmap, IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // The text rises towards the right.
+    /// let heightmap = Heightmap::new(2, vec![0.0, 0.25], Vec2::ZERO, Vec2::ONE);
+    /// generator.set_heightmap(heightmap);
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Ramp", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert_eq!(result.bbox.max.z, 0.75);
+    /// ```
+    pub fn set_heightmap(&mut self, heightmap: Heightmap) {
+        self.set_displacement(move |x, y| heightmap.sample(Vec2::new(x, y)));
+    }
+
+    /// Removes the displacement set with [MeshGenerator::set_displacement()]
+    /// or [MeshGenerator::set_heightmap()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_displacement(|x, y| 0.1 * (x + y).sin());
+    /// generator.clear_displacement();
+    /// ```
+    pub fn clear_displacement(&mut self) {
+        self.displacement = None;
+    }
+
+    /// Wraps the generated text sections around a cylinder or a sphere.
+    ///
+    /// The text is bent after the depth gradient and the displacement have been
+    /// applied. The normals of interleaved meshes follow the curved surface.
+    /// Two-dimensional meshes are not affected.
+    ///
+    /// Arguments:
+    ///
+    /// * `bend`: The [Bend] that describes the curved surface.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{Bend, IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_bend(Bend::Cylinder { radius: 2.0 });
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Label", true, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// // The ends of the text curve away from the viewer.
+    /// assert!(result.bbox.min.z < 0.0);
+    /// ```
+    pub fn set_bend(&mut self, bend: Bend) {
+        self.bend = Some(bend);
+    }
+
+    /// Removes the [Bend] set with [MeshGenerator::set_bend()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{Bend, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.set_bend(Bend::Sphere { radius: 3.0 });
+    /// generator.clear_bend();
+    /// ```
+    pub fn clear_bend(&mut self) {
+        self.bend = None;
+    }
+
+    /// Sets the [GlyphProvider] that is consulted before the font.
+    ///
+    /// Characters provided by the [GlyphProvider] replace the glyphs of the font.
+    /// Previously cached custom glyphs are removed from the internal caches.
+    ///
+    /// Arguments:
+    ///
+    /// * `provider`: The new [GlyphProvider].
+    pub fn set_glyph_provider(&mut self, provider: impl GlyphProvider + 'static) {
+        self.glyph_provider = Some(Box::new(provider));
+        self.clear_custom_glyphs();
+    }
+
+    /// Removes the [GlyphProvider], so that all glyphs are taken from the font again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.remove_glyph_provider();
+    /// ```
+    pub fn remove_glyph_provider(&mut self) {
+        if self.glyph_provider.take().is_some() {
+            self.clear_custom_glyphs();
+        }
+    }
+
+    /// Removes all glyphs of a [GlyphProvider] from the internal caches.
+    fn clear_custom_glyphs(&mut self) {
+        let is_font_glyph = |key: &CacheKey| matches!(key.0, GlyphKey::Font(_));
+        self.cache.retain(|key, _| is_font_glyph(key));
+        self.indexed_cache.retain(|key, _| is_font_glyph(key));
+        self.section_records.clear();
+    }
+
+    /// Sets a custom mesh that replaces characters which are missing from the font.
+    ///
+    /// By default the `.notdef` glyph of the font is used for missing characters,
+    /// which is usually an empty mesh or a box. A flat fallback mesh is extruded
+    /// for three-dimensional text, while only the front cap of a three-dimensional
+    /// fallback mesh is used for flat text. Like any other glyph, the fallback mesh
+    /// is cached and modified by the registered [MeshPostProcessor]s, which receive
+    /// [crate::GlyphSource::Fallback].
+    ///
+    /// Arguments:
+    ///
+    /// * `mesh`: The untransformed mesh of the fallback glyph. Its advance
+    ///   is used as the advance of the replaced characters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{Glyph, IndexedMeshText, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // Use the question mark of the font as the marker for missing characters.
+    /// let marker: IndexedMeshText = generator
+    ///     .generate_glyph('?', true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// generator.set_fallback_mesh(&marker);
+    ///
+    /// let missing: IndexedMeshText = generator
+    ///     .generate_glyph('中', true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert_eq!(missing.indices, marker.indices);
+    /// ```
+    pub fn set_fallback_mesh(&mut self, mesh: &IndexedMeshText) {
+        let vertices = if mesh.two_dimensional {
+            mesh.vertices
+                .chunks_exact(2)
+                .map(|v| Vec3A::new(v[0], v[1], 0f32))
+                .collect()
+        } else {
+            raw_to_glam_vecs(&mesh.vertices)
+        };
+
+        // Flat meshes may also be given by three-component vertices.
+        let flat = mesh.two_dimensional || mesh.bbox.min.z == mesh.bbox.max.z;
+        self.fallback_mesh = Some((
+            (mesh.indices.to_owned(), vertices, mesh.bbox),
+            flat,
+            mesh.advance,
+        ));
+        self.clear_fallback_glyphs();
+    }
+
+    /// Removes the custom fallback mesh, so that the `.notdef` glyph
+    /// of the font is used for missing characters again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.remove_fallback_mesh();
+    /// ```
+    pub fn remove_fallback_mesh(&mut self) {
+        self.fallback_mesh = None;
+        self.clear_fallback_glyphs();
+    }
+
+    /// Removes the meshes of the custom fallback glyph from the internal caches.
+    fn clear_fallback_glyphs(&mut self) {
+        let is_fallback = |key: &CacheKey| key.0 == GlyphKey::Fallback;
+        self.cache.retain(|key, _| !is_fallback(key));
+        self.indexed_cache.retain(|key, _| !is_fallback(key));
+        self.section_records.clear();
+    }
+
+    /// Registers a [MeshPostProcessor] that is applied to each freshly generated glyph.
+    ///
+    /// Post-processors run in the order in which they were registered before
+    /// a glyph is cached. Previously cached glyphs are removed from the
+    /// internal caches, so that all glyphs are processed consistently.
+    ///
+    /// Arguments:
+    ///
+    /// * `processor`: The [MeshPostProcessor] that is registered.
+    pub fn add_post_processor(&mut self, processor: impl MeshPostProcessor + 'static) {
+        self.post_processors.push(Box::new(processor));
+        self.clear_cached_meshes();
+    }
+
+    /// Removes all registered [MeshPostProcessor]s.
+    ///
+    /// Previously cached glyphs are removed from the internal caches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.clear_post_processors();
+    /// ```
+    pub fn clear_post_processors(&mut self) {
+        if !self.post_processors.is_empty() {
+            self.post_processors.clear();
+            self.clear_cached_meshes();
+        }
+    }
+
+    /// Registers an [OutlineProcessor] that adjusts the outline of each
+    /// freshly generated glyph before it is triangulated.
+    ///
+    /// Outline processors run in the order in which they were registered.
+    /// Previously cached glyphs are removed from the internal caches,
+    /// so that all glyphs are processed consistently.
+    ///
+    /// Arguments:
+    ///
+    /// * `processor`: The [OutlineProcessor] that is registered.
+    pub fn add_outline_processor(&mut self, processor: impl OutlineProcessor + 'static) {
+        self.outline_processors.push(Box::new(processor));
+        self.clear_cached_meshes();
+    }
+
+    /// Removes all registered [OutlineProcessor]s.
+    ///
+    /// Previously cached glyphs are removed from the internal caches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// generator.clear_outline_processors();
+    /// ```
+    pub fn clear_outline_processors(&mut self) {
+        if !self.outline_processors.is_empty() {
+            self.outline_processors.clear();
+            self.clear_cached_meshes();
+        }
+    }
+
+    /// Takes the warnings about problems of the font that were
+    /// worked around while generating glyphs.
+    ///
+    /// Since glyphs are cached, each warning is only reported
+    /// the first time a glyph is generated.
+    ///
+    /// Returns:
+    ///
+    /// The [GenerationWarning]s since this method was called the last time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// for warning in generator.take_warnings() {
+    ///     println!("{}", warning);
+    /// }
+    /// ```
+    pub fn take_warnings(&mut self) -> Vec<GenerationWarning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Controls wether [GenerationStats] are collected for generated glyphs.
+    ///
+    /// Only glyphs that are not cached yet contribute to the statistics.
+    ///
+    /// Arguments:
+    ///
+    /// * `collect`: Wether statistics are collected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.set_collect_stats(true);
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let stats = generator.take_stats().expect("Statistics are not collected.");
+    /// assert_eq!(stats.glyphs, 4);
+    /// ```
+    pub fn set_collect_stats(&mut self, collect: bool) {
+        self.stats = match collect {
+            true => Some(self.stats.unwrap_or_default()),
+            false => None,
+        };
+    }
+
+    /// Takes the [GenerationStats] of the glyphs that were generated since
+    /// this method was called the last time and resets them.
+    ///
+    /// Returns:
+    ///
+    /// The [GenerationStats] or `None` if no statistics are collected.
+    pub fn take_stats(&mut self) -> Option<GenerationStats> {
+        self.stats.as_mut().map(std::mem::take)
+    }
+
+    /// Fills the internal cache of a [MeshGenerator] with the given characters.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The glyphs that will be precached. Each character should appear exactly once.
+    /// * `flat`: Wether the flat or three-dimensional variant of the characters should be preloaded.
+    /// If both variants should be precached this function must be called twice with this parameter set
+    /// to `true` and `false`.
+    /// * `cache`: An optional value that controls which cache will be filled. `None` means both caches will be filled.
+    ///
+    /// Returns:
+    ///
+    /// A [Result] indicating if the operation was successful.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let common = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789".to_string();
+    ///
+    /// // Precache both flat and three-dimensional glyphs both for indexed and non-indexed meshes.
+    /// generator.precache_glyphs(&common, false, None);
+    /// generator.precache_glyphs(&common, true, None);
+    /// ```
+    pub fn precache_glyphs(
+        &mut self,
+        glyphs: &str,
+        flat: bool,
+        cache: Option<CacheType>,
+    ) -> Result<(), Box<dyn MeshTextError>> {
+        if let Some(cache_type) = cache {
+            match cache_type {
+                CacheType::Normal => {
+                    for c in glyphs.chars() {
+                        self.generate_glyph::<MeshTextGlam>(c, flat, None)?;
+                    }
+                }
+                CacheType::Indexed => {
+                    for c in glyphs.chars() {
+                        self.generate_glyph_indexed::<IndexedMeshTextGlam>(c, flat, None)?;
+                    }
+                }
+            }
+        } else {
+            // If no type is set explicitely, both variants will be precached.
+            for c in glyphs.chars() {
+                self.generate_glyph::<MeshTextGlam>(c, flat, None)?;
+            }
+            for c in glyphs.chars() {
+                self.generate_glyph_indexed::<IndexedMeshTextGlam>(c, flat, None)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a snapshot of the glyphs in the internal caches.
+    ///
+    /// The snapshot can be stored together with other baked assets and
+    /// restored with [MeshGenerator::import_cache] in order to skip the
+    /// triangulation of common glyphs at load time.
+    ///
+    /// Returns:
+    ///
+    /// A [GlyphCache] containing the cached glyphs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator
+    ///     .precache_glyphs("abc", false, None)
+    ///     .expect("Failed to precache glyphs.");
+    ///
+    /// let cache = generator.export_cache();
+    /// assert_eq!(cache.normal.len(), 3);
+    /// assert_eq!(cache.indexed.len(), 3);
+    /// ```
+    pub fn export_cache(&self) -> GlyphCache {
+        let normal = self
+            .cache
+            .iter()
+            .filter_map(|((glyph, flat, quality), mesh)| match glyph {
+                GlyphKey::Font(glyph_id) => Some(CachedGlyph {
+                    flat: *flat,
+                    glyph_id: glyph_id.0,
+                    mesh: text_mesh_from_data(mesh.to_owned(), self.glyph_advance(*glyph_id)),
+                    quality: *quality,
+                }),
+                GlyphKey::Custom(_) | GlyphKey::Fallback => None,
+            })
+            .collect();
+        let indexed = self
+            .indexed_cache
+            .iter()
+            .filter_map(|((glyph, flat, quality), mesh)| match glyph {
+                GlyphKey::Font(glyph_id) => Some(CachedGlyph {
+                    flat: *flat,
+                    glyph_id: glyph_id.0,
+                    mesh: text_mesh_from_data_indexed(
+                        mesh.to_owned(),
+                        self.glyph_advance(*glyph_id),
+                    ),
+                    quality: *quality,
+                }),
+                GlyphKey::Custom(_) | GlyphKey::Fallback => None,
+            })
+            .collect();
+
+        GlyphCache { indexed, normal }
+    }
+
+    /// Reads the cached mesh of a glyph without generating it if it is missing.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character whose mesh is requested.
+    /// * `flat`: Wether the 2D or the 3D mesh of the glyph is requested.
+    /// * `cache_type`: The [CacheType] of the cache that is read.
+    ///
+    /// Returns:
+    ///
+    /// The [CachedMesh] of the glyph generated with the current [QualitySettings],
+    /// or [None] if it is not cached.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{CacheType, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// assert!(generator.peek_glyph('a', true, CacheType::Indexed).is_none());
+    ///
+    /// generator
+    ///     .precache_glyphs("a", true, Some(CacheType::Indexed))
+    ///     .expect("Failed to precache glyphs.");
+    /// let mesh = generator
+    ///     .peek_glyph('a', true, CacheType::Indexed)
+    ///     .expect("Failed to find the cached glyph.");
+    /// assert!(mesh.indices.is_some());
+    /// assert!(generator.peek_glyph('a', true, CacheType::Normal).is_none());
+    /// ```
+    pub fn peek_glyph(
+        &self,
+        glyph: char,
+        flat: bool,
+        cache_type: CacheType,
+    ) -> Option<CachedMesh<'_>> {
+        let key = (self.glyph_key_of_char(glyph), flat, self.quality);
+        match cache_type {
+            CacheType::Normal => self.cache.peek(&key).map(|(vertices, bbox)| CachedMesh {
+                bbox: *bbox,
+                indices: None,
+                vertices,
+            }),
+            CacheType::Indexed => self
+                .indexed_cache
+                .peek(&key)
+                .map(|(indices, vertices, bbox)| CachedMesh {
+                    bbox: *bbox,
+                    indices: Some(indices),
+                    vertices,
+                }),
+        }
+    }
+
+    /// Fills the internal caches with the glyphs of a [GlyphCache].
+    ///
+    /// The glyphs must have been exported from a [MeshGenerator] using
+    /// the same font and [ExtrusionSettings]. Glyphs that are already
+    /// cached are replaced.
+    ///
+    /// This function does nothing if the current [MeshGenerator] does not have a cache.
+    ///
+    /// Arguments:
+    ///
+    /// * `cache`: The [GlyphCache] that is imported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut baker = MeshGenerator::new(font_data);
+    /// baker
+    ///     .precache_glyphs("abc", false, None)
+    ///     .expect("Failed to precache glyphs.");
+    /// let cache = baker.export_cache();
+    ///
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.import_cache(&cache);
+    /// ```
+    pub fn import_cache(&mut self, cache: &GlyphCache) {
+        for glyph in cache.normal.iter() {
+            let key = (
+                GlyphKey::Font(GlyphId(glyph.glyph_id)),
+                glyph.flat,
+                glyph.quality,
+            );
+            self.store_mesh(
+                key,
+                (raw_to_glam_vecs(&glyph.mesh.vertices), glyph.mesh.bbox),
+            );
+        }
+        for glyph in cache.indexed.iter() {
+            let key = (
+                GlyphKey::Font(GlyphId(glyph.glyph_id)),
+                glyph.flat,
+                glyph.quality,
+            );
+            self.store_mesh_indexed(
+                key,
+                (
+                    glyph.mesh.indices.to_owned(),
+                    raw_to_glam_vecs(&glyph.mesh.vertices),
+                    glyph.mesh.bbox,
+                ),
+            );
+        }
+        self.section_records.clear();
+    }
+
+    /// Generates a convex hull for each glyph of a text section.
+    ///
+    /// The hulls are closed meshes that are intended to be used as collision geometry,
+    /// so that interactive text does not need the full mesh as a collider. Glyphs
+    /// without an outline (e.g. spaces) do not produce a hull.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text for which the hulls are generated.
+    /// * `flat`: Set this to `true` for flat hulls, or to `false` in order
+    ///   to generate hulls with a depth of `1.0` units.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to the hulls.
+    ///
+    /// Returns:
+    ///
+    /// The convex hull of each glyph in the order in which the glyphs appear or
+    /// an [MeshTextError] if anything went wrong in the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let hulls = generator
+    ///     .generate_glyph_hulls("Hi there", false, None)
+    ///     .expect("Failed to generate hulls.");
+    /// assert_eq!(hulls.len(), 7);
+    /// ```
+    pub fn generate_glyph_hulls(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<Vec<IndexedMeshText>, Box<dyn MeshTextError>> {
+        let transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let glyphs = self.layout_section(text);
+        let hulls = self
+            .glyph_hull_points(&glyphs, flat)?
+            .iter()
+            .map(|(points, depth, advance)| {
+                let hull = convex_hull_2d(points);
+                let mut mesh = extrude_convex_hull(&hull, *depth);
+                transform_points(&mut mesh.1, &transform);
+                mesh.2.transform(&transform);
+
+                text_mesh_from_data_indexed(mesh, *advance)
+            })
+            .collect();
+
+        Ok(hulls)
+    }
+
+    /// Generates a single convex hull enclosing a whole text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text for which the hull is generated.
+    /// * `flat`: Set this to `true` for a flat hull, or to `false` in order
+    ///   to generate a hull with a depth of `1.0` units.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to the hull.
+    ///
+    /// Returns:
+    ///
+    /// The convex hull of the text section or an [MeshTextError] if anything
+    /// went wrong in the process.
+    pub fn generate_section_hull(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
+        let transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let glyphs = self.layout_section(text);
+        let advance = Self::section_advance(&glyphs);
+
+        let glyph_points = self.glyph_hull_points(&glyphs, flat)?;
+        let points: Vec<Vec2> = glyph_points
+            .iter()
+            .flat_map(|g| g.0.iter().copied())
+            .collect();
+        let depth = glyph_points
+            .iter()
+            .map(|g| g.1)
+            .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+            .unwrap_or((0f32, 0f32));
+
+        let hull = convex_hull_2d(&points);
+        let mut mesh = extrude_convex_hull(&hull, depth);
+        transform_points(&mut mesh.1, &transform);
+        mesh.2.transform(&transform);
+
+        Ok(text_mesh_from_data_indexed(mesh, advance))
+    }
+
+    /// Gets the kerning between two characters.
+    ///
+    /// This is the adjustment that is added to the advance of the left character
+    /// when both characters follow each other in a text section. It is read from
+    /// the pair adjustments of the `GPOS` table or from the legacy `kern` table.
+    /// With the `shaping` feature the shaper may apply further contextual
+    /// adjustments that are not part of this value.
+    ///
+    /// Arguments:
+    ///
+    /// * `left`: The character on the left side.
+    /// * `right`: The character on the right side.
+    ///
+    /// Returns:
+    ///
+    /// The kerning relative to the font size, which is `0.0` if the font has
+    /// no kerning for this pair or either character is a custom glyph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// // Monospaced fonts are not kerned.
+    /// assert_eq!(generator.kerning('A', 'V'), 0.0);
+    /// ```
+    pub fn kerning(&self, left: char, right: char) -> f32 {
+        match (self.glyph_key_of_char(left), self.glyph_key_of_char(right)) {
+            (GlyphKey::Font(left), GlyphKey::Font(right)) => self.glyph_kerning(left, right),
+            _ => 0f32,
+        }
+    }
+
+    /// Computes the positions of the text cursor within a text section.
+    ///
+    /// The carets use the same layout as the generated meshes, so they line
+    /// up with the glyphs of an untransformed section. Each caret spans from
+    /// the descender to the ascender of the font.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text for which the caret positions are computed.
+    ///
+    /// Returns:
+    ///
+    /// One [Caret] in front of each character of the text, followed by
+    /// the caret after the last character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let carets = generator.caret_positions("Hello");
+    /// assert_eq!(carets.len(), 6);
+    /// assert!(carets[0].position.x < carets[5].position.x);
+    /// ```
+    pub fn caret_positions(&self, text: &str) -> Vec<Caret> {
+        self.section_layout(text).carets
+    }
+
+    /// Lays out a text section without generating its mesh.
+    ///
+    /// The layout matches the one of the generated meshes and can
+    /// be used to interact with the text (e.g. for hit testing).
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be laid out.
+    ///
+    /// Returns:
+    ///
+    /// The [SectionLayout] of the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let layout = generator.section_layout("Hello");
+    /// assert_eq!(layout.carets.len(), 6);
+    /// ```
+    pub fn section_layout(&self, text: &str) -> SectionLayout {
+        let glyphs = self.layout_section(text);
+        let scale = self.font_scale();
+        let (ascender, descender, _) = self.vertical_metrics();
+        let height = (ascender - descender) / scale;
+        let descender = descender / scale;
+
+        SectionLayout {
+            advance: Self::section_advance(&glyphs),
+            carets: caret_positions(text, &glyphs, descender, height),
+            italic_angle: self.font.italic_angle().unwrap_or(0f32),
+        }
+    }
+
+    /// Looks up a glyph by its PostScript name.
+    ///
+    /// Icon fonts and fonts that make heavy use of the private use area often
+    /// document their glyphs by name (e.g. `"uniF013"`) instead of by character.
+    ///
+    /// Arguments:
+    ///
+    /// * `name`: The name of the glyph in the `post` or `CFF` table of the font.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphId] of the glyph or [None] if the font has no glyph with the given name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let glyph_id = generator.glyph_by_name("A").expect("Failed to find glyph.");
+    /// let mesh: IndexedMeshText = generator
+    ///     .generate_glyph_by_id(glyph_id, true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert!(!mesh.indices.is_empty());
+    /// ```
+    pub fn glyph_by_name(&self, name: &str) -> Option<GlyphId> {
+        self.font.glyph_index_by_name(name)
+    }
+
+    /// Checks wether a character is supported by the font or the [GlyphProvider].
+    ///
+    /// Arguments:
+    ///
+    /// * `character`: The character in question.
+    ///
+    /// Returns:
+    ///
+    /// `true` if the character does not have to be replaced by the fallback glyph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// assert!(generator.has_glyph('A'));
+    /// assert!(!generator.has_glyph('中'));
+    /// ```
+    pub fn has_glyph(&self, character: char) -> bool {
+        self.custom_advance(character).is_some() || self.font.glyph_index(character).is_some()
+    }
+
+    /// Gets the raster image of a glyph that has no vector outline, e.g. of a color emoji.
+    ///
+    /// Such glyphs are generated as a rectangle, onto which the image can be mapped.
+    /// The largest image of the font is returned.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character of which the image is requested.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphImage] or `None` if the glyph has a vector outline or no raster image.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// // The font only has vector outlines.
+    /// assert!(generator.glyph_image('A').is_none());
+    /// ```
+    pub fn glyph_image(&self, glyph: char) -> Option<GlyphImage> {
+        let GlyphKey::Font(glyph_id) = self.glyph_key_of_char(glyph) else {
+            return None;
+        };
+        if self.font.glyph_bounding_box(glyph_id).is_some() {
+            return None;
+        }
+
+        let (image, rect) = self.raster_image_bounds(glyph_id)?;
+        let scale = self.font_scale();
+        Some(GlyphImage {
+            data: image.data.to_vec(),
+            format: image.format,
+            height: image.height,
+            max: Vec2::new(rect.x_max as f32, rect.y_max as f32) / scale,
+            min: Vec2::new(rect.x_min as f32, rect.y_min as f32) / scale,
+            pixels_per_em: image.pixels_per_em,
+            width: image.width,
+        })
+    }
+
+    /// Gets the outline of a glyph as a [kurbo::BezPath].
+    ///
+    /// The Bézier segments of the font are preserved instead of being flattened
+    /// and the coordinates are normalized like those of the generated meshes.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character of which the outline is requested.
+    ///
+    /// Returns:
+    ///
+    /// The outline of the glyph or `None` if the glyph is not drawn from
+    /// a vector outline of the font.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let path = generator.glyph_bez_path('O').expect("The glyph has an outline.");
+    /// assert!(path.elements().len() > 2);
+    /// assert!(generator.glyph_bez_path(' ').is_none());
+    /// ```
+    #[cfg(feature = "kurbo")]
+    pub fn glyph_bez_path(&self, glyph: char) -> Option<kurbo::BezPath> {
+        let GlyphKey::Font(glyph_id) = self.glyph_key_of_char(glyph) else {
+            return None;
+        };
+
+        let mut builder = BezPathBuilder::new(self.font_scale());
+        self.font.outline_glyph(glyph_id, &mut builder)?;
+        Some(builder.into_path())
+    }
+
+    /// Gets the outline of a glyph as a [lyon_path::Path].
+    ///
+    /// The Bézier segments of the font are preserved instead of being flattened
+    /// and the coordinates are normalized like those of the generated meshes.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character of which the outline is requested.
+    ///
+    /// Returns:
+    ///
+    /// The outline of the glyph or `None` if the glyph is not drawn from
+    /// a vector outline of the font.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let path = generator.glyph_lyon_path('O').expect("The glyph has an outline.");
+    /// assert!(path.iter().count() > 2);
+    /// assert!(generator.glyph_lyon_path(' ').is_none());
+    /// ```
+    #[cfg(feature = "lyon")]
+    pub fn glyph_lyon_path(&self, glyph: char) -> Option<lyon_path::Path> {
+        let GlyphKey::Font(glyph_id) = self.glyph_key_of_char(glyph) else {
+            return None;
+        };
+
+        let mut builder = LyonPathBuilder::new(self.font_scale());
+        self.font.outline_glyph(glyph_id, &mut builder)?;
+        Some(builder.into_path())
+    }
+
+    /// Generates the [IndexedMeshText] of a single glyph of the font with a custom transformation.
+    ///
+    /// Unlike [Glyph::generate_glyph], the glyph is identified by its [GlyphId],
+    /// e.g. as returned by [MeshGenerator::glyph_by_name], so that glyphs without
+    /// a character can be generated as well.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph_id`: The id of the glyph within the font.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to the glyph.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    pub fn generate_glyph_by_id(
+        &mut self,
+        glyph_id: GlyphId,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
+        self.generate_key_indexed(GlyphKey::Font(glyph_id), flat, transform)
+    }
+
+    /// Generates a multi-channel signed distance field (MSDF) of a single character.
+    ///
+    /// The field uses the same outline as the generated meshes and can be used
+    /// to render crisp text on the GPU, even at small sizes.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character whose distance field is generated.
+    /// * `pixels_per_unit`: The resolution of the field per unit of the font size.
+    /// * `range`: The distance relative to the font size at which the field saturates.
+    ///
+    /// Returns:
+    ///
+    /// The [DistanceField] of the glyph, which is empty if the glyph has no outline.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let field = generator.generate_glyph_msdf('A', 32.0, 0.125);
+    /// assert_eq!(field.data.len(), field.width * field.height * 3);
+    /// ```
+    pub fn generate_glyph_msdf(
+        &self,
+        glyph: char,
+        pixels_per_unit: f32,
+        range: f32,
+    ) -> DistanceField {
+        let contours = self.glyph_contours(self.glyph_key_of_char(glyph));
+        multi_channel_distance_field(&contours, pixels_per_unit, range)
+    }
+
+    /// Generates a two-dimensional mesh that traces the outlines of a text section.
+    ///
+    /// The stroke is centered on the contours of the glyphs and can optionally
+    /// be interrupted by a [DashPattern], which is measured along each contour.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section whose outlines are traced.
+    /// * `width`: The width of the stroke relative to the font size.
+    /// * `dash`: The optional [DashPattern] of the stroke.
+    /// * `transform`: The optional 3x3 homogenous transformation matrix in column
+    ///   major order that will be applied to the mesh.
+    ///
+    /// Returns:
+    ///
+    /// The two-dimensional [IndexedMeshText] of the outlines or an [MeshTextError]
+    /// if anything went wrong in the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{DashPattern, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let dashed = generator
+    ///     .generate_section_outline("O", 0.02, Some(DashPattern::new(0.05, 0.05, 0.0)), None)
+    ///     .expect("Failed to generate outline.");
+    /// assert!(dashed.two_dimensional);
+    /// assert!(!dashed.indices.is_empty());
+    /// ```
+    pub fn generate_section_outline(
+        &mut self,
+        text: &str,
+        width: f32,
+        dash: Option<DashPattern>,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
+        let transform = match transform {
+            Some(value) => Mat3::from_cols_array(value),
+            None => Mat3::IDENTITY,
+        };
+
+        let glyphs = self.layout_section(text);
+        let advance = Self::section_advance(&glyphs);
+
+        let mut contours = Vec::new();
+        for glyph in glyphs.iter() {
+            for mut contour in self.glyph_contours(glyph.glyph_id) {
+                for point in contour.iter_mut() {
+                    *point += glyph.offset;
+                }
+                contours.push(contour);
+            }
+        }
+
+        let (mut indices, mut vertices, mut bbox) =
+            stroke_contours(&contours, width, dash.as_ref());
+        transform_points_2d(&mut vertices, &transform);
+        bbox.transform_2d(&transform);
+        self.quantize_indexed_2d(&mut indices, &mut vertices, &mut bbox);
+
+        Ok(text_mesh_from_data_indexed_2d(
+            (indices, vertices, bbox),
+            advance,
+        ))
+    }
+
+    /// Converts the flattened outlines of a text section into [geo::MultiPolygon]s.
+    ///
+    /// Every outer contour of a glyph becomes a polygon together with the holes
+    /// nested in it, so that the outlines can be offset, combined or simplified
+    /// with the `geo` ecosystem.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section whose outlines are converted.
+    /// * `transform`: The optional 3x3 homogenous transformation matrix in column
+    ///   major order that will be applied to the polygons.
+    ///
+    /// Returns:
+    ///
+    /// The polygons of all glyphs of the section.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let polygons = generator.section_polygons("O", None);
+    /// assert_eq!(polygons.0.len(), 1);
+    /// assert_eq!(polygons.0[0].interiors().len(), 1);
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn section_polygons(
+        &self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> geo::MultiPolygon<f32> {
+        let transform = match transform {
+            Some(value) => Mat3::from_cols_array(value),
+            None => Mat3::IDENTITY,
+        };
+
+        let mut polygons = Vec::new();
+        for glyph in self.layout_section(text).iter() {
+            let mut contours = self.glyph_contours(glyph.glyph_id);
+            for point in contours.iter_mut().flatten() {
+                *point = transform.transform_point2(*point + glyph.offset);
+            }
+            polygons.extend(contours_to_polygons(&contours));
+        }
+
+        geo::MultiPolygon::new(polygons)
+    }
+
+    /// Classifies each triangle of a text section by the glyph and the [MeshPart]
+    /// it belongs to.
+    ///
+    /// The triangles appear in the same order as in the [MeshText], [IndexedMeshText]
+    /// and [InterleavedMeshText] generated for the same text, as long as no
+    /// [MeshPostProcessor] changes the triangles.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section whose triangles are classified.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    ///
+    /// Returns:
+    ///
+    /// The [TriangleProvenance] of each triangle or an [MeshTextError]
+    /// if anything went wrong in the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, MeshPart, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let mesh: IndexedMeshText = generator
+    ///     .generate_section("Hi", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// let provenance = generator
+    ///     .triangle_provenance("Hi", false)
+    ///     .expect("Failed to classify triangles.");
+    /// assert_eq!(provenance.len(), mesh.indices.len() / 3);
+    /// assert_eq!(provenance[0].part, MeshPart::FrontCap);
+    /// ```
+    pub fn triangle_provenance(
+        &mut self,
+        text: &str,
+        flat: bool,
+    ) -> Result<Vec<TriangleProvenance>, Box<dyn MeshTextError>> {
+        let glyphs = self.layout_section(text);
+        let ((indices, vertices, _), ranges) =
+            self.build_text_section_indexed_with_ranges(&glyphs, flat, None)?;
+
+        let depth = vertices
+            .iter()
+            .fold((f32::MAX, f32::MIN), |d, v| (d.0.min(v.z), d.1.max(v.z)));
+        let characters: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+
+        let provenance = indices
+            .chunks_exact(3)
+            .map(|triangle| {
+                let corners = [
+                    vertices[triangle[0] as usize],
+                    vertices[triangle[1] as usize],
+                    vertices[triangle[2] as usize],
+                ];
+                let glyph = ranges
+                    .iter()
+                    .position(|r| r.contains(&(triangle[0] as usize)))
+                    .unwrap_or(0);
+                let cluster = glyphs.get(glyph).map_or(0, |g| g.cluster);
+
+                TriangleProvenance {
+                    character: characters.partition_point(|i| *i < cluster),
+                    glyph,
+                    part: classify_triangle(corners, depth),
+                }
+            })
+            .collect();
+
+        Ok(provenance)
+    }
+
+    /// Generates the meshes of the distinct glyphs of a text section in a single
+    /// buffer together with a draw table that places them.
+    ///
+    /// Every glyph that appears several times is only stored once, so that dynamic
+    /// text can be updated by rewriting the draw table instead of the meshes.
+    /// Deformations of the section, such as a [Bend], a displacement or a depth
+    /// gradient, can not be expressed by the transformations and are not applied.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section that will be laid out.
+    /// * `flat`: Wether the meshes are flat or have an extent of `1` unit in the z-axis.
+    /// * `transform`: The optional homogenous 4x4 transformation matrix that will be
+    ///   applied to the transformation of each glyph.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphDrawList] of the section or an [MeshTextError] if anything went wrong
+    /// in the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let list = generator
+    ///     .generate_section_draws("lol", false, None)
+    ///     .expect("Failed to generate draw list.");
+    /// assert_eq!(list.draws.len(), 3);
+    ///
+    /// // Both glyphs of 'l' share the same mesh.
+    /// assert_eq!(list.draws[0].first_index, list.draws[2].first_index);
+    /// ```
+    pub fn generate_section_draws(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<GlyphDrawList, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let glyphs = self.layout_section(text);
+        let characters: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+
+        let mut indices = Vec::new();
+        let mut vertices: Vec<Vec3A> = Vec::new();
+        let mut meshes: HashMap<GlyphKey, (Range<usize>, Range<usize>)> = HashMap::new();
+        let mut draws = Vec::with_capacity(glyphs.len());
+        let (mut min, mut max) = (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY));
+        for glyph in glyphs.iter() {
+            let (index_range, vertex_range) = match meshes.get(&glyph.glyph_id) {
+                Some(ranges) => ranges.clone(),
+                None => {
+                    let (glyph_indices, mut glyph_vertices, _) =
+                        self.load_from_cache_indexed(glyph.glyph_id, flat)?;
+                    let ranges = (
+                        indices.len()..indices.len() + glyph_indices.len(),
+                        vertices.len()..vertices.len() + glyph_vertices.len(),
+                    );
+
+                    let offset = vertices.len();
+                    for i in glyph_indices.iter() {
+                        let Some(index) = u32::from_usize(*i as usize + offset) else {
+                            return Err(Box::new(IndexOverflowError));
+                        };
+                        indices.push(index);
+                    }
+                    vertices.append(&mut glyph_vertices);
+                    meshes.insert(glyph.glyph_id, ranges.clone());
+                    ranges
+                }
+            };
+            if index_range.is_empty() {
+                continue;
+            }
+
+            let transform = base_transform * Self::glyph_transform(glyph);
+            for v in vertices[vertex_range].iter() {
+                let v = transform.transform_point3a(*v);
+                (min, max) = (min.min(v), max.max(v));
+            }
+
+            draws.push(GlyphDraw {
+                character: characters.partition_point(|i| *i < glyph.cluster),
+                first_index: index_range.start as u32,
+                glyph_id: match glyph.glyph_id {
+                    GlyphKey::Font(glyph_id) => Some(glyph_id.0),
+                    GlyphKey::Custom(_) | GlyphKey::Fallback => None,
+                },
+                index_count: index_range.len() as u32,
+                transform,
+            });
+        }
+
+        let bbox = match draws.is_empty() {
+            true => BoundingBox::empty(),
+            false => BoundingBox::new(min, max),
+        };
+
+        Ok(GlyphDrawList {
+            advance: Self::section_advance(&glyphs),
+            bbox,
+            draws,
+            indices,
+            vertices: glam_vecs_to_raw(&vertices),
+        })
+    }
+
+    /// Generates the glyphs of a text section as separate nodes that reference
+    /// the shared meshes of identical glyphs.
+    ///
+    /// This maps onto ECS or scene-graph engines, which keep each glyph as its own
+    /// entity below a parent entity that places the whole section. Deformations
+    /// of the section, such as a [Bend], a displacement or a depth gradient,
+    /// can not be expressed by the transformations and are not applied.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section that will be laid out.
+    /// * `flat`: Wether the meshes are flat or have an extent of `1` unit in the z-axis.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphScene] of the section or an [MeshTextError] if anything went wrong
+    /// in the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let scene = generator
+    ///     .generate_section_nodes("Hello", false)
+    ///     .expect("Failed to generate nodes.");
+    /// assert_eq!(scene.nodes.len(), 5);
+    /// assert_eq!(scene.meshes.len(), 4);
+    /// assert_eq!(scene.nodes[1].character, 'e');
+    /// ```
+    pub fn generate_section_nodes(
+        &mut self,
+        text: &str,
+        flat: bool,
+    ) -> Result<GlyphScene, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let glyphs = self.layout_section(text);
+
+        let mut meshes: Vec<IndexedMeshText> = Vec::new();
+        let mut mesh_indices: HashMap<GlyphKey, Option<usize>> = HashMap::new();
+        let mut nodes = Vec::with_capacity(glyphs.len());
+        let (mut min, mut max) = (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY));
+        for glyph in glyphs.iter() {
+            let mesh = match mesh_indices.get(&glyph.glyph_id) {
+                Some(mesh) => *mesh,
+                None => {
+                    let glyph_mesh = self.load_from_cache_indexed(glyph.glyph_id, flat)?;
+                    let mesh = (!glyph_mesh.0.is_empty()).then(|| {
+                        meshes.push(IndexedMeshText::from_data(glyph_mesh, glyph.advance));
+                        meshes.len() - 1
+                    });
+                    mesh_indices.insert(glyph.glyph_id, mesh);
+                    mesh
+                }
+            };
+            let Some(mesh) = mesh else {
+                continue;
+            };
+
+            let transform = Self::glyph_transform(glyph);
+            for v in meshes[mesh].vertices.chunks_exact(3) {
+                let v = transform.transform_point3a(Vec3A::new(v[0], v[1], v[2]));
+                (min, max) = (min.min(v), max.max(v));
+            }
+
+            nodes.push(GlyphNode {
+                character: text[glyph.cluster..].chars().next().unwrap_or_default(),
+                mesh,
+                transform,
+            });
+        }
+
+        let bbox = match nodes.is_empty() {
+            true => BoundingBox::empty(),
+            false => BoundingBox::new(min, max),
+        };
+
+        Ok(GlyphScene {
+            advance: Self::section_advance(&glyphs),
+            bbox,
+            meshes,
+            nodes,
+        })
+    }
+
+    /// Generates a mesh for a section of text, which is shortened with
+    /// an ellipsis if it would exceed a certain width.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section that will be transformed into a mesh.
+    /// * `max_width`: The maximum advance of the untransformed text.
+    /// * `ellipsis`: The character that replaces the tail of the text (e.g. `'…'`).
+    /// * `flat`: Wether the mesh is flat or has an extent of `1` unit in the z-axis.
+    /// * `transform`: The optional homogenous 4x4 transformation matrix that will be
+    ///   applied to each vertex of the mesh.
+    ///
+    /// Returns:
+    ///
+    /// The mesh for the given text section together with the number of characters
+    /// of the text that were kept, or an [MeshTextError] if anything went wrong
+    /// in the process. The mesh is empty if not even the ellipsis fits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let (mesh, kept): (IndexedMeshText, usize) = generator
+    ///     .generate_section_truncated("Hello World!", 3.0, '…', true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert_eq!(kept, 5);
+    /// assert!(mesh.advance <= 3.0);
+    /// ```
+    pub fn generate_section_truncated<M>(
+        &mut self,
+        text: &str,
+        max_width: f32,
+        ellipsis: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<(M, usize), Box<dyn MeshTextError>>
+    where
+        Self: TextSection<M>,
+    {
+        let glyphs = self.layout_section(text);
+        let carets = caret_positions(text, &glyphs, 0f32, 0f32);
+        if Self::section_advance(&glyphs) <= max_width {
+            let mesh = self.generate_prepared_section(text, glyphs, flat, transform)?;
+            return Ok((mesh, carets.len() - 1));
+        }
+
+        // The kerning with the ellipsis may leave room for more or fewer characters
+        // than the carets suggest, so the estimate is corrected by the actual layout.
+        let total = carets.len() - 1;
+        let available = max_width - self.key_advance(self.glyph_key_of_char(ellipsis));
+        let mut kept = carets
+            .iter()
+            .take_while(|c| c.position.x <= available)
+            .count()
+            .saturating_sub(1)
+            .min(total.saturating_sub(1));
+        let mut fitting = None;
+        let mut growing = true;
+        loop {
+            let mut truncated: String = text.chars().take(kept).collect();
+            truncated.push(ellipsis);
+            let glyphs = self.layout_section(&truncated);
+
+            if Self::section_advance(&glyphs) <= max_width {
+                fitting = Some((kept, truncated, glyphs));
+                if !growing || kept + 1 >= total {
+                    break;
+                }
+                kept += 1;
+            } else {
+                if fitting.is_some() || kept == 0 {
+                    break;
+                }
+                growing = false;
+                kept -= 1;
+            }
+        }
+
+        // Nothing is kept if not even the ellipsis fits.
+        let Some((kept, truncated, glyphs)) = fitting else {
+            return Ok((self.generate_section("", flat, transform)?, 0));
+        };
+        let mesh = self.generate_prepared_section(&truncated, glyphs, flat, transform)?;
+
+        Ok((mesh, kept))
+    }
+
+    /// Generates a mesh for a section of text at the size at which it fits a certain width.
+    ///
+    /// The scale is solved from the layout of the text, so the text is only
+    /// generated once. The depth of three-dimensional text is not scaled.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section that will be transformed into a mesh.
+    /// * `max_width`: The width that the advance of the text should fill.
+    /// * `max_height`: The optional maximum height of a line of text
+    ///   from the descender to the ascender of the font.
+    /// * `flat`: Wether the mesh is flat or has an extent of `1` unit in the z-axis.
+    /// * `transform`: The optional homogenous 4x4 transformation matrix that will be
+    ///   applied to each vertex of the scaled mesh.
+    ///
+    /// Returns:
+    ///
+    /// The mesh for the given text section together with the scale relative to the
+    /// default size, or an [MeshTextError] if anything went wrong in the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let (mesh, scale): (IndexedMeshText, f32) = generator
+    ///     .generate_section_fitted("Hello World!", 3.0, None, true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert!((mesh.advance * scale - 3.0).abs() < 1e-4);
+    /// assert!(mesh.bbox.max.x <= 3.0);
+    /// ```
+    pub fn generate_section_fitted<M>(
+        &mut self,
+        text: &str,
+        max_width: f32,
+        max_height: Option<f32>,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<(M, f32), Box<dyn MeshTextError>>
+    where
+        Self: TextSection<M>,
+    {
+        let advance = self.section_layout(text).advance;
+        let mut scale = if advance > 0f32 {
+            max_width / advance
+        } else {
+            1f32
+        };
+        if let Some(max_height) = max_height {
+            let font_scale = self.font_scale();
+            let (ascender, descender, _) = self.vertical_metrics();
+            let height = (ascender - descender) / font_scale;
+            if height > 0f32 {
+                scale = scale.min(max_height / height);
+            }
+        }
+
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+        let transform = base_transform * Mat4::from_scale(Vec3::new(scale, scale, 1f32));
+        let mesh = self.generate_section(text, flat, Some(&transform.to_cols_array()))?;
+
+        Ok((mesh, scale))
+    }
+
+    /// Generates the [IndexedMeshText] of a text section that follows a polyline in 3D space.
+    ///
+    /// Each glyph is placed rigidly at the point of the polyline that corresponds
+    /// to its horizontal center and rotated into the frame of that segment. The
+    /// frames are propagated along the polyline by parallel transport, so that the
+    /// text does not twist around a helix or while it follows a terrain.
+    /// The baseline of the text runs along the polyline, the text is oriented
+    /// upwards along the normal of the frame and faces along its binormal.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text section that will be transformed into a mesh.
+    /// * `points`: The points of the polyline, which are evaluated by arc length.
+    ///   Text that is longer than the polyline extends its last segment.
+    /// * `up`: The direction in which the text is oriented upwards at the start of the
+    ///   polyline. It is made perpendicular to the first segment.
+    /// * `flat`: Wether the mesh is flat or has an extent of `1` unit in the z-axis.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMeshText] or an [MeshTextError] if the polyline has
+    /// fewer than two distinct points or anything else went wrong in the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec3;
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// // A helix around the y-axis.
+    /// let helix: Vec<Vec3> = (0..=64)
+    ///     .map(|i| {
+    ///         let angle = i as f32 * 0.1;
+    ///         Vec3::new(3.0 * angle.cos(), 0.2 * angle, -3.0 * angle.sin())
+    ///     })
+    ///     .collect();
+    ///
+    /// let result = generator
+    ///     .generate_section_on_polyline("Spiral staircase", &helix, Vec3::Y, false)
+    ///     .expect("Failed to generate mesh.");
+    /// assert!(result.bbox.max.y > 1.0);
+    /// ```
+    pub fn generate_section_on_polyline(
+        &mut self,
+        text: &str,
+        points: &[Vec3],
+        up: Vec3,
+        flat: bool,
+    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let frames = polyline_frames(points, up);
+        if frames.is_empty() {
+            return Err(Box::new(InvalidPathError));
+        }
+
+        let glyphs = self.layout_section(text);
+        let advance = Self::section_advance(&glyphs);
+        let width = Self::section_width(&glyphs);
+
+        #[cfg(feature = "rayon")]
+        self.generate_unique_glyphs(&glyphs, flat, true)?;
+
+        let mut indices = Vec::new();
+        let mut vertices: Vec<Vec3A> = Vec::new();
+        for glyph in glyphs.iter() {
+            let (glyph_indices, mut glyph_vertices, _) =
+                self.generate_positioned_glyph_indexed(glyph, flat, Some(&Mat4::IDENTITY), width)?;
+
+            // The bounding box is computed from the vertices, since the glyphs are rotated.
+            let center = glyph.offset.x + glyph.advance / 2f32;
+            transform_points(&mut glyph_vertices, &polyline_transform(&frames, center));
+            let offset = vertices.len();
+            for i in glyph_indices.iter() {
+                let Some(index) = u32::from_usize(*i as usize + offset) else {
+                    return Err(Box::new(IndexOverflowError));
+                };
+                indices.push(index);
+            }
+            vertices.append(&mut glyph_vertices);
+        }
+
+        let mut bbox = match vertices.is_empty() {
+            true => BoundingBox::empty(),
+            false => {
+                let (min, max) = vertices.iter().fold(
+                    (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY)),
+                    |(min, max), v| (min.min(*v), max.max(*v)),
+                );
+                BoundingBox::new(min, max)
+            }
+        };
+        self.quantize_indexed(&mut indices, &mut vertices, &mut bbox);
+
+        Ok(IndexedMeshText::from_data(
+            (indices, vertices, bbox),
+            advance,
+        ))
+    }
+
+    /// Appends a section of text to an existing mesh.
+    ///
+    /// The layout continues at the [IndexedMeshText::advance] of the mesh, so that
+    /// a growing text (e.g. a chat log or a console) can extend its buffer instead
+    /// of generating the whole text again. The first appended glyph is kerned with
+    /// the last character of the previous text and tabs advance to the tab stops
+    /// of the whole line. The indices of the appended glyphs are offset and the
+    /// bounding boxes are combined.
+    ///
+    /// Arguments:
+    ///
+    /// * `mesh`: The mesh to which the text is appended.
+    /// * `previous`: The text from which the mesh was generated.
+    /// * `text`: The text section that will be appended.
+    /// * `flat`: Wether the appended text is flat or has an extent of `1` unit in the z-axis.
+    ///   This is ignored for two-dimensional meshes.
+    /// * `transform`: The optional homogenous 4x4 transformation matrix that was used to
+    ///   generate the mesh. For two-dimensional meshes the z-axis of the transformation
+    ///   is ignored.
+    ///
+    /// Returns:
+    ///
+    /// An [MeshTextError] if anything went wrong in the process, in which
+    /// case the mesh is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let mut mesh: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// generator
+    ///     .append_section(&mut mesh, "Hello", " World!", false, None)
+    ///     .expect("Failed to append text.");
+    ///
+    /// let whole: IndexedMeshText = generator
+    ///     .generate_section("Hello World!", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert_eq!(mesh.advance, whole.advance);
+    /// assert_eq!(mesh.indices.len(), whole.indices.len());
+    /// ```
+    pub fn append_section<I: IndexType>(
+        &mut self,
+        mesh: &mut IndexedMeshText<I>,
+        previous: &str,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<(), Box<dyn MeshTextError>> {
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        // The glyphs are placed behind the pen of the mesh.
+        let seam = match previous.chars().next_back() {
+            Some('\t') | None => None,
+            Some(c) => match self.glyph_key_of_char(c) {
+                GlyphKey::Font(glyph_id) => Some(glyph_id),
+                GlyphKey::Custom(_) | GlyphKey::Fallback => None,
+            },
+        };
+        let (glyphs, advance) = self.layout_section_after(text, seam, mesh.advance);
+        let (indices, vertices, bbox) = if mesh.two_dimensional {
+            let column = |c: Vec4| Vec3::new(c.x, c.y, c.w);
+            let base_transform = Mat3::from_cols(
+                column(base_transform.x_axis),
+                column(base_transform.y_axis),
+                column(base_transform.w_axis),
+            );
+            let mut section = self.build_text_section_indexed_2d(&glyphs, &base_transform)?;
+            self.quantize_indexed_2d(&mut section.0, &mut section.1, &mut section.2);
+            (section.0, glam_vecs_to_raw_2d(&section.1), section.2)
+        } else {
+            let mut section =
+                self.build_text_section_indexed(&glyphs, flat, Some(&base_transform))?;
+            self.quantize_indexed(&mut section.0, &mut section.1, &mut section.2);
+            (section.0, glam_vecs_to_raw(&section.1), section.2)
+        };
+
+        // The indices of the appended glyphs follow the existing vertices.
+        let components = if mesh.two_dimensional { 2 } else { 3 };
+        let offset = mesh.vertices.len() / components;
+        let Some(indices) = indices
+            .into_iter()
+            .map(|i| I::from_usize(i as usize + offset))
+            .collect::<Option<Vec<I>>>()
+        else {
+            return Err(Box::new(IndexOverflowError));
+        };
+
+        if !vertices.is_empty() {
+            mesh.bbox = if mesh.vertices.is_empty() {
+                bbox
+            } else {
+                mesh.bbox.combine(&bbox)
+            };
+        }
+        mesh.advance = advance;
+        mesh.indices.extend(indices);
+        mesh.vertices.extend(vertices);
+
+        Ok(())
+    }
+
+    /// Updates the mesh of a text section to a new text by only regenerating
+    /// the glyphs that changed.
+    ///
+    /// The glyphs at the start and at the end of the section that keep their position
+    /// are reused, so that frequently updating text (e.g. counters or timers) does not
+    /// need to be generated again as a whole. Glyphs are only reused if the mesh is one
+    /// of the recently generated or updated sections of this generator and no setting
+    /// that changes the meshes of the glyphs (e.g. the [QualitySettings], the
+    /// [ExtrusionSettings] or the post-processors) was changed since, otherwise the
+    /// section is regenerated completely. If the section is deformed by a [Bend],
+    /// a displacement or a depth gradient, it is regenerated completely, since the
+    /// deformation depends on the width of the whole section. The same applies if the
+    /// vertices are quantized, since snapping may remove triangles of the glyphs.
+    ///
+    /// Arguments:
+    ///
+    /// * `mesh`: The mesh that was generated for the previous text.
+    /// * `previous`: The text from which the mesh was generated.
+    /// * `text`: The new text of the section.
+    /// * `flat`: Wether the mesh is flat or has an extent of `1` unit in the z-axis.
+    ///   This is ignored for two-dimensional meshes.
+    /// * `transform`: The optional homogenous 4x4 transformation matrix that was used to
+    ///   generate the mesh. For two-dimensional meshes the z-axis of the transformation
+    ///   is ignored.
+    ///
+    /// Returns:
+    ///
+    /// The range of vertices that were regenerated or an [MeshTextError] if anything
+    /// went wrong in the process, in which case the mesh is left unchanged. The vertices
+    /// before the range are untouched and the ones after it were moved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let mut mesh: IndexedMeshText = generator
+    ///     .generate_section("Time: 0:59", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// let changed = generator
+    ///     .update_section(&mut mesh, "Time: 0:59", "Time: 1:00", false, None)
+    ///     .expect("Failed to update mesh.");
+    ///
+    /// let whole: IndexedMeshText = generator
+    ///     .generate_section("Time: 1:00", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert_eq!(mesh.vertices, whole.vertices);
+    /// assert!(changed.start > 0);
+    /// ```
+    pub fn update_section<I: IndexType>(
+        &mut self,
+        mesh: &mut IndexedMeshText<I>,
+        previous: &str,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<Range<usize>, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+        let flat = flat || mesh.two_dimensional;
+        let components = if mesh.two_dimensional { 2 } else { 3 };
+        let column = |c: Vec4| Vec3::new(c.x, c.y, c.w);
+        let base_transform_2d = Mat3::from_cols(
+            column(base_transform.x_axis),
+            column(base_transform.y_axis),
+            column(base_transform.w_axis),
+        );
+        let section_transform = match mesh.two_dimensional {
+            true => Mat4::from_mat3(base_transform_2d),
+            false => base_transform,
+        };
+
+        let previous_glyphs = self.layout_section(previous);
+        let glyphs = self.layout_section(text);
+
+        // The glyphs are only located within the mesh if it was generated with the current
+        // settings, since the records are removed whenever the meshes of the glyphs change.
+        let size = (mesh.indices.len(), mesh.vertices.len() / components);
+        let key = Self::section_key(previous, flat, mesh.two_dimensional, &section_transform);
+        let spans = self
+            .section_records
+            .get(&key, None)
+            .filter(|(bbox, spans)| {
+                let end = spans.last().map_or((0, 0), |span| (span.0, span.1));
+                *bbox == mesh.bbox && end == size && spans.len() == previous_glyphs.len()
+            })
+            .map(|(_, spans)| spans.clone());
+
+        // Glyphs are reused if they look the same and did not move.
+        let unchanged = |(a, b): &(&PositionedGlyph, &PositionedGlyph)| {
+            a.glyph_id == b.glyph_id && a.offset == b.offset && a.depth == b.depth
+        };
+        let (mut prefix, mut suffix) = (0, 0);
+        if spans.is_some() && !self.deforms_sections() && self.quantization_step().is_none() {
+            prefix = previous_glyphs
+                .iter()
+                .zip(glyphs.iter())
+                .take_while(unchanged)
+                .count();
+            suffix = previous_glyphs
+                .iter()
+                .rev()
+                .zip(glyphs.iter().rev())
+                .take(previous_glyphs.len().min(glyphs.len()) - prefix)
+                .take_while(unchanged)
+                .count();
+        }
+        let spans = spans.unwrap_or_default();
+        let end_of = |count: usize| match count {
+            0 => (0, 0),
+            count => (spans[count - 1].0, spans[count - 1].1),
+        };
+        let kept = end_of(prefix);
+        let moved_start = match suffix {
+            0 => size,
+            suffix => end_of(spans.len() - suffix),
+        };
+        let moved = (size.0 - moved_start.0, size.1 - moved_start.1);
+
+        let changed = &glyphs[prefix..glyphs.len() - suffix];
+        let (indices, vertices, changed_spans) = if mesh.two_dimensional {
+            let (mut section, ranges) =
+                self.build_text_section_indexed_2d_with_ranges(changed, &base_transform_2d)?;
+            self.quantize_indexed_2d(&mut section.0, &mut section.1, &mut section.2);
+            let changed_spans = Self::glyph_spans(&section.0, &section.1, &ranges);
+            (section.0, glam_vecs_to_raw_2d(&section.1), changed_spans)
+        } else {
+            let (mut section, ranges) =
+                self.build_text_section_indexed_with_ranges(changed, flat, Some(&base_transform))?;
+            self.quantize_indexed(&mut section.0, &mut section.1, &mut section.2);
+            let changed_spans = Self::glyph_spans(&section.0, &section.1, &ranges);
+            (section.0, glam_vecs_to_raw(&section.1), changed_spans)
+        };
+        let added = (indices.len(), vertices.len() / components);
+        let removed = (size.0 - moved.0 - kept.0, size.1 - moved.1 - kept.1);
+
+        // The indices of the regenerated glyphs follow the kept vertices and
+        // the indices of the moved glyphs follow the regenerated vertices.
+        let vertex_count = kept.1 + added.1 + moved.1;
+        if vertex_count > 0 && I::from_usize(vertex_count - 1).is_none() {
+            return Err(Box::new(IndexOverflowError));
+        }
+        let convert = |i: usize| I::from_usize(i).expect("Index overflow was checked before.");
+        if added.1 != removed.1 {
+            for i in mesh.indices[size.0 - moved.0..].iter_mut() {
+                *i = convert(i.to_usize() + added.1 - removed.1);
+            }
+        }
+        mesh.indices.splice(
+            kept.0..size.0 - moved.0,
+            indices.into_iter().map(|i| convert(i as usize + kept.1)),
+        );
+        mesh.vertices.splice(
+            kept.1 * components..(size.1 - moved.1) * components,
+            vertices,
+        );
+
+        // The reused glyphs keep their bounding boxes, so that only
+        // the vertices of the regenerated glyphs are scanned.
+        let shift = |span: &GlyphSpan, from: (usize, usize), to: (usize, usize)| {
+            (span.0 - from.0 + to.0, span.1 - from.1 + to.1, span.2)
+        };
+        let new_spans: Vec<GlyphSpan> = spans[..prefix]
+            .iter()
+            .copied()
+            .chain(changed_spans.iter().map(|span| shift(span, (0, 0), kept)))
+            .chain(
+                spans[spans.len() - suffix..]
+                    .iter()
+                    .map(|span| shift(span, moved_start, (kept.0 + added.0, kept.1 + added.1))),
+            )
+            .collect();
+        mesh.bbox = new_spans
+            .iter()
+            .filter_map(|span| span.2)
+            .reduce(|a, b| a.combine(&b))
+            .unwrap_or_else(BoundingBox::empty);
+        mesh.advance = Self::section_advance(&glyphs);
+
+        let key = Self::section_key(text, flat, mesh.two_dimensional, &section_transform);
+        self.record_section(key, (mesh.bbox, new_spans));
+
+        Ok(kept.1..kept.1 + added.1)
+    }
+
+    /// Generates a single mesh for a [Paragraph] that is wrapped into multiple lines.
+    ///
+    /// The baseline of the first line is at `y = 0` and each following line
+    /// is placed below the previous one.
+    ///
+    /// Arguments:
+    ///
+    /// * `paragraph`: The [Paragraph] that will be transformed into a mesh.
+    /// * `flat`: Wether the mesh is flat or has an extent of `1` unit in the z-axis.
+    /// * `transform`: The optional homogenous 4x4 transformation matrix that will be
+    ///   applied to each vertex of the mesh.
+    ///
+    /// Returns:
+    ///
+    /// The mesh of the whole paragraph together with the [LineMetrics] of each line,
+    /// or an [MeshTextError] if anything went wrong in the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{MeshGenerator, Paragraph};
+    ///
+    /// let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let paragraph = Paragraph::new().with_run("Hello\nWorld!");
+    /// let (mesh, lines) = generator
+    ///     .generate_paragraph(&paragraph, false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// assert_eq!(lines.len(), 2);
+    /// assert!(lines[1].baseline < lines[0].baseline);
+    /// assert_eq!(mesh.advance, lines[1].advance);
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(runs = paragraph.runs.len())))]
+    pub fn generate_paragraph(
+        &mut self,
+        paragraph: &Paragraph,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<(IndexedMeshText, Vec<LineMetrics>), Box<dyn MeshTextError>> {
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let text = paragraph.text();
+        let scale = self.font_scale();
+        let (ascender, descender, line_gap) = self.vertical_metrics();
+        let line_height = (ascender - descender + line_gap) / scale * paragraph.line_spacing;
+
+        // Words are hyphenated by the dictionary of the paragraph, if there is one,
+        // and a hyphen is added to the lines that are broken within a word.
+        let hyphen_advance = self.glyph_advance(self.glyph_id_of_char(HYPHEN));
+        #[cfg(feature = "hyphenation")]
+        let hyphenation_points = |word: &str| match paragraph.hyphenation.as_ref() {
+            Some(dictionary) => dictionary_hyphenation_points(dictionary, word),
+            None => Vec::new(),
+        };
+        #[cfg(not(feature = "hyphenation"))]
+        let hyphenation_points = |_: &str| Vec::new();
+
+        // Wrap each line that was ended by a line feed on its own.
+        let mut lines: Vec<(String, Range<usize>, bool)> = Vec::new();
+        let mut first_character = 0;
+        for hard_line in text.split('\n') {
+            let (hard_line, indices) = apply_whitespace_mode(hard_line, paragraph.whitespace);
+            let positions: Vec<f32> = self
+                .section_layout(&hard_line)
+                .carets
+                .iter()
+                .map(|c| c.position.x)
+                .collect();
+            let characters: Vec<char> = hard_line.chars().collect();
+
+            for (range, hyphenated) in wrap_line(
+                &hard_line,
+                &positions,
+                paragraph.max_width,
+                hyphenation_points,
+                hyphen_advance,
+            ) {
+                let mut line: String = characters[range.clone()].iter().collect();
+                if paragraph.whitespace != WhitespaceMode::Preserve {
+                    line.truncate(line.trim_end_matches(is_collapsible_whitespace).len());
+                }
+                if hyphenated {
+                    line.push(HYPHEN);
+                }
+                // The first line also covers the whitespace that was trimmed at its start.
+                let start = if range.start == 0 {
+                    0
+                } else {
+                    indices[range.start]
+                };
+                let characters = first_character + start..first_character + indices[range.end];
+                lines.push((line, characters, hyphenated));
+            }
+            first_character += indices[characters.len()] + 1;
+        }
+
+        let laid_out: Vec<Vec<PositionedGlyph>> = lines
+            .iter()
+            .map(|(line, characters, _)| {
+                let mut glyphs = self.layout_section(line);
+                self.assign_depths(line, &mut glyphs, characters.start);
+                glyphs
+            })
+            .collect();
+        let advances: Vec<f32> = laid_out.iter().map(|g| Self::section_advance(g)).collect();
+        let width = paragraph
+            .max_width
+            .unwrap_or_else(|| advances.iter().copied().fold(0f32, f32::max));
+
+        let mut glyphs = Vec::new();
+        let mut metrics = Vec::with_capacity(lines.len());
+        for (i, ((_, characters, hyphenated), line_glyphs)) in
+            lines.into_iter().zip(laid_out).enumerate()
+        {
+            let advance = advances[i];
+            let offset = match paragraph.alignment {
+                TextAlignment::Left => 0f32,
+                TextAlignment::Center => (width - advance) * 0.5f32,
+                TextAlignment::Right => width - advance,
+            };
+            let baseline = -(i as f32) * line_height;
+
+            glyphs.extend(line_glyphs.into_iter().map(|mut glyph| {
+                glyph.offset += Vec2::new(offset, baseline);
+                glyph
+            }));
+            metrics.push(LineMetrics {
+                advance,
+                baseline,
+                characters,
+                hyphenated,
+                offset,
+            });
+        }
+
+        let mut mesh = self.build_text_section_indexed(&glyphs, flat, Some(&base_transform))?;
+        self.quantize_indexed(&mut mesh.0, &mut mesh.1, &mut mesh.2);
+        let advance = metrics.last().map(|l| l.offset + l.advance).unwrap_or(0f32);
+
+        Ok((text_mesh_from_data_indexed(mesh, advance), metrics))
+    }
+
+    /// Generates the [MeshText] of a single character with a custom transformation.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    /// to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The 4x4 homogenous transformation matrix in column
+    /// major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_glyph<M: FromMeshData>(
+        &mut self,
+        glyph: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let glyph_id = self.glyph_key_of_char(glyph);
+        let advance = self.key_advance(glyph_id);
+        let mut mesh = self.load_from_cache(glyph_id, flat)?;
+
+        if let Some(value) = transform {
+            let transform = Mat4::from_cols_array(value);
+
+            transform_points(&mut mesh.0, &transform);
+            mesh.1.transform(&transform);
+        }
+        self.quantize(&mut mesh.0, &mut mesh.1);
+
+        Ok(M::from_data(mesh, advance))
+    }
+
+    /// Generates the two-dimensional [MeshText] of a single character with a custom transformation.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character that should be converted to a mesh.
+    /// * `transform`: The 3x3 homogenous transformation matrix in column
+    /// major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired two-dimensional [MeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_glyph_2d<M: FromMeshData>(
+        &mut self,
+        glyph: char,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let glyph_id = self.glyph_key_of_char(glyph);
+        let advance = self.key_advance(glyph_id);
+        let mesh = self.load_from_cache(glyph_id, true)?;
+        let mut mesh = mesh_to_flat_2d(mesh);
+
+        if let Some(value) = transform {
+            let transform = Mat3::from_cols_array(value);
+
+            transform_points_2d(&mut mesh.0, &transform);
+            mesh.1.transform_2d(&transform);
+        }
+        self.quantize_2d(&mut mesh.0, &mut mesh.1);
+
+        Ok(M::from_data_2d(mesh, advance))
+    }
+
+    /// Generates the [IndexedMeshText] of a single character with a custom transformation.
+    ///
+    /// This function generates a mesh with indices and vertices.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    /// to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The 4x4 homogenous transformation matrix in column
+    /// major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_glyph_indexed<M: FromIndexedMeshData>(
+        &mut self,
+        glyph: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        let glyph_id = self.glyph_key_of_char(glyph);
+        self.generate_key_indexed(glyph_id, flat, transform)
+    }
+
+    /// Generates the indexed mesh of a single glyph with a custom transformation.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph_id`: The key of the glyph that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to the glyph.
+    ///
+    /// Returns:
+    ///
+    /// The desired mesh or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_key_indexed<M: FromIndexedMeshData>(
+        &mut self,
+        glyph_id: GlyphKey,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let advance = self.key_advance(glyph_id);
+        let mut mesh = self.load_from_cache_indexed(glyph_id, flat)?;
+
+        if let Some(value) = transform {
+            let transform = Mat4::from_cols_array(value);
+
+            transform_points(&mut mesh.1, &transform);
+            mesh.2.transform(&transform);
+        }
+        self.quantize_indexed(&mut mesh.0, &mut mesh.1, &mut mesh.2);
+
+        Ok(M::from_data(mesh, advance))
+    }
+
+    /// Generates the two-dimensional [IndexedMeshText] of a single character
+    /// with a custom transformation.
+    ///
+    /// This function generates a mesh with indices and vertices.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character that should be converted to a mesh.
+    /// * `transform`: The 3x3 homogenous transformation matrix in column
+    /// major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_glyph_indexed_2d<M: FromIndexedMeshData>(
+        &mut self,
+        glyph: char,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let glyph_id = self.glyph_key_of_char(glyph);
+        let advance = self.key_advance(glyph_id);
+        let mesh = self.load_from_cache_indexed(glyph_id, true)?;
+        let mut mesh = mesh_to_indexed_flat_2d(mesh);
+
+        if let Some(value) = transform {
+            let transform = Mat3::from_cols_array(value);
+
+            transform_points_2d(&mut mesh.1, &transform);
+            mesh.2.transform_2d(&transform);
+        }
+        self.quantize_indexed_2d(&mut mesh.0, &mut mesh.1, &mut mesh.2);
+
+        Ok(M::from_data_2d(mesh, advance))
+    }
+
+    /// Generates the [Mesh] of a single glyph with a custom transformation given
+    /// as a [Mat4].
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The id of the glyph that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    /// to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The 4x4 homogenous transformation matrix.
+    ///
+    /// Returns:
+    ///
+    /// The desired [Mesh] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    pub(crate) fn generate_glyph_with_glam_transform(
+        &mut self,
+        glyph: GlyphKey,
+        flat: bool,
+        transform: &Mat4,
+    ) -> Result<Mesh, Box<dyn MeshTextError>> {
+        let mut mesh = self.load_from_cache(glyph, flat)?;
+
+        transform_points(&mut mesh.0, transform);
+        mesh.1.transform(transform);
+
+        Ok(mesh)
+    }
+
+    /// Generates the [Mesh2D] of a single glyph with a custom transformation given
+    /// as a [Mat3].
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The id of the glyph that should be converted to a mesh.
+    /// * `transform`: The 3x3 homogenous transformation matrix.
+    ///
+    /// Returns:
+    ///
+    /// The desired [Mesh2D] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    pub(crate) fn generate_glyph_with_glam_transform_2d(
+        &mut self,
+        glyph: GlyphKey,
+        transform: &Mat3,
+    ) -> Result<Mesh2D, Box<dyn MeshTextError>> {
+        let mesh = self.load_from_cache(glyph, true)?;
+        let mut mesh = mesh_to_flat_2d(mesh);
+
+        transform_points_2d(&mut mesh.0, transform);
+        mesh.1.transform_2d(transform);
+
+        Ok(mesh)
+    }
+
+    /// Generates the [IndexedMesh] of a single glyph with a custom transformation given
+    /// as a [Mat4].
+    ///
+    /// This function handles indexed meshes.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The id of the glyph that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    /// to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The 4x4 homogenous transformation matrix.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMesh] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    pub(crate) fn generate_glyph_with_glam_transform_indexed(
+        &mut self,
+        glyph: GlyphKey,
+        flat: bool,
+        transform: &Mat4,
+    ) -> Result<IndexedMesh, Box<dyn MeshTextError>> {
+        let mut mesh = self.load_from_cache_indexed(glyph, flat)?;
+
+        transform_points(&mut mesh.1, transform);
+        mesh.2.transform(transform);
+
+        Ok(mesh)
+    }
+
+    /// Generates the [IndexedMesh2D] of a single glyph with a custom transformation given
+    /// as a [Mat3].
+    ///
+    /// This function handles indexed meshes.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The id of the glyph that should be converted to a mesh.
+    /// * `transform`: The 3x3 homogenous transformation matrix.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMesh2D] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    pub(crate) fn generate_glyph_with_glam_transform_indexed_2d(
+        &mut self,
+        glyph: GlyphKey,
+        transform: &Mat3,
+    ) -> Result<IndexedMesh2D, Box<dyn MeshTextError>> {
+        let mesh = self.load_from_cache_indexed(glyph, true)?;
+        let mut mesh = mesh_to_indexed_flat_2d(mesh);
+
+        transform_points_2d(&mut mesh.1, transform);
+        mesh.2.transform_2d(transform);
+
+        Ok(mesh)
+    }
+
+    /// Generates the [Mesh] of a laid out glyph within a text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The laid out glyph.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `base_transform`: The 4x4 homogenous transformation matrix that will be
+    ///   applied to the text section.
+    /// * `width`: The width of the text section.
+    ///
+    /// Returns:
+    ///
+    /// The desired [Mesh] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_positioned_glyph(
+        &mut self,
+        glyph: &PositionedGlyph,
+        flat: bool,
+        base_transform: &Mat4,
+        width: f32,
+    ) -> Result<Mesh, Box<dyn MeshTextError>> {
+        if !self.deforms_sections() {
+            let transform = *base_transform * Self::glyph_transform(glyph);
+            return self.generate_glyph_with_glam_transform(glyph.glyph_id, flat, &transform);
+        }
+
+        let transform = Self::glyph_transform(glyph);
+        let mut mesh = self.generate_glyph_with_glam_transform(glyph.glyph_id, flat, &transform)?;
+        let bbox = self.deform_section(&mut mesh.0, mesh.1, flat, width);
+        mesh.1 = transform_vertices(&mut mesh.0, bbox, base_transform);
+
+        Ok(mesh)
+    }
+
+    /// Generates the [IndexedMesh] of a laid out glyph within a text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The laid out glyph.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `base_transform`: The 4x4 homogenous transformation matrix that will be
+    ///   applied to the text section, or [None] in order to keep the glyph
+    ///   undeformed and untransformed.
+    /// * `width`: The width of the text section.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMesh] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_positioned_glyph_indexed(
+        &mut self,
+        glyph: &PositionedGlyph,
+        flat: bool,
+        base_transform: Option<&Mat4>,
+        width: f32,
+    ) -> Result<IndexedMesh, Box<dyn MeshTextError>> {
+        let Some(base_transform) = base_transform else {
+            let transform = Self::glyph_transform(glyph);
+            return self.generate_glyph_with_glam_transform_indexed(
+                glyph.glyph_id,
+                flat,
+                &transform,
+            );
+        };
+        if !self.deforms_sections() {
+            let transform = *base_transform * Self::glyph_transform(glyph);
+            return self.generate_glyph_with_glam_transform_indexed(
+                glyph.glyph_id,
+                flat,
+                &transform,
+            );
+        }
+
+        let transform = Self::glyph_transform(glyph);
+        let mut mesh =
+            self.generate_glyph_with_glam_transform_indexed(glyph.glyph_id, flat, &transform)?;
+        let bbox = self.deform_section(&mut mesh.1, mesh.2, flat, width);
+        mesh.2 = transform_vertices(&mut mesh.1, bbox, base_transform);
+
+        Ok(mesh)
+    }
+
+    /// Checks wether text sections are deformed before they are transformed.
+    ///
+    /// Returns:
+    ///
+    /// `true` if a depth gradient, a displacement or a [Bend] is set.
+    fn deforms_sections(&self) -> bool {
+        self.depth_gradient.is_some() || self.displacement.is_some() || self.bend.is_some()
+    }
+
+    /// Applies the depth gradient, the displacement and the [Bend] to the vertices of an
+    /// untransformed text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `vertices`: The vertices in the coordinate system of the untransformed section.
+    /// * `bbox`: The untransformed [BoundingBox] of the vertices.
+    /// * `flat`: Wether the vertices belong to a flat mesh.
+    /// * `width`: The width of the section.
+    ///
+    /// Returns:
+    ///
+    /// The [BoundingBox] of the deformed vertices.
+    fn deform_section(
+        &self,
+        vertices: &mut [Vec3A],
+        bbox: BoundingBox,
+        flat: bool,
+        width: f32,
+    ) -> BoundingBox {
+        if vertices.is_empty() || !self.deforms_sections() {
+            return bbox;
+        }
+
+        let (mut min, mut max) = (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY));
+        for v in vertices.iter_mut() {
+            if let Some(gradient) = self.depth_gradient {
+                let t = if width > 0f32 {
+                    (v.x / width).clamp(0f32, 1f32)
+                } else {
+                    0f32
+                };
+                v.z *= gradient.0 + (gradient.1 - gradient.0) * t;
+            }
+
+            // The glyphs are centered about the XY-plane, so the front cap is in front of it.
+            if let Some(displacement) = self.displacement.as_ref() {
+                if flat || v.z > 0f32 {
+                    v.z += displacement(v.x, v.y);
+                }
+            }
+
+            if let Some(bend) = self.bend {
+                *v = bend.apply(*v, width * 0.5f32);
+            }
+
+            (min, max) = (min.min(*v), max.max(*v));
+        }
+
+        BoundingBox::new(min, max)
+    }
+
+    /// Generates the [MeshText] of a given text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    /// to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    /// major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_text_section<M: FromMeshData>(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let mut mesh = (Vec::new(), BoundingBox::empty());
+
+        let glyphs = self.take_layout(text);
+        let advance = Self::section_advance(&glyphs);
+        let width = Self::section_width(&glyphs);
+
+        #[cfg(feature = "rayon")]
+        self.generate_unique_glyphs(&glyphs, flat, false)?;
+
+        let mut glyphs_iter = glyphs.into_iter();
+
+        // The first glyph will be handled differently if present.
+        if let Some(first_glyph) = glyphs_iter.next() {
+            let mut glyph_mesh =
+                self.generate_positioned_glyph(&first_glyph, flat, &base_transform, width)?;
+
+            // Add vertices and replace bbox.
+            mesh.0.append(&mut glyph_mesh.0);
+            mesh = (mesh.0, glyph_mesh.1);
+        }
+
+        for glyph in glyphs_iter {
+            let mut glyph_mesh =
+                self.generate_positioned_glyph(&glyph, flat, &base_transform, width)?;
+
+            // Add vertices and adjust bbox.
+            mesh.0.append(&mut glyph_mesh.0);
+            mesh = (mesh.0, mesh.1.combine(&glyph_mesh.1));
+        }
+        self.quantize(&mut mesh.0, &mut mesh.1);
+
+        Ok(M::from_data(mesh, advance))
+    }
+
+    /// Generates two-dimensional [MeshText] for a given text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be converted to a mesh.
+    /// * `transform`: The optional 3x3 homogenous transformation matrix in column
+    /// major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_text_section_2d<M: FromMeshData>(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let base_transform = match transform {
+            Some(value) => Mat3::from_cols_array(value),
+            None => Mat3::IDENTITY,
+        };
+
+        let mut mesh = (Vec::new(), BoundingBox::empty());
+
+        let glyphs = self.layout_section(text);
+        let advance = Self::section_advance(&glyphs);
+
+        #[cfg(feature = "rayon")]
+        self.generate_unique_glyphs(&glyphs, true, false)?;
+
+        let mut glyphs_iter = glyphs.into_iter();
+
+        // The first glyph will be handled differently if present.
+        if let Some(first_glyph) = glyphs_iter.next() {
+            let transform = base_transform * Mat3::from_translation(first_glyph.offset);
+            let mut glyph_mesh =
+                self.generate_glyph_with_glam_transform_2d(first_glyph.glyph_id, &transform)?;
+
+            // Add vertices and replace bbox.
+            mesh.0.append(&mut glyph_mesh.0);
+            mesh = (mesh.0, glyph_mesh.1);
+        }
+
+        for glyph in glyphs_iter {
+            let transform = base_transform * Mat3::from_translation(glyph.offset);
+            let mut glyph_mesh =
+                self.generate_glyph_with_glam_transform_2d(glyph.glyph_id, &transform)?;
+
+            // Add vertices and adjust bbox.
+            mesh.0.append(&mut glyph_mesh.0);
+            mesh = (mesh.0, mesh.1.combine(&glyph_mesh.1));
+        }
+        self.quantize_2d(&mut mesh.0, &mut mesh.1);
+
+        Ok(M::from_data_2d(mesh, advance))
+    }
+
+    /// Generates the [MeshText] of a given text section.
+    ///
+    /// This function handles indexed meshes.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    /// to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    /// major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_text_section_indexed<M: FromIndexedMeshData>(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let glyphs = self.take_layout(text);
+        let advance = Self::section_advance(&glyphs);
+        let (mut mesh, ranges) =
+            self.build_text_section_indexed_with_ranges(&glyphs, flat, Some(&base_transform))?;
+        self.quantize_indexed(&mut mesh.0, &mut mesh.1, &mut mesh.2);
+        self.record_section(
+            Self::section_key(text, flat, false, &base_transform),
+            (mesh.2, Self::glyph_spans(&mesh.0, &mesh.1, &ranges)),
+        );
+
+        Ok(M::from_data(mesh, advance))
+    }
+
+    /// Generates the [IndexedMesh] of a given text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The laid out glyphs of the text section.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `base_transform`: The 4x4 homogenous transformation matrix that will be
+    ///   applied to this text, or [None] in order to keep the text undeformed and
+    ///   untransformed.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMesh] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn build_text_section_indexed(
+        &mut self,
+        glyphs: &[PositionedGlyph],
+        flat: bool,
+        base_transform: Option<&Mat4>,
+    ) -> Result<IndexedMesh, Box<dyn MeshTextError>> {
+        self.build_text_section_indexed_with_ranges(glyphs, flat, base_transform)
+            .map(|(mesh, _)| mesh)
+    }
+
+    /// Generates the [IndexedMesh] of a given text section together with the
+    /// vertices that belong to each glyph.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The laid out glyphs of the text section.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `base_transform`: The 4x4 homogenous transformation matrix that will be
+    ///   applied to this text, or [None] in order to keep the text undeformed and
+    ///   untransformed.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMesh] and the range of vertices of each glyph or an
+    /// [MeshTextError] if anything went wrong in the process.
+    fn build_text_section_indexed_with_ranges(
+        &mut self,
+        glyphs: &[PositionedGlyph],
+        flat: bool,
+        base_transform: Option<&Mat4>,
+    ) -> Result<SectionMesh, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let mut mesh = (Vec::new(), Vec::new(), BoundingBox::empty());
+        let mut ranges = Vec::with_capacity(glyphs.len());
+        let mut index_offset = 0;
+        let width = Self::section_width(glyphs);
+
+        #[cfg(feature = "rayon")]
+        self.generate_unique_glyphs(glyphs, flat, true)?;
+
+        let mut glyphs_iter = glyphs.iter();
+
+        // The first glyph will be handled differently if present.
+        if let Some(first_glyph) = glyphs_iter.next() {
+            let mut glyph_mesh =
+                self.generate_positioned_glyph_indexed(first_glyph, flat, base_transform, width)?;
+            ranges.push(0..glyph_mesh.1.len());
+
+            // Update index offset (note that glyph meshes can be empty).
+            if let Some(max) = glyph_mesh.0.iter().max() {
+                index_offset = *max + 1;
+            }
+
+            // Add vertices and replace bbox.
+            mesh.0.append(&mut glyph_mesh.0);
+            mesh.1.append(&mut glyph_mesh.1);
+            mesh = (mesh.0, mesh.1, glyph_mesh.2);
+        }
+
+        for glyph in glyphs_iter {
+            let mut glyph_mesh =
+                self.generate_positioned_glyph_indexed(glyph, flat, base_transform, width)?;
+            ranges.push(mesh.1.len()..mesh.1.len() + glyph_mesh.1.len());
+
+            // Offset indices.
+            for i in glyph_mesh.0.iter_mut() {
+                *i += index_offset;
+            }
+
+            // Update index offset (note that glyph meshes can be empty).
+            if let Some(max) = glyph_mesh.0.iter().max() {
+                index_offset = *max + 1;
+            }
+
+            // Add vertices and indices and adjust bbox.
+            mesh.0.append(&mut glyph_mesh.0);
+            mesh.1.append(&mut glyph_mesh.1);
+            mesh = (mesh.0, mesh.1, mesh.2.combine(&glyph_mesh.2));
+        }
+
+        Ok((mesh, ranges))
+    }
+
+    /// Generates two-dimensional [MeshText] for a given text section.
+    ///
+    /// This function handles indexed meshes.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be converted to a mesh.
+    /// * `transform`: The optional 3x3 homogenous transformation matrix in column
+    /// major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_text_section_indexed_2d<M: FromIndexedMeshData>(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<M, Box<dyn MeshTextError>> {
+        let base_transform = match transform {
+            Some(value) => Mat3::from_cols_array(value),
+            None => Mat3::IDENTITY,
+        };
+
+        let glyphs = self.layout_section(text);
+        let advance = Self::section_advance(&glyphs);
+        let (mut mesh, ranges) =
+            self.build_text_section_indexed_2d_with_ranges(&glyphs, &base_transform)?;
+        self.quantize_indexed_2d(&mut mesh.0, &mut mesh.1, &mut mesh.2);
+        self.record_section(
+            Self::section_key(text, true, true, &Mat4::from_mat3(base_transform)),
+            (mesh.2, Self::glyph_spans(&mesh.0, &mesh.1, &ranges)),
+        );
+
+        Ok(M::from_data_2d(mesh, advance))
+    }
+
+    /// Generates the [IndexedMesh2D] of a given text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The laid out glyphs of the text section.
+    /// * `base_transform`: The 3x3 homogenous transformation matrix that will be
+    ///   applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMesh2D] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn build_text_section_indexed_2d(
+        &mut self,
+        glyphs: &[PositionedGlyph],
+        base_transform: &Mat3,
+    ) -> Result<IndexedMesh2D, Box<dyn MeshTextError>> {
+        self.build_text_section_indexed_2d_with_ranges(glyphs, base_transform)
+            .map(|(mesh, _)| mesh)
+    }
+
+    /// Generates the [IndexedMesh2D] of a given text section together with the
+    /// vertices that belong to each glyph.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The laid out glyphs of the text section.
+    /// * `base_transform`: The 3x3 homogenous transformation matrix that will be
+    ///   applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [IndexedMesh2D] and the range of vertices of each glyph or an
+    /// [MeshTextError] if anything went wrong in the process.
+    fn build_text_section_indexed_2d_with_ranges(
+        &mut self,
+        glyphs: &[PositionedGlyph],
+        base_transform: &Mat3,
+    ) -> Result<SectionMesh2D, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let mut mesh = (Vec::new(), Vec::new(), BoundingBox::empty());
+        let mut ranges = Vec::with_capacity(glyphs.len());
+        let mut index_offset = 0;
+
+        #[cfg(feature = "rayon")]
+        self.generate_unique_glyphs(glyphs, true, true)?;
+
+        let mut glyphs_iter = glyphs.iter();
+
+        // The first glyph will be handled differently if present.
+        if let Some(first_glyph) = glyphs_iter.next() {
+            let transform = *base_transform * Mat3::from_translation(first_glyph.offset);
+            let mut glyph_mesh = self
+                .generate_glyph_with_glam_transform_indexed_2d(first_glyph.glyph_id, &transform)?;
+            ranges.push(0..glyph_mesh.1.len());
+
+            // Update index offset (note that glyph meshes can be empty).
+            if let Some(max) = glyph_mesh.0.iter().max() {
+                index_offset = *max + 1;
+            }
+
+            // Add vertices and replace bbox.
+            mesh.0.append(&mut glyph_mesh.0);
+            mesh.1.append(&mut glyph_mesh.1);
+            mesh = (mesh.0, mesh.1, glyph_mesh.2);
+        }
+
+        for glyph in glyphs_iter {
+            let transform = *base_transform * Mat3::from_translation(glyph.offset);
+            let mut glyph_mesh =
+                self.generate_glyph_with_glam_transform_indexed_2d(glyph.glyph_id, &transform)?;
+            ranges.push(mesh.1.len()..mesh.1.len() + glyph_mesh.1.len());
+
+            // Offset indices.
+            for i in glyph_mesh.0.iter_mut() {
+                *i += index_offset;
+            }
+
+            // Update index offset (note that glyph meshes can be empty).
+            if let Some(max) = glyph_mesh.0.iter().max() {
+                index_offset = *max + 1;
+            }
+
+            // Add vertices and indices and adjust bbox.
+            mesh.0.append(&mut glyph_mesh.0);
+            mesh.1.append(&mut glyph_mesh.1);
+            mesh = (mesh.0, mesh.1, mesh.2.combine(&glyph_mesh.2));
+        }
+
+        Ok((mesh, ranges))
+    }
+
+    /// Generates the [InterleavedMeshText] of a single character with a custom transformation.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [InterleavedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_glyph_interleaved(
+        &mut self,
+        glyph: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<InterleavedMeshText, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let glyph_id = self.glyph_key_of_char(glyph);
+
+        let advance = self.key_advance(glyph_id);
+
+        let mesh = self.load_from_cache_indexed(glyph_id, flat)?;
+
+        let vertices = 0..mesh.1.len();
+        Ok(interleaved_mesh_from_data(
+            mesh,
+            &[vertices],
+            advance,
+            |_, bbox| bbox,
+            &transform,
+            &self.vertex_layout,
+            self.quantization_step(),
+        ))
+    }
+
+    /// Generates the two-dimensional [InterleavedMeshText] of a single character
+    /// with a custom transformation.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character that should be converted to a mesh.
+    /// * `transform`: The 3x3 homogenous transformation matrix in column
+    ///   major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [InterleavedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_glyph_interleaved_2d(
+        &mut self,
+        glyph: char,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<InterleavedMeshText, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let transform = match transform {
+            Some(value) => Mat3::from_cols_array(value),
+            None => Mat3::IDENTITY,
+        };
+
+        let glyph_id = self.glyph_key_of_char(glyph);
+
+        let advance = self.key_advance(glyph_id);
+
+        let mesh = self.load_from_cache_indexed(glyph_id, true)?;
+        let mesh = mesh_to_indexed_flat_2d(mesh);
+
+        let vertices = 0..mesh.1.len();
+        Ok(interleaved_mesh_from_data_2d(
+            mesh,
+            &[vertices],
+            advance,
+            &transform,
+            &self.vertex_layout,
+            self.quantization_step(),
+        ))
+    }
+
+    /// Generates the [InterleavedMeshText] of a given text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [InterleavedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_text_section_interleaved(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<InterleavedMeshText, Box<dyn MeshTextError>> {
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        // The section is deformed and transformed during interleaving, so that the
+        // texture coordinates can be computed on the untransformed section.
+        let glyphs = self.take_layout(text);
+        let advance = Self::section_advance(&glyphs);
+        let width = Self::section_width(&glyphs);
+        let (mesh, ranges) = self.build_text_section_indexed_with_ranges(&glyphs, flat, None)?;
+
+        Ok(interleaved_mesh_from_data(
+            mesh,
+            &ranges,
+            advance,
+            |v, bbox| self.deform_section(v, bbox, flat, width),
+            &base_transform,
+            &self.vertex_layout,
+            self.quantization_step(),
+        ))
+    }
+
+    /// Generates the two-dimensional [InterleavedMeshText] of a given text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be converted to a mesh.
+    /// * `transform`: The optional 3x3 homogenous transformation matrix in column
+    ///   major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [InterleavedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_text_section_interleaved_2d(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<InterleavedMeshText, Box<dyn MeshTextError>> {
+        let base_transform = match transform {
+            Some(value) => Mat3::from_cols_array(value),
+            None => Mat3::IDENTITY,
+        };
+
+        let glyphs = self.layout_section(text);
+        let advance = Self::section_advance(&glyphs);
+        let (mesh, ranges) =
+            self.build_text_section_indexed_2d_with_ranges(&glyphs, &Mat3::IDENTITY)?;
+
+        Ok(interleaved_mesh_from_data_2d(
+            mesh,
+            &ranges,
+            advance,
+            &base_transform,
+            &self.vertex_layout,
+            self.quantization_step(),
+        ))
+    }
+
+    /// Generates the [PartitionedMeshText] of a single character with a custom transformation.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [PartitionedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_glyph_partitioned(
+        &mut self,
+        glyph: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<PartitionedMeshText, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let glyph_id = self.glyph_key_of_char(glyph);
+
+        let advance = self.key_advance(glyph_id);
+
+        let mesh = self.load_from_cache_indexed(glyph_id, flat)?;
+
+        Ok(partitioned_mesh_from_data(
+            mesh,
+            advance,
+            |v, bbox| transform_vertices(v, bbox, &transform),
+            self.quantization_step(),
+        ))
+    }
+
+    /// Generates the two-dimensional [PartitionedMeshText] of a single character
+    /// with a custom transformation.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character that should be converted to a mesh.
+    /// * `transform`: The 3x3 homogenous transformation matrix in column
+    ///   major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [PartitionedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_glyph_partitioned_2d(
+        &mut self,
+        glyph: char,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<PartitionedMeshText, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let transform = match transform {
+            Some(value) => Mat3::from_cols_array(value),
+            None => Mat3::IDENTITY,
+        };
+
+        let glyph_id = self.glyph_key_of_char(glyph);
+
+        let advance = self.key_advance(glyph_id);
+
+        let mesh = self.load_from_cache_indexed(glyph_id, true)?;
+        let mesh = mesh_to_indexed_flat_2d(mesh);
+
+        Ok(partitioned_mesh_from_data_2d(
+            mesh,
+            advance,
+            &transform,
+            self.quantization_step(),
+        ))
+    }
+
+    /// Generates the [PartitionedMeshText] of a given text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be converted to a mesh.
+    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
+    ///   to generate a mesh with a depth of `1.0` units.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [PartitionedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_text_section_partitioned(
+        &mut self,
+        text: &str,
         flat: bool,
         transform: Option<&[f32; 16]>,
-    ) -> Result<MeshText, Box<dyn MeshTextError>> {
-        let mut mesh = self.load_from_cache(glyph, flat)?;
+    ) -> Result<PartitionedMeshText, Box<dyn MeshTextError>> {
+        let base_transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        // The parts are determined on the undeformed and untransformed section.
+        let glyphs = self.take_layout(text);
+        let advance = Self::section_advance(&glyphs);
+        let width = Self::section_width(&glyphs);
+        let mesh = self.build_text_section_indexed(&glyphs, flat, None)?;
+
+        let step = self.quantization_step();
+        Ok(partitioned_mesh_from_data(
+            mesh,
+            advance,
+            |v, bbox| {
+                let bbox = self.deform_section(v, bbox, flat, width);
+                transform_vertices(v, bbox, &base_transform)
+            },
+            step,
+        ))
+    }
+
+    /// Generates the two-dimensional [PartitionedMeshText] of a given text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be converted to a mesh.
+    /// * `transform`: The optional 3x3 homogenous transformation matrix in column
+    ///   major order that will be applied to this text.
+    ///
+    /// Returns:
+    ///
+    /// The desired [PartitionedMeshText] or an [MeshTextError] if anything went wrong in the
+    /// process.
+    fn generate_text_section_partitioned_2d(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<PartitionedMeshText, Box<dyn MeshTextError>> {
+        let base_transform = match transform {
+            Some(value) => Mat3::from_cols_array(value),
+            None => Mat3::IDENTITY,
+        };
+
+        let glyphs = self.layout_section(text);
+        let advance = Self::section_advance(&glyphs);
+        let mesh = self.build_text_section_indexed_2d(&glyphs, &Mat3::IDENTITY)?;
+
+        Ok(partitioned_mesh_from_data_2d(
+            mesh,
+            advance,
+            &base_transform,
+            self.quantization_step(),
+        ))
+    }
+
+    /// Collects the laid out points of each glyph in a text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The laid out glyphs of the text section.
+    /// * `flat`: Wether the glyphs should be laid out as 2D meshes.
+    ///
+    /// Returns:
+    ///
+    /// The points of each glyph that has an outline in the XY-plane together with
+    /// the minimum and maximum z-coordinate and the advance of the glyph.
+    fn glyph_hull_points(
+        &mut self,
+        glyphs: &[PositionedGlyph],
+        flat: bool,
+    ) -> Result<Vec<GlyphPoints>, Box<dyn MeshTextError>> {
+        self.expire_cache();
+        let mut points = Vec::new();
+
+        for glyph in glyphs.iter() {
+            let mesh = self.load_from_cache_indexed(glyph.glyph_id, flat)?;
+            if !mesh.1.is_empty() {
+                let glyph_points = mesh
+                    .1
+                    .iter()
+                    .map(|v| Vec2::new(v.x, v.y) + glyph.offset)
+                    .collect();
+                points.push((glyph_points, (mesh.2.min.z, mesh.2.max.z), glyph.advance));
+            }
+        }
+
+        Ok(points)
+    }
+
+    /// Loads the given glyph from the cache or adds it.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The id of the glyph that should be loaded.
+    /// * `flat`: Wether the glyph should be laid out in a 2D mesh.
+    ///
+    /// Returns:
+    ///
+    /// A [Result] containing the [Mesh] if successful, otherwise an [MeshTextError].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    fn load_from_cache(
+        &mut self,
+        glyph: GlyphKey,
+        flat: bool,
+    ) -> Result<Mesh, Box<dyn MeshTextError>> {
+        let now = self.cache_now();
+        match self.cache.get(&(glyph, flat, self.quality), now) {
+            Some(glyph_mesh) => Ok(glyph_mesh.to_owned()),
+            None => self.insert_into_cache(glyph, flat),
+        }
+    }
+
+    /// Loads the given glyph from the cache or adds it.
+    ///
+    /// This function deals with indexed meshes.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The id of the glyph that should be loaded.
+    /// * `flat`: Wether the glyph should be laid out in a 2D mesh.
+    ///
+    /// Returns:
+    ///
+    /// A [Result] containing the [IndexedMesh] if successful, otherwise an [MeshTextError].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    fn load_from_cache_indexed(
+        &mut self,
+        glyph: GlyphKey,
+        flat: bool,
+    ) -> Result<IndexedMesh, Box<dyn MeshTextError>> {
+        let now = self.cache_now();
+        match self.indexed_cache.get(&(glyph, flat, self.quality), now) {
+            Some(glyph_mesh) => Ok(glyph_mesh.to_owned()),
+            None => self.insert_into_cache_indexed(glyph, flat),
+        }
+    }
+
+    /// Identifies a generated indexed text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text of the section.
+    /// * `flat`: Wether the glyphs of the section are flat.
+    /// * `two_dimensional`: Wether the mesh of the section is two-dimensional.
+    /// * `transform`: The transformation of the section, into which the 3x3
+    ///   transformation of two-dimensional sections is embedded.
+    ///
+    /// Returns:
+    ///
+    /// The [SectionKey] of the section.
+    fn section_key(text: &str, flat: bool, two_dimensional: bool, transform: &Mat4) -> SectionKey {
+        let transform = transform.to_cols_array().map(f32::to_bits);
+        (text.to_owned(), flat, two_dimensional, transform)
+    }
+
+    /// Locates the glyphs of a text section within the buffers of its mesh.
+    ///
+    /// The triangles of each glyph follow the triangles of the previous glyph
+    /// and only reference the vertices of the glyph itself.
+    ///
+    /// Arguments:
+    ///
+    /// * `indices`: The indices of the section.
+    /// * `vertices`: The vertices of the section.
+    /// * `ranges`: The range of vertices of each glyph.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphSpan] of each glyph.
+    fn glyph_spans<V: GlamVertex>(
+        indices: &[u32],
+        vertices: &[V],
+        ranges: &[Range<usize>],
+    ) -> Vec<GlyphSpan> {
+        let mut end = 0;
+        ranges
+            .iter()
+            .map(|range| {
+                while end < indices.len() && (indices[end] as usize) < range.end {
+                    end += 3;
+                }
+                let bbox = (!range.is_empty()).then(|| {
+                    let (min, max) = vertices[range.clone()].iter().fold(
+                        (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY)),
+                        |(min, max), v| {
+                            let v = Vec3A::from(v.to_vec3());
+                            (min.min(v), max.max(v))
+                        },
+                    );
+                    BoundingBox::new(min, max)
+                });
+                (end, range.end, bbox)
+            })
+            .collect()
+    }
+
+    /// Stores the [SectionRecord] of a generated indexed text section as
+    /// the most recently used one, evicting the least recently used records.
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The [SectionKey] of the section.
+    /// * `record`: The [SectionRecord] of the section.
+    fn record_section(&mut self, key: SectionKey, record: SectionRecord) {
+        self.section_records.insert(key, record, None);
+        self.section_records.trim(SECTION_RECORDS);
+    }
+
+    /// Inserts a freshly generated glyph into the cache that handles
+    /// non-indexed meshes according to its [CachePolicy].
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The key of the glyph in the cache.
+    /// * `mesh`: The [Mesh] of the glyph.
+    fn store_mesh(&mut self, key: CacheKey, mesh: Mesh) {
+        if self.cache_policy == CachePolicy::Disabled {
+            return;
+        }
+        let now = self.cache_now();
+        self.cache.insert(key, mesh, now);
+        if let CachePolicy::Limited(capacity) = self.cache_policy {
+            self.cache.trim(capacity);
+        }
+    }
+
+    /// Inserts a freshly generated glyph into the cache that handles
+    /// indexed meshes according to its [CachePolicy].
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The key of the glyph in the cache.
+    /// * `mesh`: The [IndexedMesh] of the glyph.
+    fn store_mesh_indexed(&mut self, key: CacheKey, mesh: IndexedMesh) {
+        if self.indexed_cache_policy == CachePolicy::Disabled {
+            return;
+        }
+        let now = self.cache_now();
+        self.indexed_cache.insert(key, mesh, now);
+        if let CachePolicy::Limited(capacity) = self.indexed_cache_policy {
+            self.indexed_cache.trim(capacity);
+        }
+    }
+
+    /// Removes all meshes from the internal caches, e.g. after a setting
+    /// changed that affects every glyph.
+    ///
+    /// The shared triangulations of the outlines are kept, since they
+    /// only depend on the outline itself.
+    fn clear_cached_meshes(&mut self) {
+        self.cache.clear();
+        self.indexed_cache.clear();
+        self.section_records.clear();
+    }
+
+    /// Checks wether identical glyph outlines share a single triangulation.
+    ///
+    /// Arguments:
+    ///
+    /// * `indexed`: Wether the triangulation is used for indexed meshes.
+    ///
+    /// Returns:
+    ///
+    /// `true` if the corresponding cache is enabled.
+    fn shares_triangulations(&self, indexed: bool) -> bool {
+        match indexed {
+            true => self.indexed_cache_policy != CachePolicy::Disabled,
+            false => self.cache_policy != CachePolicy::Disabled,
+        }
+    }
+
+    /// Evicts the glyphs that have not been used within the `cache_ttl`
+    /// from the internal caches.
+    ///
+    /// This is done once before a glyph or section is generated
+    /// rather than each time a glyph is loaded from the cache.
+    fn expire_cache(&mut self) {
+        let (Some(ttl), Some(now)) = (self.cache_ttl, self.cache_now()) else {
+            return;
+        };
+
+        self.cache.expire(now, ttl);
+        self.indexed_cache.expire(now, ttl);
+        self.outline_cache.expire(now, ttl);
+        self.indexed_outline_cache.expire(now, ttl);
+    }
+
+    /// Generates a new [Mesh] from the loaded font and the given `glyph`
+    /// and inserts it into the internal `cache`.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The id of the glyph that should be loaded.
+    /// * `flat`: Wether the glyph should be laid out in a 2D mesh.
+    ///
+    /// Returns:
+    ///
+    /// A [Result] containing the [Mesh] if successful, otherwise an [MeshTextError].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    fn insert_into_cache(
+        &mut self,
+        glyph: GlyphKey,
+        flat: bool,
+    ) -> Result<Mesh, Box<dyn MeshTextError>> {
+        let font_height = self.font_scale();
+        let mut builder = std::mem::take(&mut self.outline_builder);
+        builder.reset(font_height, self.quality);
+
+        let started = self.stats_clock();
+        let mut outlined = (Duration::ZERO, 0, 0);
+        let result = match self.outline_glyph(glyph, &mut builder) {
+            Some(bbox) => {
+                let center = Self::glyph_center(&bbox);
+                let bbox = self.extruded_bounds(bbox, flat);
+                let outline = self.repaired_outline(glyph, &builder);
+                outlined = (
+                    Self::elapsed(started),
+                    outline.points.len(),
+                    outline.contours.len(),
+                );
+                self.triangulate_outline(&outline, flat, center)
+                    .map(|mesh| (bbox, mesh))
+            }
+            None => match self.fallback_glyph(glyph, flat) {
+                Some((indices, vertices, bbox)) => Ok((
+                    bbox,
+                    indices.iter().map(|i| vertices[*i as usize]).collect(),
+                )),
+                // The glyph has no outline so it is most likely a space or any other
+                // charcter that can not be displayed.
+                // An empty mesh is cached for simplicity nevertheless.
+                None => Ok((BoundingBox::empty(), Vec::new())),
+            },
+        };
+        let discarded = (builder.discarded_contours(), builder.non_finite_contours());
+        self.outline_builder = builder;
+        let (bbox, mesh) = result?;
+        self.warn_discarded_contours(self.glyph_id_of_key(glyph), discarded);
+        let triangulation = Self::elapsed(started).saturating_sub(outlined.0);
+
+        Ok(self.finish_glyph(glyph, flat, (mesh, bbox), outlined, triangulation))
+    }
+
+    /// Applies the [MeshPostProcessor]s to a freshly triangulated glyph
+    /// and inserts it into the internal `cache`.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The key of the glyph.
+    /// * `flat`: Wether the glyph is laid out in a 2D mesh.
+    /// * `mesh`: The triangulated [Mesh] of the glyph.
+    /// * `outlined`: The duration of outlining the glyph together
+    ///   with the number of points and contours of its outline.
+    /// * `triangulation`: The duration of the triangulation of the glyph.
+    ///
+    /// Returns:
+    ///
+    /// The processed [Mesh] of the glyph.
+    fn finish_glyph(
+        &mut self,
+        glyph: GlyphKey,
+        flat: bool,
+        mesh: Mesh,
+        outlined: (Duration, usize, usize),
+        triangulation: Duration,
+    ) -> Mesh {
+        let (mut mesh, bbox) = mesh;
+        let started = self.stats_clock();
+        for processor in self.post_processors.iter() {
+            processor.process(glyph.into(), flat, &mut mesh, None);
+        }
+        let post_processing = Self::elapsed(started);
+        self.record_stats(outlined, (triangulation, post_processing), mesh.len() / 3);
+
+        // Add mesh to cache.
+        let bbox = self.post_processed_bounds(&mesh, bbox);
+        self.store_mesh((glyph, flat, self.quality), (mesh.clone(), bbox));
+
+        (mesh, bbox)
+    }
+
+    /// Generates a new [IndexedMesh] from the loaded font and the given `glyph`
+    /// and inserts it into the internal `cache`.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The id of the glyph that should be loaded.
+    /// * `flat`: Wether the glyph should be laid out in a 2D mesh.
+    ///
+    /// Returns:
+    ///
+    /// A [Result] containing the [IndexedMesh] if successful, otherwise an [MeshTextError].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self)))]
+    fn insert_into_cache_indexed(
+        &mut self,
+        glyph: GlyphKey,
+        flat: bool,
+    ) -> Result<IndexedMesh, Box<dyn MeshTextError>> {
+        let font_height = self.font_scale();
+        let mut builder = std::mem::take(&mut self.outline_builder);
+        builder.reset(font_height, self.quality);
+
+        let started = self.stats_clock();
+        let mut outlined = (Duration::ZERO, 0, 0);
+        let result = match self.outline_glyph(glyph, &mut builder) {
+            Some(bbox) => {
+                let center = Self::glyph_center(&bbox);
+                let bbox = self.extruded_bounds(bbox, flat);
+                let outline = self.repaired_outline(glyph, &builder);
+                outlined = (
+                    Self::elapsed(started),
+                    outline.points.len(),
+                    outline.contours.len(),
+                );
+                self.triangulate_outline_indexed(&outline, flat, center)
+                    .map(|mesh| (bbox, mesh.0, mesh.1))
+            }
+            None => match self.fallback_glyph(glyph, flat) {
+                Some((indices, vertices, bbox)) => Ok((bbox, vertices, indices)),
+                // The glyph has no outline so it is most likely a space or any other
+                // charcter that can not be displayed.
+                // An empty mesh is cached for simplicity nevertheless.
+                None => Ok((BoundingBox::empty(), Vec::new(), Vec::new())),
+            },
+        };
+        let discarded = (builder.discarded_contours(), builder.non_finite_contours());
+        self.outline_builder = builder;
+        let (bbox, vertices, indices) = result?;
+        self.warn_discarded_contours(self.glyph_id_of_key(glyph), discarded);
+        let triangulation = Self::elapsed(started).saturating_sub(outlined.0);
+
+        Ok(self.finish_glyph_indexed(
+            glyph,
+            flat,
+            (indices, vertices, bbox),
+            outlined,
+            triangulation,
+        ))
+    }
+
+    /// Applies the [MeshPostProcessor]s to a freshly triangulated glyph
+    /// and inserts it into the internal `indexed_cache`.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The key of the glyph.
+    /// * `flat`: Wether the glyph is laid out in a 2D mesh.
+    /// * `mesh`: The triangulated [IndexedMesh] of the glyph.
+    /// * `outlined`: The duration of outlining the glyph together
+    ///   with the number of points and contours of its outline.
+    /// * `triangulation`: The duration of the triangulation of the glyph.
+    ///
+    /// Returns:
+    ///
+    /// The processed [IndexedMesh] of the glyph.
+    fn finish_glyph_indexed(
+        &mut self,
+        glyph: GlyphKey,
+        flat: bool,
+        mesh: IndexedMesh,
+        outlined: (Duration, usize, usize),
+        triangulation: Duration,
+    ) -> IndexedMesh {
+        let (mut indices, mut vertices, bbox) = mesh;
+        let started = self.stats_clock();
+        for processor in self.post_processors.iter() {
+            processor.process(glyph.into(), flat, &mut vertices, Some(&mut indices));
+        }
+        let post_processing = Self::elapsed(started);
+        self.record_stats(
+            outlined,
+            (triangulation, post_processing),
+            indices.len() / 3,
+        );
+
+        // Add mesh to cache.
+        let bbox = self.post_processed_bounds(&vertices, bbox);
+        self.store_mesh_indexed(
+            (glyph, flat, self.quality),
+            (indices.clone(), vertices.clone(), bbox),
+        );
+
+        (indices, vertices, bbox)
+    }
+
+    /// Triangulates a glyph outline, unless an identical outline has been triangulated before.
+    ///
+    /// Arguments:
+    ///
+    /// * `outline`: The outline of the glyph.
+    /// * `flat`: Wether the glyph should be laid out in a 2D mesh.
+    /// * `center`: The center of the glyph about which the back cap is scaled.
+    ///
+    /// Returns:
+    ///
+    /// A [Result] containing the triangulated vertices if successful, otherwise an [MeshTextError].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(flat))
+    )]
+    fn triangulate_outline(
+        &mut self,
+        outline: &GlyphOutline,
+        flat: bool,
+        center: Vec2,
+    ) -> Result<Vec<Vec3A>, Box<dyn MeshTextError>> {
+        if !self.shares_triangulations(false) {
+            return raster_to_mesh(
+                outline,
+                flat,
+                &self.extrusion,
+                center,
+                &mut self.triangulation_scratch,
+            );
+        }
+
+        let key = OutlineKey::new(outline, flat, &self.extrusion, center);
+        let hash = key.hash();
+        let now = self.cache_now();
+        if let Some((content, mesh)) = self.outline_cache.get(&hash, now) {
+            if key.matches(content) {
+                return Ok(mesh.clone());
+            }
+        }
+        let mesh = raster_to_mesh(
+            outline,
+            flat,
+            &self.extrusion,
+            center,
+            &mut self.triangulation_scratch,
+        )?;
+        self.outline_cache
+            .insert(hash, (key.content(), mesh.clone()), now);
+        if let CachePolicy::Limited(capacity) = self.cache_policy {
+            self.outline_cache.trim(capacity);
+        }
+
+        Ok(mesh)
+    }
+
+    /// Triangulates a glyph outline into an indexed mesh, unless an identical
+    /// outline has been triangulated before.
+    ///
+    /// Arguments:
+    ///
+    /// * `outline`: The outline of the glyph.
+    /// * `flat`: Wether the glyph should be laid out in a 2D mesh.
+    /// * `center`: The center of the glyph about which the back cap is scaled.
+    ///
+    /// Returns:
+    ///
+    /// A [Result] containing the triangulated vertices and indices if successful,
+    /// otherwise an [MeshTextError].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(flat))
+    )]
+    fn triangulate_outline_indexed(
+        &mut self,
+        outline: &GlyphOutline,
+        flat: bool,
+        center: Vec2,
+    ) -> Result<IndexedTriangulation, Box<dyn MeshTextError>> {
+        if !self.shares_triangulations(true) {
+            return raster_to_mesh_indexed(
+                outline,
+                flat,
+                &self.extrusion,
+                center,
+                &mut self.triangulation_scratch,
+            );
+        }
+
+        let key = OutlineKey::new(outline, flat, &self.extrusion, center);
+        let hash = key.hash();
+        let now = self.cache_now();
+        if let Some((content, mesh)) = self.indexed_outline_cache.get(&hash, now) {
+            if key.matches(content) {
+                return Ok(mesh.clone());
+            }
+        }
+        let mesh = raster_to_mesh_indexed(
+            outline,
+            flat,
+            &self.extrusion,
+            center,
+            &mut self.triangulation_scratch,
+        )?;
+        self.indexed_outline_cache
+            .insert(hash, (key.content(), mesh.clone()), now);
+        if let CachePolicy::Limited(capacity) = self.indexed_cache_policy {
+            self.indexed_outline_cache.trim(capacity);
+        }
+
+        Ok(mesh)
+    }
+
+    /// Generates all uncached glyphs of a text section before the serial layout pass,
+    /// so that the outlines of distinct glyphs are triangulated in parallel.
+    ///
+    /// The outlines are loaded and processed sequentially, since neither the font,
+    /// a [GlyphProvider] nor an [OutlineProcessor] have to be thread safe. The finished
+    /// glyphs are stored in the cache, so that the layout pass loads them from there.
+    /// Glyphs whose triangulation failed are generated again sequentially, which
+    /// reports the error of the first one of them.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The laid out glyphs of the text section.
+    /// * `flat`: Wether the glyphs should be laid out in a 2D mesh.
+    /// * `indexed`: Wether the glyphs will be loaded as indexed meshes.
+    ///
+    /// Returns:
+    ///
+    /// A [Result] indicating if all glyphs could be generated, otherwise an [MeshTextError].
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(glyphs = glyphs.len())))]
+    fn generate_unique_glyphs(
+        &mut self,
+        glyphs: &[PositionedGlyph],
+        flat: bool,
+        indexed: bool,
+    ) -> Result<(), Box<dyn MeshTextError>> {
+        let policy = match indexed {
+            true => self.indexed_cache_policy,
+            false => self.cache_policy,
+        };
+        let capacity = match policy {
+            CachePolicy::Disabled => 0,
+            CachePolicy::Unlimited => usize::MAX,
+            CachePolicy::Limited(capacity) => capacity,
+        };
+
+        // Cached glyphs of the section are marked as used,
+        // so that they are not evicted in favor of the new glyphs.
+        let now = self.cache_now();
+        let mut visited = HashSet::new();
+        let mut pending = Vec::new();
+        for glyph in glyphs.iter() {
+            if !visited.insert(glyph.glyph_id) {
+                continue;
+            }
+            let key = (glyph.glyph_id, flat, self.quality);
+            let cached = match indexed {
+                true => self.indexed_cache.get(&key, now).is_some(),
+                false => self.cache.get(&key, now).is_some(),
+            };
+            if !cached {
+                pending.push(glyph.glyph_id);
+            }
+        }
 
-        if let Some(value) = transform {
-            let transform = Mat4::from_cols_array(value);
+        // A single glyph is generated faster without spawning any tasks, while
+        // glyphs that would not all fit into the cache are left to the layout pass.
+        if pending.len() < 2 || visited.len() > capacity {
+            return Ok(());
+        }
 
-            for v in mesh.0.iter_mut() {
-                *v = transform.transform_point3a(*v);
+        let font_height = self.font_scale();
+        let mut builder = std::mem::take(&mut self.outline_builder);
+        let mut prepared: Vec<PreparedGlyph> = Vec::with_capacity(pending.len());
+        for glyph in pending {
+            builder.reset(font_height, self.quality);
+            let started = self.stats_clock();
+            let outline = self.outline_glyph(glyph, &mut builder).map(|bbox| {
+                let outline = self.repaired_outline(glyph, &builder).into_owned();
+                (
+                    outline,
+                    Self::glyph_center(&bbox),
+                    self.extruded_bounds(bbox, flat),
+                )
+            });
+            let discarded = (builder.discarded_contours(), builder.non_finite_contours());
+            prepared.push((glyph, outline, discarded, Self::elapsed(started)));
+        }
+        self.outline_builder = builder;
+
+        if indexed {
+            let meshes = self.triangulate_in_parallel(
+                &prepared,
+                flat,
+                |generator| &mut generator.indexed_outline_cache,
+                raster_to_mesh_indexed,
+            );
+            if let CachePolicy::Limited(capacity) = policy {
+                self.indexed_outline_cache.trim(capacity);
+            }
+            for ((glyph, outline, discarded, outlining), mesh) in prepared.into_iter().zip(meshes) {
+                match (outline, mesh) {
+                    (Some((outline, _, bbox)), Some(((vertices, indices), triangulation))) => {
+                        self.warn_discarded_contours(self.glyph_id_of_key(glyph), discarded);
+                        let outlined = (outlining, outline.points.len(), outline.contours.len());
+                        self.finish_glyph_indexed(
+                            glyph,
+                            flat,
+                            (indices, vertices, bbox),
+                            outlined,
+                            triangulation,
+                        );
+                    }
+                    _ => {
+                        self.insert_into_cache_indexed(glyph, flat)?;
+                    }
+                }
+            }
+        } else {
+            let meshes = self.triangulate_in_parallel(
+                &prepared,
+                flat,
+                |generator| &mut generator.outline_cache,
+                raster_to_mesh,
+            );
+            if let CachePolicy::Limited(capacity) = policy {
+                self.outline_cache.trim(capacity);
+            }
+            for ((glyph, outline, discarded, outlining), mesh) in prepared.into_iter().zip(meshes) {
+                match (outline, mesh) {
+                    (Some((outline, _, bbox)), Some((mesh, triangulation))) => {
+                        self.warn_discarded_contours(self.glyph_id_of_key(glyph), discarded);
+                        let outlined = (outlining, outline.points.len(), outline.contours.len());
+                        self.finish_glyph(glyph, flat, (mesh, bbox), outlined, triangulation);
+                    }
+                    _ => {
+                        self.insert_into_cache(glyph, flat)?;
+                    }
+                }
             }
-            mesh.1.transform(&transform);
         }
 
-        Ok(text_mesh_from_data(mesh))
+        Ok(())
     }
 
-    /// Generates the two-dimensional [MeshText] of a single character with a custom transformation.
+    /// Triangulates the outlines of the given glyphs in parallel, unless
+    /// an identical outline has been triangulated before.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be converted to a mesh.
-    /// * `transform`: The 3x3 homogenous transformation matrix in column
-    /// major order that will be applied to this text.
+    /// * `prepared`: The glyphs whose outlines have been loaded.
+    /// * `flat`: Wether the glyphs should be laid out in a 2D mesh.
+    /// * `cache`: Selects the cache of the shared triangulations.
+    /// * `triangulate`: The function that triangulates a single outline.
     ///
     /// Returns:
     ///
-    /// The desired two-dimensional [MeshText] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    fn generate_glyph_2d(
+    /// The triangulation of each glyph together with the duration of the triangulation,
+    /// which is [None] if the glyph has no outline or its triangulation failed.
+    #[cfg(feature = "rayon")]
+    fn triangulate_in_parallel<M: Clone + Send>(
         &mut self,
-        glyph: char,
-        transform: Option<&[f32; 9]>,
-    ) -> Result<MeshText, Box<dyn MeshTextError>> {
-        let mesh = self.load_from_cache(glyph, true)?;
-        let mut mesh = mesh_to_flat_2d(mesh);
-
-        if let Some(value) = transform {
-            let transform = Mat3::from_cols_array(value);
+        prepared: &[PreparedGlyph],
+        flat: bool,
+        cache: fn(&mut Self) -> &mut LruCache<u64, CachedTriangulation<M>>,
+        triangulate: Triangulator<M>,
+    ) -> Vec<Option<(M, Duration)>> {
+        let extrusion = self.extrusion;
+        let measure = self.stats.is_some();
+        let now = self.cache_now();
+
+        // Identical outlines are only triangulated once.
+        let mut meshes: Vec<Option<(M, Duration)>> = Vec::with_capacity(prepared.len());
+        let mut job_of_glyph = Vec::with_capacity(prepared.len());
+        let mut jobs: Vec<(u64, Box<[u32]>, usize)> = Vec::new();
+        let mut job_of_hash: HashMap<u64, usize> = HashMap::new();
+        for (index, (_, outline, _, _)) in prepared.iter().enumerate() {
+            let Some((outline, center, _)) = outline else {
+                meshes.push(None);
+                job_of_glyph.push(None);
+                continue;
+            };
+            let key = OutlineKey::new(outline, flat, &extrusion, *center);
+            let hash = key.hash();
+            match cache(self).get(&hash, now) {
+                Some((content, mesh)) if key.matches(content) => {
+                    meshes.push(Some((mesh.clone(), Duration::ZERO)));
+                    job_of_glyph.push(None);
+                    continue;
+                }
+                _ => meshes.push(None),
+            }
+            let job = match job_of_hash.get(&hash) {
+                Some(&job) if key.matches(&jobs[job].1) => job,
+                shared => {
+                    if shared.is_none() {
+                        job_of_hash.insert(hash, jobs.len());
+                    }
+                    jobs.push((hash, key.content(), index));
+                    jobs.len() - 1
+                }
+            };
+            job_of_glyph.push(Some(job));
+        }
 
-            for v in mesh.0.iter_mut() {
-                *v = transform.transform_point2(*v);
+        let mut triangulations: Vec<Option<(M, Duration)>> = jobs
+            .par_iter()
+            .map_init(TriangulationScratch::default, |scratch, (_, _, index)| {
+                let (outline, center, _) = prepared[*index].1.as_ref()?;
+                let started = measure.then(Instant::now);
+                // The error is reported when the glyph is generated again sequentially.
+                let mesh = triangulate(outline, flat, &extrusion, *center, scratch).ok()?;
+                Some((mesh, Self::elapsed(started)))
+            })
+            .collect();
+
+        for ((hash, content, _), triangulation) in jobs.into_iter().zip(triangulations.iter()) {
+            if let Some((mesh, _)) = triangulation {
+                cache(self).insert(hash, (content, mesh.clone()), now);
+            }
+        }
+        for (mesh, job) in meshes.iter_mut().zip(job_of_glyph) {
+            let Some(job) = job else {
+                continue;
+            };
+            if let Some((triangulation, duration)) = triangulations[job].as_mut() {
+                // Only the first glyph with the outline accounts for its triangulation.
+                *mesh = Some((triangulation.clone(), std::mem::take(duration)));
             }
-            mesh.1.transform_2d(&transform);
         }
 
-        Ok(text_mesh_from_data_2d(mesh))
+        meshes
     }
 
-    /// Generates the [IndexedMeshText] of a single character with a custom transformation.
-    ///
-    /// This function generates a mesh with indices and vertices.
+    /// Gets the closed contours of a glyph relative to the font size.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be converted to a mesh.
-    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
-    /// to generate a mesh with a depth of `1.0` units.
-    /// * `transform`: The 4x4 homogenous transformation matrix in column
-    /// major order that will be applied to this text.
+    /// * `glyph`: The glyph whose contours are requested.
     ///
     /// Returns:
     ///
-    /// The desired [IndexedMeshText] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    fn generate_glyph_indexed(
+    /// The points of each contour without repeating the first point,
+    /// which are empty if the glyph has no outline.
+    fn glyph_contours(&self, glyph: GlyphKey) -> Vec<Vec<Vec2>> {
+        let mut builder = GlyphOutlineBuilder::new(self.font_scale(), self.quality);
+        if self.outline_glyph(glyph, &mut builder).is_none() {
+            return Vec::new();
+        }
+
+        let outline = self.repaired_outline(glyph, &builder);
+        outline
+            .contours
+            .iter()
+            .map(|contour| {
+                let mut points: Vec<Vec2> = contour
+                    .iter()
+                    .map(|i| {
+                        Vec2::new(outline.points[*i as usize].0, outline.points[*i as usize].1)
+                    })
+                    .collect();
+
+                // Closed contours repeat their first point.
+                if points.len() > 1 && contour.first() == contour.last() {
+                    points.pop();
+                }
+                points
+            })
+            .collect()
+    }
+
+    /// Adds a freshly generated glyph to the [GenerationStats], if they are collected.
+    ///
+    /// Arguments:
+    ///
+    /// * `outlined`: The duration of outlining the glyph together
+    ///   with the number of points and contours of its outline.
+    /// * `durations`: The durations of the triangulation and the post-processing.
+    /// * `triangles`: The number of triangles of the glyph.
+    fn record_stats(
         &mut self,
-        glyph: char,
-        flat: bool,
-        transform: Option<&[f32; 16]>,
-    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
-        let mut mesh = self.load_from_cache_indexed(glyph, flat)?;
+        outlined: (Duration, usize, usize),
+        durations: (Duration, Duration),
+        triangles: usize,
+    ) {
+        let Some(stats) = self.stats.as_mut() else {
+            return;
+        };
+        let (outlining, points, contours) = outlined;
+
+        stats.glyphs += 1;
+        stats.points += points;
+        stats.contours += contours;
+        stats.triangles += triangles;
+        stats.outlining += outlining;
+        stats.triangulation += durations.0;
+        stats.post_processing += durations.1;
+    }
 
-        if let Some(value) = transform {
-            let transform = Mat4::from_cols_array(value);
+    /// Gets the current time of the cache clock if a `cache_ttl` is set,
+    /// so that the clock is not read otherwise.
+    fn cache_now(&self) -> Option<Instant> {
+        self.cache_ttl?;
 
-            for v in mesh.1.iter_mut() {
-                *v = transform.transform_point3a(*v);
-            }
-            mesh.2.transform(&transform);
+        match self.cache_clock.as_ref() {
+            Some(clock) => Some(clock()),
+            None => Some(Instant::now()),
         }
+    }
 
-        Ok(text_mesh_from_data_indexed(mesh))
+    /// Gets the current time if [GenerationStats] are collected,
+    /// so that the clock is not read otherwise.
+    fn stats_clock(&self) -> Option<Instant> {
+        self.stats.as_ref().map(|_| Instant::now())
     }
 
-    /// Generates the two-dimensional [IndexedMeshText] of a single character
-    /// with a custom transformation.
-    ///
-    /// This function generates a mesh with indices and vertices.
+    /// Gets the time that elapsed since a reading of the [MeshGenerator::stats_clock].
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be converted to a mesh.
-    /// * `transform`: The 3x3 homogenous transformation matrix in column
-    /// major order that will be applied to this text.
+    /// * `started`: The reading of the clock.
     ///
     /// Returns:
     ///
-    /// The desired [IndexedMeshText] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    fn generate_glyph_indexed_2d(
-        &mut self,
-        glyph: char,
-        transform: Option<&[f32; 9]>,
-    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
-        let mesh = self.load_from_cache_indexed(glyph, true)?;
-        let mut mesh = mesh_to_indexed_flat_2d(mesh);
+    /// The elapsed time or zero if the clock was not read.
+    fn elapsed(started: Option<Instant>) -> Duration {
+        started.map_or(Duration::ZERO, |started| started.elapsed())
+    }
 
-        if let Some(value) = transform {
-            let transform = Mat3::from_cols_array(value);
+    /// Records a warning if degenerate or non-finite contours of a glyph were discarded.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph_id`: The id of the glyph in the font.
+    /// * `count`: The number of discarded degenerate and non-finite contours.
+    fn warn_discarded_contours(&mut self, glyph_id: u16, count: (usize, usize)) {
+        if count.0 > 0 {
+            self.warnings.push(GenerationWarning::DegenerateContours {
+                glyph_id,
+                count: count.0,
+            });
+        }
+        if count.1 > 0 {
+            self.warnings.push(GenerationWarning::NonFiniteContours {
+                glyph_id,
+                count: count.1,
+            });
+        }
+    }
 
-            for v in mesh.1.iter_mut() {
-                *v = transform.transform_point2(*v);
+    /// Gets the outline of a glyph, which is adjusted by the registered
+    /// [OutlineProcessor]s and repaired if watertight meshes are requested.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The key of the glyph.
+    /// * `builder`: The builder that received the outline of the glyph.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphOutline] of the glyph, which is borrowed from the builder
+    /// unless it had to be processed or repaired.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self, builder))
+    )]
+    fn repaired_outline<'a>(
+        &self,
+        glyph: GlyphKey,
+        builder: &'a GlyphOutlineBuilder,
+    ) -> Cow<'a, GlyphOutline> {
+        let mut outline = Cow::Borrowed(builder.get_glyph_outline());
+        if !self.outline_processors.is_empty() {
+            let mut points: Vec<Vec2> =
+                outline.points.iter().map(|p| Vec2::new(p.0, p.1)).collect();
+            for processor in self.outline_processors.iter() {
+                processor.process(glyph.into(), &outline.contours, &mut points);
             }
-            mesh.2.transform_2d(&transform);
+            outline.to_mut().points = points.iter().map(|p| (p.x, p.y)).collect();
         }
 
-        Ok(text_mesh_from_data_indexed_2d(mesh))
+        match self.watertight {
+            true => Cow::Owned(repair_outline(&outline)),
+            false => outline,
+        }
     }
 
-    /// Generates the [Mesh] of a single character with a custom transformation given
-    /// as a [Mat4].
+    /// Gets the id of a glyph that is reported in a [GenerationWarning].
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be converted to a mesh.
-    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
-    /// to generate a mesh with a depth of `1.0` units.
-    /// * `transform`: The 4x4 homogenous transformation matrix.
+    /// * `glyph`: The key of the glyph.
     ///
     /// Returns:
     ///
-    /// The desired [Mesh] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    pub(crate) fn generate_glyph_with_glam_transform(
-        &mut self,
-        glyph: char,
-        flat: bool,
-        transform: &Mat4,
-    ) -> Result<Mesh, Box<dyn MeshTextError>> {
-        let mut mesh = self.load_from_cache(glyph, flat)?;
+    /// The id of the glyph in the font, which is `0` for the fallback mesh.
+    fn glyph_id_of_key(&self, glyph: GlyphKey) -> u16 {
+        match glyph {
+            GlyphKey::Font(glyph_id) => glyph_id.0,
+            GlyphKey::Custom(character) => self.glyph_id_of_char(character).0,
+            GlyphKey::Fallback => 0,
+        }
+    }
 
-        for v in mesh.0.iter_mut() {
-            *v = transform.transform_point3a(*v);
+    /// Computes the bounding box of a glyph after post-processing.
+    ///
+    /// Arguments:
+    ///
+    /// * `vertices`: The vertices of the post-processed glyph.
+    /// * `bbox`: The bounding box of the glyph before post-processing.
+    ///
+    /// Returns:
+    ///
+    /// The bounding box of the given vertices or the original bounding box
+    /// if no post-processor is registered or the glyph has no vertices.
+    fn post_processed_bounds(&self, vertices: &[Vec3A], bbox: BoundingBox) -> BoundingBox {
+        if self.post_processors.is_empty() || vertices.is_empty() {
+            return bbox;
         }
-        mesh.1.transform(transform);
 
-        Ok(mesh)
+        let (min, max) = vertices.iter().fold(
+            (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY)),
+            |(min, max), v| (min.min(*v), max.max(*v)),
+        );
+        BoundingBox::new(min, max)
     }
 
-    /// Generates the [Mesh2D] of a single character with a custom transformation given
-    /// as a [Mat3].
+    /// Converts the [u32] indices of a generated mesh into the requested [IndexType].
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be converted to a mesh.
-    /// * `transform`: The 3x3 homogenous transformation matrix.
+    /// * `mesh`: The generated mesh.
     ///
     /// Returns:
     ///
-    /// The desired [Mesh2D] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    pub(crate) fn generate_glyph_with_glam_transform_2d(
-        &mut self,
-        glyph: char,
-        transform: &Mat3,
-    ) -> Result<Mesh2D, Box<dyn MeshTextError>> {
-        let mesh = self.load_from_cache(glyph, true)?;
-        let mut mesh = mesh_to_flat_2d(mesh);
+    /// The mesh with converted indices or an [IndexOverflowError] if the
+    /// mesh has too many vertices for the requested [IndexType].
+    fn convert_indices<I: IndexType>(
+        mesh: IndexedMeshText,
+    ) -> Result<IndexedMeshText<I>, Box<dyn MeshTextError>> {
+        let IndexedMeshText {
+            advance,
+            bbox,
+            indices,
+            two_dimensional,
+            vertices,
+        } = mesh;
+        let Some(indices) = I::from_u32_indices(indices) else {
+            return Err(Box::new(IndexOverflowError));
+        };
+
+        Ok(IndexedMeshText {
+            advance,
+            bbox,
+            indices,
+            two_dimensional,
+            vertices,
+        })
+    }
+
+    /// Converts the [u32] indices of a generated [IndexedMeshTextGlam] into the requested [IndexType].
+    ///
+    /// Arguments:
+    ///
+    /// * `mesh`: The generated mesh.
+    ///
+    /// Returns:
+    ///
+    /// The mesh with converted indices or an [IndexOverflowError] if the
+    /// mesh has too many vertices for the requested [IndexType].
+    fn convert_glam_indices<V: GlamVertex, I: IndexType>(
+        mesh: IndexedMeshTextGlam<V>,
+    ) -> Result<IndexedMeshTextGlam<V, I>, Box<dyn MeshTextError>> {
+        let IndexedMeshTextGlam {
+            advance,
+            bbox,
+            indices,
+            vertices,
+        } = mesh;
+        let Some(indices) = I::from_u32_indices(indices) else {
+            return Err(Box::new(IndexOverflowError));
+        };
+
+        Ok(IndexedMeshTextGlam {
+            advance,
+            bbox,
+            indices,
+            vertices,
+        })
+    }
 
-        for v in mesh.0.iter_mut() {
-            *v = transform.transform_point2(*v);
+    /// Checks that a [GlamVertex] type can hold the vertices of three-dimensional text.
+    ///
+    /// Returns:
+    ///
+    /// A [VertexDimensionError] if the type is two-dimensional.
+    fn check_three_dimensional<V: GlamVertex>() -> Result<(), Box<dyn MeshTextError>> {
+        match V::COMPONENTS {
+            3 => Ok(()),
+            _ => Err(Box::new(VertexDimensionError)),
         }
-        mesh.1.transform_2d(transform);
+    }
 
-        Ok(mesh)
+    /// Computes the normalized center of a glyph.
+    ///
+    /// Arguments:
+    ///
+    /// * `bbox`: The normalized bounding box of the glyph's outline.
+    ///
+    /// Returns:
+    ///
+    /// The center of the given bounding box.
+    fn glyph_center(bbox: &BoundingBox) -> Vec2 {
+        let center = bbox.center();
+        Vec2::new(center.x, center.y)
     }
 
-    /// Generates the [IndexedMesh] of a single character with a custom transformation given
-    /// as a [Mat4].
+    /// Computes the [BoundingBox] of a glyph from the bounding box of its outline.
     ///
-    /// This function handles indexed meshes.
+    /// Arguments:
+    ///
+    /// * `bbox`: The normalized bounding box of the glyph's outline.
+    /// * `flat`: Wether the glyph is laid out in a 2D mesh.
+    ///
+    /// Returns:
+    ///
+    /// The bounding box of the flat glyph or of the extruded glyph including its back cap.
+    fn extruded_bounds(&self, bbox: BoundingBox, flat: bool) -> BoundingBox {
+        if flat {
+            return bbox;
+        }
+
+        self.include_back_cap(BoundingBox::new(
+            Vec3A::new(bbox.min.x, bbox.min.y, -0.5f32),
+            Vec3A::new(bbox.max.x, bbox.max.y, 0.5f32),
+        ))
+    }
+
+    /// Enlarges the [BoundingBox] of a three-dimensional glyph, so that
+    /// it also contains the scaled back cap.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be converted to a mesh.
-    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
-    /// to generate a mesh with a depth of `1.0` units.
-    /// * `transform`: The 4x4 homogenous transformation matrix.
+    /// * `bbox`: The [BoundingBox] of the front cap.
     ///
     /// Returns:
     ///
-    /// The desired [IndexedMesh] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    pub(crate) fn generate_glyph_with_glam_transform_indexed(
-        &mut self,
-        glyph: char,
-        flat: bool,
-        transform: &Mat4,
-    ) -> Result<IndexedMesh, Box<dyn MeshTextError>> {
-        let mut mesh = self.load_from_cache_indexed(glyph, flat)?;
+    /// The [BoundingBox] of the whole glyph.
+    fn include_back_cap(&self, bbox: BoundingBox) -> BoundingBox {
+        let scale = self.extrusion.back_cap_scale.abs();
+        if scale <= 1f32 {
+            return bbox;
+        }
+
+        let center = bbox.center();
+        let extent = (bbox.max - bbox.min) * 0.5f32 * scale;
 
-        for v in mesh.1.iter_mut() {
-            *v = transform.transform_point3a(*v);
+        BoundingBox::new(
+            Vec3A::new(center.x - extent.x, center.y - extent.y, bbox.min.z),
+            Vec3A::new(center.x + extent.x, center.y + extent.y, bbox.max.z),
+        )
+    }
+
+    /// Gets the value by which all glyph coordinates are normalized.
+    ///
+    /// Returns:
+    ///
+    /// The value of the [ScaleReference] in font units.
+    fn font_scale(&self) -> f32 {
+        match self.scale_reference() {
+            ScaleReference::Height => {
+                let (ascender, descender, _) = self.vertical_metrics();
+                ascender - descender
+            }
+            ScaleReference::UnitsPerEm => self.font.units_per_em() as f32,
         }
-        mesh.2.transform(transform);
+    }
 
-        Ok(mesh)
+    /// Snaps the vertices of a generated mesh to the quantization grid, if one is set.
+    ///
+    /// Triangles that lose their area are removed.
+    ///
+    /// Arguments:
+    ///
+    /// * `vertices`: The vertices that will be snapped in place, where each
+    ///   consecutive three vertices form a triangle.
+    /// * `bbox`: The [BoundingBox] of the vertices.
+    fn quantize(&self, vertices: &mut Vec<Vec3A>, bbox: &mut BoundingBox) {
+        if let Some(step) = self.quantization_step() {
+            quantize_points(vertices, bbox, step);
+            remove_degenerate_triangles(vertices, step);
+        }
     }
 
-    /// Generates the [IndexedMesh2D] of a single character with a custom transformation given
-    /// as a [Mat3].
+    /// Snaps the vertices of a generated two-dimensional mesh
+    /// to the quantization grid, if one is set.
     ///
-    /// This function handles indexed meshes.
+    /// Triangles that lose their area are removed.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be converted to a mesh.
-    /// * `transform`: The 3x3 homogenous transformation matrix.
+    /// * `vertices`: The vertices that will be snapped in place, where each
+    ///   consecutive three vertices form a triangle.
+    /// * `bbox`: The [BoundingBox] of the vertices.
+    fn quantize_2d(&self, vertices: &mut Vec<Vec2>, bbox: &mut BoundingBox) {
+        if let Some(step) = self.quantization_step() {
+            quantize_points_2d(vertices, bbox, step);
+            remove_degenerate_triangles(vertices, step);
+        }
+    }
+
+    /// Snaps the vertices of a generated indexed mesh to the quantization grid, if one is set.
     ///
-    /// Returns:
+    /// Triangles that lose their area are removed.
     ///
-    /// The desired [IndexedMesh2D] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    pub(crate) fn generate_glyph_with_glam_transform_indexed_2d(
-        &mut self,
-        glyph: char,
-        transform: &Mat3,
-    ) -> Result<IndexedMesh2D, Box<dyn MeshTextError>> {
-        let mesh = self.load_from_cache_indexed(glyph, true)?;
-        let mut mesh = mesh_to_indexed_flat_2d(mesh);
+    /// Arguments:
+    ///
+    /// * `indices`: The indices of the mesh.
+    /// * `vertices`: The vertices that will be snapped in place.
+    /// * `bbox`: The [BoundingBox] of the vertices.
+    fn quantize_indexed(
+        &self,
+        indices: &mut Vec<u32>,
+        vertices: &mut [Vec3A],
+        bbox: &mut BoundingBox,
+    ) {
+        if let Some(step) = self.quantization_step() {
+            quantize_points(vertices, bbox, step);
+            remove_degenerate_triangles_indexed(indices, vertices, step);
+        }
+    }
 
-        for v in mesh.1.iter_mut() {
-            *v = transform.transform_point2(*v);
+    /// Snaps the vertices of a generated two-dimensional indexed mesh
+    /// to the quantization grid, if one is set.
+    ///
+    /// Triangles that lose their area are removed.
+    ///
+    /// Arguments:
+    ///
+    /// * `indices`: The indices of the mesh.
+    /// * `vertices`: The vertices that will be snapped in place.
+    /// * `bbox`: The [BoundingBox] of the vertices.
+    fn quantize_indexed_2d(
+        &self,
+        indices: &mut Vec<u32>,
+        vertices: &mut [Vec2],
+        bbox: &mut BoundingBox,
+    ) {
+        if let Some(step) = self.quantization_step() {
+            quantize_points_2d(vertices, bbox, step);
+            remove_degenerate_triangles_indexed(indices, vertices, step);
         }
-        mesh.2.transform_2d(transform);
+    }
 
-        Ok(mesh)
+    /// Gets the spacing of the quantization grid in normalized coordinates.
+    ///
+    /// Returns:
+    ///
+    /// The spacing or `None` if the vertices are not quantized.
+    fn quantization_step(&self) -> Option<f32> {
+        let step = self.quantization?;
+        let step = self.font_units_to_normalized(step * self.font.units_per_em() as f32);
+
+        (step > 0f32 && step.is_finite()).then_some(step)
     }
 
-    /// Generates the [MeshText] of a given text section.
+    /// Gets the vertical metrics of the font from the selected [MetricsSource].
+    ///
+    /// Returns:
+    ///
+    /// The ascender, descender and line gap in font units.
+    fn vertical_metrics(&self) -> (f32, f32, f32) {
+        let (ascender, descender, line_gap) = self
+            .font
+            .vertical_metrics(self.metrics_source)
+            .unwrap_or_else(|| {
+                (
+                    self.font.ascender(),
+                    self.font.descender(),
+                    self.font.line_gap(),
+                )
+            });
+
+        (ascender as f32, descender as f32, line_gap as f32)
+    }
+
+    /// Places the glyphs of a text section next to each other.
+    ///
+    /// Combining marks do not advance and are centered above the
+    /// preceding glyph. Variation selectors choose the glyph of the
+    /// preceding character and do not produce a glyph themselves.
+    /// Characters combined by zero-width joiners are replaced by
+    /// a ligature of the font if there is one.
+    /// Soft hyphens are replaced by an empty glyph and tabs advance
+    /// to the next tab stop.
     ///
     /// Arguments:
     ///
-    /// * `text`: The text that should be converted to a mesh.
-    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
-    /// to generate a mesh with a depth of `1.0` units.
-    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
-    /// major order that will be applied to this text.
+    /// * `text`: The text that should be laid out.
+    /// * `seam`: The glyph in front of the text, which is kerned with its first glyph.
+    /// * `pen`: The position of the pen in front of the text.
     ///
     /// Returns:
     ///
-    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    fn generate_text_section(
-        &mut self,
+    /// The glyphs of the text section in visual order, which are placed
+    /// behind the pen, and the position of the pen after the last glyph.
+    #[cfg(not(feature = "shaping"))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(length = text.len())))]
+    fn layout_section_after(
+        &self,
         text: &str,
-        flat: bool,
-        transform: Option<&[f32; 16]>,
-    ) -> Result<MeshText, Box<dyn MeshTextError>> {
-        let base_transform = match transform {
-            Some(value) => Mat4::from_cols_array(value),
-            None => Mat4::IDENTITY,
-        };
-
-        let mut mesh = (Vec::new(), BoundingBox::empty());
-        let mut overall_advance = 0f32;
-
-        let mut chars_iter = text.chars();
-
-        // The first char will be handled differently if present.
-        if let Some(first_glyph) = chars_iter.next() {
-            let x_advance = self
-                .font
-                .glyph_hor_advance(self.glyph_id_of_char(first_glyph))
-                .unwrap_or(0) as f32
-                / self.font.height() as f32;
-
-            let transform =
-                base_transform * Mat4::from_translation(Vec3::new(overall_advance, 0f32, 0f32));
-            let mut glyph_mesh =
-                self.generate_glyph_with_glam_transform(first_glyph, flat, &transform)?;
-
-            // Add vertices and replace bbox.
-            mesh.0.append(&mut glyph_mesh.0);
-            mesh = (mesh.0, glyph_mesh.1);
+        mut seam: Option<GlyphId>,
+        pen: f32,
+    ) -> (Vec<PositionedGlyph>, f32) {
+        let mut glyphs: Vec<PositionedGlyph> = Vec::new();
+        let mut overall_advance = pen;
+
+        let characters: Vec<(usize, char)> = text.char_indices().collect();
+        let mut index = 0;
+
+        // The index of the previous glyph of the font, which is kerned with the next one.
+        let mut previous: Option<usize> = None;
+        while let Some(&(cluster, c)) = characters.get(index) {
+            index += 1;
+
+            // Joiners and selectors only affect the surrounding characters.
+            if is_variation_selector(c) || c == ZERO_WIDTH_JOINER {
+                continue;
+            }
 
-            overall_advance += x_advance;
-        }
+            if c == '\t' {
+                previous = None;
+                seam = None;
+                let advance = self.tab_advance(overall_advance);
+                glyphs.push(PositionedGlyph {
+                    advance,
+                    cluster,
+                    depth: 1f32,
+                    glyph_id: GlyphKey::Font(self.glyph_id_of_char(' ')),
+                    offset: Vec2::new(overall_advance, 0f32),
+                });
+                overall_advance += advance;
+                continue;
+            }
 
-        for glyph in chars_iter {
-            let x_advance = self
-                .font
-                .glyph_hor_advance(self.glyph_id_of_char(glyph))
-                .unwrap_or(0) as f32
-                / self.font.height() as f32;
+            // Soft hyphens are invisible, unless a line is broken at them.
+            if c == SOFT_HYPHEN {
+                previous = None;
+                seam = None;
+                glyphs.push(PositionedGlyph {
+                    advance: 0f32,
+                    cluster,
+                    depth: 1f32,
+                    glyph_id: GlyphKey::Font(self.glyph_id_of_char(' ')),
+                    offset: Vec2::new(overall_advance, 0f32),
+                });
+                continue;
+            }
 
-            let transform =
-                base_transform * Mat4::from_translation(Vec3::new(overall_advance, 0f32, 0f32));
-            let mut glyph_mesh =
-                self.generate_glyph_with_glam_transform(glyph, flat, &transform)?;
+            let glyph_id = match self.joined_ligature(&characters[index - 1..]) {
+                Some((ligature, length)) => {
+                    index += length - 1;
+                    GlyphKey::Font(ligature)
+                }
+                None => match characters.get(index) {
+                    Some((_, selector)) if is_variation_selector(*selector) => {
+                        self.glyph_key_of_sequence(c, *selector)
+                    }
+                    _ => self.glyph_key_of_char(c),
+                },
+            };
 
-            // Add vertices and adjust bbox.
-            mesh.0.append(&mut glyph_mesh.0);
-            mesh = (mesh.0, mesh.1.combine(&glyph_mesh.1));
+            if let (true, Some(base)) = (is_combining_mark(c), glyphs.last()) {
+                let offset = self.glyph_center_x(base.glyph_id, base.advance)
+                    - self.glyph_center_x(glyph_id, self.key_advance(glyph_id));
+                glyphs.push(PositionedGlyph {
+                    advance: 0f32,
+                    cluster,
+                    depth: 1f32,
+                    glyph_id,
+                    offset: Vec2::new(base.offset.x + offset, base.offset.y),
+                });
+                continue;
+            }
 
-            overall_advance += x_advance;
+            // The kerning adjusts the advance of the previous glyph.
+            if let (Some(i), GlyphKey::Font(right)) = (previous, glyph_id) {
+                if let GlyphKey::Font(left) = glyphs[i].glyph_id {
+                    let kerning = self.glyph_kerning(left, right);
+                    glyphs[i].advance += kerning;
+                    overall_advance += kerning;
+                }
+            }
+            if let (Some(left), GlyphKey::Font(right)) = (seam.take(), glyph_id) {
+                overall_advance += self.glyph_kerning(left, right);
+            }
+            previous = Some(glyphs.len());
+
+            let advance = self.key_advance(glyph_id);
+            glyphs.push(PositionedGlyph {
+                advance,
+                cluster,
+                depth: 1f32,
+                glyph_id,
+                offset: Vec2::new(overall_advance, 0f32),
+            });
+            overall_advance += advance;
         }
+        self.assign_depths(text, &mut glyphs, 0);
 
-        Ok(text_mesh_from_data(mesh))
+        (glyphs, overall_advance)
     }
 
-    /// Generates two-dimensional [MeshText] for a given text section.
+    /// Shapes a text section using the `GSUB` and `GPOS` tables of the font.
+    ///
+    /// This handles ligatures, kerning and the attachment of combining marks.
+    /// Tabs advance to the next tab stop.
     ///
     /// Arguments:
     ///
-    /// * `text`: The text that should be converted to a mesh.
-    /// * `transform`: The optional 3x3 homogenous transformation matrix in column
-    /// major order that will be applied to this text.
+    /// * `text`: The text that should be laid out.
+    /// * `seam`: The glyph in front of the text, which is kerned with its first glyph.
+    /// * `pen`: The position of the pen in front of the text.
     ///
     /// Returns:
     ///
-    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    fn generate_text_section_2d(
-        &mut self,
+    /// The glyphs of the text section in visual order, which are placed
+    /// behind the pen, and the position of the pen after the last glyph.
+    #[cfg(feature = "shaping")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(length = text.len())))]
+    fn layout_section_after(
+        &self,
         text: &str,
-        transform: Option<&[f32; 9]>,
-    ) -> Result<MeshText, Box<dyn MeshTextError>> {
-        let base_transform = match transform {
-            Some(value) => Mat3::from_cols_array(value),
-            None => Mat3::IDENTITY,
+        seam: Option<GlyphId>,
+        pen: f32,
+    ) -> (Vec<PositionedGlyph>, f32) {
+        let scale = self.font_scale();
+        let shaped = match self.shaper.as_ref() {
+            Some(shaper) => shape_text(shaper, text, &self.shaping),
+            None => {
+                let shaper = rustybuzz::Face::from_face(self.font.as_face().clone());
+                shape_text(&shaper, text, &self.shaping)
+            }
         };
 
-        let mut mesh = (Vec::new(), BoundingBox::empty());
-        let mut overall_advance = 0f32;
-
-        let mut chars_iter = text.chars();
-
-        // The first char will be handled differently if present.
-        if let Some(first_glyph) = chars_iter.next() {
-            let x_advance = self
-                .font
-                .glyph_hor_advance(self.glyph_id_of_char(first_glyph))
-                .unwrap_or(0) as f32
-                / self.font.height() as f32;
-
-            let transform =
-                base_transform * Mat3::from_translation(Vec2::new(overall_advance, 0f32));
-            let mut glyph_mesh =
-                self.generate_glyph_with_glam_transform_2d(first_glyph, &transform)?;
+        // Tabs and glyphs without an advance are moved by the tab stops or
+        // the fallback respectively, which also shifts all following glyphs.
+        let kerning = match (seam, shaped.first()) {
+            (Some(left), Some((glyph, false))) if !text.starts_with('\t') => match glyph.glyph_id {
+                GlyphKey::Font(right) => self.glyph_kerning(left, right),
+                GlyphKey::Custom(_) | GlyphKey::Fallback => 0f32,
+            },
+            _ => 0f32,
+        };
 
-            // Add vertices and replace bbox.
-            mesh.0.append(&mut glyph_mesh.0);
-            mesh = (mesh.0, glyph_mesh.1);
+        let mut glyphs: Vec<PositionedGlyph> = Vec::with_capacity(shaped.len());
+        let mut shift = pen + kerning;
+        let mut pen = pen + kerning;
+        for (mut glyph, detached_mark) in shaped {
+            glyph.advance /= scale;
+            glyph.offset = glyph.offset / scale + Vec2::new(shift, 0f32);
+
+            // Marks the font does not attach are centered above their base.
+            if let (true, Some(base)) = (detached_mark, glyphs.last()) {
+                let offset = self.glyph_center_x(base.glyph_id, base.advance)
+                    - self.glyph_center_x(glyph.glyph_id, self.key_advance(glyph.glyph_id));
+                shift -= glyph.advance;
+                glyph.advance = 0f32;
+                glyph.offset = Vec2::new(base.offset.x + offset, base.offset.y);
+                glyphs.push(glyph);
+                continue;
+            }
 
-            overall_advance += x_advance;
+            let character = text[glyph.cluster..].chars().next();
+            let custom = character.and_then(|c| Some((c, self.custom_advance(c)?)));
+            let advance = if character == Some('\t') {
+                glyph.glyph_id = GlyphKey::Font(self.glyph_id_of_char(' '));
+                Some(self.tab_advance(pen))
+            } else if let Some((character, advance)) = custom {
+                glyph.glyph_id = GlyphKey::Custom(character);
+                Some(advance)
+            } else if let (GlyphKey::Font(GlyphId(0)), Some((_, _, advance))) =
+                (glyph.glyph_id, &self.fallback_mesh)
+            {
+                glyph.glyph_id = GlyphKey::Fallback;
+                Some(*advance)
+            } else if let GlyphKey::Font(glyph_id) = glyph.glyph_id {
+                self.font
+                    .glyph_hor_advance(glyph_id)
+                    .is_none()
+                    .then(|| self.glyph_advance(glyph_id))
+            } else {
+                None
+            };
+            if let Some(advance) = advance {
+                shift += advance - glyph.advance;
+                glyph.advance = advance;
+            }
+            pen += glyph.advance;
+            glyphs.push(glyph);
         }
+        self.assign_depths(text, &mut glyphs, 0);
 
-        for glyph in chars_iter {
-            let x_advance = self
-                .font
-                .glyph_hor_advance(self.glyph_id_of_char(glyph))
-                .unwrap_or(0) as f32
-                / self.font.height() as f32;
-
-            let transform =
-                base_transform * Mat3::from_translation(Vec2::new(overall_advance, 0f32));
-            let mut glyph_mesh = self.generate_glyph_with_glam_transform_2d(glyph, &transform)?;
+        (glyphs, pen)
+    }
 
-            // Add vertices and adjust bbox.
-            mesh.0.append(&mut glyph_mesh.0);
-            mesh = (mesh.0, mesh.1.combine(&glyph_mesh.1));
+    /// Lays out a text section on its own.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be laid out.
+    ///
+    /// Returns:
+    ///
+    /// The glyphs of the text section in visual order.
+    fn layout_section(&self, text: &str) -> Vec<PositionedGlyph> {
+        self.layout_section_after(text, None, 0f32).0
+    }
 
-            overall_advance += x_advance;
+    /// Takes the layout that was prepared for a text section
+    /// or lays out the text section if no layout was prepared.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that should be laid out.
+    ///
+    /// Returns:
+    ///
+    /// The glyphs of the text section in visual order.
+    fn take_layout(&mut self, text: &str) -> Vec<PositionedGlyph> {
+        match self.prepared_layout.take() {
+            Some((prepared, glyphs)) if prepared == text => glyphs,
+            _ => self.layout_section(text),
         }
-
-        Ok(text_mesh_from_data_2d(mesh))
     }
 
-    /// Generates the [MeshText] of a given text section.
-    ///
-    /// This function handles indexed meshes.
+    /// Generates the mesh of a text section that has already been laid out.
     ///
     /// Arguments:
     ///
-    /// * `text`: The text that should be converted to a mesh.
-    /// * `flat`: Set this to `true` for 2D meshes, or to `false` in order
-    /// to generate a mesh with a depth of `1.0` units.
-    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
-    /// major order that will be applied to this text.
+    /// * `text`: The text section that will be transformed into a mesh.
+    /// * `glyphs`: The layout of the text section.
+    /// * `flat`: Wether the mesh is flat or has an extent of `1` unit in the z-axis.
+    /// * `transform`: The optional homogenous 4x4 transformation matrix that will be
+    ///   applied to each vertex of the mesh.
     ///
     /// Returns:
     ///
-    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    fn generate_text_section_indexed(
+    /// The mesh for the given text section or an [MeshTextError]
+    /// if anything went wrong in the process.
+    fn generate_prepared_section<M>(
         &mut self,
         text: &str,
+        glyphs: Vec<PositionedGlyph>,
         flat: bool,
         transform: Option<&[f32; 16]>,
-    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
-        let base_transform = match transform {
-            Some(value) => Mat4::from_cols_array(value),
-            None => Mat4::IDENTITY,
-        };
-
-        let mut mesh = (Vec::new(), Vec::new(), BoundingBox::empty());
-        let mut overall_advance = 0f32;
-        let mut index_offset = 0;
-
-        let mut chars_iter = text.chars();
-
-        // The first char will be handled differently if present.
-        if let Some(first_glyph) = chars_iter.next() {
-            let x_advance = self
-                .font
-                .glyph_hor_advance(self.glyph_id_of_char(first_glyph))
-                .unwrap_or(0) as f32
-                / self.font.height() as f32;
+    ) -> Result<M, Box<dyn MeshTextError>>
+    where
+        Self: TextSection<M>,
+    {
+        self.prepared_layout = Some((text.to_owned(), glyphs));
+        let mesh = self.generate_section(text, flat, transform);
+        self.prepared_layout = None;
+
+        mesh
+    }
 
-            let transform =
-                base_transform * Mat4::from_translation(Vec3::new(overall_advance, 0f32, 0f32));
-            let mut glyph_mesh =
-                self.generate_glyph_with_glam_transform_indexed(first_glyph, flat, &transform)?;
+    /// Computes the extrusion depth of each glyph of a text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text from which the glyphs originate.
+    /// * `glyphs`: The laid out glyphs of the text.
+    /// * `first_index`: The index of the first character of the text
+    ///   within the whole text section counted in characters.
+    fn assign_depths(&self, text: &str, glyphs: &mut [PositionedGlyph], first_index: usize) {
+        let Some(depth_for) = self.depth_for.as_ref() else {
+            return;
+        };
 
-            // Update index offset (note that glyph meshes can be empty).
-            if let Some(max) = glyph_mesh.0.iter().max() {
-                index_offset = *max + 1;
+        let characters: Vec<(usize, char)> = text.char_indices().collect();
+        for glyph in glyphs.iter_mut() {
+            if let Ok(index) = characters.binary_search_by_key(&glyph.cluster, |(i, _)| *i) {
+                glyph.depth = depth_for(first_index + index, characters[index].1);
             }
-
-            // Add vertices and replace bbox.
-            mesh.0.append(&mut glyph_mesh.0);
-            mesh.1.append(&mut glyph_mesh.1);
-            mesh = (mesh.0, mesh.1, glyph_mesh.2);
-
-            overall_advance += x_advance;
         }
+    }
 
-        for glyph in chars_iter {
-            let x_advance = self
-                .font
-                .glyph_hor_advance(self.glyph_id_of_char(glyph))
-                .unwrap_or(0) as f32
-                / self.font.height() as f32;
-
-            let transform =
-                base_transform * Mat4::from_translation(Vec3::new(overall_advance, 0f32, 0f32));
-            let mut glyph_mesh =
-                self.generate_glyph_with_glam_transform_indexed(glyph, flat, &transform)?;
+    /// Computes the transformation that places a glyph within its text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The laid out glyph.
+    ///
+    /// Returns:
+    ///
+    /// The transformation that moves the glyph to its offset and
+    /// scales it to its extrusion depth.
+    fn glyph_transform(glyph: &PositionedGlyph) -> Mat4 {
+        Mat4::from_translation(glyph.offset.extend(0f32))
+            * Mat4::from_scale(Vec3::new(1f32, 1f32, glyph.depth))
+    }
 
-            // Offset indices.
-            for i in glyph_mesh.0.iter_mut() {
-                *i += index_offset;
-            }
+    /// Computes the width of a laid out text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The laid out glyphs of the text section.
+    ///
+    /// Returns:
+    ///
+    /// The largest extent of a glyph advance, which is the width of the
+    /// widest line for paragraphs.
+    fn section_width(glyphs: &[PositionedGlyph]) -> f32 {
+        glyphs
+            .iter()
+            .map(|g| g.offset.x + g.advance)
+            .fold(0f32, f32::max)
+    }
 
-            // Update index offset (note that glyph meshes can be empty).
-            if let Some(max) = glyph_mesh.0.iter().max() {
-                index_offset = *max + 1;
-            }
+    /// Computes the advance of a laid out text section.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The laid out glyphs of the text section.
+    ///
+    /// Returns:
+    ///
+    /// The position of the pen after the last glyph.
+    fn section_advance(glyphs: &[PositionedGlyph]) -> f32 {
+        glyphs.iter().map(|g| g.advance).sum()
+    }
 
-            // Add vertices and indices and adjust bbox.
-            mesh.0.append(&mut glyph_mesh.0);
-            mesh.1.append(&mut glyph_mesh.1);
-            mesh = (mesh.0, mesh.1, mesh.2.combine(&glyph_mesh.2));
+    /// Computes the advance of a tab.
+    ///
+    /// Arguments:
+    ///
+    /// * `pen`: The position of the pen in front of the tab.
+    ///
+    /// Returns:
+    ///
+    /// The distance to the next tab stop.
+    fn tab_advance(&self, pen: f32) -> f32 {
+        if let Some(stop) = self.tabs.stops.iter().find(|s| **s > pen) {
+            return stop - pen;
+        }
 
-            overall_advance += x_advance;
+        let tab_size = match self.tabs.tab_size {
+            TabSize::Spaces(count) => count * self.glyph_advance(self.glyph_id_of_char(' ')),
+            TabSize::Advance(advance) => advance,
+        };
+        if tab_size <= 0f32 {
+            return 0f32;
         }
 
-        Ok(text_mesh_from_data_indexed(mesh))
+        ((pen / tab_size).floor() + 1f32) * tab_size - pen
     }
 
-    /// Generates two-dimensional [MeshText] for a given text section.
-    ///
-    /// This function handles indexed meshes.
+    /// Computes the horizontal center of a glyph.
     ///
     /// Arguments:
     ///
-    /// * `text`: The text that should be converted to a mesh.
-    /// * `transform`: The optional 3x3 homogenous transformation matrix in column
-    /// major order that will be applied to this text.
+    /// * `glyph_id`: The id of the glyph.
+    /// * `advance`: The horizontal advance of the glyph.
     ///
     /// Returns:
     ///
-    /// The desired [MeshText] or an [MeshTextError] if anything went wrong in the
-    /// process.
-    fn generate_text_section_indexed_2d(
-        &mut self,
-        text: &str,
-        transform: Option<&[f32; 9]>,
-    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
-        let base_transform = match transform {
-            Some(value) => Mat3::from_cols_array(value),
-            None => Mat3::IDENTITY,
+    /// The center of the glyph's bounding box relative to the font size or
+    /// half of its advance if it has no outline.
+    fn glyph_center_x(&self, glyph_id: GlyphKey, advance: f32) -> f32 {
+        let glyph_id = match glyph_id {
+            GlyphKey::Font(glyph_id) => glyph_id,
+            GlyphKey::Custom(_) | GlyphKey::Fallback => return advance * 0.5f32,
         };
 
-        let mut mesh = (Vec::new(), Vec::new(), BoundingBox::empty());
-        let mut overall_advance = 0f32;
-        let mut index_offset = 0;
-
-        let mut chars_iter = text.chars();
+        match self.font.glyph_bounding_box(glyph_id) {
+            Some(rect) => (rect.x_min as f32 + rect.x_max as f32) * 0.5f32 / self.font_scale(),
+            None => advance * 0.5f32,
+        }
+    }
 
-        // The first char will be handled differently if present.
-        if let Some(first_glyph) = chars_iter.next() {
-            let x_advance = self
-                .font
-                .glyph_hor_advance(self.glyph_id_of_char(first_glyph))
-                .unwrap_or(0) as f32
-                / self.font.height() as f32;
+    /// Gets the kerning between two glyphs of the font.
+    ///
+    /// Arguments:
+    ///
+    /// * `left`: The glyph on the left side.
+    /// * `right`: The glyph on the right side.
+    ///
+    /// Returns:
+    ///
+    /// The kerning relative to the font size.
+    fn glyph_kerning(&self, left: GlyphId, right: GlyphId) -> f32 {
+        match self.font.kerning(left, right) {
+            Some(kerning) => kerning as f32 / self.font_scale(),
+            None => 0f32,
+        }
+    }
 
-            let transform =
-                base_transform * Mat3::from_translation(Vec2::new(overall_advance, 0f32));
-            let mut glyph_mesh =
-                self.generate_glyph_with_glam_transform_indexed_2d(first_glyph, &transform)?;
+    /// Gets the horizontal advance of a certain glyph.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph_id`: The id of the glyph of which the advance is determined.
+    ///
+    /// Returns:
+    ///
+    /// The advance relative to the font size, which uses the
+    /// [AdvanceFallback] if the font does not specify it.
+    fn glyph_advance(&self, glyph_id: GlyphId) -> f32 {
+        if let Some(advance) = self.font.glyph_hor_advance(glyph_id) {
+            return advance as f32 / self.font_scale();
+        }
 
-            // Update index offset (note that glyph meshes can be empty).
-            if let Some(max) = glyph_mesh.0.iter().max() {
-                index_offset = *max + 1;
+        let glyph_width = |side_bearing: f32| match self.font.glyph_bounding_box(glyph_id) {
+            Some(rect) => {
+                (rect.x_max as f32 - rect.x_min as f32) / self.font_scale() + 2f32 * side_bearing
             }
+            None => 0f32,
+        };
 
-            // Add vertices and replace bbox.
-            mesh.0.append(&mut glyph_mesh.0);
-            mesh.1.append(&mut glyph_mesh.1);
-            mesh = (mesh.0, mesh.1, glyph_mesh.2);
-
-            overall_advance += x_advance;
+        match self.advance_fallback {
+            AdvanceFallback::Zero => 0f32,
+            AdvanceFallback::GlyphWidth { side_bearing } => glyph_width(side_bearing),
+            AdvanceFallback::AverageAdvance => match self.font.average_advance() {
+                Some(advance) => advance as f32 / self.font_scale(),
+                None => glyph_width(0f32),
+            },
         }
+    }
 
-        for glyph in chars_iter {
-            let x_advance = self
-                .font
-                .glyph_hor_advance(self.glyph_id_of_char(glyph))
-                .unwrap_or(0) as f32
-                / self.font.height() as f32;
-
-            let transform =
-                base_transform * Mat3::from_translation(Vec2::new(overall_advance, 0f32));
-            let mut glyph_mesh =
-                self.generate_glyph_with_glam_transform_indexed_2d(glyph, &transform)?;
-
-            // Offset indices.
-            for i in glyph_mesh.0.iter_mut() {
-                *i += index_offset;
-            }
+    /// Finds the [GlyphId] of a certain [char].
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character of which the id is determined.
+    ///
+    /// Returns:
+    ///
+    /// The corresponding [GlyphId].
+    fn glyph_id_of_char(&self, glyph: char) -> GlyphId {
+        self.font
+            .glyph_index(glyph)
+            .unwrap_or(ttf_parser::GlyphId(0))
+    }
 
-            // Update index offset (note that glyph meshes can be empty).
-            if let Some(max) = glyph_mesh.0.iter().max() {
-                index_offset = *max + 1;
-            }
+    /// Finds the [GlyphKey] of a certain [char].
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character of which the glyph is determined.
+    ///
+    /// Returns:
+    ///
+    /// The custom glyph of the [GlyphProvider], the glyph of the font or
+    /// the fallback mesh if the character is missing from the font.
+    fn glyph_key_of_char(&self, glyph: char) -> GlyphKey {
+        if self.custom_advance(glyph).is_some() {
+            return GlyphKey::Custom(glyph);
+        }
 
-            // Add vertices and indices and adjust bbox.
-            mesh.0.append(&mut glyph_mesh.0);
-            mesh.1.append(&mut glyph_mesh.1);
-            mesh = (mesh.0, mesh.1, mesh.2.combine(&glyph_mesh.2));
+        match self.font.glyph_index(glyph) {
+            Some(glyph_id) => GlyphKey::Font(glyph_id),
+            None if self.fallback_mesh.is_some() => GlyphKey::Fallback,
+            None => GlyphKey::Font(GlyphId(0)),
+        }
+    }
 
-            overall_advance += x_advance;
+    /// Finds the [GlyphKey] of a [char] followed by a variation selector.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The character of which the glyph is determined.
+    /// * `selector`: The variation selector that follows the character.
+    ///
+    /// Returns:
+    ///
+    /// The variation of the glyph from the font or the same [GlyphKey]
+    /// as [MeshGenerator::glyph_key_of_char] if the font has no such variation.
+    #[cfg(not(feature = "shaping"))]
+    fn glyph_key_of_sequence(&self, glyph: char, selector: char) -> GlyphKey {
+        if self.custom_advance(glyph).is_some() {
+            return GlyphKey::Custom(glyph);
         }
 
-        Ok(text_mesh_from_data_indexed_2d(mesh))
+        match self.font.glyph_variation_index(glyph, selector) {
+            Some(glyph_id) => GlyphKey::Font(glyph_id),
+            None => self.glyph_key_of_char(glyph),
+        }
     }
 
-    /// Loads the given glyph from the cache or adds it.
+    /// Finds the ligature of the font for a sequence of characters
+    /// that are combined by zero-width joiners, such as emoji sequences.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be loaded.
-    /// * `flat`: Wether the character should be laid out in a 2D mesh.
+    /// * `characters`: The characters starting with the first character
+    ///   of the sequence and their byte offsets.
     ///
     /// Returns:
     ///
-    /// A [Result] containing the [Mesh] if successful, otherwise an [MeshTextError].
-    fn load_from_cache(&mut self, glyph: char, flat: bool) -> Result<Mesh, Box<dyn MeshTextError>> {
-        if flat {
-            match self.cache.get(&glyph.to_string()) {
-                Some(glyph_mesh) => Ok(glyph_mesh.to_owned()),
-                None => self.insert_into_cache(glyph, flat),
-            }
-        } else {
-            match self.cache.get(&format!("_{}", glyph)) {
-                Some(glyph_mesh) => Ok(glyph_mesh.to_owned()),
-                None => self.insert_into_cache(glyph, flat),
+    /// The ligature and the number of characters it replaces or `None` if the
+    /// characters are not joined or the font has no ligature for them.
+    #[cfg(not(feature = "shaping"))]
+    fn joined_ligature(&self, characters: &[(usize, char)]) -> Option<(GlyphId, usize)> {
+        let (_, first) = *characters.first()?;
+        if self.custom_advance(first).is_some() {
+            return None;
+        }
+
+        // The glyphs of the sequence and the number of characters up to each glyph.
+        let mut glyphs: Vec<(GlyphId, bool)> = Vec::new();
+        let mut ends: Vec<usize> = Vec::new();
+        let mut index = 0;
+        while let Some(&(_, c)) = characters.get(index) {
+            let selector = characters
+                .get(index + 1)
+                .map(|(_, s)| *s)
+                .filter(|s| is_variation_selector(*s));
+            let glyph_id = selector
+                .and_then(|s| self.font.glyph_variation_index(c, s))
+                .unwrap_or_else(|| self.glyph_id_of_char(c));
+            index += 1 + selector.is_some() as usize;
+            glyphs.push((glyph_id, false));
+            ends.push(index);
+
+            match characters.get(index..index + 2) {
+                Some([(_, ZERO_WIDTH_JOINER), _]) => {
+                    glyphs.push((self.glyph_id_of_char(ZERO_WIDTH_JOINER), true));
+                    index += 1;
+                    ends.push(index);
+                }
+                _ => break,
             }
         }
+        if glyphs.len() < 2 {
+            return None;
+        }
+
+        let (ligature, length) = self.font.ligature(&glyphs)?;
+        Some((ligature, ends[length - 1]))
     }
 
-    /// Loads the given glyph from the cache or adds it.
+    /// Gets the advance of a custom glyph.
     ///
-    /// This function deals with indexed meshes.
+    /// Arguments:
+    ///
+    /// * `glyph`: The character of the glyph.
+    ///
+    /// Returns:
+    ///
+    /// The advance or `None` if the [GlyphProvider] does not provide the glyph.
+    fn custom_advance(&self, glyph: char) -> Option<f32> {
+        self.glyph_provider.as_ref()?.advance(glyph)
+    }
+
+    /// Gets the horizontal advance of a glyph of the font or a custom glyph.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be loaded.
-    /// * `flat`: Wether the character should be laid out in a 2D mesh.
+    /// * `glyph`: The glyph of which the advance is determined.
     ///
     /// Returns:
     ///
-    /// A [Result] containing the [IndexedMesh] if successful, otherwise an [MeshTextError].
-    fn load_from_cache_indexed(
-        &mut self,
-        glyph: char,
-        flat: bool,
-    ) -> Result<IndexedMesh, Box<dyn MeshTextError>> {
-        if flat {
-            match self.indexed_cache.get(&glyph.to_string()) {
-                Some(glyph_mesh) => Ok(glyph_mesh.to_owned()),
-                None => self.insert_into_cache_indexed(glyph, flat),
-            }
-        } else {
-            match self.indexed_cache.get(&format!("_{}", glyph)) {
-                Some(glyph_mesh) => Ok(glyph_mesh.to_owned()),
-                None => self.insert_into_cache_indexed(glyph, flat),
-            }
+    /// The advance relative to the font size.
+    fn key_advance(&self, glyph: GlyphKey) -> f32 {
+        match glyph {
+            GlyphKey::Font(glyph_id) => self.glyph_advance(glyph_id),
+            GlyphKey::Custom(character) => self.custom_advance(character).unwrap_or(0f32),
+            GlyphKey::Fallback => self.fallback_mesh.as_ref().map_or(0f32, |m| m.2),
         }
     }
 
-    /// Generates a new [Mesh] from the loaded font and the given `glyph`
-    /// and inserts it into the internal `cache`.
+    /// Gets the custom fallback mesh for flat or three-dimensional text.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be loaded.
-    /// * `flat`: Wether the character should be laid out in a 2D mesh.
+    /// * `glyph`: The key of the glyph.
+    /// * `flat`: Wether the glyph should be laid out in a 2D mesh.
     ///
     /// Returns:
     ///
-    /// A [Result] containing the [Mesh] if successful, otherwise an [MeshTextError].
-    fn insert_into_cache(
-        &mut self,
-        glyph: char,
-        flat: bool,
-    ) -> Result<Mesh, Box<dyn MeshTextError>> {
-        let font_height = self.font.height() as f32;
-        let mut builder = GlyphOutlineBuilder::new(font_height, self.quality);
-
-        let glyph_index = self.glyph_id_of_char(glyph);
-
-        let mut depth = (0.5f32, -0.5f32);
-        let (rect, mesh) = match self.font.outline_glyph(glyph_index, &mut builder) {
-            Some(bbox) => {
-                let mesh = raster_to_mesh(&builder.get_glyph_outline(), flat)?;
-                (bbox, mesh)
-            }
-            None => {
-                // The glyph has no outline so it is most likely a space or any other
-                // charcter that can not be displayed.
-                // An empty mesh is cached for simplicity nevertheless.
-                depth = (0f32, 0f32);
-                (
-                    ttf_parser::Rect {
-                        x_min: 0,
-                        y_min: 0,
-                        x_max: 0,
-                        y_max: 0,
-                    },
-                    Vec::new(),
-                )
-            }
-        };
-
-        // Add mesh to cache.
-        let bbox;
-        if flat {
-            bbox = BoundingBox {
-                max: Vec3A::new(
-                    rect.x_max as f32 / font_height,
-                    rect.y_max as f32 / font_height,
-                    0f32,
-                ),
-                min: Vec3A::new(
-                    rect.x_min as f32 / font_height,
-                    rect.y_min as f32 / font_height,
-                    0f32,
-                ),
-            };
-            self.cache.insert(glyph.to_string(), (mesh.clone(), bbox));
-        } else {
-            bbox = BoundingBox {
-                max: Vec3A::new(
-                    rect.x_max as f32 / font_height,
-                    rect.y_max as f32 / font_height,
-                    depth.0,
-                ),
-                min: Vec3A::new(
-                    rect.x_min as f32 / font_height,
-                    rect.y_min as f32 / font_height,
-                    depth.1,
-                ),
-            };
-            self.cache
-                .insert(format!("_{}", glyph), (mesh.clone(), bbox));
+    /// The [IndexedMesh] of the fallback glyph or `None` if the glyph
+    /// is not replaced by a custom fallback mesh.
+    fn fallback_glyph(&self, glyph: GlyphKey, flat: bool) -> Option<IndexedMesh> {
+        if glyph != GlyphKey::Fallback {
+            return None;
         }
-
-        Ok((mesh, bbox))
+        let (mesh, flat_mesh, _) = self.fallback_mesh.as_ref()?;
+
+        Some(convert_fallback_mesh(
+            mesh,
+            *flat_mesh,
+            flat,
+            &self.extrusion,
+        ))
     }
 
-    /// Generates a new [IndexedMesh] from the loaded font and the given `glyph`
-    /// and inserts it into the internal `cache`.
+    /// Outlines a glyph of the font or a custom glyph.
+    ///
+    /// Glyphs of the font that only have a raster image are outlined as a rectangle.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character that should be loaded.
-    /// * `flat`: Wether the character should be laid out in a 2D mesh.
+    /// * `glyph`: The glyph that is outlined.
+    /// * `builder`: The builder that receives the outline.
     ///
     /// Returns:
     ///
-    /// A [Result] containing the [IndexedMesh] if successful, otherwise an [MeshTextError].
-    fn insert_into_cache_indexed(
-        &mut self,
-        glyph: char,
-        flat: bool,
-    ) -> Result<IndexedMesh, Box<dyn MeshTextError>> {
-        let font_height = self.font.height() as f32;
-        let mut builder = GlyphOutlineBuilder::new(font_height, self.quality);
-
-        let glyph_index = self.glyph_id_of_char(glyph);
-
-        let mut depth = (0.5f32, -0.5f32);
-        let (rect, vertices, indices) = match self.font.outline_glyph(glyph_index, &mut builder) {
-            Some(bbox) => {
-                let mesh = raster_to_mesh_indexed(&builder.get_glyph_outline(), flat)?;
-                (bbox, mesh.0, mesh.1)
-            }
-            None => {
-                // The glyph has no outline so it is most likely a space or any other
-                // charcter that can not be displayed.
-                // An empty mesh is cached for simplicity nevertheless.
-                depth = (0f32, 0f32);
-                (
-                    ttf_parser::Rect {
-                        x_min: 0,
-                        y_min: 0,
-                        x_max: 0,
-                        y_max: 0,
-                    },
-                    Vec::new(),
-                    Vec::new(),
-                )
+    /// The normalized bounding box of the glyph or `None` if it has no outline.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self, builder))
+    )]
+    fn outline_glyph(
+        &self,
+        glyph: GlyphKey,
+        builder: &mut GlyphOutlineBuilder,
+    ) -> Option<BoundingBox> {
+        builder.set_close_contours(self.close_contours);
+        builder.set_min_contour_area(self.min_contour_area);
+        builder.set_max_segment_length(self.max_segment_length);
+        let character = match glyph {
+            GlyphKey::Font(glyph_id) => {
+                let rect = self.font.outline_glyph(glyph_id, builder);
+                builder.close_open_contour();
+                let rect = rect.or_else(|| self.outline_raster_image(glyph_id, builder))?;
+
+                let scale = self.font_scale();
+                return Some(BoundingBox::new(
+                    Vec3A::new(rect.x_min as f32, rect.y_min as f32, 0f32) / scale,
+                    Vec3A::new(rect.x_max as f32, rect.y_max as f32, 0f32) / scale,
+                ));
             }
+            GlyphKey::Custom(character) => character,
+            GlyphKey::Fallback => return None,
         };
-
-        // Add mesh to cache.
-        let bbox;
-        if flat {
-            bbox = BoundingBox {
-                max: Vec3A::new(
-                    rect.x_max as f32 / font_height,
-                    rect.y_max as f32 / font_height,
-                    0f32,
-                ),
-                min: Vec3A::new(
-                    rect.x_min as f32 / font_height,
-                    rect.y_min as f32 / font_height,
-                    0f32,
-                ),
-            };
-            self.indexed_cache
-                .insert(glyph.to_string(), (indices.clone(), vertices.clone(), bbox));
-        } else {
-            bbox = BoundingBox {
-                max: Vec3A::new(
-                    rect.x_max as f32 / font_height,
-                    rect.y_max as f32 / font_height,
-                    depth.0,
-                ),
-                min: Vec3A::new(
-                    rect.x_min as f32 / font_height,
-                    rect.y_min as f32 / font_height,
-                    depth.1,
-                ),
-            };
-            self.indexed_cache.insert(
-                format!("_{}", glyph),
-                (indices.clone(), vertices.clone(), bbox),
-            );
+        let provider = self.glyph_provider.as_ref()?;
+
+        // The builder normalizes the points, so they are converted to font units.
+        let scale = self.font_scale();
+        let mut min = Vec2::splat(f32::INFINITY);
+        let mut max = Vec2::splat(f32::NEG_INFINITY);
+        for contour in provider.outline(character).iter().filter(|c| c.len() >= 3) {
+            let first = contour[0] * scale;
+            builder.move_to(first.x, first.y);
+            for (i, point) in contour.iter().enumerate() {
+                if i > 0 {
+                    let scaled = *point * scale;
+                    builder.line_to(scaled.x, scaled.y);
+                }
+                // Non-finite contours are discarded by the builder.
+                if point.is_finite() {
+                    min = min.min(*point);
+                    max = max.max(*point);
+                }
+            }
+            builder.line_to(first.x, first.y);
+            builder.close();
         }
 
-        Ok((indices, vertices, bbox))
+        (min.x <= max.x).then(|| {
+            BoundingBox::new(
+                Vec3A::new(min.x, min.y, 0f32),
+                Vec3A::new(max.x, max.y, 0f32),
+            )
+        })
     }
 
-    /// Finds the [GlyphId] of a certain [char].
+    /// Outlines the rectangle of a glyph that only has a raster image.
     ///
     /// Arguments:
     ///
-    /// * `glyph`: The character of which the id is determined.
+    /// * `glyph_id`: The id of the glyph in the font.
+    /// * `builder`: The builder that receives the outline.
     ///
     /// Returns:
     ///
-    /// The corresponding [GlyphId].
-    fn glyph_id_of_char(&self, glyph: char) -> GlyphId {
-        self.font
-            .glyph_index(glyph)
-            .unwrap_or(ttf_parser::GlyphId(0))
+    /// The bounding box of the glyph in font units or `None` if it has no raster image.
+    fn outline_raster_image(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut GlyphOutlineBuilder,
+    ) -> Option<ttf_parser::Rect> {
+        let (_, rect) = self.raster_image_bounds(glyph_id)?;
+
+        // The contour is clockwise like the outer contours of TrueType glyphs.
+        builder.move_to(rect.x_min as f32, rect.y_min as f32);
+        builder.line_to(rect.x_min as f32, rect.y_max as f32);
+        builder.line_to(rect.x_max as f32, rect.y_max as f32);
+        builder.line_to(rect.x_max as f32, rect.y_min as f32);
+        builder.line_to(rect.x_min as f32, rect.y_min as f32);
+        builder.close();
+
+        Some(rect)
+    }
+
+    /// Gets the largest raster image of a glyph together with the area it covers.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph_id`: The id of the glyph in the font.
+    ///
+    /// Returns:
+    ///
+    /// The raster image and its bounding box in font units
+    /// or `None` if the glyph has no raster image.
+    fn raster_image_bounds(
+        &self,
+        glyph_id: GlyphId,
+    ) -> Option<(ttf_parser::RasterGlyphImage<'_>, ttf_parser::Rect)> {
+        let image = self.font.raster_image(glyph_id, u16::MAX)?;
+        if image.pixels_per_em == 0 || image.width == 0 || image.height == 0 {
+            return None;
+        }
+
+        let scale = self.font.units_per_em() as f32 / image.pixels_per_em as f32;
+        let x = image.x as f32;
+        let y = image.y as f32;
+        let rect = ttf_parser::Rect {
+            x_min: (x * scale).floor() as i16,
+            y_min: (y * scale).floor() as i16,
+            x_max: ((x + image.width as f32) * scale).ceil() as i16,
+            y_max: ((y + image.height as f32) * scale).ceil() as i16,
+        };
+
+        Some((image, rect))
     }
 }
 
@@ -1302,25 +7541,26 @@ where
     }
 }
 
-impl<T> TextSection<IndexedMeshText> for MeshGenerator<T>
+impl<T, I> TextSection<IndexedMeshText<I>> for MeshGenerator<T>
 where
     T: FontFace,
+    I: IndexType,
 {
     fn generate_section(
         &mut self,
         text: &str,
         flat: bool,
         transform: Option<&[f32; 16]>,
-    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
-        self.generate_text_section_indexed(text, flat, transform)
+    ) -> Result<IndexedMeshText<I>, Box<dyn MeshTextError>> {
+        Self::convert_indices(self.generate_text_section_indexed(text, flat, transform)?)
     }
 
     fn generate_section_2d(
         &mut self,
         text: &str,
         transform: Option<&[f32; 9]>,
-    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
-        self.generate_text_section_indexed_2d(text, transform)
+    ) -> Result<IndexedMeshText<I>, Box<dyn MeshTextError>> {
+        Self::convert_indices(self.generate_text_section_indexed_2d(text, transform)?)
     }
 }
 
@@ -1346,24 +7586,211 @@ where
     }
 }
 
-impl<T> Glyph<IndexedMeshText> for MeshGenerator<T>
+impl<T, I> Glyph<IndexedMeshText<I>> for MeshGenerator<T>
 where
     T: FontFace,
+    I: IndexType,
 {
     fn generate_glyph(
         &mut self,
         glyph: char,
         flat: bool,
         transform: Option<&[f32; 16]>,
-    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
-        self.generate_glyph_indexed(glyph, flat, transform)
+    ) -> Result<IndexedMeshText<I>, Box<dyn MeshTextError>> {
+        Self::convert_indices(self.generate_glyph_indexed(glyph, flat, transform)?)
     }
 
     fn generate_glyph_2d(
         &mut self,
         glyph: char,
         transform: Option<&[f32; 9]>,
-    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
-        self.generate_glyph_indexed_2d(glyph, transform)
+    ) -> Result<IndexedMeshText<I>, Box<dyn MeshTextError>> {
+        Self::convert_indices(self.generate_glyph_indexed_2d(glyph, transform)?)
+    }
+}
+
+impl<T, V> TextSection<MeshTextGlam<V>> for MeshGenerator<T>
+where
+    T: FontFace,
+    V: GlamVertex,
+{
+    fn generate_section(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<MeshTextGlam<V>, Box<dyn MeshTextError>> {
+        Self::check_three_dimensional::<V>()?;
+        self.generate_text_section(text, flat, transform)
+    }
+
+    fn generate_section_2d(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<MeshTextGlam<V>, Box<dyn MeshTextError>> {
+        self.generate_text_section_2d(text, transform)
+    }
+}
+
+impl<T, V> Glyph<MeshTextGlam<V>> for MeshGenerator<T>
+where
+    T: FontFace,
+    V: GlamVertex,
+{
+    fn generate_glyph(
+        &mut self,
+        glyph: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<MeshTextGlam<V>, Box<dyn MeshTextError>> {
+        Self::check_three_dimensional::<V>()?;
+        self.generate_glyph(glyph, flat, transform)
+    }
+
+    fn generate_glyph_2d(
+        &mut self,
+        glyph: char,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<MeshTextGlam<V>, Box<dyn MeshTextError>> {
+        self.generate_glyph_2d(glyph, transform)
+    }
+}
+
+impl<T, V, I> TextSection<IndexedMeshTextGlam<V, I>> for MeshGenerator<T>
+where
+    T: FontFace,
+    V: GlamVertex,
+    I: IndexType,
+{
+    fn generate_section(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<IndexedMeshTextGlam<V, I>, Box<dyn MeshTextError>> {
+        Self::check_three_dimensional::<V>()?;
+        Self::convert_glam_indices(self.generate_text_section_indexed(text, flat, transform)?)
+    }
+
+    fn generate_section_2d(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<IndexedMeshTextGlam<V, I>, Box<dyn MeshTextError>> {
+        Self::convert_glam_indices(self.generate_text_section_indexed_2d(text, transform)?)
+    }
+}
+
+impl<T, V, I> Glyph<IndexedMeshTextGlam<V, I>> for MeshGenerator<T>
+where
+    T: FontFace,
+    V: GlamVertex,
+    I: IndexType,
+{
+    fn generate_glyph(
+        &mut self,
+        glyph: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<IndexedMeshTextGlam<V, I>, Box<dyn MeshTextError>> {
+        Self::check_three_dimensional::<V>()?;
+        Self::convert_glam_indices(self.generate_glyph_indexed(glyph, flat, transform)?)
+    }
+
+    fn generate_glyph_2d(
+        &mut self,
+        glyph: char,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<IndexedMeshTextGlam<V, I>, Box<dyn MeshTextError>> {
+        Self::convert_glam_indices(self.generate_glyph_indexed_2d(glyph, transform)?)
+    }
+}
+
+impl<T> TextSection<InterleavedMeshText> for MeshGenerator<T>
+where
+    T: FontFace,
+{
+    fn generate_section(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<InterleavedMeshText, Box<dyn MeshTextError>> {
+        self.generate_text_section_interleaved(text, flat, transform)
+    }
+
+    fn generate_section_2d(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<InterleavedMeshText, Box<dyn MeshTextError>> {
+        self.generate_text_section_interleaved_2d(text, transform)
+    }
+}
+
+impl<T> Glyph<InterleavedMeshText> for MeshGenerator<T>
+where
+    T: FontFace,
+{
+    fn generate_glyph(
+        &mut self,
+        glyph: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<InterleavedMeshText, Box<dyn MeshTextError>> {
+        self.generate_glyph_interleaved(glyph, flat, transform)
+    }
+
+    fn generate_glyph_2d(
+        &mut self,
+        glyph: char,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<InterleavedMeshText, Box<dyn MeshTextError>> {
+        self.generate_glyph_interleaved_2d(glyph, transform)
+    }
+}
+
+impl<T> TextSection<PartitionedMeshText> for MeshGenerator<T>
+where
+    T: FontFace,
+{
+    fn generate_section(
+        &mut self,
+        text: &str,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<PartitionedMeshText, Box<dyn MeshTextError>> {
+        self.generate_text_section_partitioned(text, flat, transform)
+    }
+
+    fn generate_section_2d(
+        &mut self,
+        text: &str,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<PartitionedMeshText, Box<dyn MeshTextError>> {
+        self.generate_text_section_partitioned_2d(text, transform)
+    }
+}
+
+impl<T> Glyph<PartitionedMeshText> for MeshGenerator<T>
+where
+    T: FontFace,
+{
+    fn generate_glyph(
+        &mut self,
+        glyph: char,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> Result<PartitionedMeshText, Box<dyn MeshTextError>> {
+        self.generate_glyph_partitioned(glyph, flat, transform)
+    }
+
+    fn generate_glyph_2d(
+        &mut self,
+        glyph: char,
+        transform: Option<&[f32; 9]>,
+    ) -> Result<PartitionedMeshText, Box<dyn MeshTextError>> {
+        self.generate_glyph_partitioned_2d(glyph, transform)
     }
 }
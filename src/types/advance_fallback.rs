@@ -0,0 +1,22 @@
+/// Controls the horizontal advance of glyphs for which
+/// the font does not specify one.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AdvanceFallback {
+    /// The advance is zero, which means the following glyph
+    /// will be placed on top of the current one.
+    #[default]
+    Zero,
+
+    /// The advance is the width of the glyph plus the given side bearing
+    /// on both sides, which is relative to the font size.
+    GlyphWidth {
+        /// The space that is added on the left and on the right of the glyph.
+        side_bearing: f32,
+    },
+
+    /// The advance is the average advance specified by the font.
+    ///
+    /// If the font does not specify an average advance,
+    /// the width of the glyph is used instead.
+    AverageAdvance,
+}
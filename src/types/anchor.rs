@@ -0,0 +1,43 @@
+use glam::Vec3A;
+
+use crate::BoundingBox;
+
+/// The point of a generated mesh that is moved to the origin when it is aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Anchor {
+    /// The start of the baseline.
+    ///
+    /// The left edge of the bounding box is moved to `x = 0`, while the
+    /// baseline and the depth of the mesh keep their position.
+    #[default]
+    BaselineStart,
+
+    /// The center of the bounding box.
+    Center,
+
+    /// The corner of the bounding box with the smallest coordinates.
+    MinCorner,
+
+    /// The corner of the bounding box with the largest coordinates.
+    MaxCorner,
+}
+
+impl Anchor {
+    /// Computes the position of this anchor.
+    ///
+    /// Arguments:
+    ///
+    /// * `bbox`: The [BoundingBox] of the mesh.
+    ///
+    /// Returns:
+    ///
+    /// The position of this anchor, which is moved to the origin.
+    pub(crate) fn position(&self, bbox: &BoundingBox) -> Vec3A {
+        match self {
+            Anchor::BaselineStart => Vec3A::new(bbox.min.x, 0f32, 0f32),
+            Anchor::Center => bbox.center(),
+            Anchor::MinCorner => bbox.min,
+            Anchor::MaxCorner => bbox.max,
+        }
+    }
+}
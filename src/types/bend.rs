@@ -0,0 +1,64 @@
+use glam::Vec3A;
+
+/// Wraps text sections around a curved surface.
+///
+/// The horizontal center of each section stays in place, while the text
+/// wraps around the surface towards both sides. The surface touches the
+/// XY-plane at the origin of the untransformed text and curves away
+/// along the negative z-axis, so that the text is on the outside.
+/// A negative radius places the text on the inside instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bend {
+    /// The text is wrapped around a cylinder whose axis is parallel to the y-axis.
+    Cylinder {
+        /// The radius of the cylinder relative to the font size.
+        radius: f32,
+    },
+
+    /// The text is wrapped around a sphere, horizontally along the
+    /// lines of latitude and vertically along the lines of longitude.
+    Sphere {
+        /// The radius of the sphere relative to the font size.
+        radius: f32,
+    },
+}
+
+impl Bend {
+    /// Maps a point of the untransformed text onto the curved surface.
+    ///
+    /// Distances along the text are preserved on the surface, while the
+    /// z-coordinate is used as the distance from the surface.
+    ///
+    /// Arguments:
+    ///
+    /// * `point`: The point in the coordinate system of the untransformed text.
+    /// * `center`: The horizontal center of the text, which stays in place.
+    ///
+    /// Returns:
+    ///
+    /// The bent point.
+    pub(crate) fn apply(&self, point: Vec3A, center: f32) -> Vec3A {
+        let (radius, spherical) = match *self {
+            Bend::Cylinder { radius } => (radius, false),
+            Bend::Sphere { radius } => (radius, true),
+        };
+        if radius == 0f32 {
+            return point;
+        }
+
+        let distance = radius + point.z;
+        let longitude = (point.x - center) / radius;
+        let (y, ring) = if spherical {
+            let latitude = point.y / radius;
+            (distance * latitude.sin(), distance * latitude.cos())
+        } else {
+            (point.y, distance)
+        };
+
+        Vec3A::new(
+            center + ring * longitude.sin(),
+            y,
+            ring * longitude.cos() - radius,
+        )
+    }
+}
@@ -1,8 +1,12 @@
-use glam::{Mat4, Vec3A};
+use glam::{Mat4, Vec3, Vec3A};
 
 /// A bounding box or bounding rectangle in the case of
 /// a flat mesh.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
 pub struct BoundingBox {
     /// The coordinates of the maximum point.
     ///
@@ -180,6 +184,106 @@ impl BoundingBox {
         self.min = Vec3A::new(min.x, min.y, 0f32);
         self.max = Vec3A::new(max.x, max.y, 0f32);
     }
+
+    /// Computes the transformation that uniformly scales and centers
+    /// this [BoundingBox], so that it fits into another one.
+    ///
+    /// Axes along which either box has no extent are ignored for the scale.
+    ///
+    /// Arguments:
+    ///
+    /// * `target`: The [BoundingBox] into which this one is fitted.
+    ///
+    /// Returns:
+    ///
+    /// The 4x4 homogenous transformation matrix and the uniform scale.
+    pub(crate) fn fit_transform(&self, target: &BoundingBox) -> (Mat4, f32) {
+        let (size, target_size) = (self.size(), target.size());
+        let scale = (0..3)
+            .filter(|a| size[*a] > 0f32 && target_size[*a] > 0f32)
+            .map(|a| target_size[a] / size[a])
+            .fold(f32::INFINITY, f32::min);
+        let scale = if scale.is_finite() { scale } else { 1f32 };
+
+        let transform = Mat4::from_translation(target.center().into())
+            * Mat4::from_scale(Vec3::splat(scale))
+            * Mat4::from_translation(-Vec3::from(self.center()));
+
+        (transform, scale)
+    }
+}
+
+#[cfg(feature = "arrays")]
+impl BoundingBox {
+    /// Creates a new [BoundingBox] from plain arrays.
+    ///
+    /// Arguments:
+    ///
+    /// * `min`: The minimum vertex (XYZ) of this bounding box.
+    /// * `max`: The maximum vertex (XYZ) of this bounding box.
+    ///
+    /// Returns:
+    ///
+    /// The new [BoundingBox].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::BoundingBox;
+    ///
+    /// let bbox = BoundingBox::from_arrays([0f32, 0f32, 0f32], [1f32, 2f32, 3f32]);
+    ///
+    /// assert_eq!(bbox.center_array(), [0.5, 1.0, 1.5]);
+    /// assert_eq!(bbox.size_array(), [1.0, 2.0, 3.0]);
+    /// ```
+    pub fn from_arrays(min: [f32; 3], max: [f32; 3]) -> Self {
+        Self::new(Vec3A::from_array(min), Vec3A::from_array(max))
+    }
+
+    /// Gets the coordinates of the maximum point.
+    ///
+    /// Returns:
+    ///
+    /// The maximum point (XYZ) of this [BoundingBox].
+    pub fn max_array(&self) -> [f32; 3] {
+        self.max.to_array()
+    }
+
+    /// Gets the coordinates of the minimum point.
+    ///
+    /// Returns:
+    ///
+    /// The minimum point (XYZ) of this [BoundingBox].
+    pub fn min_array(&self) -> [f32; 3] {
+        self.min.to_array()
+    }
+
+    /// Calculates the center of this [BoundingBox].
+    ///
+    /// Returns:
+    ///
+    /// The point (XYZ) in the geometric center of this [BoundingBox].
+    pub fn center_array(&self) -> [f32; 3] {
+        self.center().to_array()
+    }
+
+    /// Gets the size of this [BoundingBox].
+    ///
+    /// Returns:
+    ///
+    /// The extent (XYZ) of this [BoundingBox] along each coordinate axis.
+    pub fn size_array(&self) -> [f32; 3] {
+        self.size().to_array()
+    }
+
+    /// Applies the given transformation to this [BoundingBox].
+    ///
+    /// Arguments:
+    ///
+    /// * `transformation`: The 4x4 homogenous transformation matrix in column major order.
+    pub fn transform_array(&mut self, transformation: &[f32; 16]) {
+        self.transform(&Mat4::from_cols_array(transformation));
+    }
 }
 
 impl Default for BoundingBox {
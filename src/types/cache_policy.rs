@@ -0,0 +1,19 @@
+/// Controls which glyphs one of the internal caches of a [crate::MeshGenerator] keeps.
+///
+/// Unless the cache is disabled, identical glyph outlines
+/// additionally share a single triangulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CachePolicy {
+    /// Glyphs are not cached, so they are generated again each time they are requested.
+    Disabled,
+
+    /// All glyphs are cached until they are evicted manually or expire.
+    #[default]
+    Unlimited,
+
+    /// At most the given number of glyphs is cached.
+    ///
+    /// If the cache is full, the least recently used glyph is evicted.
+    /// The shared triangulations of glyph outlines are limited likewise.
+    Limited(usize),
+}
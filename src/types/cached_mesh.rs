@@ -0,0 +1,30 @@
+use glam::Vec3A;
+
+use crate::BoundingBox;
+
+/// The untransformed mesh of a glyph borrowed from one of the
+/// internal caches of a [crate::MeshGenerator].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CachedMesh<'a> {
+    /// The bounding box of the glyph.
+    pub bbox: BoundingBox,
+
+    /// The indices of the mesh if it was read from the [crate::CacheType::Indexed] cache.
+    pub indices: Option<&'a [u32]>,
+
+    /// The vertices of the mesh, which form a triangle list unless
+    /// the mesh is indexed.
+    pub vertices: &'a [Vec3A],
+}
+
+#[cfg(feature = "arrays")]
+impl CachedMesh<'_> {
+    /// Iterates over the vertices of the mesh.
+    ///
+    /// Returns:
+    ///
+    /// An iterator over the coordinates (XYZ) of each vertex.
+    pub fn vertex_arrays(&self) -> impl Iterator<Item = [f32; 3]> + '_ {
+        self.vertices.iter().map(|v| v.to_array())
+    }
+}
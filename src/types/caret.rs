@@ -0,0 +1,32 @@
+use glam::Vec2;
+
+/// The position of the text cursor in front of a character.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Caret {
+    /// The height of the caret, which spans from the
+    /// descender to the ascender of the font.
+    pub height: f32,
+
+    /// The index of the character in front of which the
+    /// caret is placed.
+    ///
+    /// The index is counted in characters, not bytes. A caret
+    /// with an index equal to the number of characters is
+    /// placed after the last character.
+    pub index: usize,
+
+    /// The bottom end of the caret.
+    pub position: Vec2,
+}
+
+#[cfg(feature = "arrays")]
+impl Caret {
+    /// Gets the bottom end of the caret.
+    ///
+    /// Returns:
+    ///
+    /// The position (XY) of the bottom end of the caret.
+    pub fn position_array(&self) -> [f32; 2] {
+        self.position.to_array()
+    }
+}
@@ -0,0 +1,32 @@
+/// A bitmap that stores how much of each pixel is covered by a mesh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageMask {
+    /// The coverage of all pixels in row-major order starting with the top row,
+    /// where `0` is uncovered and `255` is fully covered.
+    pub data: Vec<u8>,
+
+    /// The number of rows.
+    pub height: usize,
+
+    /// The number of pixels in each row.
+    pub width: usize,
+}
+
+impl CoverageMask {
+    /// Gets the coverage of a certain pixel.
+    ///
+    /// Arguments:
+    ///
+    /// * `column`: The column of the pixel starting at the left.
+    /// * `row`: The row of the pixel starting at the top.
+    ///
+    /// Returns:
+    ///
+    /// The coverage of the pixel or [None] if it is outside of the mask.
+    pub fn get(&self, column: usize, row: usize) -> Option<u8> {
+        if column >= self.width || row >= self.height {
+            return None;
+        }
+        Some(self.data[row * self.width + column])
+    }
+}
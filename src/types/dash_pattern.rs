@@ -0,0 +1,31 @@
+/// A repeating pattern of dashes and gaps along the contours of an outline.
+///
+/// Dotted outlines can be achieved with dashes that are about as long as the stroke is wide.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DashPattern {
+    /// The length of each dash relative to the font size.
+    pub dash: f32,
+
+    /// The length of each gap relative to the font size.
+    pub gap: f32,
+
+    /// The distance into the pattern at the start of each contour relative to the font size.
+    pub offset: f32,
+}
+
+impl DashPattern {
+    /// Creates a new [DashPattern].
+    ///
+    /// Arguments:
+    ///
+    /// * `dash`: The length of each dash relative to the font size.
+    /// * `gap`: The length of each gap relative to the font size.
+    /// * `offset`: The distance into the pattern at the start of each contour.
+    ///
+    /// Returns:
+    ///
+    /// The new [DashPattern].
+    pub fn new(dash: f32, gap: f32, offset: f32) -> Self {
+        Self { dash, gap, offset }
+    }
+}
@@ -0,0 +1,12 @@
+/// The placement of a line that decorates text, such as an underline.
+///
+/// All values are relative to the font size, just like the generated meshes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecorationMetrics {
+    /// The y-coordinate of the top edge of the line relative to the baseline,
+    /// which is negative for lines below the baseline.
+    pub position: f32,
+
+    /// The thickness of the line.
+    pub thickness: f32,
+}
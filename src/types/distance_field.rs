@@ -0,0 +1,66 @@
+use glam::Vec2;
+
+/// A multi-channel signed distance field (MSDF) of a glyph.
+///
+/// Each pixel consists of three `u8` channels (RGB). The median of the three
+/// channels is the signed distance to the outline, mapped so that `128`
+/// lies on the outline, larger values are inside of the glyph and the
+/// values saturate at a distance of [DistanceField::range].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceField {
+    /// The channels of all pixels in row-major order starting with the top row.
+    pub data: Vec<u8>,
+
+    /// The number of rows.
+    pub height: usize,
+
+    /// The lower left corner of the field relative to the font size.
+    pub origin: Vec2,
+
+    /// The size of a single pixel relative to the font size.
+    pub pixel_size: f32,
+
+    /// The distance relative to the font size at which the field saturates.
+    pub range: f32,
+
+    /// The number of pixels in each row.
+    pub width: usize,
+}
+
+impl DistanceField {
+    /// Gets the signed distance at a certain pixel.
+    ///
+    /// Arguments:
+    ///
+    /// * `column`: The column of the pixel starting at the left.
+    /// * `row`: The row of the pixel starting at the top.
+    ///
+    /// Returns:
+    ///
+    /// The median of the three channels relative to the font size,
+    /// which is positive inside of the glyph, or [None] if the
+    /// pixel is outside of the field.
+    pub fn distance(&self, column: usize, row: usize) -> Option<f32> {
+        if column >= self.width || row >= self.height {
+            return None;
+        }
+
+        let start = (row * self.width + column) * 3;
+        let mut channels = [self.data[start], self.data[start + 1], self.data[start + 2]];
+        channels.sort_unstable();
+
+        Some((channels[1] as f32 / 255f32 - 0.5f32) * 2f32 * self.range)
+    }
+}
+
+#[cfg(feature = "arrays")]
+impl DistanceField {
+    /// Gets the lower left corner of the field.
+    ///
+    /// Returns:
+    ///
+    /// The lower left corner (XY) relative to the font size.
+    pub fn origin_array(&self) -> [f32; 2] {
+        self.origin.to_array()
+    }
+}
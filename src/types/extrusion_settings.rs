@@ -0,0 +1,21 @@
+/// Controls the shape of three-dimensional glyphs.
+///
+/// These settings have no effect on flat meshes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtrusionSettings {
+    /// The scale of the back cap relative to the front cap.
+    ///
+    /// The back cap of each glyph is scaled about the center of the
+    /// glyph's bounding box, which results in slanted side walls.
+    /// Values below `1.0` generate a pyramid-like look, values above
+    /// `1.0` generate glyphs that widen towards the back.
+    pub back_cap_scale: f32,
+}
+
+impl Default for ExtrusionSettings {
+    fn default() -> Self {
+        Self {
+            back_cap_scale: 1f32,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/// An OpenType feature that is applied while shaping a text section.
+///
+/// Common features are small capitals (`smcp`), oldstyle numerals (`onum`),
+/// tabular figures (`tnum`) or the stylistic sets `ss01` to `ss20`.
+/// Which features are available depends on the font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontFeature {
+    /// The four letter tag of the feature.
+    pub tag: [u8; 4],
+
+    /// The value of the feature.
+    ///
+    /// `0` disables the feature and `1` enables it. Some features use
+    /// higher values to select one of multiple alternates.
+    pub value: u32,
+}
+
+impl FontFeature {
+    /// Creates a [FontFeature] that enables the given feature.
+    ///
+    /// Arguments:
+    ///
+    /// * `tag`: The four letter tag of the feature.
+    ///
+    /// Returns:
+    ///
+    /// The new [FontFeature].
+    pub fn enable(tag: &[u8; 4]) -> Self {
+        Self {
+            tag: *tag,
+            value: 1,
+        }
+    }
+
+    /// Creates a [FontFeature] that disables the given feature.
+    ///
+    /// Arguments:
+    ///
+    /// * `tag`: The four letter tag of the feature.
+    ///
+    /// Returns:
+    ///
+    /// The new [FontFeature].
+    pub fn disable(tag: &[u8; 4]) -> Self {
+        Self {
+            tag: *tag,
+            value: 0,
+        }
+    }
+}
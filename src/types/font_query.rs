@@ -0,0 +1,73 @@
+/// Describes a font that is loaded from the fonts installed on the system.
+///
+/// Besides the names of installed families the generic families
+/// `serif`, `sans-serif`, `cursive`, `fantasy` and `monospace` are supported,
+/// which are resolved with the font configuration of the system.
+///
+/// # Example
+///
+/// ```rust
+/// use meshtext::FontQuery;
+///
+/// let query = FontQuery::new("DejaVu Sans")
+///     .with_weight(700)
+///     .with_italic(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontQuery {
+    /// The name of the font family.
+    pub(crate) family: String,
+
+    /// Wether an italic or oblique style is requested.
+    pub(crate) italic: bool,
+
+    /// The weight of the font, e.g. `400` for regular and `700` for bold.
+    pub(crate) weight: u16,
+}
+
+impl FontQuery {
+    /// Creates a new [FontQuery].
+    ///
+    /// Arguments:
+    ///
+    /// * `family`: The name of the font family or one of the generic families.
+    ///
+    /// Returns:
+    ///
+    /// The new [FontQuery] for the regular upright style of the family.
+    pub fn new(family: &str) -> Self {
+        Self {
+            family: family.to_owned(),
+            italic: false,
+            weight: 400,
+        }
+    }
+
+    /// Sets the weight of the requested font.
+    ///
+    /// Arguments:
+    ///
+    /// * `weight`: The weight in the range `1..=1000`, which is `400` by default.
+    ///
+    /// Returns:
+    ///
+    /// The modified [FontQuery].
+    pub fn with_weight(mut self, weight: u16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets wether an italic style is requested.
+    ///
+    /// Arguments:
+    ///
+    /// * `italic`: Wether an italic or oblique style is requested.
+    ///
+    /// Returns:
+    ///
+    /// The modified [FontQuery].
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+}
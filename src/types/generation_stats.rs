@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+/// Statistics about the glyphs that were generated by a [crate::MeshGenerator].
+///
+/// Only glyphs that are generated from scratch are counted,
+/// glyphs that are loaded from the cache do not contribute.
+/// The statistics can be used to tune the [crate::QualitySettings].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GenerationStats {
+    /// The number of glyphs that were generated.
+    pub glyphs: usize,
+
+    /// The number of outline points of all generated glyphs.
+    pub points: usize,
+
+    /// The number of outline contours of all generated glyphs.
+    pub contours: usize,
+
+    /// The number of triangles of all generated glyphs.
+    pub triangles: usize,
+
+    /// The time spent flattening and repairing the outlines.
+    pub outlining: Duration,
+
+    /// The time spent triangulating and extruding the outlines.
+    pub triangulation: Duration,
+
+    /// The time spent running the [crate::MeshPostProcessor]s.
+    pub post_processing: Duration,
+}
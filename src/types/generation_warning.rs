@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// A problem of the font that was worked around while generating a glyph.
+///
+/// Warnings are collected by the [crate::MeshGenerator] and can be retrieved
+/// with [crate::MeshGenerator::take_warnings]. Since glyphs are cached, each
+/// warning is only reported the first time a glyph is generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerationWarning {
+    /// Contours with fewer than three distinct points were discarded,
+    /// since they do not enclose an area and can not be triangulated.
+    DegenerateContours {
+        /// The id of the glyph in the font.
+        glyph_id: u16,
+
+        /// The number of discarded contours.
+        count: usize,
+    },
+
+    /// Contours with coordinates that are `NaN` or infinite were discarded,
+    /// since they would corrupt the triangulation and the bounding box.
+    NonFiniteContours {
+        /// The id of the glyph in the font.
+        glyph_id: u16,
+
+        /// The number of discarded contours.
+        count: usize,
+    },
+}
+
+impl fmt::Display for GenerationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenerationWarning::DegenerateContours { glyph_id, count } => write!(
+                f,
+                "Discarded {} degenerate contour(s) of the glyph with the id {}.",
+                count, glyph_id
+            ),
+            GenerationWarning::NonFiniteContours { glyph_id, count } => write!(
+                f,
+                "Discarded {} contour(s) with non-finite coordinates of the glyph with the id {}.",
+                count, glyph_id
+            ),
+        }
+    }
+}
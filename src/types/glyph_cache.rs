@@ -0,0 +1,40 @@
+use crate::{IndexedMeshText, MeshText, QualitySettings};
+
+/// A single glyph of a [GlyphCache].
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+pub struct CachedGlyph<M> {
+    /// Wether the glyph is flat.
+    pub flat: bool,
+
+    /// The id of the glyph in the font.
+    pub glyph_id: u16,
+
+    /// The untransformed mesh of the glyph.
+    pub mesh: M,
+
+    /// The [QualitySettings] the glyph was generated with.
+    pub quality: QualitySettings,
+}
+
+/// A snapshot of the internal caches of a [crate::MeshGenerator].
+///
+/// The glyphs are only valid for the font and the
+/// [crate::ExtrusionSettings] they were generated with.
+///
+/// With the `rkyv` feature enabled, the cache can be archived, so that
+/// baked glyphs can be accessed without deserialization at load time.
+#[derive(Default)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+pub struct GlyphCache {
+    /// The glyphs of the cache that handles indexed meshes.
+    pub indexed: Vec<CachedGlyph<IndexedMeshText>>,
+
+    /// The glyphs of the cache that handles non-indexed meshes.
+    pub normal: Vec<CachedGlyph<MeshText>>,
+}
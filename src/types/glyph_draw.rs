@@ -0,0 +1,23 @@
+use glam::Mat4;
+
+/// A single glyph of a [crate::GlyphDrawList], which can be issued
+/// as one indirect draw command.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphDraw {
+    /// The index of the first character in the text from which the glyph originates.
+    pub character: usize,
+
+    /// The position of the first index of the glyph's mesh within
+    /// [crate::GlyphDrawList::indices].
+    pub first_index: u32,
+
+    /// The id of the glyph within the font or `None` if the glyph is
+    /// provided by a [crate::GlyphProvider] or the fallback mesh.
+    pub glyph_id: Option<u16>,
+
+    /// The number of indices of the glyph's mesh.
+    pub index_count: u32,
+
+    /// The transformation that places the glyph's mesh within the text section.
+    pub transform: Mat4,
+}
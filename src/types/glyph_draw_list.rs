@@ -0,0 +1,31 @@
+use crate::{BoundingBox, GlyphDraw};
+
+/// Holds the meshes of all distinct glyphs of a text section in a single
+/// vertex and index buffer together with a draw table that places them.
+///
+/// This is suited for GPU-driven rendering with multi-draw-indirect, where each
+/// [GlyphDraw] becomes one draw command that transforms the shared mesh of its
+/// glyph in the vertex shader. The indices refer to the whole vertex buffer,
+/// so the base vertex of every draw command is `0`.
+pub struct GlyphDrawList {
+    /// The horizontal advance of the untransformed text.
+    ///
+    /// This is the position of the pen after the last glyph, which
+    /// can be used to continue the layout where this text ended.
+    pub advance: f32,
+
+    /// The bounding box of the text section once every glyph is transformed.
+    pub bbox: BoundingBox,
+
+    /// The glyphs of the text section in the order in which they were laid out.
+    ///
+    /// Glyphs without a mesh, such as spaces, are omitted.
+    pub draws: Vec<GlyphDraw>,
+
+    /// The indices of the glyph meshes.
+    pub indices: Vec<u32>,
+
+    /// The vertices of the untransformed glyph meshes, which consist
+    /// of three components (XYZ).
+    pub vertices: Vec<f32>,
+}
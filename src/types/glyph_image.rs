@@ -0,0 +1,32 @@
+use glam::Vec2;
+use ttf_parser::RasterImageFormat;
+
+/// The raster image of a glyph without a vector outline, e.g. of a color emoji.
+///
+/// Such glyphs are generated as a rectangle between [GlyphImage::min]
+/// and [GlyphImage::max], onto which the image can be mapped.
+#[derive(Debug, Clone)]
+pub struct GlyphImage {
+    /// The encoded image data, which has to be decoded according to the format.
+    pub data: Vec<u8>,
+
+    /// The format of the image data, which is mostly [RasterImageFormat::PNG].
+    pub format: RasterImageFormat,
+
+    /// The height of the image in pixels.
+    pub height: u16,
+
+    /// The upper right corner of the rectangle of the glyph
+    /// in the untransformed coordinate system of the glyph.
+    pub max: Vec2,
+
+    /// The lower left corner of the rectangle of the glyph
+    /// in the untransformed coordinate system of the glyph.
+    pub min: Vec2,
+
+    /// The size of the strike of the image in pixels per em.
+    pub pixels_per_em: u16,
+
+    /// The width of the image in pixels.
+    pub width: u16,
+}
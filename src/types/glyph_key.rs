@@ -0,0 +1,27 @@
+use ttf_parser::GlyphId;
+
+use crate::GlyphSource;
+
+/// Identifies a glyph that is either part of the font or
+/// provided by a [crate::GlyphProvider].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum GlyphKey {
+    /// A glyph of the font.
+    Font(GlyphId),
+
+    /// The glyph of a character that is provided by a [crate::GlyphProvider].
+    Custom(char),
+
+    /// The custom mesh that replaces characters missing from the font.
+    Fallback,
+}
+
+impl From<GlyphKey> for GlyphSource {
+    fn from(key: GlyphKey) -> Self {
+        match key {
+            GlyphKey::Font(glyph_id) => GlyphSource::Font(glyph_id.0),
+            GlyphKey::Custom(character) => GlyphSource::Custom(character),
+            GlyphKey::Fallback => GlyphSource::Fallback,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use glam::Mat4;
+
+/// A single glyph of a [crate::GlyphScene], which can be spawned as a separate
+/// entity of an ECS or a node of a scene graph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphNode {
+    /// The first character in the text from which the glyph originates.
+    pub character: char,
+
+    /// The index of the glyph's mesh within [crate::GlyphScene::meshes].
+    pub mesh: usize,
+
+    /// The transformation that places the glyph's mesh relative to the origin
+    /// of the text section.
+    pub transform: Mat4,
+}
@@ -1,6 +1,7 @@
 type Point = (f32, f32);
 
 /// The internal representation of a rasterized glyph outline.
+#[derive(Clone, Default)]
 pub(crate) struct GlyphOutline {
     /// The indices that form closed contours of points.
     pub contours: Vec<Vec<u32>>,
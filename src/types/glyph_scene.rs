@@ -0,0 +1,22 @@
+use crate::{BoundingBox, GlyphNode, IndexedMeshText};
+
+/// Holds the glyphs of a text section as separate nodes, which share
+/// the meshes of identical glyphs instead of being merged into one mesh.
+pub struct GlyphScene {
+    /// The horizontal advance of the text section.
+    ///
+    /// This is the position of the pen after the last glyph, which
+    /// can be used to continue the layout where this text ended.
+    pub advance: f32,
+
+    /// The bounding box of the text section once every node is transformed.
+    pub bbox: BoundingBox,
+
+    /// The untransformed meshes of the distinct glyphs of the text section.
+    pub meshes: Vec<IndexedMeshText>,
+
+    /// The glyphs of the text section in the order in which they were laid out.
+    ///
+    /// Glyphs without a mesh, such as spaces, are omitted.
+    pub nodes: Vec<GlyphNode>,
+}
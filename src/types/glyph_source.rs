@@ -0,0 +1,14 @@
+/// Identifies the origin of a glyph that is passed to a
+/// [crate::MeshPostProcessor] or an [crate::OutlineProcessor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlyphSource {
+    /// A glyph of the font with the given id.
+    Font(u16),
+
+    /// The glyph of a character that is provided by a [crate::GlyphProvider].
+    Custom(char),
+
+    /// The custom mesh that replaces characters missing from the font
+    /// (see [crate::MeshGenerator::set_fallback_mesh]).
+    Fallback,
+}
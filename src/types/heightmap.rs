@@ -0,0 +1,141 @@
+use glam::Vec2;
+
+/// A regular grid of heights that can be used to displace the
+/// front cap of text sections.
+///
+/// # Example
+///
+/// ```rust
+/// use glam::Vec2;
+/// use meshtext::Heightmap;
+///
+/// let heightmap = Heightmap::new(2, vec![0.0, 1.0, 0.0, 1.0], Vec2::ZERO, Vec2::ONE);
+/// assert_eq!(heightmap.sample(Vec2::new(0.5, 0.5)), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heightmap {
+    /// The heights in row-major order starting with the top row.
+    pub heights: Vec<f32>,
+
+    /// The upper right corner of the area covered by the heightmap
+    /// in the coordinate system of the untransformed text.
+    pub max: Vec2,
+
+    /// The lower left corner of the area covered by the heightmap
+    /// in the coordinate system of the untransformed text.
+    pub min: Vec2,
+
+    /// The number of heights in each row.
+    pub width: usize,
+}
+
+impl Heightmap {
+    /// Creates a new [Heightmap].
+    ///
+    /// Arguments:
+    ///
+    /// * `width`: The number of heights in each row.
+    /// * `heights`: The heights in row-major order starting with the top row.
+    /// * `min`: The lower left corner of the area covered by the heightmap.
+    /// * `max`: The upper right corner of the area covered by the heightmap.
+    ///
+    /// Returns:
+    ///
+    /// The new [Heightmap].
+    pub fn new(width: usize, heights: Vec<f32>, min: Vec2, max: Vec2) -> Self {
+        Self {
+            heights,
+            max,
+            min,
+            width,
+        }
+    }
+
+    /// Samples the heightmap using bilinear interpolation.
+    ///
+    /// Points outside of the covered area are clamped to its border.
+    ///
+    /// Arguments:
+    ///
+    /// * `point`: The point in the coordinate system of the untransformed text.
+    ///
+    /// Returns:
+    ///
+    /// The interpolated height or `0.0` if the heightmap is empty.
+    pub fn sample(&self, point: Vec2) -> f32 {
+        if self.width == 0 || self.heights.len() < self.width {
+            return 0f32;
+        }
+        let height = self.heights.len() / self.width;
+
+        let size = self.max - self.min;
+        let relative = Vec2::new(
+            if size.x > 0f32 {
+                (point.x - self.min.x) / size.x
+            } else {
+                0f32
+            },
+            if size.y > 0f32 {
+                (self.max.y - point.y) / size.y
+            } else {
+                0f32
+            },
+        )
+        .clamp(Vec2::ZERO, Vec2::ONE);
+
+        // The position within the grid of heights.
+        let x = relative.x * (self.width - 1) as f32;
+        let y = relative.y * (height - 1) as f32;
+        let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(height - 1));
+        let (tx, ty) = (x - x0 as f32, y - y0 as f32);
+
+        let at = |x: usize, y: usize| self.heights[y * self.width + x];
+        let top = at(x0, y0) + (at(x1, y0) - at(x0, y0)) * tx;
+        let bottom = at(x0, y1) + (at(x1, y1) - at(x0, y1)) * tx;
+        top + (bottom - top) * ty
+    }
+}
+
+#[cfg(feature = "arrays")]
+impl Heightmap {
+    /// Creates a new [Heightmap] from plain arrays.
+    ///
+    /// Arguments:
+    ///
+    /// * `width`: The number of heights in each row.
+    /// * `heights`: The heights in row-major order starting with the top row.
+    /// * `min`: The lower left corner (XY) of the area covered by the heightmap.
+    /// * `max`: The upper right corner (XY) of the area covered by the heightmap.
+    ///
+    /// Returns:
+    ///
+    /// The new [Heightmap].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::Heightmap;
+    ///
+    /// let heightmap = Heightmap::from_arrays(2, vec![0.0, 1.0, 0.0, 1.0], [0.0, 0.0], [1.0, 1.0]);
+    /// assert_eq!(heightmap.sample_array([0.5, 0.5]), 0.5);
+    /// ```
+    pub fn from_arrays(width: usize, heights: Vec<f32>, min: [f32; 2], max: [f32; 2]) -> Self {
+        Self::new(width, heights, Vec2::from_array(min), Vec2::from_array(max))
+    }
+
+    /// Samples the heightmap using bilinear interpolation.
+    ///
+    /// See [Heightmap::sample] for details.
+    ///
+    /// Arguments:
+    ///
+    /// * `point`: The point (XY) in the coordinate system of the untransformed text.
+    ///
+    /// Returns:
+    ///
+    /// The interpolated height or `0.0` if the heightmap is empty.
+    pub fn sample_array(&self, point: [f32; 2]) -> f32 {
+        self.sample(Vec2::from_array(point))
+    }
+}
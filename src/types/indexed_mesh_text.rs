@@ -1,15 +1,572 @@
-use crate::BoundingBox;
+use glam::{Mat3, Mat4, Vec2, Vec3, Vec3A};
+
+use crate::{
+    error::{IndexOverflowError, MeshTextError},
+    util::{
+        glam_vecs_to_raw, glam_vecs_to_raw_2d, mirror_raw_vertices, project_onto_surface,
+        remove_degenerate_triangles_indexed, transform_points, transform_raw_vertices,
+    },
+    Anchor, BoundingBox, IndexType, MirrorAxis, TriangleMesh,
+};
 
 /// Holds the generated mesh data for the given text input.
 ///
-/// The triangles use indexed vertices.
-pub struct IndexedMeshText {
+/// The triangles use indexed vertices, whose [IndexType] defaults to [u32].
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+pub struct IndexedMeshText<I: IndexType = u32> {
+    /// The horizontal advance of the untransformed text.
+    ///
+    /// This is the position of the pen after the last glyph, which
+    /// can be used to continue the layout where this text ended.
+    pub advance: f32,
+
     /// The bounding box of this mesh.
     pub bbox: BoundingBox,
 
     /// The indices of this mesh.
-    pub indices: Vec<u32>,
+    pub indices: Vec<I>,
+
+    /// Wether the vertices of this mesh consist of two components (XY)
+    /// instead of three components (XYZ).
+    pub two_dimensional: bool,
 
     /// The vertices of this mesh.
     pub vertices: Vec<f32>,
 }
+
+impl TriangleMesh for IndexedMeshText {
+    fn vertices_ref(&self) -> Option<&[f32]> {
+        Some(&self.vertices)
+    }
+
+    fn vertex_components(&self) -> usize {
+        self.components()
+    }
+
+    fn indices_ref(&self) -> Option<&[u32]> {
+        Some(&self.indices)
+    }
+}
+
+impl<I: IndexType> IndexedMeshText<I> {
+    /// Gets the number of components of a single vertex regardless of the [IndexType].
+    ///
+    /// Returns:
+    ///
+    /// `2` for two-dimensional meshes, otherwise `3`.
+    fn components(&self) -> usize {
+        if self.two_dimensional {
+            2
+        } else {
+            3
+        }
+    }
+
+    /// Transforms the vertices of this mesh in place.
+    ///
+    /// The bounding box is computed again from the transformed vertices, so that it
+    /// stays tight for rotations. Vertices of two-dimensional meshes are placed in the
+    /// XY plane before they are transformed and the mesh stays two-dimensional.
+    ///
+    /// Arguments:
+    ///
+    /// * `transform`: The 4x4 homogenous transformation matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Mat4;
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let width = mesh.bbox.size().x;
+    /// mesh.transform(&Mat4::from_rotation_z(std::f32::consts::FRAC_PI_2));
+    /// assert!((mesh.bbox.size().y - width).abs() < 1e-4);
+    /// ```
+    pub fn transform(&mut self, transform: &Mat4) {
+        let components = self.components();
+        self.bbox = transform_raw_vertices(&mut self.vertices, components, transform);
+    }
+
+    /// Converts the indices of this mesh into another [IndexType].
+    ///
+    /// Returns:
+    ///
+    /// The same mesh with converted indices or an [MeshTextError] if any
+    /// index can not be represented by the requested type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let converted: IndexedMeshText<u16> = result
+    ///     .with_index_type()
+    ///     .expect("Failed to convert indices.");
+    /// ```
+    pub fn with_index_type<J: IndexType>(
+        self,
+    ) -> Result<IndexedMeshText<J>, Box<dyn MeshTextError>> {
+        let indices = self
+            .indices
+            .into_iter()
+            .map(|i| J::from_usize(i.to_usize()))
+            .collect::<Option<Vec<J>>>()
+            .ok_or_else(|| Box::new(IndexOverflowError) as Box<dyn MeshTextError>)?;
+
+        Ok(IndexedMeshText {
+            advance: self.advance,
+            bbox: self.bbox,
+            indices,
+            two_dimensional: self.two_dimensional,
+            vertices: self.vertices,
+        })
+    }
+
+    /// Translates this mesh, so that an [Anchor] is at the origin.
+    ///
+    /// This re-anchors a mesh without generating it again.
+    ///
+    /// Arguments:
+    ///
+    /// * `anchor`: The [Anchor] that will be moved to the origin.
+    ///
+    /// Returns:
+    ///
+    /// The translation that was applied to the mesh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec3A;
+    /// use meshtext::{Anchor, IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// mesh.align(Anchor::Center);
+    /// assert!(mesh.bbox.center().abs().max_element() < 1e-5);
+    /// ```
+    pub fn align(&mut self, anchor: Anchor) -> Vec3 {
+        let translation = -Vec3::from(anchor.position(&self.bbox));
+        self.transform(&Mat4::from_translation(translation));
+
+        translation
+    }
+
+    /// Reverses the winding order of the triangles of this mesh.
+    ///
+    /// This turns the front faces into back faces, e.g. for engines or exporters
+    /// that assume the opposite convention.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection, TriangleMesh};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let volume = mesh.volume().unwrap();
+    /// mesh.flip();
+    /// assert!((mesh.volume().unwrap() + volume).abs() < 1e-4);
+    /// ```
+    pub fn flip(&mut self) {
+        for triangle in self.indices.chunks_exact_mut(3) {
+            triangle.swap(1, 2);
+        }
+    }
+
+    /// Snaps the vertices of this mesh to a regular grid.
+    ///
+    /// Triangles that lose their area are removed, while all vertices are kept.
+    /// Meshes generated with [crate::MeshGenerator::set_quantization] are already
+    /// snapped, but transforming or merging them moves the vertices off the grid.
+    ///
+    /// Arguments:
+    ///
+    /// * `step`: The spacing of the grid in the units of this mesh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Mat4;
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mesh: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let step = generator.font_units_to_normalized(generator.units_per_em() as f32 / 1024f32);
+    /// let mut merged = IndexedMeshText::merge(&[(mesh, Mat4::from_rotation_z(0.5))])
+    ///     .expect("Failed to merge meshes.");
+    /// merged.quantize(step);
+    /// assert!(merged
+    ///     .vertices
+    ///     .iter()
+    ///     .all(|x| (x / step - (x / step).round()).abs() < 1e-3));
+    /// ```
+    pub fn quantize(&mut self, step: f32) {
+        for x in self.vertices.iter_mut() {
+            *x = (*x / step).round() * step;
+        }
+        self.bbox.min = (self.bbox.min / step).round() * step;
+        self.bbox.max = (self.bbox.max / step).round() * step;
+
+        let components = if self.two_dimensional { 2 } else { 3 };
+        let points: Vec<Vec3A> = self
+            .vertices
+            .chunks_exact(components)
+            .map(|v| Vec3A::new(v[0], v[1], v.get(2).copied().unwrap_or(0f32)))
+            .collect();
+        remove_degenerate_triangles_indexed(&mut self.indices, &points, step);
+    }
+
+    /// Uniformly scales and centers this mesh, so that it fits into a [BoundingBox].
+    ///
+    /// This is useful for labels on fixed-size plaques or buttons. Axes along which
+    /// the mesh or the box have no extent (e.g. the depth of flat text) are ignored
+    /// for the scale.
+    ///
+    /// Arguments:
+    ///
+    /// * `target`: The [BoundingBox] into which this mesh is fitted.
+    ///
+    /// Returns:
+    ///
+    /// The uniform scale that was applied to the mesh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec3A;
+    /// use meshtext::{BoundingBox, IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: IndexedMeshText = generator
+    ///     .generate_section("Push", true, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// // Fit the label onto a button.
+    /// let button = BoundingBox::new(Vec3A::new(-2.0, -0.5, 0.0), Vec3A::new(2.0, 0.5, 0.0));
+    /// mesh.fit_into(&button);
+    /// assert!((mesh.bbox.size().y - 1.0).abs() < 1e-4);
+    /// assert!(mesh.bbox.size().x <= 4.0);
+    /// ```
+    pub fn fit_into(&mut self, target: &BoundingBox) -> f32 {
+        let (transform, scale) = self.bbox.fit_transform(target);
+        self.transform(&transform);
+
+        scale
+    }
+
+    /// Mirrors this mesh along an axis.
+    ///
+    /// Unlike a transformation with a negative scale, the winding order of the
+    /// triangles is reversed as well, so that the front faces keep facing outwards
+    /// and backface culling keeps working. Mirroring a two-dimensional mesh along
+    /// [MirrorAxis::Z] only reverses the winding order.
+    ///
+    /// Arguments:
+    ///
+    /// * `axis`: The [MirrorAxis] whose coordinates are negated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, MirrorAxis, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: IndexedMeshText = generator
+    ///     .generate_section("Stamp", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let max = mesh.bbox.max.x;
+    /// mesh.mirror(MirrorAxis::X);
+    /// assert_eq!(mesh.bbox.min.x, -max);
+    /// ```
+    pub fn mirror(&mut self, axis: MirrorAxis) {
+        let components = self.components();
+        self.bbox =
+            mirror_raw_vertices(&mut self.vertices, components, axis.component(), self.bbox);
+        self.flip();
+    }
+
+    /// Projects this mesh onto the surface of another mesh along a direction.
+    ///
+    /// Each vertex is moved along the direction (or against it) to the closest
+    /// intersection with the surface, which conforms flat text to curved
+    /// geometry like a decal. Vertices that do not hit the surface keep their position.
+    /// Three-dimensional text keeps its depth, since its vertices are lifted off the
+    /// surface by their distance from the side of the text that faces the surface.
+    ///
+    /// Arguments:
+    ///
+    /// * `surface`: The mesh onto which this mesh is projected.
+    /// * `direction`: The direction of the projection.
+    /// * `offset`: The distance by which the projected vertices are lifted off the
+    ///   surface along its normal, e.g. in order to avoid z-fighting.
+    ///
+    /// Returns:
+    ///
+    /// The projected three-dimensional mesh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec3;
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let text: IndexedMeshText = generator
+    ///     .generate_section("Decal", true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// let wall: IndexedMeshText = generator
+    ///     .generate_section("██████", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// // Place the text on the front of the wall.
+    /// let decal = text.project_onto(&wall, Vec3::NEG_Z, 0.01);
+    /// assert!((decal.bbox.max.z - 0.51).abs() < 1e-5);
+    /// ```
+    pub fn project_onto(
+        &self,
+        surface: &impl TriangleMesh,
+        direction: Vec3,
+        offset: f32,
+    ) -> IndexedMeshText<I> {
+        let (vertices, bbox) = project_onto_surface(
+            &self.vertices,
+            self.components(),
+            surface,
+            direction.into(),
+            offset,
+        );
+
+        IndexedMeshText {
+            advance: self.advance,
+            bbox,
+            indices: self.indices.clone(),
+            two_dimensional: false,
+            vertices: glam_vecs_to_raw(&vertices),
+        }
+    }
+}
+
+impl IndexedMeshText {
+    /// Merges multiple meshes into a single mesh.
+    ///
+    /// Each mesh is transformed before it is merged, its indices are offset by the
+    /// number of preceding vertices and the bounding box encloses all transformed
+    /// vertices. The triangles of mirrored meshes are flipped, so that they keep
+    /// facing outwards. The merged mesh is two-dimensional only if all meshes are
+    /// two-dimensional, in which case the z-coordinates of the transformed vertices
+    /// are dropped. Quantized meshes can be snapped to their grid again with
+    /// [IndexedMeshText::quantize] after they were merged.
+    ///
+    /// Arguments:
+    ///
+    /// * `meshes`: The meshes together with the 4x4 homogenous transformation
+    ///   matrix that will be applied to each of them.
+    ///
+    /// Returns:
+    ///
+    /// The merged mesh, whose advance is the largest advance of all meshes, or an
+    /// [IndexOverflowError] if the merged mesh has more vertices than `u32` can index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::{Mat4, Vec3};
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let first: IndexedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    /// let second: IndexedMeshText = generator
+    ///     .generate_section("World", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// // Place the second line below the first one.
+    /// let merged = IndexedMeshText::merge(&[
+    ///     (first, Mat4::IDENTITY),
+    ///     (second, Mat4::from_translation(Vec3::new(0.0, -1.0, 0.0))),
+    /// ])
+    /// .expect("Failed to merge meshes.");
+    /// assert!(merged.bbox.min.y < -0.5);
+    /// ```
+    pub fn merge(
+        meshes: &[(IndexedMeshText, Mat4)],
+    ) -> Result<IndexedMeshText, Box<dyn MeshTextError>> {
+        let two_dimensional = meshes.iter().all(|(m, _)| m.two_dimensional);
+        let mut advance = 0f32;
+        let mut indices = Vec::new();
+        let mut vertices: Vec<Vec3A> = Vec::new();
+
+        for (mesh, transform) in meshes.iter() {
+            let offset = vertices.len();
+            let components = mesh.vertex_components();
+            let first = vertices.len();
+            vertices.extend(
+                mesh.vertices
+                    .chunks_exact(components)
+                    .map(|v| Vec3A::new(v[0], v[1], if components == 3 { v[2] } else { 0f32 })),
+            );
+            transform_points(&mut vertices[first..], transform);
+
+            // Mirroring transformations reverse the winding order of the triangles.
+            let mirrored = match two_dimensional {
+                true => {
+                    transform.x_axis.x * transform.y_axis.y
+                        - transform.y_axis.x * transform.x_axis.y
+                }
+                false => Mat3::from_mat4(*transform).determinant(),
+            } < 0f32;
+            for triangle in mesh.indices.chunks_exact(3) {
+                let triangle = match mirrored {
+                    true => [triangle[0], triangle[2], triangle[1]],
+                    false => [triangle[0], triangle[1], triangle[2]],
+                };
+                for i in triangle {
+                    let Some(index) = u32::from_usize(i as usize + offset) else {
+                        return Err(Box::new(IndexOverflowError));
+                    };
+                    indices.push(index);
+                }
+            }
+            advance = advance.max(mesh.advance);
+        }
+        if two_dimensional {
+            vertices.iter_mut().for_each(|v| v.z = 0f32);
+        }
+
+        let bbox = if vertices.is_empty() {
+            BoundingBox::empty()
+        } else {
+            let (min, max) = vertices.iter().fold(
+                (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY)),
+                |(min, max), v| (min.min(*v), max.max(*v)),
+            );
+            BoundingBox::new(min, max)
+        };
+
+        let vertices = if two_dimensional {
+            let points: Vec<Vec2> = vertices.iter().map(|v| v.truncate()).collect();
+            glam_vecs_to_raw_2d(&points)
+        } else {
+            glam_vecs_to_raw(&vertices)
+        };
+
+        Ok(IndexedMeshText {
+            advance,
+            bbox,
+            indices,
+            two_dimensional,
+            vertices,
+        })
+    }
+}
+
+#[cfg(feature = "rapier")]
+mod rapier_colliders {
+    use rapier3d::{geometry::ColliderBuilder, math::Point};
+
+    use crate::{IndexedMeshText, TriangleMesh};
+
+    impl IndexedMeshText {
+        /// Creates a collider with a triangle mesh shape from this mesh.
+        ///
+        /// Vertices of two-dimensional meshes are placed in the XY-plane.
+        ///
+        /// Returns:
+        ///
+        /// The [ColliderBuilder] of the triangle mesh collider.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+        ///
+        /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+        /// let mut generator = MeshGenerator::new(font_data);
+        /// let result: IndexedMeshText = generator
+        ///     .generate_section("Hello", false, None)
+        ///     .expect("Failed to generate mesh.");
+        ///
+        /// let collider = result.to_trimesh_collider().build();
+        /// ```
+        pub fn to_trimesh_collider(&self) -> ColliderBuilder {
+            ColliderBuilder::trimesh(self.collider_points(), self.collider_triangles())
+        }
+
+        /// Creates a collider that approximates this mesh by a compound of convex shapes.
+        ///
+        /// The decomposition is considerably more expensive than
+        /// [IndexedMeshText::to_trimesh_collider] but the resulting collider
+        /// also works for dynamic bodies.
+        ///
+        /// Returns:
+        ///
+        /// The [ColliderBuilder] of the compound collider.
+        pub fn to_convex_decomposition_collider(&self) -> ColliderBuilder {
+            ColliderBuilder::convex_decomposition(
+                &self.collider_points(),
+                &self.collider_triangles(),
+            )
+        }
+
+        /// Converts the vertices of this mesh into points.
+        ///
+        /// Returns:
+        ///
+        /// The vertices as three-dimensional points.
+        fn collider_points(&self) -> Vec<Point<f32>> {
+            let components = self.vertex_components();
+
+            self.vertices
+                .chunks_exact(components)
+                .map(|v| Point::new(v[0], v[1], if components == 3 { v[2] } else { 0f32 }))
+                .collect()
+        }
+
+        /// Groups the indices of this mesh into triangles.
+        ///
+        /// Returns:
+        ///
+        /// The indices of the corners of each triangle.
+        fn collider_triangles(&self) -> Vec<[u32; 3]> {
+            self.indices
+                .chunks_exact(3)
+                .map(|t| [t[0], t[1], t[2]])
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use glam::{Vec3, Vec3A};
+
+use crate::{BoundingBox, GlamVertex, IndexType, TriangleMesh};
+
+/// Holds the generated mesh data for the given text input as [glam] vectors.
+///
+/// The triangles use indexed vertices. Unlike an [crate::IndexedMeshText], the
+/// vertices are not flattened into a list of components, which saves a copy of each
+/// vertex when loading glyphs from the cache. The [GlamVertex] type defaults
+/// to [Vec3A], while [glam::Vec2] holds two-dimensional vertices and can only be
+/// generated by the two-dimensional generators. Like for an [crate::IndexedMeshText],
+/// the [IndexType] defaults to [u32].
+///
+/// # Example
+///
+/// ```rust
+/// use glam::Vec3A;
+/// use meshtext::{IndexedMeshTextGlam, MeshGenerator, TextSection};
+///
+/// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+/// let mut generator = MeshGenerator::new(font_data);
+///
+/// let result: IndexedMeshTextGlam = generator
+///     .generate_section("Hello", false, None)
+///     .expect("Failed to generate mesh.");
+/// assert!(result.indices.iter().all(|i| (*i as usize) < result.vertices.len()));
+///
+/// let result: IndexedMeshTextGlam<Vec3A, u16> = generator
+///     .generate_section("Hello", false, None)
+///     .expect("Failed to generate mesh.");
+/// assert!(result.indices.iter().all(|i| (*i as usize) < result.vertices.len()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedMeshTextGlam<V: GlamVertex = Vec3A, I: IndexType = u32> {
+    /// The horizontal advance of the untransformed text.
+    ///
+    /// This is the position of the pen after the last glyph, which
+    /// can be used to continue the layout where this text ended.
+    pub advance: f32,
+
+    /// The bounding box of this mesh.
+    pub bbox: BoundingBox,
+
+    /// The indices of this mesh.
+    pub indices: Vec<I>,
+
+    /// The vertices of this mesh.
+    pub vertices: Vec<V>,
+}
+
+impl<V: GlamVertex> TriangleMesh for IndexedMeshTextGlam<V> {
+    fn vertices_ref(&self) -> Option<&[f32]> {
+        None
+    }
+
+    fn indices_ref(&self) -> Option<&[u32]> {
+        Some(&self.indices)
+    }
+
+    fn vertex_components(&self) -> usize {
+        V::COMPONENTS
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn vertex(&self, index: usize) -> Vec3 {
+        self.vertices[index].to_vec3()
+    }
+}
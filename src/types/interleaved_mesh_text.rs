@@ -0,0 +1,124 @@
+use crate::{BoundingBox, VertexAttribute, VertexAttributeDescriptor};
+
+/// Holds the generated mesh data for the given text input
+/// in a single interleaved vertex buffer.
+///
+/// The triangles use indexed vertices. The attributes of each vertex
+/// are controlled by the [crate::VertexLayout] of the generator. By default each
+/// vertex consists of its position, normal and texture coordinates in that order.
+///
+/// The vertex data can be uploaded to the GPU as is and the
+/// [InterleavedMeshText::stride] and [InterleavedMeshText::attributes]
+/// describe the corresponding vertex buffer layout.
+pub struct InterleavedMeshText {
+    /// The horizontal advance of the untransformed text.
+    ///
+    /// This is the position of the pen after the last glyph, which
+    /// can be used to continue the layout where this text ended.
+    pub advance: f32,
+
+    /// The attributes of each vertex in the order in which they appear.
+    pub attributes: Vec<VertexAttributeDescriptor>,
+
+    /// The bounding box of this mesh.
+    pub bbox: BoundingBox,
+
+    /// The indices of this mesh.
+    pub indices: Vec<u32>,
+
+    /// The distance between two consecutive vertices in bytes.
+    pub stride: u64,
+
+    /// The interleaved vertex data of this mesh.
+    ///
+    /// All components are `f32` values in native byte order.
+    pub vertices: Vec<u8>,
+}
+
+impl InterleavedMeshText {
+    /// Finds the description of a certain [VertexAttribute].
+    ///
+    /// Arguments:
+    ///
+    /// * `attribute`: The attribute that should be looked up.
+    ///
+    /// Returns:
+    ///
+    /// The [VertexAttributeDescriptor] or `None` if this mesh
+    /// does not contain the given attribute.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{InterleavedMeshText, MeshGenerator, TextSection, VertexAttribute};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let result: InterleavedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let normal = result.attribute(VertexAttribute::Normal).unwrap();
+    /// assert_eq!(normal.offset, 12);
+    /// assert_eq!(result.stride, 32);
+    /// ```
+    pub fn attribute(&self, attribute: VertexAttribute) -> Option<&VertexAttributeDescriptor> {
+        self.attributes.iter().find(|a| a.attribute == attribute)
+    }
+
+    /// Gets the number of vertices of this mesh.
+    ///
+    /// Returns:
+    ///
+    /// The number of vertices in [InterleavedMeshText::vertices].
+    pub fn vertex_count(&self) -> usize {
+        if self.stride == 0 {
+            return 0;
+        }
+
+        self.vertices.len() / self.stride as usize
+    }
+
+    /// Reverses the winding order of the triangles of this mesh
+    /// and negates the [VertexAttribute::Normal] of each vertex.
+    ///
+    /// This turns the front faces into back faces, e.g. for engines or exporters
+    /// that assume the opposite convention.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{InterleavedMeshText, MeshGenerator, TextSection, VertexAttribute};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: InterleavedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let first = mesh.indices[1];
+    /// mesh.flip();
+    /// assert_eq!(mesh.indices[2], first);
+    /// ```
+    pub fn flip(&mut self) {
+        for triangle in self.indices.chunks_exact_mut(3) {
+            triangle.swap(1, 2);
+        }
+
+        let Some(normal) = self.attribute(VertexAttribute::Normal).copied() else {
+            return;
+        };
+        if self.stride == 0 {
+            return;
+        }
+        let size = std::mem::size_of::<f32>();
+        let start = normal.offset as usize;
+        let end = start + normal.size() as usize;
+        for vertex in self.vertices.chunks_exact_mut(self.stride as usize) {
+            for component in vertex[start..end].chunks_exact_mut(size) {
+                let value = f32::from_ne_bytes(component.try_into().unwrap());
+                component.copy_from_slice(&(-value).to_ne_bytes());
+            }
+        }
+    }
+}
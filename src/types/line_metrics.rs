@@ -0,0 +1,27 @@
+use std::ops::Range;
+
+/// Describes a single line of a laid out [crate::Paragraph].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineMetrics {
+    /// The horizontal advance of the line including the appended hyphen.
+    ///
+    /// Trailing whitespace is only included if it is preserved
+    /// (see [crate::WhitespaceMode]).
+    pub advance: f32,
+
+    /// The y-coordinate of the baseline of the line.
+    pub baseline: f32,
+
+    /// The characters of the paragraph that are part of the line.
+    ///
+    /// The range is counted in characters, not bytes and does not
+    /// include the line break that ended the line.
+    pub characters: Range<usize>,
+
+    /// Wether a word was broken at the end of the line,
+    /// in which case a hyphen was appended.
+    pub hyphenated: bool,
+
+    /// The x-coordinate at which the line starts.
+    pub offset: f32,
+}
@@ -0,0 +1,14 @@
+/// The parts a three-dimensional glyph mesh consists of.
+///
+/// Flat meshes only consist of a front cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeshPart {
+    /// The cap facing in the positive z-direction.
+    FrontCap,
+
+    /// The cap facing in the negative z-direction.
+    BackCap,
+
+    /// The walls connecting the front and the back cap.
+    SideWall,
+}
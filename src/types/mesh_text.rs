@@ -1,10 +1,275 @@
-use crate::BoundingBox;
+use glam::{Mat4, Vec3};
+
+use crate::{
+    util::{
+        glam_vecs_to_raw, mirror_raw_vertices, project_onto_surface, reverse_winding_raw,
+        transform_raw_vertices,
+    },
+    Anchor, BoundingBox, MirrorAxis, TriangleMesh,
+};
 
 /// Holds the generated mesh data for the given text input.
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
 pub struct MeshText {
+    /// The horizontal advance of the untransformed text.
+    ///
+    /// This is the position of the pen after the last glyph, which
+    /// can be used to continue the layout where this text ended.
+    pub advance: f32,
+
     /// The bounding box of this mesh.
     pub bbox: BoundingBox,
 
+    /// Wether the vertices of this mesh consist of two components (XY)
+    /// instead of three components (XYZ).
+    pub two_dimensional: bool,
+
     /// The vertices of this mesh.
     pub vertices: Vec<f32>,
 }
+
+impl TriangleMesh for MeshText {
+    fn vertices_ref(&self) -> Option<&[f32]> {
+        Some(&self.vertices)
+    }
+
+    fn vertex_components(&self) -> usize {
+        if self.two_dimensional {
+            2
+        } else {
+            3
+        }
+    }
+
+    fn indices_ref(&self) -> Option<&[u32]> {
+        None
+    }
+}
+
+impl MeshText {
+    /// Transforms the vertices of this mesh in place.
+    ///
+    /// The bounding box is computed again from the transformed vertices, so that it
+    /// stays tight for rotations. Vertices of two-dimensional meshes are placed in the
+    /// XY plane before they are transformed and the mesh stays two-dimensional.
+    ///
+    /// Arguments:
+    ///
+    /// * `transform`: The 4x4 homogenous transformation matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Mat4;
+    /// use meshtext::{MeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: MeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let width = mesh.bbox.size().x;
+    /// mesh.transform(&Mat4::from_rotation_z(std::f32::consts::FRAC_PI_2));
+    /// assert!((mesh.bbox.size().y - width).abs() < 1e-4);
+    /// ```
+    pub fn transform(&mut self, transform: &Mat4) {
+        let components = self.vertex_components();
+        self.bbox = transform_raw_vertices(&mut self.vertices, components, transform);
+    }
+
+    /// Translates this mesh, so that an [Anchor] is at the origin.
+    ///
+    /// This re-anchors a mesh without generating it again.
+    ///
+    /// Arguments:
+    ///
+    /// * `anchor`: The [Anchor] that will be moved to the origin.
+    ///
+    /// Returns:
+    ///
+    /// The translation that was applied to the mesh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec3A;
+    /// use meshtext::{Anchor, MeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: MeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// mesh.align(Anchor::Center);
+    /// assert!(mesh.bbox.center().abs().max_element() < 1e-5);
+    /// ```
+    pub fn align(&mut self, anchor: Anchor) -> Vec3 {
+        let translation = -Vec3::from(anchor.position(&self.bbox));
+        self.transform(&Mat4::from_translation(translation));
+
+        translation
+    }
+
+    /// Reverses the winding order of the triangles of this mesh.
+    ///
+    /// This turns the front faces into back faces, e.g. for engines or exporters
+    /// that assume the opposite convention.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{MeshText, MeshGenerator, TextSection, TriangleMesh};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: MeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let volume = mesh.volume().unwrap();
+    /// mesh.flip();
+    /// assert!((mesh.volume().unwrap() + volume).abs() < 1e-4);
+    /// ```
+    pub fn flip(&mut self) {
+        let components = self.vertex_components();
+        reverse_winding_raw(&mut self.vertices, components);
+    }
+
+    /// Uniformly scales and centers this mesh, so that it fits into a [BoundingBox].
+    ///
+    /// This is useful for labels on fixed-size plaques or buttons. Axes along which
+    /// the mesh or the box have no extent (e.g. the depth of flat text) are ignored
+    /// for the scale.
+    ///
+    /// Arguments:
+    ///
+    /// * `target`: The [BoundingBox] into which this mesh is fitted.
+    ///
+    /// Returns:
+    ///
+    /// The uniform scale that was applied to the mesh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec3A;
+    /// use meshtext::{BoundingBox, MeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: MeshText = generator
+    ///     .generate_section("Push", true, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// // Fit the label onto a button.
+    /// let button = BoundingBox::new(Vec3A::new(-2.0, -0.5, 0.0), Vec3A::new(2.0, 0.5, 0.0));
+    /// mesh.fit_into(&button);
+    /// assert!((mesh.bbox.size().y - 1.0).abs() < 1e-4);
+    /// assert!(mesh.bbox.size().x <= 4.0);
+    /// ```
+    pub fn fit_into(&mut self, target: &BoundingBox) -> f32 {
+        let (transform, scale) = self.bbox.fit_transform(target);
+        self.transform(&transform);
+
+        scale
+    }
+
+    /// Mirrors this mesh along an axis.
+    ///
+    /// Unlike a transformation with a negative scale, the winding order of the
+    /// triangles is reversed as well, so that the front faces keep facing outwards
+    /// and backface culling keeps working. Mirroring a two-dimensional mesh along
+    /// [MirrorAxis::Z] only reverses the winding order.
+    ///
+    /// Arguments:
+    ///
+    /// * `axis`: The [MirrorAxis] whose coordinates are negated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{MeshText, MeshGenerator, MirrorAxis, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: MeshText = generator
+    ///     .generate_section("Stamp", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let max = mesh.bbox.max.x;
+    /// mesh.mirror(MirrorAxis::X);
+    /// assert_eq!(mesh.bbox.min.x, -max);
+    /// ```
+    pub fn mirror(&mut self, axis: MirrorAxis) {
+        let components = self.vertex_components();
+        self.bbox =
+            mirror_raw_vertices(&mut self.vertices, components, axis.component(), self.bbox);
+        self.flip();
+    }
+
+    /// Projects this mesh onto the surface of another mesh along a direction.
+    ///
+    /// Each vertex is moved along the direction (or against it) to the closest
+    /// intersection with the surface, which conforms flat text to curved
+    /// geometry like a decal. Vertices that do not hit the surface keep their position.
+    /// Three-dimensional text keeps its depth, since its vertices are lifted off the
+    /// surface by their distance from the side of the text that faces the surface.
+    ///
+    /// Arguments:
+    ///
+    /// * `surface`: The mesh onto which this mesh is projected.
+    /// * `direction`: The direction of the projection.
+    /// * `offset`: The distance by which the projected vertices are lifted off the
+    ///   surface along its normal, e.g. in order to avoid z-fighting.
+    ///
+    /// Returns:
+    ///
+    /// The projected three-dimensional mesh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec3;
+    /// use meshtext::{MeshText, MeshGenerator, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let text: MeshText = generator
+    ///     .generate_section("Decal", true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// let wall: MeshText = generator
+    ///     .generate_section("██████", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// // Place the text on the front of the wall.
+    /// let decal = text.project_onto(&wall, Vec3::NEG_Z, 0.01);
+    /// assert!((decal.bbox.max.z - 0.51).abs() < 1e-5);
+    /// ```
+    pub fn project_onto(
+        &self,
+        surface: &impl TriangleMesh,
+        direction: Vec3,
+        offset: f32,
+    ) -> MeshText {
+        let (vertices, bbox) = project_onto_surface(
+            &self.vertices,
+            self.vertex_components(),
+            surface,
+            direction.into(),
+            offset,
+        );
+
+        MeshText {
+            advance: self.advance,
+            bbox,
+            two_dimensional: false,
+            vertices: glam_vecs_to_raw(&vertices),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use glam::{Vec3, Vec3A};
+
+use crate::{BoundingBox, GlamVertex, TriangleMesh};
+
+/// Holds the generated mesh data for the given text input as [glam] vectors.
+///
+/// Unlike a [crate::MeshText], the vertices are not flattened into a list of
+/// components, which saves a copy of each vertex when loading glyphs from the cache.
+/// The [GlamVertex] type defaults to [Vec3A], while [glam::Vec2] holds
+/// two-dimensional vertices and can only be generated by the two-dimensional generators.
+///
+/// # Example
+///
+/// ```rust
+/// use glam::Vec2;
+/// use meshtext::{MeshGenerator, MeshTextGlam, TextSection};
+///
+/// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+/// let mut generator = MeshGenerator::new(font_data);
+///
+/// let result: MeshTextGlam = generator
+///     .generate_section("Hello", false, None)
+///     .expect("Failed to generate mesh.");
+/// assert_eq!(result.vertices.len() % 3, 0);
+///
+/// let result: MeshTextGlam<Vec2> = generator
+///     .generate_section_2d("Hello", None)
+///     .expect("Failed to generate mesh.");
+/// assert!(result.vertices.iter().all(|v| v.x <= result.bbox.max.x));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshTextGlam<V: GlamVertex = Vec3A> {
+    /// The horizontal advance of the untransformed text.
+    ///
+    /// This is the position of the pen after the last glyph, which
+    /// can be used to continue the layout where this text ended.
+    pub advance: f32,
+
+    /// The bounding box of this mesh.
+    pub bbox: BoundingBox,
+
+    /// The vertices of this mesh, where each consecutive three vertices form a triangle.
+    pub vertices: Vec<V>,
+}
+
+impl<V: GlamVertex> TriangleMesh for MeshTextGlam<V> {
+    fn vertices_ref(&self) -> Option<&[f32]> {
+        None
+    }
+
+    fn indices_ref(&self) -> Option<&[u32]> {
+        None
+    }
+
+    fn vertex_components(&self) -> usize {
+        V::COMPONENTS
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn vertex(&self, index: usize) -> Vec3 {
+        self.vertices[index].to_vec3()
+    }
+}
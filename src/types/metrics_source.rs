@@ -0,0 +1,23 @@
+/// Selects the table from which the vertical metrics of a font are read.
+///
+/// Fonts often specify different ascenders and descenders in their `hhea`
+/// and `OS/2` tables. Since the line height is used to normalize the glyph
+/// coordinates, the source changes the size of the generated text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MetricsSource {
+    /// The typographic metrics of the `OS/2` table are used if the font
+    /// sets the `USE_TYPO_METRICS` flag, otherwise the metrics of the `hhea` table.
+    #[default]
+    Auto,
+
+    /// The ascender, descender and line gap of the `hhea` table,
+    /// which is what most browsers and macOS use.
+    Hhea,
+
+    /// The typographic ascender, descender and line gap of the `OS/2` table.
+    Typographic,
+
+    /// The Windows ascent and descent of the `OS/2` table, which
+    /// already include the line gap, so no additional line gap is used.
+    Windows,
+}
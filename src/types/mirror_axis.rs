@@ -0,0 +1,30 @@
+/// The axis along which a generated mesh is mirrored.
+///
+/// The coordinates along the axis are negated, which mirrors the
+/// mesh across the plane spanned by the two other axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MirrorAxis {
+    /// Mirrors the mesh across the YZ plane, e.g. for mirrored signage.
+    X,
+
+    /// Mirrors the mesh across the XZ plane.
+    Y,
+
+    /// Mirrors the mesh across the XY plane, e.g. for stamps and molds.
+    Z,
+}
+
+impl MirrorAxis {
+    /// Gets the index of the vertex component along this axis.
+    ///
+    /// Returns:
+    ///
+    /// The index of the x-, y- or z-component.
+    pub(crate) fn component(&self) -> usize {
+        match self {
+            MirrorAxis::X => 0,
+            MirrorAxis::Y => 1,
+            MirrorAxis::Z => 2,
+        }
+    }
+}
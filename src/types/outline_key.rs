@@ -0,0 +1,108 @@
+use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+
+use glam::Vec2;
+
+use crate::{ExtrusionSettings, GlyphOutline};
+
+/// Identifies the triangulation of a glyph outline by its content,
+/// so that glyphs with identical outlines are only triangulated once.
+///
+/// The key borrows the outline, so that looking up a triangulation does
+/// not copy the outline. Only the content of triangulated outlines is stored
+/// next to the triangulation to tell apart outlines with the same hash.
+#[derive(Clone, Copy)]
+pub(crate) struct OutlineKey<'a> {
+    /// The center about which the back cap is scaled.
+    center: Vec2,
+
+    /// The scale of the back cap.
+    extrusion: f32,
+
+    /// Wether the triangulation is flat.
+    flat: bool,
+
+    /// The outline that will be triangulated.
+    outline: &'a GlyphOutline,
+}
+
+impl<'a> OutlineKey<'a> {
+    /// Creates a new [OutlineKey].
+    ///
+    /// Arguments:
+    ///
+    /// * `outline`: The outline that will be triangulated.
+    /// * `flat`: Wether the triangulation is flat.
+    /// * `extrusion`: The [ExtrusionSettings] used for three dimensional meshes.
+    /// * `center`: The center of the glyph about which the back cap is scaled.
+    ///
+    /// Returns:
+    ///
+    /// The new [OutlineKey].
+    pub(crate) fn new(
+        outline: &'a GlyphOutline,
+        flat: bool,
+        extrusion: &ExtrusionSettings,
+        center: Vec2,
+    ) -> Self {
+        Self {
+            center,
+            extrusion: extrusion.back_cap_scale,
+            flat,
+            outline,
+        }
+    }
+
+    /// Gets the hash of the content of the outline, under which
+    /// its triangulation is stored.
+    pub(crate) fn hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for word in self.words() {
+            hasher.write_u32(word);
+        }
+
+        hasher.finish()
+    }
+
+    /// Copies the content of the outline, so that it can be stored next to its triangulation.
+    pub(crate) fn content(&self) -> Box<[u32]> {
+        self.words().collect()
+    }
+
+    /// Checks wether the outline has the given content.
+    ///
+    /// Arguments:
+    ///
+    /// * `content`: The stored content of a triangulated outline.
+    ///
+    /// Returns:
+    ///
+    /// `true` if the outline would be triangulated identically.
+    pub(crate) fn matches(&self, content: &[u32]) -> bool {
+        self.words().eq(content.iter().copied())
+    }
+
+    /// Gets the bits of all properties that affect the triangulation.
+    ///
+    /// The lengths of the contours are included, so that
+    /// different outlines never share the same content.
+    fn words(&self) -> impl Iterator<Item = u32> + '_ {
+        let header = [
+            self.center.x.to_bits(),
+            self.center.y.to_bits(),
+            self.extrusion.to_bits(),
+            self.flat as u32,
+            self.outline.points.len() as u32,
+            self.outline.contours.len() as u32,
+        ];
+        let points = self
+            .outline
+            .points
+            .iter()
+            .flat_map(|p| [p.0.to_bits(), p.1.to_bits()]);
+        let contours = self.outline.contours.iter().flat_map(|contour| {
+            std::iter::once(contour.len() as u32).chain(contour.iter().copied())
+        });
+
+        header.into_iter().chain(points).chain(contours)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{TextAlignment, WhitespaceMode};
+
+/// A block of text that is wrapped into multiple lines.
+///
+/// The lines of a paragraph are broken at the break opportunities of the
+/// Unicode line breaking algorithm (with the `linebreak` feature) or after
+/// whitespace and hyphens when they would exceed the maximum width, as well
+/// as at each mandatory break (e.g. a line feed).
+///
+/// # Example
+///
+/// ```rust
+/// use meshtext::{IndexedMeshText, MeshGenerator, Paragraph, TextAlignment};
+///
+/// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+/// let mut generator = MeshGenerator::new(font_data);
+///
+/// let paragraph = Paragraph::new()
+///     .with_run("Hello World! ")
+///     .with_run("This text is wrapped.")
+///     .with_max_width(6.0)
+///     .with_alignment(TextAlignment::Center)
+///     .with_line_spacing(1.2);
+///
+/// let (mesh, lines) = generator
+///     .generate_paragraph(&paragraph, true, None)
+///     .expect("Failed to generate mesh.");
+/// assert_eq!(lines.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Paragraph {
+    /// The horizontal alignment of the lines.
+    pub(crate) alignment: TextAlignment,
+
+    /// The dictionary that is used to break words that do not fit into a line.
+    #[cfg(feature = "hyphenation")]
+    pub(crate) hyphenation: Option<hyphenation::Standard>,
+
+    /// The distance between two baselines relative to the line height of the font.
+    pub(crate) line_spacing: f32,
+
+    /// The maximum advance of a line.
+    pub(crate) max_width: Option<f32>,
+
+    /// The text of the paragraph.
+    pub(crate) runs: Vec<String>,
+
+    /// Controls how the whitespace of the text is handled.
+    pub(crate) whitespace: WhitespaceMode,
+}
+
+impl Paragraph {
+    /// Creates a new empty [Paragraph].
+    ///
+    /// Returns:
+    ///
+    /// The new [Paragraph], which is not wrapped and aligned to the left.
+    pub fn new() -> Self {
+        Self {
+            alignment: TextAlignment::Left,
+            #[cfg(feature = "hyphenation")]
+            hyphenation: None,
+            line_spacing: 1f32,
+            max_width: None,
+            runs: Vec::new(),
+            whitespace: WhitespaceMode::Trim,
+        }
+    }
+
+    /// Appends a run of text to the paragraph.
+    ///
+    /// Arguments:
+    ///
+    /// * `text`: The text that is appended directly after the previous run.
+    ///
+    /// Returns:
+    ///
+    /// The modified [Paragraph].
+    pub fn with_run(mut self, text: &str) -> Self {
+        self.runs.push(text.to_owned());
+        self
+    }
+
+    /// Sets the maximum width of a line.
+    ///
+    /// Arguments:
+    ///
+    /// * `max_width`: The maximum advance of a line relative to the font size.
+    ///
+    /// Returns:
+    ///
+    /// The modified [Paragraph].
+    pub fn with_max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the horizontal alignment of the lines.
+    ///
+    /// The lines are aligned within the maximum width or within
+    /// the widest line if the paragraph has no maximum width.
+    ///
+    /// Arguments:
+    ///
+    /// * `alignment`: The new [TextAlignment].
+    ///
+    /// Returns:
+    ///
+    /// The modified [Paragraph].
+    pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the distance between two lines.
+    ///
+    /// Arguments:
+    ///
+    /// * `line_spacing`: The distance between two baselines relative to the
+    ///   line height of the font, which is `1.0` by default.
+    ///
+    /// Returns:
+    ///
+    /// The modified [Paragraph].
+    pub fn with_line_spacing(mut self, line_spacing: f32) -> Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+
+    /// Enables the hyphenation of words that do not fit into a line.
+    ///
+    /// Words are split at the hyphenation points of the dictionary
+    /// and a hyphen is inserted at the end of the line.
+    ///
+    /// Arguments:
+    ///
+    /// * `dictionary`: The hyphenation dictionary of the language of the text.
+    ///
+    /// Returns:
+    ///
+    /// The modified [Paragraph].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use hyphenation::{Language, Load, Standard};
+    /// use meshtext::{MeshGenerator, Paragraph};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    ///
+    /// let dictionary = Standard::from_embedded(Language::EnglishUS)
+    ///     .expect("Failed to load dictionary.");
+    /// let paragraph = Paragraph::new()
+    ///     .with_run("hyphenation")
+    ///     .with_max_width(3.0)
+    ///     .with_hyphenation(dictionary);
+    ///
+    /// let (_, lines) = generator
+    ///     .generate_paragraph(&paragraph, true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// assert!(lines[0].hyphenated);
+    /// ```
+    #[cfg(feature = "hyphenation")]
+    pub fn with_hyphenation(mut self, dictionary: hyphenation::Standard) -> Self {
+        self.hyphenation = Some(dictionary);
+        self
+    }
+
+    /// Sets how the whitespace of the text is handled.
+    ///
+    /// Arguments:
+    ///
+    /// * `whitespace`: The new [WhitespaceMode], which is [WhitespaceMode::Trim] by default.
+    ///
+    /// Returns:
+    ///
+    /// The modified [Paragraph].
+    pub fn with_whitespace(mut self, whitespace: WhitespaceMode) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Gets the text of the paragraph.
+    ///
+    /// Returns:
+    ///
+    /// All runs of the paragraph joined together.
+    pub fn text(&self) -> String {
+        self.runs.concat()
+    }
+}
+
+impl Default for Paragraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,120 @@
+use std::ops::Range;
+
+use crate::{BoundingBox, MeshPart, TriangleMesh};
+
+/// Holds the generated mesh data for the given text input
+/// with the triangles grouped by the [MeshPart] they belong to.
+///
+/// The triangles use indexed vertices. All triangles of the front caps
+/// come first, followed by the back caps and finally the side walls,
+/// so that each part can be drawn with a different material.
+pub struct PartitionedMeshText {
+    /// The horizontal advance of the untransformed text.
+    ///
+    /// This is the position of the pen after the last glyph, which
+    /// can be used to continue the layout where this text ended.
+    pub advance: f32,
+
+    /// The range of [PartitionedMeshText::indices] that contains the back caps.
+    pub back_cap: Range<usize>,
+
+    /// The bounding box of this mesh.
+    pub bbox: BoundingBox,
+
+    /// The range of [PartitionedMeshText::indices] that contains the front caps.
+    pub front_cap: Range<usize>,
+
+    /// The indices of this mesh.
+    pub indices: Vec<u32>,
+
+    /// The range of [PartitionedMeshText::indices] that contains the side walls.
+    pub side_walls: Range<usize>,
+
+    /// Wether the vertices of this mesh consist of two components (XY)
+    /// instead of three components (XYZ).
+    pub two_dimensional: bool,
+
+    /// The vertices of this mesh.
+    pub vertices: Vec<f32>,
+}
+
+impl PartitionedMeshText {
+    /// Gets the indices of a single part of this mesh.
+    ///
+    /// Arguments:
+    ///
+    /// * `part`: The desired [MeshPart].
+    ///
+    /// Returns:
+    ///
+    /// The indices of all triangles that belong to the given part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{MeshGenerator, MeshPart, PartitionedMeshText, TextSection};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let result: PartitionedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// // Both caps are made of the same triangles.
+    /// assert_eq!(
+    ///     result.part(MeshPart::FrontCap).len(),
+    ///     result.part(MeshPart::BackCap).len()
+    /// );
+    /// ```
+    pub fn part(&self, part: MeshPart) -> &[u32] {
+        match part {
+            MeshPart::FrontCap => &self.indices[self.front_cap.clone()],
+            MeshPart::BackCap => &self.indices[self.back_cap.clone()],
+            MeshPart::SideWall => &self.indices[self.side_walls.clone()],
+        }
+    }
+
+    /// Reverses the winding order of the triangles of this mesh.
+    ///
+    /// This turns the front faces into back faces, e.g. for engines or exporters
+    /// that assume the opposite convention.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{PartitionedMeshText, MeshGenerator, TextSection, TriangleMesh};
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let mut mesh: PartitionedMeshText = generator
+    ///     .generate_section("Hello", false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let volume = mesh.volume().unwrap();
+    /// mesh.flip();
+    /// assert!((mesh.volume().unwrap() + volume).abs() < 1e-4);
+    /// ```
+    pub fn flip(&mut self) {
+        for triangle in self.indices.chunks_exact_mut(3) {
+            triangle.swap(1, 2);
+        }
+    }
+}
+
+impl TriangleMesh for PartitionedMeshText {
+    fn vertices_ref(&self) -> Option<&[f32]> {
+        Some(&self.vertices)
+    }
+
+    fn indices_ref(&self) -> Option<&[u32]> {
+        Some(&self.indices)
+    }
+
+    fn vertex_components(&self) -> usize {
+        if self.two_dimensional {
+            2
+        } else {
+            3
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use glam::Vec2;
+
+use crate::GlyphKey;
+
+/// A glyph that has been placed within a text section.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PositionedGlyph {
+    /// The horizontal advance of the glyph.
+    pub(crate) advance: f32,
+
+    /// The byte index of the first character in the text
+    /// from which this glyph originates.
+    pub(crate) cluster: usize,
+
+    /// The extrusion depth of the glyph relative to the default depth.
+    pub(crate) depth: f32,
+
+    /// The id of the glyph within the font or its
+    /// character if it is a custom glyph.
+    pub(crate) glyph_id: GlyphKey,
+
+    /// The position of the origin of the glyph.
+    pub(crate) offset: Vec2,
+}
@@ -2,7 +2,11 @@
 ///
 /// Generally each setting can be tweaked to generate better
 /// looking glyphs at the cost of a certain performance impact.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
 pub struct QualitySettings {
     /// The number of linear interpolation steps performed
     /// on a _quadratic bezier curve_.
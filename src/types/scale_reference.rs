@@ -0,0 +1,12 @@
+/// The font metric by which all glyph coordinates are normalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleReference {
+    /// The line height of the font, which is the default.
+    Height,
+
+    /// The units per em of the font.
+    ///
+    /// This is used as a fallback for fonts with a line height
+    /// that is not positive or too small to be meaningful.
+    UnitsPerEm,
+}
@@ -0,0 +1,121 @@
+/// The writing system of a character, which is used by a [crate::FontSet]
+/// to route runs of text to an appropriate face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// The Arabic script.
+    Arabic,
+
+    /// The Armenian script.
+    Armenian,
+
+    /// The Bengali script.
+    Bengali,
+
+    /// The Cyrillic script.
+    Cyrillic,
+
+    /// The Devanagari script.
+    Devanagari,
+
+    /// The Georgian script.
+    Georgian,
+
+    /// The Greek script.
+    Greek,
+
+    /// The Han ideographs of Chinese, Japanese and Korean.
+    Han,
+
+    /// The Hangul script of Korean.
+    Hangul,
+
+    /// The Hebrew script.
+    Hebrew,
+
+    /// The Hiragana syllabary of Japanese.
+    Hiragana,
+
+    /// The Katakana syllabary of Japanese.
+    Katakana,
+
+    /// The Latin script.
+    Latin,
+
+    /// The Thai script.
+    Thai,
+}
+
+impl Script {
+    /// Detects the script of a character.
+    ///
+    /// Only the main blocks of each script are considered.
+    ///
+    /// Arguments:
+    ///
+    /// * `c`: The character in question.
+    ///
+    /// Returns:
+    ///
+    /// The [Script] of the character or `None` if the character is shared by
+    /// multiple scripts (e.g. digits, punctuation and whitespace) or unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::Script;
+    ///
+    /// assert_eq!(Script::of_char('a'), Some(Script::Latin));
+    /// assert_eq!(Script::of_char('ж'), Some(Script::Cyrillic));
+    /// assert_eq!(Script::of_char('中'), Some(Script::Han));
+    /// assert_eq!(Script::of_char('1'), None);
+    /// ```
+    pub fn of_char(c: char) -> Option<Self> {
+        let script = match c {
+            'A'..='Z'
+            | 'a'..='z'
+            | '\u{AA}'
+            | '\u{BA}'
+            | '\u{C0}'..='\u{D6}'
+            | '\u{D8}'..='\u{F6}'
+            | '\u{F8}'..='\u{24F}'
+            | '\u{1E00}'..='\u{1EFF}'
+            | '\u{2C60}'..='\u{2C7F}'
+            | '\u{A720}'..='\u{A7FF}'
+            | '\u{FF21}'..='\u{FF3A}'
+            | '\u{FF41}'..='\u{FF5A}' => Self::Latin,
+            '\u{370}'..='\u{3FF}' | '\u{1F00}'..='\u{1FFF}' => Self::Greek,
+            '\u{400}'..='\u{52F}'
+            | '\u{1C80}'..='\u{1C8F}'
+            | '\u{2DE0}'..='\u{2DFF}'
+            | '\u{A640}'..='\u{A69F}' => Self::Cyrillic,
+            '\u{530}'..='\u{58F}' => Self::Armenian,
+            '\u{590}'..='\u{5FF}' => Self::Hebrew,
+            '\u{600}'..='\u{6FF}'
+            | '\u{750}'..='\u{77F}'
+            | '\u{8A0}'..='\u{8FF}'
+            | '\u{FB50}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}' => Self::Arabic,
+            '\u{900}'..='\u{97F}' => Self::Devanagari,
+            '\u{980}'..='\u{9FF}' => Self::Bengali,
+            '\u{E00}'..='\u{E7F}' => Self::Thai,
+            '\u{10A0}'..='\u{10FF}' => Self::Georgian,
+            '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => {
+                Self::Hangul
+            }
+            '\u{3040}'..='\u{309F}' => Self::Hiragana,
+            '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => {
+                Self::Katakana
+            }
+            '\u{2E80}'..='\u{2FDF}'
+            | '\u{3005}'
+            | '\u{3007}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3134F}' => Self::Han,
+            _ => return None,
+        };
+
+        Some(script)
+    }
+}
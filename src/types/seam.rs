@@ -0,0 +1,12 @@
+/// Controls how the vertices along the boundary between a cap
+/// and the side walls of a three-dimensional mesh are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Seam {
+    /// The cap and the side walls use separate vertices,
+    /// which results in a sharp edge.
+    Hard,
+
+    /// The cap and the side walls share their vertices and
+    /// the normals are averaged, which results in a rounded edge.
+    Smooth,
+}
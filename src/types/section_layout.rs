@@ -0,0 +1,256 @@
+use std::ops::Range;
+
+use glam::{Mat4, Vec2};
+
+use crate::{
+    util::{extrude_convex_hull, text_mesh_from_data_indexed, transform_points},
+    BoundingBox, Caret, IndexedMeshText,
+};
+
+/// The layout of a text section, which can be used to
+/// interact with the generated text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionLayout {
+    /// The horizontal advance of the untransformed text.
+    pub advance: f32,
+
+    /// One [Caret] in front of each character of the text,
+    /// followed by the caret after the last character.
+    pub carets: Vec<Caret>,
+
+    /// The italic angle of the font in counter-clockwise degrees
+    /// from the vertical or `0.0` if the font does not specify it.
+    ///
+    /// The angle is negative for glyphs leaning to the right. Besides the
+    /// carets generated by [SectionLayout::generate_caret], it can be used
+    /// to shear selections or decorations to match oblique text.
+    pub italic_angle: f32,
+}
+
+impl SectionLayout {
+    /// Finds the character at a certain point.
+    ///
+    /// Each character covers the area between its own caret and the caret
+    /// of the following character. Characters without an advance (e.g.
+    /// combining marks) can therefore not be hit.
+    ///
+    /// Arguments:
+    ///
+    /// * `point`: The point in the coordinate system of the untransformed text.
+    ///
+    /// Returns:
+    ///
+    /// The index of the character at the given point counted in characters
+    /// or [None] if the point is not on the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec2;
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let layout = generator.section_layout("Hello");
+    /// assert_eq!(layout.hit_test(Vec2::new(0.1, 0.3)), Some(0));
+    /// assert_eq!(layout.hit_test(Vec2::new(-0.1, 0.3)), None);
+    /// ```
+    pub fn hit_test(&self, point: Vec2) -> Option<usize> {
+        self.carets
+            .iter()
+            .zip(self.carets.iter().skip(1))
+            .find(|(caret, next)| {
+                let (min, max) = if caret.position.x <= next.position.x {
+                    (caret.position.x, next.position.x)
+                } else {
+                    (next.position.x, caret.position.x)
+                };
+
+                (min..max).contains(&point.x)
+                    && (caret.position.y..=caret.position.y + caret.height).contains(&point.y)
+            })
+            .map(|(caret, _)| caret.index)
+    }
+
+    /// Generates the geometry highlighting a selected range of characters.
+    ///
+    /// The selection covers the area between the carets of the first and
+    /// the last selected character and spans the height of the font.
+    ///
+    /// Arguments:
+    ///
+    /// * `range`: The selected characters counted in characters.
+    /// * `flat`: Set this to `true` for a flat quad, or to `false` in order
+    ///   to generate a box with the same depth as the glyphs.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to the selection.
+    ///
+    /// Returns:
+    ///
+    /// The mesh of the selection, which is empty if no character is selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let layout = generator.section_layout("Hello");
+    /// let selection = layout.generate_selection(1..3, true, None);
+    /// assert_eq!(selection.indices.len(), 6);
+    /// ```
+    pub fn generate_selection(
+        &self,
+        range: Range<usize>,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> IndexedMeshText {
+        let last = self.carets.len().saturating_sub(1);
+        let (start, end) = (range.start.min(last), range.end.min(last));
+        if start >= end {
+            return text_mesh_from_data_indexed(
+                (Vec::new(), Vec::new(), BoundingBox::empty()),
+                0f32,
+            );
+        }
+        let (start, end) = (&self.carets[start], &self.carets[end]);
+
+        let min = start.position.x.min(end.position.x);
+        let max = start.position.x.max(end.position.x);
+        let bottom = start.position.y;
+        let top = bottom + start.height;
+        let hull = [
+            Vec2::new(min, bottom),
+            Vec2::new(max, bottom),
+            Vec2::new(max, top),
+            Vec2::new(min, top),
+        ];
+        Self::extrude_quad(&hull, flat, transform, end.position.x)
+    }
+
+    /// Generates the mesh of the text cursor in front of a character.
+    ///
+    /// The caret spans the height of the font and is slanted
+    /// by the italic angle of the font.
+    ///
+    /// Arguments:
+    ///
+    /// * `index`: The index of the character in front of which the caret is
+    ///   placed counted in characters. Indices past the end of the text
+    ///   place the caret after the last character.
+    /// * `width`: The width of the caret relative to the font size.
+    /// * `flat`: Set this to `true` for a flat quad, or to `false` in order
+    ///   to generate a box with the same depth as the glyphs.
+    /// * `transform`: The optional 4x4 homogenous transformation matrix in column
+    ///   major order that will be applied to the caret.
+    ///
+    /// Returns:
+    ///
+    /// The mesh of the caret.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let layout = generator.section_layout("Hello");
+    /// let caret = layout.generate_caret(2, 0.05, false, None);
+    /// assert_eq!(caret.indices.len(), 36);
+    /// ```
+    pub fn generate_caret(
+        &self,
+        index: usize,
+        width: f32,
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+    ) -> IndexedMeshText {
+        let caret = self.carets[index.min(self.carets.len() - 1)];
+
+        // The caret leans along the italic angle through its position on the baseline.
+        let slant = -self.italic_angle.to_radians().tan();
+        let at = |y: f32| Vec2::new(caret.position.x + y * slant, y);
+        let (bottom, top) = (at(caret.position.y), at(caret.position.y + caret.height));
+
+        let half_width = Vec2::new(width * 0.5f32, 0f32);
+        let hull = [
+            bottom - half_width,
+            bottom + half_width,
+            top + half_width,
+            top - half_width,
+        ];
+
+        Self::extrude_quad(&hull, flat, transform, caret.position.x)
+    }
+
+    /// Generates a flat quad or a box from a convex quadrilateral.
+    ///
+    /// Arguments:
+    ///
+    /// * `hull`: The corners of the quadrilateral in counter-clockwise order.
+    /// * `flat`: Wether a flat quad or a box with a depth of `1.0` units is generated.
+    /// * `transform`: The optional transformation that is applied to the mesh.
+    /// * `advance`: The horizontal advance that is reported by the mesh.
+    ///
+    /// Returns:
+    ///
+    /// The generated mesh.
+    fn extrude_quad(
+        hull: &[Vec2; 4],
+        flat: bool,
+        transform: Option<&[f32; 16]>,
+        advance: f32,
+    ) -> IndexedMeshText {
+        let depth = if flat {
+            (0f32, 0f32)
+        } else {
+            (-0.5f32, 0.5f32)
+        };
+        let transform = match transform {
+            Some(value) => Mat4::from_cols_array(value),
+            None => Mat4::IDENTITY,
+        };
+
+        let mut mesh = extrude_convex_hull(hull, depth);
+        transform_points(&mut mesh.1, &transform);
+        mesh.2.transform(&transform);
+
+        text_mesh_from_data_indexed(mesh, advance)
+    }
+}
+
+#[cfg(feature = "arrays")]
+impl SectionLayout {
+    /// Finds the character at a certain point of the text section.
+    ///
+    /// See [SectionLayout::hit_test] for details.
+    ///
+    /// Arguments:
+    ///
+    /// * `point`: The point (XY) in the coordinate system of the untransformed text.
+    ///
+    /// Returns:
+    ///
+    /// The index of the character at the given point counted in characters
+    /// or [None] if the point is not on the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::MeshGenerator;
+    ///
+    /// let font_data = include_bytes!("../../assets/font/FiraMono-Regular.ttf");
+    /// let generator = MeshGenerator::new(font_data);
+    ///
+    /// let layout = generator.section_layout("Hello");
+    /// assert_eq!(layout.hit_test_array([0.1, 0.3]), Some(0));
+    /// ```
+    pub fn hit_test_array(&self, point: [f32; 2]) -> Option<usize> {
+        self.hit_test(Vec2::from_array(point))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::FontFeature;
+
+/// Controls how text sections are shaped.
+///
+/// These settings only have an effect if the `shaping` feature is enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapingSettings {
+    /// Wether the standard and contextual ligatures (`liga` and `clig`)
+    /// of the font are used, e.g. to replace "fi" with a single glyph.
+    pub ligatures: bool,
+
+    /// Additional OpenType features that are enabled or disabled
+    /// for the whole text section.
+    ///
+    /// Features listed here take precedence over [ShapingSettings::ligatures].
+    pub features: Vec<FontFeature>,
+
+    /// The ISO 15924 tag of the script of the text section, e.g. `*b"Arab"`.
+    ///
+    /// If this is `None` or the tag is unknown, the script is guessed from the text.
+    pub script: Option<[u8; 4]>,
+
+    /// The BCP 47 language tag of the text section, e.g. `"tr"` for Turkish.
+    ///
+    /// The language selects locale specific forms of the font,
+    /// such as the dotted capital I in Turkish.
+    pub language: Option<String>,
+}
+
+impl Default for ShapingSettings {
+    fn default() -> Self {
+        Self {
+            ligatures: true,
+            features: Vec::new(),
+            script: None,
+            language: None,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+/// Controls how the texture coordinates of the side walls of
+/// three-dimensional text are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SideWallMapping {
+    /// The side walls use the same [crate::UvProjection] as the caps,
+    /// which stretches the texture along the depth of planar projections.
+    #[default]
+    Projected,
+
+    /// The side walls are unwrapped, so that the horizontal coordinate is the
+    /// distance along the contour and the vertical coordinate is the distance
+    /// from the front of the text, both relative to the font size.
+    ///
+    /// The texture should be set to repeat, since the coordinates exceed `1.0`.
+    Unwrapped,
+}
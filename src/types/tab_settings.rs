@@ -0,0 +1,33 @@
+/// The distance between two default tab stops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TabSize {
+    /// The tab stops are a multiple of the advance of a space apart.
+    Spaces(f32),
+
+    /// The tab stops are a fixed advance apart, which is relative to the font size.
+    Advance(f32),
+}
+
+/// Controls where tabs (`'\t'`) move the following text.
+///
+/// A tab advances to the next explicit tab stop. Once all explicit
+/// tab stops have been passed, it advances to the next multiple
+/// of the [TabSize] instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabSettings {
+    /// The distance between two default tab stops.
+    pub tab_size: TabSize,
+
+    /// The positions of explicit tab stops in ascending order,
+    /// which are relative to the start of the line.
+    pub stops: Vec<f32>,
+}
+
+impl Default for TabSettings {
+    fn default() -> Self {
+        Self {
+            tab_size: TabSize::Spaces(4f32),
+            stops: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+/// The horizontal alignment of the lines of a [crate::Paragraph].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextAlignment {
+    /// The lines start at the left edge of the paragraph.
+    #[default]
+    Left,
+
+    /// The lines are centered within the paragraph.
+    Center,
+
+    /// The lines end at the right edge of the paragraph.
+    Right,
+}
@@ -1,5 +1,7 @@
 use ttf_parser::{GlyphId, OutlineBuilder, Rect};
 
+use crate::MetricsSource;
+
 /// Common methods of a [ttf_parser::Face] or `OwnedFace` that
 /// are used within the crate.
 pub trait FontFace {
@@ -12,6 +14,20 @@ pub trait FontFace {
     /// The horizontal advance of the glyph.
     fn glyph_hor_advance(&self, glyph_id: GlyphId) -> Option<u16>;
 
+    /// Gets the average horizontal advance of all glyphs.
+    ///
+    /// Returns:
+    ///
+    /// The average advance or `None` if the font does not specify it.
+    fn average_advance(&self) -> Option<u16>;
+
+    /// Gets a glyph's tight bounding box without outlining it.
+    ///
+    /// Returns:
+    ///
+    /// The bounding box or `None` when the glyph has no outline.
+    fn glyph_bounding_box(&self, glyph_id: GlyphId) -> Option<Rect>;
+
     /// Resolves a Glyph ID for a code point.
     ///
     /// All subtable formats except Mixed Coverage (8) are supported.
@@ -23,6 +39,39 @@ pub trait FontFace {
     /// The [GlyphId] or `None` when the glyph is not found.
     fn glyph_index(&self, code_point: char) -> Option<GlyphId>;
 
+    /// Resolves a Glyph ID for a glyph name.
+    ///
+    /// Uses the `post` and `CFF` tables as sources.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphId] or `None` when no glyph has the given name.
+    fn glyph_index_by_name(&self, name: &str) -> Option<GlyphId>;
+
+    /// Resolves a variation of a Glyph ID from two code points.
+    ///
+    /// Uses the format 14 subtable of the `cmap` table.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphId] or `None` when the font has no such variation.
+    fn glyph_variation_index(&self, code_point: char, variation: char) -> Option<GlyphId>;
+
+    /// Finds the longest ligature that starts a sequence of glyphs.
+    ///
+    /// Uses the ligature substitutions of the `GSUB` table.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyphs`: The glyphs of the sequence and wether each glyph is a joiner,
+    ///   which may be skipped if the ligature does not list it as a component.
+    ///
+    /// Returns:
+    ///
+    /// The ligature and the number of glyphs it replaces or `None` if no ligature matches.
+    #[cfg(not(feature = "shaping"))]
+    fn ligature(&self, glyphs: &[(GlyphId, bool)]) -> Option<(GlyphId, usize)>;
+
     /// Computes the face's height.
     ///
     /// This method is affected by variation axes.
@@ -32,6 +81,184 @@ pub trait FontFace {
     /// The line height.
     fn height(&self) -> i16;
 
+    /// Gets the number of font units per em.
+    ///
+    /// Returns:
+    ///
+    /// The units per em, which are guaranteed to be in the range `16..=16384`.
+    fn units_per_em(&self) -> u16;
+
+    /// Gets the face's horizontal ascender.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns:
+    ///
+    /// The distance from the baseline to the top of the line.
+    fn ascender(&self) -> i16;
+
+    /// Gets the face's horizontal descender.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns:
+    ///
+    /// The distance from the baseline to the bottom of the line, which is usually negative.
+    fn descender(&self) -> i16;
+
+    /// Gets the face's horizontal line gap.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns:
+    ///
+    /// The additional space between two lines.
+    fn line_gap(&self) -> i16;
+
+    /// Gets the face's vertical ascender.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns:
+    ///
+    /// The vertical ascender or `None` if the font has no `vhea` table.
+    fn vertical_ascender(&self) -> Option<i16>;
+
+    /// Gets the face's vertical descender.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns:
+    ///
+    /// The vertical descender or `None` if the font has no `vhea` table.
+    fn vertical_descender(&self) -> Option<i16>;
+
+    /// Gets the face's vertical line gap.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns:
+    ///
+    /// The vertical line gap or `None` if the font has no `vhea` table.
+    fn vertical_line_gap(&self) -> Option<i16>;
+
+    /// Computes glyph's vertical advance.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns:
+    ///
+    /// The vertical advance of the glyph or `None` if the font has no vertical metrics.
+    fn glyph_ver_advance(&self, glyph_id: GlyphId) -> Option<u16>;
+
+    /// Computes glyph's vertical side bearing.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns:
+    ///
+    /// The top side bearing of the glyph or `None` if the font has no vertical metrics.
+    fn glyph_ver_side_bearing(&self, glyph_id: GlyphId) -> Option<i16>;
+
+    /// Gets the y-coordinate of the glyph's vertical origin.
+    ///
+    /// Only fonts with a `VORG` table (usually CFF fonts) specify the vertical origin.
+    ///
+    /// Returns:
+    ///
+    /// The vertical origin of the glyph or `None` if it is not specified.
+    fn vertical_origin(&self, glyph_id: GlyphId) -> Option<i16>;
+
+    /// Gets the italic angle of the face.
+    ///
+    /// The angle is given in counter-clockwise degrees from the vertical,
+    /// which means it is negative for glyphs leaning to the right.
+    ///
+    /// Returns:
+    ///
+    /// The italic angle or `None` if the font has no `post` table.
+    fn italic_angle(&self) -> Option<f32>;
+
+    /// Gets the underline metrics of the face.
+    ///
+    /// Returns:
+    ///
+    /// The position and thickness of the underline or
+    /// `None` if the font has no `post` table.
+    fn underline_metrics(&self) -> Option<ttf_parser::LineMetrics>;
+
+    /// Gets the kerning between two glyphs.
+    ///
+    /// Arguments:
+    ///
+    /// * `left`: The glyph on the left side.
+    /// * `right`: The glyph on the right side.
+    ///
+    /// Returns:
+    ///
+    /// The adjustment of the advance of the left glyph
+    /// or `None` if the font has no kerning for this pair.
+    fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i16>;
+
+    /// Gets the raster image of a glyph, e.g. of a color emoji.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph_id`: The id of the glyph.
+    /// * `pixels_per_em`: The preferred size of the image.
+    ///
+    /// Returns:
+    ///
+    /// The encoded image of the closest strike or `None` if the glyph has no raster image.
+    fn raster_image(
+        &self,
+        glyph_id: GlyphId,
+        pixels_per_em: u16,
+    ) -> Option<ttf_parser::RasterGlyphImage<'_>>;
+
+    /// Gets an entry of the `name` table of the face.
+    ///
+    /// Only entries with a Unicode encoding are considered.
+    ///
+    /// Arguments:
+    ///
+    /// * `name_id`: The ID of the name, e.g. [ttf_parser::name_id::FAMILY].
+    ///
+    /// Returns:
+    ///
+    /// The name or `None` if the font has no such entry.
+    fn name(&self, name_id: u16) -> Option<String>;
+
+    /// Checks wether all glyphs of the face have the same advance.
+    ///
+    /// Uses the `isFixedPitch` flag of the `post` table and the
+    /// PANOSE classification of the `OS/2` table.
+    ///
+    /// Returns:
+    ///
+    /// `true` if the face is monospaced.
+    fn is_monospaced(&self) -> bool;
+
+    /// Reads the vertical metrics of the face from the given source.
+    ///
+    /// Arguments:
+    ///
+    /// * `source`: The table from which the metrics are read.
+    ///
+    /// Returns:
+    ///
+    /// The ascender, descender and line gap or `None`
+    /// if the face does not contain the required table.
+    fn vertical_metrics(&self, source: MetricsSource) -> Option<(i16, i16, i16)>;
+
+    /// Gets the underlying [ttf_parser::Face].
+    ///
+    /// Returns:
+    ///
+    /// The parsed font that is used for shaping.
+    #[cfg(feature = "shaping")]
+    fn as_face(&self) -> &ttf_parser::Face<'_>;
+
     /// Outlines a glyph and returns its tight bounding box.
     ///
     /// **Warning**: since `ttf-parser` is a pull parser,
@@ -0,0 +1,83 @@
+use std::fmt::Debug;
+
+use glam::{Vec2, Vec3, Vec3A};
+
+/// A [glam] vector type that can be used for the vertices of a [crate::MeshTextGlam]
+/// or an [crate::IndexedMeshTextGlam].
+///
+/// This is implemented for [Vec3A] and [Vec2]. The vertices are taken from the
+/// cache as they are if the dimension matches, so that no conversion is needed.
+/// Since [Vec2] can not hold the depth of extruded text, it can only be used
+/// with the two-dimensional generators, e.g. [crate::TextSection::generate_section_2d].
+pub trait GlamVertex: Copy + Debug + PartialEq + 'static {
+    /// The number of components of a single vertex.
+    const COMPONENTS: usize;
+
+    /// Converts the three-dimensional vertices generated by the [crate::MeshGenerator]
+    /// into this type.
+    ///
+    /// This is only called if [GlamVertex::COMPONENTS] is `3`.
+    ///
+    /// Arguments:
+    ///
+    /// * `vertices`: The generated vertices.
+    ///
+    /// Returns:
+    ///
+    /// The converted vertices.
+    fn from_vecs(vertices: Vec<Vec3A>) -> Vec<Self>;
+
+    /// Converts the two-dimensional vertices generated by the [crate::MeshGenerator]
+    /// into this type.
+    ///
+    /// Arguments:
+    ///
+    /// * `vertices`: The generated vertices.
+    ///
+    /// Returns:
+    ///
+    /// The converted vertices, which lie in the XY plane if this type is three-dimensional.
+    fn from_vecs_2d(vertices: Vec<Vec2>) -> Vec<Self>;
+
+    /// Converts this vertex into a three-dimensional vector.
+    ///
+    /// Returns:
+    ///
+    /// The vertex with a z-coordinate of `0` if this type is two-dimensional.
+    fn to_vec3(self) -> Vec3;
+}
+
+impl GlamVertex for Vec3A {
+    const COMPONENTS: usize = 3;
+
+    fn from_vecs(vertices: Vec<Vec3A>) -> Vec<Self> {
+        vertices
+    }
+
+    fn from_vecs_2d(vertices: Vec<Vec2>) -> Vec<Self> {
+        vertices
+            .into_iter()
+            .map(|v| v.extend(0f32).into())
+            .collect()
+    }
+
+    fn to_vec3(self) -> Vec3 {
+        self.into()
+    }
+}
+
+impl GlamVertex for Vec2 {
+    const COMPONENTS: usize = 2;
+
+    fn from_vecs(vertices: Vec<Vec3A>) -> Vec<Self> {
+        vertices.into_iter().map(|v| v.truncate()).collect()
+    }
+
+    fn from_vecs_2d(vertices: Vec<Vec2>) -> Vec<Self> {
+        vertices
+    }
+
+    fn to_vec3(self) -> Vec3 {
+        self.extend(0f32)
+    }
+}
@@ -0,0 +1,72 @@
+use glam::Vec2;
+
+/// Provides custom glyphs that take precedence over the glyphs of the font.
+///
+/// This allows serving whole icon sets or procedurally generated
+/// glyphs (e.g. boxes or the modules of a QR code) without
+/// modifying the font.
+///
+/// # Example
+///
+/// ```rust
+/// use glam::Vec2;
+/// use meshtext::{Glyph, GlyphProvider, IndexedMeshText, MeshGenerator};
+///
+/// /// Provides a square for the character `■`.
+/// struct Squares;
+///
+/// impl GlyphProvider for Squares {
+///     fn advance(&self, character: char) -> Option<f32> {
+///         (character == '■').then_some(0.5)
+///     }
+///
+///     fn outline(&self, _character: char) -> Vec<Vec<Vec2>> {
+///         vec![vec![
+///             Vec2::new(0.05, 0.0),
+///             Vec2::new(0.05, 0.4),
+///             Vec2::new(0.45, 0.4),
+///             Vec2::new(0.45, 0.0),
+///         ]]
+///     }
+/// }
+///
+/// let font_data = include_bytes!("../../../assets/font/FiraMono-Regular.ttf");
+/// let mut generator = MeshGenerator::new(font_data);
+/// generator.set_glyph_provider(Squares);
+///
+/// let square: IndexedMeshText = generator
+///     .generate_glyph('■', true, None)
+///     .expect("Failed to generate mesh.");
+/// assert_eq!(square.indices.len(), 6);
+/// ```
+pub trait GlyphProvider: Send + Sync {
+    /// Gets the horizontal advance of a custom glyph.
+    ///
+    /// This is called whenever text is laid out, so it should be cheap.
+    ///
+    /// Arguments:
+    ///
+    /// * `character`: The character that is laid out.
+    ///
+    /// Returns:
+    ///
+    /// The advance relative to the font size or `None` if the
+    /// glyph of the font should be used instead.
+    fn advance(&self, character: char) -> Option<f32>;
+
+    /// Gets the outline of a custom glyph.
+    ///
+    /// This is only called for characters that have an advance
+    /// and only once per cached glyph.
+    ///
+    /// Arguments:
+    ///
+    /// * `character`: The character of which the outline is generated.
+    ///
+    /// Returns:
+    ///
+    /// The closed contours of the glyph relative to the font size. Like in
+    /// TrueType fonts, outer contours are clockwise and holes are counter-clockwise.
+    /// The last point of a contour is implicitly connected to its first point.
+    fn outline(&self, character: char) -> Vec<Vec<Vec2>>;
+}
@@ -0,0 +1,75 @@
+use std::fmt::Debug;
+
+/// An unsigned integer type that can be used for the indices of an [crate::IndexedMeshText].
+///
+/// This is implemented for [u16], [u32] and [usize], so that the indices can
+/// match the index buffer format of the target platform.
+pub trait IndexType: Copy + Debug + PartialEq + 'static {
+    /// Converts a vertex index into this type.
+    ///
+    /// Arguments:
+    ///
+    /// * `index`: The vertex index.
+    ///
+    /// Returns:
+    ///
+    /// The converted index or [None] if it can not be represented by this type.
+    fn from_usize(index: usize) -> Option<Self>;
+
+    /// Converts this index into a [usize].
+    ///
+    /// Returns:
+    ///
+    /// The vertex index.
+    fn to_usize(self) -> usize;
+
+    /// Converts the [u32] indices generated by the [crate::MeshGenerator] into this type.
+    ///
+    /// Arguments:
+    ///
+    /// * `indices`: The generated indices.
+    ///
+    /// Returns:
+    ///
+    /// The converted indices or [None] if any index can not be represented by this type.
+    fn from_u32_indices(indices: Vec<u32>) -> Option<Vec<Self>> {
+        indices
+            .into_iter()
+            .map(|i| Self::from_usize(i as usize))
+            .collect()
+    }
+}
+
+impl IndexType for u16 {
+    fn from_usize(index: usize) -> Option<Self> {
+        u16::try_from(index).ok()
+    }
+
+    fn to_usize(self) -> usize {
+        self as usize
+    }
+}
+
+impl IndexType for u32 {
+    fn from_usize(index: usize) -> Option<Self> {
+        u32::try_from(index).ok()
+    }
+
+    fn to_usize(self) -> usize {
+        self as usize
+    }
+
+    fn from_u32_indices(indices: Vec<u32>) -> Option<Vec<Self>> {
+        Some(indices)
+    }
+}
+
+impl IndexType for usize {
+    fn from_usize(index: usize) -> Option<Self> {
+        Some(index)
+    }
+
+    fn to_usize(self) -> usize {
+        self
+    }
+}
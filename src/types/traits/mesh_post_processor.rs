@@ -0,0 +1,62 @@
+use glam::Vec3A;
+
+use crate::GlyphSource;
+
+/// Modifies freshly generated glyph meshes before they are cached.
+///
+/// Post-processors registered with [crate::MeshGenerator::add_post_processor]
+/// run once per glyph, so their cost is not paid again when a glyph is
+/// loaded from the cache.
+///
+/// The vertices are in the untransformed coordinate system of the glyph.
+/// The bounding box of a glyph is recomputed after all post-processors ran.
+pub trait MeshPostProcessor: Send + Sync {
+    /// Processes the mesh of a single glyph.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The [GlyphSource] of the glyph.
+    /// * `flat`: Wether the mesh is flat.
+    /// * `vertices`: The vertices of the mesh. Without indices every three
+    ///   consecutive vertices form a triangle.
+    /// * `indices`: The indices of the mesh or `None` if the mesh is not indexed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec3A;
+    /// use meshtext::{Glyph, GlyphSource, IndexedMeshText, MeshGenerator, MeshPostProcessor};
+    ///
+    /// /// Shears all glyphs to the right.
+    /// struct Shear(f32);
+    ///
+    /// impl MeshPostProcessor for Shear {
+    ///     fn process(
+    ///         &self,
+    ///         _glyph: GlyphSource,
+    ///         _flat: bool,
+    ///         vertices: &mut Vec<Vec3A>,
+    ///         _indices: Option<&mut Vec<u32>>,
+    ///     ) {
+    ///         for v in vertices.iter_mut() {
+    ///             v.x += v.y * self.0;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let font_data = include_bytes!("../../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.add_post_processor(Shear(0.2));
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_glyph('I', true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// ```
+    fn process(
+        &self,
+        glyph: GlyphSource,
+        flat: bool,
+        vertices: &mut Vec<Vec3A>,
+        indices: Option<&mut Vec<u32>>,
+    );
+}
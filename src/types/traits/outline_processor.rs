@@ -0,0 +1,51 @@
+use glam::Vec2;
+
+use crate::GlyphSource;
+
+/// Adjusts the flattened outlines of freshly generated glyphs before they are triangulated.
+///
+/// Outline processors registered with [crate::MeshGenerator::add_outline_processor]
+/// can for example snap stems to a grid for tiny text or apply a custom warp.
+/// Since they run before a glyph is cached, their cost is only paid once per glyph.
+///
+/// The points are in the untransformed coordinate system of the glyph,
+/// in which the font height is `1`. Processors that can make points
+/// coincide or contours intersect should be combined with watertight
+/// meshes, since the processed outlines are repaired in that case
+/// (see [crate::MeshGenerator::set_watertight]).
+pub trait OutlineProcessor: Send + Sync {
+    /// Processes the outline of a single glyph.
+    ///
+    /// Arguments:
+    ///
+    /// * `glyph`: The [GlyphSource] of the glyph.
+    /// * `contours`: The indices of the points that form each closed contour.
+    /// * `points`: The points of the outline.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec2;
+    /// use meshtext::{Glyph, GlyphSource, IndexedMeshText, MeshGenerator, OutlineProcessor};
+    ///
+    /// /// Snaps all outline points to a grid.
+    /// struct Snap(f32);
+    ///
+    /// impl OutlineProcessor for Snap {
+    ///     fn process(&self, _glyph: GlyphSource, _contours: &[Vec<u32>], points: &mut [Vec2]) {
+    ///         for p in points.iter_mut() {
+    ///             *p = (*p / self.0).round() * self.0;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let font_data = include_bytes!("../../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// generator.add_outline_processor(Snap(0.05));
+    ///
+    /// let result: IndexedMeshText = generator
+    ///     .generate_glyph('I', true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// ```
+    fn process(&self, glyph: GlyphSource, contours: &[Vec<u32>], points: &mut [Vec2]);
+}
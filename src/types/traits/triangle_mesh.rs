@@ -0,0 +1,268 @@
+use glam::{Vec2, Vec3};
+
+use crate::{util::rasterize_triangles, CoverageMask};
+
+/// Common access to the data of a generated triangle mesh.
+pub trait TriangleMesh {
+    /// Gets a copy of the vertices of this mesh.
+    ///
+    /// Prefer [TriangleMesh::vertices_ref] if the data only needs
+    /// to be inspected.
+    ///
+    /// Returns:
+    ///
+    /// The vertex positions of this mesh.
+    fn vertices(&self) -> Vec<f32> {
+        if let Some(vertices) = self.vertices_ref() {
+            return vertices.to_vec();
+        }
+
+        let components = self.vertex_components();
+        (0..self.vertex_count())
+            .flat_map(|i| self.vertex(i).to_array().into_iter().take(components))
+            .collect()
+    }
+
+    /// Gets a copy of the indices of this mesh.
+    ///
+    /// Prefer [TriangleMesh::indices_ref] if the data only needs
+    /// to be inspected.
+    ///
+    /// Returns:
+    ///
+    /// The indices of this mesh or `None` if the mesh does not use indices.
+    fn indices(&self) -> Option<Vec<u32>> {
+        self.indices_ref().map(|i| i.to_vec())
+    }
+
+    /// Borrows the vertices of this mesh as a flat list of components.
+    ///
+    /// Returns:
+    ///
+    /// The vertex positions of this mesh or `None` if the vertices are not
+    /// stored as components, like those of a [crate::MeshTextGlam]. Use
+    /// [TriangleMesh::vertex] to inspect the vertices in that case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{Glyph, MeshGenerator, MeshText, TriangleMesh};
+    ///
+    /// let font_data = include_bytes!("../../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let result: MeshText = generator
+    ///     .generate_glyph('A', true, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// assert_eq!(result.vertices_ref().unwrap().len() % 9, 0);
+    /// assert!(result.indices_ref().is_none());
+    /// ```
+    fn vertices_ref(&self) -> Option<&[f32]>;
+
+    /// Borrows the indices of this mesh.
+    ///
+    /// Returns:
+    ///
+    /// The indices of this mesh or `None` if the mesh does not use indices.
+    fn indices_ref(&self) -> Option<&[u32]>;
+
+    /// Gets the number of components of a single vertex.
+    ///
+    /// Returns:
+    ///
+    /// `2` for two-dimensional meshes, otherwise `3`.
+    fn vertex_components(&self) -> usize;
+
+    /// Gets the number of vertices of this mesh.
+    ///
+    /// Types that do not store their vertices as components have to override
+    /// this together with [TriangleMesh::vertex].
+    ///
+    /// Returns:
+    ///
+    /// The number of vertices.
+    fn vertex_count(&self) -> usize {
+        self.vertices_ref()
+            .map_or(0, |vertices| vertices.len() / self.vertex_components())
+    }
+
+    /// Gets a single vertex of this mesh.
+    ///
+    /// Arguments:
+    ///
+    /// * `index`: The index of the vertex.
+    ///
+    /// Returns:
+    ///
+    /// The vertex with a z-coordinate of `0` if this mesh is two-dimensional.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not smaller than [TriangleMesh::vertex_count].
+    fn vertex(&self, index: usize) -> Vec3 {
+        let components = self.vertex_components();
+        let vertices = self.vertices_ref().unwrap_or_default();
+        let v = &vertices[index * components..(index + 1) * components];
+        Vec3::new(v[0], v[1], if components == 3 { v[2] } else { 0f32 })
+    }
+
+    /// Iterates over the triangles of this mesh.
+    ///
+    /// Both indexed and non-indexed meshes are supported. The vertices
+    /// of two-dimensional meshes have a z-coordinate of `0`.
+    ///
+    /// Returns:
+    ///
+    /// An [Iterator] over the corners of each triangle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{IndexedMeshText, MeshGenerator, TextSection, TriangleMesh};
+    ///
+    /// let font_data = include_bytes!("../../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let result: IndexedMeshText = generator
+    ///     .generate_section_2d("Hello", None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// assert_eq!(result.triangles().count(), result.indices.len() / 3);
+    /// ```
+    fn triangles(&self) -> impl Iterator<Item = [Vec3; 3]> + '_
+    where
+        Self: Sized,
+    {
+        let indices = self.indices_ref();
+
+        let count = match indices {
+            Some(indices) => indices.len() / 3,
+            None => self.vertex_count() / 3,
+        };
+
+        let vertex = move |corner: usize| match indices {
+            Some(indices) => self.vertex(indices[corner] as usize),
+            None => self.vertex(corner),
+        };
+
+        (0..count).map(move |t| [vertex(t * 3), vertex(t * 3 + 1), vertex(t * 3 + 2)])
+    }
+
+    /// Iterates over the triangles of this mesh as plain arrays.
+    ///
+    /// See [TriangleMesh::triangles] for details.
+    ///
+    /// Returns:
+    ///
+    /// An iterator over the corners (XYZ) of each triangle.
+    #[cfg(feature = "arrays")]
+    fn triangle_arrays(&self) -> impl Iterator<Item = [[f32; 3]; 3]> + '_
+    where
+        Self: Sized,
+    {
+        self.triangles().map(|t| t.map(|corner| corner.to_array()))
+    }
+
+    /// Computes the surface area of this mesh.
+    ///
+    /// Returns:
+    ///
+    /// The sum of the areas of all triangles.
+    fn surface_area(&self) -> f32
+    where
+        Self: Sized,
+    {
+        self.triangles()
+            .map(|[a, b, c]| (b - a).cross(c - a).length() * 0.5f32)
+            .sum()
+    }
+
+    /// Computes the volume enclosed by this mesh.
+    ///
+    /// The result is only meaningful for closed three-dimensional meshes. Since the
+    /// volume is signed, a negative value indicates that the triangles face inward.
+    ///
+    /// Returns:
+    ///
+    /// The enclosed volume or `None` if this mesh is two-dimensional.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{Glyph, MeshGenerator, MeshText, TriangleMesh};
+    ///
+    /// let font_data = include_bytes!("../../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let flat: MeshText = generator
+    ///     .generate_glyph('A', true, None)
+    ///     .expect("Failed to generate mesh.");
+    /// let extruded: MeshText = generator
+    ///     .generate_glyph('A', false, None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// // The glyph is extruded by one unit.
+    /// let volume = extruded.volume().unwrap();
+    /// assert!((volume - flat.surface_area()).abs() < 1e-4);
+    /// ```
+    fn volume(&self) -> Option<f32>
+    where
+        Self: Sized,
+    {
+        if self.vertex_components() != 3 {
+            return None;
+        }
+
+        Some(
+            self.triangles()
+                .map(|[a, b, c]| a.dot(b.cross(c)) / 6f32)
+                .sum(),
+        )
+    }
+
+    /// Rasterizes this mesh projected onto the XY-plane into a [CoverageMask].
+    ///
+    /// This can be used to generate stencils of flat text or to compare
+    /// the triangulation against a reference image.
+    ///
+    /// Arguments:
+    ///
+    /// * `min`: The lower left corner of the rasterized area.
+    /// * `max`: The upper right corner of the rasterized area.
+    /// * `width`: The number of pixels in each row.
+    /// * `height`: The number of rows.
+    /// * `samples`: The number of samples along each axis of a pixel. Use `1`
+    ///   for a binary mask, larger values produce an anti-aliased mask.
+    ///
+    /// Returns:
+    ///
+    /// The [CoverageMask] of this mesh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glam::Vec2;
+    /// use meshtext::{Glyph, MeshGenerator, MeshText, TriangleMesh};
+    ///
+    /// let font_data = include_bytes!("../../../assets/font/FiraMono-Regular.ttf");
+    /// let mut generator = MeshGenerator::new(font_data);
+    /// let result: MeshText = generator
+    ///     .generate_glyph_2d('I', None)
+    ///     .expect("Failed to generate mesh.");
+    ///
+    /// let mask = result.rasterize(Vec2::ZERO, Vec2::new(0.6, 0.8), 24, 32, 4);
+    /// assert_eq!(mask.data.len(), 24 * 32);
+    /// assert!(mask.data.iter().any(|c| *c == 255));
+    /// ```
+    fn rasterize(
+        &self,
+        min: Vec2,
+        max: Vec2,
+        width: usize,
+        height: usize,
+        samples: u32,
+    ) -> CoverageMask
+    where
+        Self: Sized,
+    {
+        rasterize_triangles(self.triangles(), min, max, width, height, samples)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::MeshPart;
+
+/// Describes where a triangle of a generated text section originates from.
+///
+/// This can be used to retexture, recolor or strip parts of a mesh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriangleProvenance {
+    /// The index of the first character in the text from which the glyph
+    /// that produced the triangle originates.
+    pub character: usize,
+
+    /// The index of the glyph that produced the triangle in the order
+    /// in which the glyphs were laid out.
+    pub glyph: usize,
+
+    /// The [MeshPart] the triangle belongs to.
+    pub part: MeshPart,
+}
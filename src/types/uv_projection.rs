@@ -0,0 +1,24 @@
+/// Controls how the texture coordinates of interleaved vertices are computed.
+///
+/// Two-dimensional meshes always use [UvProjection::Planar].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UvProjection {
+    /// The texture is projected along the z-axis onto the bounding box
+    /// of the untransformed text.
+    #[default]
+    Planar,
+
+    /// The texture is wrapped around the vertical axis through the center
+    /// of the text after it has been deformed (e.g. bent), but before it is
+    /// transformed. The horizontal coordinate follows the angle around the axis.
+    Cylindrical,
+
+    /// The texture is wrapped around the center of the text after it has been
+    /// deformed (e.g. bent), but before it is transformed. The coordinates follow
+    /// the longitude and the latitude around the center.
+    Spherical,
+
+    /// The texture is projected along the z-axis onto the bounding box of each
+    /// glyph, so that each glyph spans the whole texture.
+    PerGlyph,
+}
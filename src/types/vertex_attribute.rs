@@ -0,0 +1,94 @@
+/// The attributes that can be part of an interleaved vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VertexAttribute {
+    /// The position of the vertex.
+    ///
+    /// Consists of three `f32` components (XYZ) or two `f32`
+    /// components (XY) in the case of a two-dimensional mesh.
+    Position,
+
+    /// The normal vector of the vertex.
+    ///
+    /// Consists of three `f32` components (XYZ). This attribute is
+    /// omitted for two-dimensional meshes.
+    Normal,
+
+    /// The texture coordinates of the vertex.
+    ///
+    /// Consists of two `f32` components (UV). The coordinates are computed
+    /// according to [crate::VertexLayout::uv_projection] and
+    /// [crate::VertexLayout::side_wall_mapping]. The origin of the texture
+    /// coordinates is in the upper left corner.
+    Uv,
+
+    /// A second set of texture coordinates suitable for lightmaps.
+    ///
+    /// Consists of two `f32` components (UV). The caps of each glyph and the side
+    /// walls along each contour are packed into the unit square without overlapping,
+    /// separated by [crate::VertexLayout::lightmap_padding]. Two-dimensional meshes
+    /// use the planar texture coordinates instead.
+    Uv2,
+
+    /// The color of the vertex.
+    ///
+    /// Consists of four `f32` components (RGBA) computed according to
+    /// [crate::VertexLayout::coloring].
+    Color,
+
+    /// The glyph the vertex belongs to, which allows vertex shaders to animate
+    /// the characters independently (e.g. typewriter reveals or waves).
+    ///
+    /// Consists of three `f32` components. The first component is the index of the
+    /// glyph within the text, which is the index of the character unless characters
+    /// are combined (e.g. into ligatures). The other two components are the position
+    /// of the untransformed vertex within the bounding box of its glyph, which is
+    /// `(0.0, 0.0)` in the lower left corner and `(1.0, 1.0)` in the upper right corner.
+    Character,
+}
+
+impl VertexAttribute {
+    /// Gets the number of `f32` components of this attribute.
+    ///
+    /// Arguments:
+    ///
+    /// * `two_dimensional`: Wether the attribute belongs to a two-dimensional mesh.
+    ///
+    /// Returns:
+    ///
+    /// The number of components.
+    pub fn components(&self, two_dimensional: bool) -> u32 {
+        match self {
+            VertexAttribute::Position if two_dimensional => 2,
+            VertexAttribute::Position | VertexAttribute::Normal | VertexAttribute::Character => 3,
+            VertexAttribute::Uv | VertexAttribute::Uv2 => 2,
+            VertexAttribute::Color => 4,
+        }
+    }
+}
+
+/// Describes where a [VertexAttribute] is located within an interleaved vertex.
+///
+/// This maps directly onto the attribute descriptions most graphics APIs
+/// expect, e.g. `wgpu::VertexAttribute`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexAttributeDescriptor {
+    /// The described attribute.
+    pub attribute: VertexAttribute,
+
+    /// The number of `f32` components of this attribute.
+    pub components: u32,
+
+    /// The offset of this attribute from the start of a vertex in bytes.
+    pub offset: u64,
+}
+
+impl VertexAttributeDescriptor {
+    /// Gets the size of this attribute.
+    ///
+    /// Returns:
+    ///
+    /// The size of this attribute in bytes.
+    pub fn size(&self) -> u64 {
+        self.components as u64 * std::mem::size_of::<f32>() as u64
+    }
+}
@@ -0,0 +1,94 @@
+/// Controls how the [crate::VertexAttribute::Color] attribute of interleaved vertices is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum VertexColoring {
+    /// Each vertex uses [crate::VertexLayout::color].
+    #[default]
+    Uniform,
+
+    /// The color is interpolated linearly from the top to the bottom
+    /// of the bounding box of the undeformed and untransformed text.
+    VerticalGradient {
+        /// The color (RGBA) at the top of the text.
+        top: [f32; 4],
+
+        /// The color (RGBA) at the bottom of the text.
+        bottom: [f32; 4],
+    },
+
+    /// The hue of [crate::VertexLayout::color] is rotated by a fixed amount
+    /// for each glyph, while its saturation, value and alpha stay the same.
+    HueRotation {
+        /// The rotation between two consecutive glyphs in full turns.
+        step: f32,
+    },
+}
+
+impl VertexColoring {
+    /// Computes the color of a vertex.
+    ///
+    /// Arguments:
+    ///
+    /// * `color`: The uniform color of the [crate::VertexLayout].
+    /// * `height`: The relative height of the vertex within the text,
+    ///   which is `0.0` at the bottom and `1.0` at the top.
+    /// * `glyph`: The index of the glyph the vertex belongs to.
+    ///
+    /// Returns:
+    ///
+    /// The color (RGBA) of the vertex.
+    pub(crate) fn color_of(&self, color: [f32; 4], height: f32, glyph: usize) -> [f32; 4] {
+        match *self {
+            VertexColoring::Uniform => color,
+            VertexColoring::VerticalGradient { top, bottom } => {
+                let mut mixed = [0f32; 4];
+                for (c, (t, b)) in mixed.iter_mut().zip(top.iter().zip(bottom.iter())) {
+                    *c = b + (t - b) * height;
+                }
+                mixed
+            }
+            VertexColoring::HueRotation { step } => rotate_hue(color, step * glyph as f32),
+        }
+    }
+}
+
+/// Rotates the hue of a color in the HSV color space.
+///
+/// Arguments:
+///
+/// * `color`: The color (RGBA) that will be rotated.
+/// * `turns`: The rotation in full turns.
+///
+/// Returns:
+///
+/// The rotated color (RGBA).
+fn rotate_hue(color: [f32; 4], turns: f32) -> [f32; 4] {
+    let [r, g, b, a] = color;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+    if chroma <= 0f32 {
+        return color;
+    }
+
+    // The hue in sixths of a turn.
+    let hue = if max == r {
+        (g - b) / chroma
+    } else if max == g {
+        (b - r) / chroma + 2f32
+    } else {
+        (r - g) / chroma + 4f32
+    };
+    let hue = (hue + turns * 6f32).rem_euclid(6f32);
+
+    let x = chroma * (1f32 - (hue % 2f32 - 1f32).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (chroma, x, 0f32),
+        1 => (x, chroma, 0f32),
+        2 => (0f32, chroma, x),
+        3 => (0f32, x, chroma),
+        4 => (x, 0f32, chroma),
+        _ => (chroma, 0f32, x),
+    };
+
+    [r + min, g + min, b + min, a]
+}
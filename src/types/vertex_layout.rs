@@ -0,0 +1,97 @@
+use crate::{Seam, SideWallMapping, UvProjection, VertexAttribute, VertexColoring};
+
+/// Controls which attributes an interleaved vertex consists of
+/// and in which order they appear.
+///
+/// The layout is used for generating an [crate::InterleavedMeshText].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VertexLayout {
+    /// The attributes of each vertex in the order in which they appear.
+    ///
+    /// Each attribute should appear at most once.
+    pub attributes: Vec<VertexAttribute>,
+
+    /// The color (RGBA) that is written to the [VertexAttribute::Color]
+    /// attribute of each vertex, unless the [VertexColoring] says otherwise.
+    pub color: [f32; 4],
+
+    /// The [VertexColoring] of the [VertexAttribute::Color] attribute.
+    pub coloring: VertexColoring,
+
+    /// The [Seam] between the front cap and the side walls.
+    ///
+    /// Only has an effect if the layout contains [VertexAttribute::Normal].
+    pub front_seam: Seam,
+
+    /// The [Seam] between the back cap and the side walls.
+    ///
+    /// Only has an effect if the layout contains [VertexAttribute::Normal].
+    pub back_seam: Seam,
+
+    /// The [UvProjection] of the [VertexAttribute::Uv] attribute.
+    pub uv_projection: UvProjection,
+
+    /// The [SideWallMapping] of the [VertexAttribute::Uv] attribute.
+    pub side_wall_mapping: SideWallMapping,
+
+    /// The minimum distance between two charts of the [VertexAttribute::Uv2]
+    /// attribute relative to the size of the texture.
+    pub lightmap_padding: f32,
+}
+
+impl VertexLayout {
+    /// Creates a new [VertexLayout].
+    ///
+    /// Arguments:
+    ///
+    /// * `attributes`: The attributes of each vertex in the order in which they appear.
+    ///
+    /// Returns:
+    ///
+    /// The new [VertexLayout] using a uniform white vertex color, hard seams, planar
+    /// texture coordinates and a lightmap padding of `0.01`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use meshtext::{VertexAttribute, VertexLayout};
+    ///
+    /// // Only positions followed by texture coordinates.
+    /// let layout = VertexLayout::new(&[VertexAttribute::Position, VertexAttribute::Uv]);
+    /// ```
+    pub fn new(attributes: &[VertexAttribute]) -> Self {
+        Self {
+            attributes: attributes.to_vec(),
+            color: [1f32, 1f32, 1f32, 1f32],
+            coloring: VertexColoring::Uniform,
+            front_seam: Seam::Hard,
+            back_seam: Seam::Hard,
+            uv_projection: UvProjection::Planar,
+            side_wall_mapping: SideWallMapping::Projected,
+            lightmap_padding: 0.01f32,
+        }
+    }
+
+    /// Checks wether this layout contains a certain [VertexAttribute].
+    ///
+    /// Arguments:
+    ///
+    /// * `attribute`: The attribute in question.
+    ///
+    /// Returns:
+    ///
+    /// `true` if the attribute is part of this layout.
+    pub fn contains(&self, attribute: VertexAttribute) -> bool {
+        self.attributes.contains(&attribute)
+    }
+}
+
+impl Default for VertexLayout {
+    fn default() -> Self {
+        Self::new(&[
+            VertexAttribute::Position,
+            VertexAttribute::Normal,
+            VertexAttribute::Uv,
+        ])
+    }
+}
@@ -0,0 +1,18 @@
+/// Controls how the whitespace of a [crate::Paragraph] is handled.
+///
+/// Non-breaking spaces are never collapsed or trimmed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WhitespaceMode {
+    /// All whitespace is kept as it is, including the whitespace
+    /// at the start and the end of each line.
+    Preserve,
+
+    /// The whitespace at the start and the end of each line is removed,
+    /// while runs of whitespace within a line are kept.
+    #[default]
+    Trim,
+
+    /// Runs of whitespace are collapsed into a single space and the
+    /// whitespace at the start and the end of each line is removed.
+    Collapse,
+}
@@ -0,0 +1,141 @@
+use ttf_parser::OutlineBuilder;
+
+/// Collects the outline of a glyph as a [kurbo::BezPath].
+#[cfg(feature = "kurbo")]
+pub(crate) struct BezPathBuilder {
+    font_height: f64,
+    path: kurbo::BezPath,
+}
+
+#[cfg(feature = "kurbo")]
+impl BezPathBuilder {
+    /// Creates a new [BezPathBuilder].
+    ///
+    /// Arguments:
+    ///
+    /// * `font_height`: The font metric by which the coordinates are normalized.
+    pub(crate) fn new(font_height: f32) -> Self {
+        Self {
+            font_height: font_height as f64,
+            path: kurbo::BezPath::new(),
+        }
+    }
+
+    /// Gets the collected path.
+    ///
+    /// Returns:
+    ///
+    /// The outline of the glyph.
+    pub(crate) fn into_path(self) -> kurbo::BezPath {
+        self.path
+    }
+
+    /// Normalizes a point of the outline.
+    fn point(&self, x: f32, y: f32) -> kurbo::Point {
+        kurbo::Point::new(x as f64 / self.font_height, y as f64 / self.font_height)
+    }
+}
+
+#[cfg(feature = "kurbo")]
+impl OutlineBuilder for BezPathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.path.move_to(point);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.path.line_to(point);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (control, point) = (self.point(x1, y1), self.point(x, y));
+        self.path.quad_to(control, point);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (first, second, point) = (self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        self.path.curve_to(first, second, point);
+    }
+
+    fn close(&mut self) {
+        self.path.close_path();
+    }
+}
+
+/// Collects the outline of a glyph as a [lyon_path::Path].
+#[cfg(feature = "lyon")]
+pub(crate) struct LyonPathBuilder {
+    builder: lyon_path::path::Builder,
+    font_height: f32,
+    open: bool,
+}
+
+#[cfg(feature = "lyon")]
+impl LyonPathBuilder {
+    /// Creates a new [LyonPathBuilder].
+    ///
+    /// Arguments:
+    ///
+    /// * `font_height`: The font metric by which the coordinates are normalized.
+    pub(crate) fn new(font_height: f32) -> Self {
+        Self {
+            builder: lyon_path::Path::builder(),
+            font_height,
+            open: false,
+        }
+    }
+
+    /// Gets the collected path.
+    ///
+    /// A sub-path that was not closed by the font is ended without closing it.
+    ///
+    /// Returns:
+    ///
+    /// The outline of the glyph.
+    pub(crate) fn into_path(mut self) -> lyon_path::Path {
+        if self.open {
+            self.builder.end(false);
+        }
+        self.builder.build()
+    }
+
+    /// Normalizes a point of the outline.
+    fn point(&self, x: f32, y: f32) -> lyon_path::math::Point {
+        lyon_path::math::point(x / self.font_height, y / self.font_height)
+    }
+}
+
+#[cfg(feature = "lyon")]
+impl OutlineBuilder for LyonPathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        if self.open {
+            self.builder.end(false);
+        }
+        let point = self.point(x, y);
+        self.builder.begin(point);
+        self.open = true;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.builder.line_to(point);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (control, point) = (self.point(x1, y1), self.point(x, y));
+        self.builder.quadratic_bezier_to(control, point);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (first, second, point) = (self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        self.builder.cubic_bezier_to(first, second, point);
+    }
+
+    fn close(&mut self) {
+        if self.open {
+            self.builder.end(true);
+            self.open = false;
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use glam::Vec2;
+
+use crate::{Caret, PositionedGlyph};
+
+/// Computes the caret positions of a laid out text section.
+///
+/// Characters that were combined into a single glyph (e.g. a ligature)
+/// share the advance of that glyph evenly.
+///
+/// Arguments:
+///
+/// * `text`: The text of the section.
+/// * `glyphs`: The laid out glyphs of the text section.
+/// * `descender`: The descender of the font relative to the font size.
+/// * `height`: The distance between the descender and the ascender
+///   relative to the font size.
+///
+/// Returns:
+///
+/// One [Caret] in front of each character and one after the last character.
+pub(crate) fn caret_positions(
+    text: &str,
+    glyphs: &[PositionedGlyph],
+    descender: f32,
+    height: f32,
+) -> Vec<Caret> {
+    // The start and the advance of each cluster, keyed by its first byte.
+    let mut clusters: BTreeMap<usize, (f32, f32)> = BTreeMap::new();
+    let mut pen = 0f32;
+    for glyph in glyphs.iter() {
+        let cluster = clusters.entry(glyph.cluster).or_insert((pen, 0f32));
+        cluster.0 = cluster.0.min(pen);
+        cluster.1 += glyph.advance;
+        pen += glyph.advance;
+    }
+
+    let caret = |index: usize, x: f32| Caret {
+        height,
+        index,
+        position: Vec2::new(x, descender),
+    };
+
+    let mut carets = Vec::with_capacity(text.len() + 1);
+    let characters: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    for (index, byte) in characters.iter().enumerate() {
+        let (start, (x, advance)) = match clusters.range(..=byte).next_back() {
+            Some(cluster) => cluster,
+            None => {
+                carets.push(caret(index, 0f32));
+                continue;
+            }
+        };
+        let end = clusters
+            .range(byte + 1..)
+            .next()
+            .map(|(end, _)| *end)
+            .unwrap_or(text.len());
+
+        let count = characters
+            .iter()
+            .filter(|c| (*start..end).contains(c))
+            .count();
+        let preceding = characters[..index].iter().filter(|c| **c >= *start).count();
+        carets.push(caret(index, x + advance * preceding as f32 / count as f32));
+    }
+    carets.push(caret(characters.len(), pen));
+
+    carets
+}
@@ -0,0 +1,36 @@
+/// Checks wether a character is a combining mark that should be
+/// placed on top of the preceding character.
+///
+/// Only the common blocks of combining diacritical marks are considered.
+///
+/// Arguments:
+///
+/// * `c`: The character in question.
+///
+/// Returns:
+///
+/// `true` if the character is a combining mark.
+pub(crate) fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// Checks wether a character is a variation selector that chooses
+/// the appearance of the preceding character.
+///
+/// Arguments:
+///
+/// * `c`: The character in question.
+///
+/// Returns:
+///
+/// `true` if the character is a variation selector.
+pub(crate) fn is_variation_selector(c: char) -> bool {
+    matches!(c, '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}')
+}
@@ -0,0 +1,127 @@
+use glam::{Vec2, Vec3A};
+
+use crate::BoundingBox;
+
+type IndexedMesh = (Vec<u32>, Vec<Vec3A>, BoundingBox);
+
+/// Computes the convex hull of a set of points in the XY-plane.
+///
+/// Uses the monotone chain algorithm.
+///
+/// Arguments:
+///
+/// * `points`: The points of which the convex hull will be computed.
+///
+/// Returns:
+///
+/// The corners of the convex hull in counter-clockwise order
+/// without collinear points.
+pub(crate) fn convex_hull_2d(points: &[Vec2]) -> Vec<Vec2> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Vec2> = Vec::with_capacity(sorted.len() * 2);
+
+    // The lower hull.
+    for p in sorted.iter() {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], *p) <= 0f32 {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+
+    // The upper hull.
+    let lower_len = hull.len() + 1;
+    for p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], *p) <= 0f32
+        {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+
+    // The last point is the same as the first one.
+    hull.pop();
+    hull
+}
+
+/// Generates a closed prism from a convex polygon.
+///
+/// If both z-coordinates are equal, only a single cap facing in the
+/// positive z-direction is generated.
+///
+/// Arguments:
+///
+/// * `hull`: The corners of the convex polygon in counter-clockwise order.
+/// * `depth`: The minimum and maximum z-coordinate of the prism.
+///
+/// Returns:
+///
+/// The indexed mesh of the prism.
+pub(crate) fn extrude_convex_hull(hull: &[Vec2], depth: (f32, f32)) -> IndexedMesh {
+    let (z_min, z_max) = depth;
+    let mut indices = Vec::new();
+    let mut vertices: Vec<Vec3A> = hull.iter().map(|p| Vec3A::new(p.x, p.y, z_max)).collect();
+
+    if hull.len() < 3 {
+        return (indices, vertices, hull_bbox(hull, depth));
+    }
+
+    let count = hull.len() as u32;
+    for i in 1..count - 1 {
+        indices.extend_from_slice(&[0, i, i + 1]);
+    }
+
+    if z_max > z_min {
+        vertices.extend(hull.iter().map(|p| Vec3A::new(p.x, p.y, z_min)));
+
+        // The back cap faces in the opposite direction.
+        for i in 1..count - 1 {
+            indices.extend_from_slice(&[count, count + i + 1, count + i]);
+        }
+
+        // The side walls.
+        for i in 0..count {
+            let j = (i + 1) % count;
+            indices.extend_from_slice(&[count + i, count + j, j]);
+            indices.extend_from_slice(&[count + i, j, i]);
+        }
+    }
+
+    (indices, vertices, hull_bbox(hull, depth))
+}
+
+/// Computes the [BoundingBox] of a prism.
+///
+/// Arguments:
+///
+/// * `hull`: The corners of the base polygon.
+/// * `depth`: The minimum and maximum z-coordinate of the prism.
+///
+/// Returns:
+///
+/// The [BoundingBox] of the prism.
+fn hull_bbox(hull: &[Vec2], depth: (f32, f32)) -> BoundingBox {
+    if hull.is_empty() {
+        return BoundingBox::empty();
+    }
+
+    let min = hull.iter().fold(Vec2::splat(f32::MAX), |a, b| a.min(*b));
+    let max = hull.iter().fold(Vec2::splat(f32::MIN), |a, b| a.max(*b));
+
+    BoundingBox::new(
+        Vec3A::new(min.x, min.y, depth.0),
+        Vec3A::new(max.x, max.y, depth.1),
+    )
+}
+
+/// Computes the z-component of the cross product of `a -> b` and `a -> c`.
+fn cross(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    (b - a).perp_dot(c - a)
+}
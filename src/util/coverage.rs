@@ -0,0 +1,100 @@
+use glam::{Vec2, Vec3};
+
+use crate::CoverageMask;
+
+/// Rasterizes triangles projected onto the XY-plane into a [CoverageMask].
+///
+/// Each pixel is sampled on a regular grid and its coverage is the
+/// fraction of samples that lie within any of the triangles.
+///
+/// Arguments:
+///
+/// * `triangles`: The corners of each triangle.
+/// * `min`: The lower left corner of the rasterized area.
+/// * `max`: The upper right corner of the rasterized area.
+/// * `width`: The number of pixels in each row.
+/// * `height`: The number of rows.
+/// * `samples`: The number of samples along each axis of a pixel.
+///
+/// Returns:
+///
+/// The [CoverageMask] of the triangles.
+pub(crate) fn rasterize_triangles(
+    triangles: impl Iterator<Item = [Vec3; 3]>,
+    min: Vec2,
+    max: Vec2,
+    width: usize,
+    height: usize,
+    samples: u32,
+) -> CoverageMask {
+    let samples = samples.max(1) as usize;
+    let (columns, rows) = (width * samples, height * samples);
+    let mut covered = vec![false; columns * rows];
+
+    let size = max - min;
+    if columns > 0 && rows > 0 && size.x > 0f32 && size.y > 0f32 {
+        let step = Vec2::new(size.x / columns as f32, size.y / rows as f32);
+
+        // The position of a sample, where the rows start at the top.
+        let sample = |column: usize, row: usize| {
+            Vec2::new(
+                min.x + (column as f32 + 0.5f32) * step.x,
+                max.y - (row as f32 + 0.5f32) * step.y,
+            )
+        };
+
+        for triangle in triangles {
+            let [a, b, c] = triangle.map(|v| Vec2::new(v.x, v.y));
+            let lower = a.min(b).min(c);
+            let upper = a.max(b).max(c);
+
+            // Only the samples within the bounds of the triangle are tested.
+            let first_column = ((lower.x - min.x) / step.x - 0.5f32).ceil().max(0f32) as usize;
+            let last_column = ((upper.x - min.x) / step.x - 0.5f32).floor();
+            let first_row = ((max.y - upper.y) / step.y - 0.5f32).ceil().max(0f32) as usize;
+            let last_row = ((max.y - lower.y) / step.y - 0.5f32).floor();
+            if last_column < 0f32 || last_row < 0f32 {
+                continue;
+            }
+            let last_column = (last_column as usize).min(columns - 1);
+            let last_row = (last_row as usize).min(rows - 1);
+
+            for row in first_row..=last_row {
+                for column in first_column..=last_column {
+                    let p = sample(column, row);
+                    let (ab, bc, ca) = (
+                        (b - a).perp_dot(p - a),
+                        (c - b).perp_dot(p - b),
+                        (a - c).perp_dot(p - c),
+                    );
+                    if (ab >= 0f32 && bc >= 0f32 && ca >= 0f32)
+                        || (ab <= 0f32 && bc <= 0f32 && ca <= 0f32)
+                    {
+                        covered[row * columns + column] = true;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut data = Vec::with_capacity(width * height);
+    for row in 0..height {
+        for column in 0..width {
+            let mut count = 0;
+            for r in row * samples..(row + 1) * samples {
+                for c in column * samples..(column + 1) * samples {
+                    if covered[r * columns + c] {
+                        count += 1;
+                    }
+                }
+            }
+            data.push((count * 255 / (samples * samples)) as u8);
+        }
+    }
+
+    CoverageMask {
+        data,
+        height,
+        width,
+    }
+}
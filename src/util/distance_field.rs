@@ -0,0 +1,317 @@
+use glam::Vec2;
+
+use crate::DistanceField;
+
+/// The channels (RGB) that each edge contributes to.
+type EdgeColor = [bool; 3];
+
+const CYAN: EdgeColor = [false, true, true];
+const MAGENTA: EdgeColor = [true, false, true];
+const YELLOW: EdgeColor = [true, true, false];
+const WHITE: EdgeColor = [true, true, true];
+
+/// The sine of the smallest angle between two segments that is considered a corner.
+const CORNER_THRESHOLD: f32 = 0.141;
+
+/// A straight segment of a contour together with its edge color.
+struct Segment {
+    /// The start point of the segment.
+    a: Vec2,
+
+    /// The end point of the segment.
+    b: Vec2,
+
+    /// The channels this segment contributes to.
+    color: EdgeColor,
+
+    /// Wether the segment starts an edge, so its line is extended backwards.
+    starts_edge: bool,
+
+    /// Wether the segment ends an edge, so its line is extended forwards.
+    ends_edge: bool,
+}
+
+/// Generates a multi-channel signed distance field from the contours of a glyph.
+///
+/// The contours are split into edges at their corners and the edges are colored,
+/// so that the median of the three channels reconstructs sharp corners.
+///
+/// Arguments:
+///
+/// * `contours`: The closed contours, which do not repeat their first point.
+/// * `pixels_per_unit`: The resolution of the field per unit of the font size.
+/// * `range`: The distance relative to the font size at which the field saturates.
+///
+/// Returns:
+///
+/// The [DistanceField] covering the contours with a margin of `range` on each side.
+pub(crate) fn multi_channel_distance_field(
+    contours: &[Vec<Vec2>],
+    pixels_per_unit: f32,
+    range: f32,
+) -> DistanceField {
+    // Repeated points would be mistaken for corners.
+    let contours: Vec<Vec<Vec2>> = contours
+        .iter()
+        .map(|c| {
+            let mut contour = c.clone();
+            contour.dedup();
+            while contour.len() > 1 && contour.first() == contour.last() {
+                contour.pop();
+            }
+            contour
+        })
+        .filter(|c| c.len() >= 3)
+        .collect();
+    if contours.is_empty() || pixels_per_unit <= 0f32 {
+        return DistanceField {
+            data: Vec::new(),
+            height: 0,
+            origin: Vec2::ZERO,
+            pixel_size: 0f32,
+            range,
+            width: 0,
+        };
+    }
+
+    let (min, max) = contours.iter().flatten().fold(
+        (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    );
+    let range = range.max(f32::EPSILON);
+    let origin = min - Vec2::splat(range);
+    let size = ((max - min + Vec2::splat(2f32 * range)) * pixels_per_unit).ceil();
+    let (width, height) = (size.x.max(1f32) as usize, size.y.max(1f32) as usize);
+    let pixel_size = 1f32 / pixels_per_unit;
+
+    let segments = colored_segments(&oriented_contours(&contours));
+
+    // The rows are stored from top to bottom.
+    let mut data = Vec::with_capacity(width * height * 3);
+    for row in 0..height {
+        for column in 0..width {
+            let point = Vec2::new(
+                origin.x + (column as f32 + 0.5f32) * pixel_size,
+                origin.y + ((height - row) as f32 - 0.5f32) * pixel_size,
+            );
+            for channel in 0..3 {
+                let distance = channel_distance(&segments, point, channel);
+                let value = (0.5f32 + distance / (2f32 * range)).clamp(0f32, 1f32);
+                data.push((value * 255f32).round() as u8);
+            }
+        }
+    }
+
+    DistanceField {
+        data,
+        height,
+        origin,
+        pixel_size,
+        range,
+        width,
+    }
+}
+
+/// Orients the contours, so that the filled area is always on the left.
+///
+/// Arguments:
+///
+/// * `contours`: The closed contours of the glyph.
+///
+/// Returns:
+///
+/// The contours with counter-clockwise outer contours and clockwise holes.
+pub(crate) fn oriented_contours(contours: &[Vec<Vec2>]) -> Vec<Vec<Vec2>> {
+    contours
+        .iter()
+        .enumerate()
+        .map(|(i, contour)| {
+            // Contours nested in an odd number of other contours are holes.
+            let depth = contours
+                .iter()
+                .enumerate()
+                .filter(|(j, other)| *j != i && contains(other, contour[0]))
+                .count();
+            let counter_clockwise = signed_area(contour) > 0f32;
+            let mut oriented = contour.clone();
+            if counter_clockwise == (depth % 2 == 1) {
+                oriented.reverse();
+            }
+            oriented
+        })
+        .collect()
+}
+
+/// Splits the contours into edges at their corners and assigns a color to each edge.
+///
+/// Arguments:
+///
+/// * `contours`: The oriented contours of the glyph.
+///
+/// Returns:
+///
+/// The colored segments of all contours.
+fn colored_segments(contours: &[Vec<Vec2>]) -> Vec<Segment> {
+    let mut segments = Vec::new();
+
+    for contour in contours.iter() {
+        let count = contour.len();
+        let direction = |i: usize| (contour[(i + 1) % count] - contour[i]).normalize_or_zero();
+        let corners: Vec<usize> = (0..count)
+            .filter(|i| {
+                let (incoming, outgoing) = (direction((i + count - 1) % count), direction(*i));
+                incoming.dot(outgoing) <= 0f32
+                    || incoming.perp_dot(outgoing).abs() > CORNER_THRESHOLD
+            })
+            .collect();
+
+        // The color of each segment and wether it starts a new edge.
+        let mut colors = vec![(WHITE, false); count];
+        match corners.len() {
+            0 => (),
+            1 => {
+                // A single corner (teardrop) is split into three edges.
+                let group = |k: usize| (k * 3 / count).min(2);
+                for k in 0..count {
+                    let color = [MAGENTA, WHITE, YELLOW][group(k)];
+                    colors[(corners[0] + k) % count] = (color, k == 0 || group(k) != group(k - 1));
+                }
+            }
+            edges => {
+                for (e, corner) in corners.iter().enumerate() {
+                    let color = if e == edges - 1 && edges % 2 == 1 {
+                        CYAN
+                    } else if e % 2 == 0 {
+                        MAGENTA
+                    } else {
+                        YELLOW
+                    };
+                    let end = corners[(e + 1) % edges];
+                    let mut k = *corner;
+                    loop {
+                        colors[k] = (color, k == *corner);
+                        k = (k + 1) % count;
+                        if k == end {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        for i in 0..count {
+            let next = (i + 1) % count;
+            segments.push(Segment {
+                a: contour[i],
+                b: contour[next],
+                color: colors[i].0,
+                starts_edge: colors[i].1,
+                ends_edge: colors[next].1,
+            });
+        }
+    }
+
+    segments
+}
+
+/// Computes the signed distance of a point for a single channel.
+///
+/// Arguments:
+///
+/// * `segments`: The colored segments of the glyph.
+/// * `point`: The point in question.
+/// * `channel`: The index of the channel (RGB).
+///
+/// Returns:
+///
+/// The signed pseudo-distance to the closest edge of the channel,
+/// which is positive inside of the glyph.
+fn channel_distance(segments: &[Segment], point: Vec2, channel: usize) -> f32 {
+    // The closest segment, its distance and how orthogonal the point is to it.
+    let mut closest: Option<(&Segment, f32, f32)> = None;
+    for segment in segments.iter().filter(|s| s.color[channel]) {
+        let edge = segment.b - segment.a;
+        let length_squared = edge.length_squared();
+        if length_squared <= 0f32 {
+            continue;
+        }
+        let t = ((point - segment.a).dot(edge) / length_squared).clamp(0f32, 1f32);
+        let offset = point - (segment.a + edge * t);
+        let distance = offset.length();
+        let orthogonality = if distance > 0f32 {
+            (edge.normalize().perp_dot(offset) / distance).abs()
+        } else {
+            1f32
+        };
+
+        let closer = match closest {
+            None => true,
+            Some((_, d, o)) => {
+                distance < d - f32::EPSILON || (distance <= d + f32::EPSILON && orthogonality > o)
+            }
+        };
+        if closer {
+            closest = Some((segment, distance, orthogonality));
+        }
+    }
+
+    let Some((segment, distance, _)) = closest else {
+        return f32::NEG_INFINITY;
+    };
+    let edge = segment.b - segment.a;
+    let side = edge.perp_dot(point - segment.a);
+    let sign = if side >= 0f32 { 1f32 } else { -1f32 };
+
+    // The lines of the outermost segments of an edge are extended beyond the edge.
+    let t = (point - segment.a).dot(edge) / edge.length_squared();
+    if (t < 0f32 && segment.starts_edge) || (t > 1f32 && segment.ends_edge) {
+        let pseudo = side.abs() / edge.length();
+        if pseudo <= distance {
+            return sign * pseudo;
+        }
+    }
+
+    sign * distance
+}
+
+/// Computes the signed area of a closed contour.
+///
+/// Arguments:
+///
+/// * `contour`: The points of the contour.
+///
+/// Returns:
+///
+/// The area, which is positive for counter-clockwise contours.
+pub(crate) fn signed_area(contour: &[Vec2]) -> f32 {
+    let count = contour.len();
+    (0..count)
+        .map(|i| contour[i].perp_dot(contour[(i + 1) % count]))
+        .sum::<f32>()
+        * 0.5f32
+}
+
+/// Checks wether a point lies within a closed contour using the even-odd rule.
+///
+/// Arguments:
+///
+/// * `contour`: The points of the contour.
+/// * `point`: The point in question.
+///
+/// Returns:
+///
+/// `true` if the point is inside of the contour.
+pub(crate) fn contains(contour: &[Vec2], point: Vec2) -> bool {
+    let count = contour.len();
+    let mut inside = false;
+    for i in 0..count {
+        let (a, b) = (contour[i], contour[(i + 1) % count]);
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use glam::Vec3A;
+
+use crate::{util::triangulate_between_edges_indexed, BoundingBox, ExtrusionSettings};
+
+type IndexedMesh = (Vec<u32>, Vec<Vec3A>, BoundingBox);
+
+/// Converts the custom fallback mesh into a flat or three-dimensional mesh.
+///
+/// Flat meshes are extruded like the glyphs of the font, while only the
+/// front cap of three-dimensional meshes is kept for flat text.
+///
+/// Arguments:
+///
+/// * `mesh`: The fallback mesh.
+/// * `flat_mesh`: Wether the fallback mesh is flat.
+/// * `flat`: Wether a flat mesh is requested.
+/// * `extrusion`: The [ExtrusionSettings] that control the scale of the back cap.
+///
+/// Returns:
+///
+/// The requested [IndexedMesh].
+pub(crate) fn convert_fallback_mesh(
+    mesh: &IndexedMesh,
+    flat_mesh: bool,
+    flat: bool,
+    extrusion: &ExtrusionSettings,
+) -> IndexedMesh {
+    let (indices, vertices, bbox) = mesh;
+    let (vertices, indices) = match (flat_mesh, flat) {
+        (true, true) | (false, false) => return mesh.to_owned(),
+        (false, true) => front_cap(indices, vertices, bbox.max.z),
+        (true, false) => extrude(indices, vertices, bbox, extrusion),
+    };
+
+    let bbox = match vertices.is_empty() {
+        true => BoundingBox::empty(),
+        false => {
+            let (min, max) = vertices.iter().fold(
+                (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY)),
+                |(min, max), v| (min.min(*v), max.max(*v)),
+            );
+            BoundingBox::new(min, max)
+        }
+    };
+
+    (indices, vertices, bbox)
+}
+
+/// Keeps the triangles of a three-dimensional mesh that lie in its front plane.
+///
+/// Arguments:
+///
+/// * `indices`: The indices of the mesh.
+/// * `vertices`: The vertices of the mesh.
+/// * `front`: The z-coordinate of the front plane.
+///
+/// Returns:
+///
+/// The vertices and indices of the front cap moved into the XY-plane.
+fn front_cap(indices: &[u32], vertices: &[Vec3A], front: f32) -> (Vec<Vec3A>, Vec<u32>) {
+    let is_front = |i: &u32| (vertices[*i as usize].z - front).abs() <= f32::EPSILON * 4f32;
+
+    let mut remapped = HashMap::new();
+    let mut flat_vertices = Vec::new();
+    let mut flat_indices = Vec::new();
+    for triangle in indices.chunks_exact(3) {
+        if !triangle.iter().all(is_front) {
+            continue;
+        }
+        for i in triangle {
+            let index = *remapped.entry(*i).or_insert_with(|| {
+                let v = vertices[*i as usize];
+                flat_vertices.push(Vec3A::new(v.x, v.y, 0f32));
+                flat_vertices.len() as u32 - 1
+            });
+            flat_indices.push(index);
+        }
+    }
+
+    (flat_vertices, flat_indices)
+}
+
+/// Extrudes a flat mesh into a three-dimensional mesh of unit depth.
+///
+/// Side walls are added along the edges that only belong to a single triangle.
+///
+/// Arguments:
+///
+/// * `indices`: The indices of the flat mesh.
+/// * `vertices`: The vertices of the flat mesh.
+/// * `bbox`: The [BoundingBox] of the flat mesh.
+/// * `extrusion`: The [ExtrusionSettings] that control the scale of the back cap.
+///
+/// Returns:
+///
+/// The vertices and indices of the extruded mesh.
+fn extrude(
+    indices: &[u32],
+    vertices: &[Vec3A],
+    bbox: &BoundingBox,
+    extrusion: &ExtrusionSettings,
+) -> (Vec<Vec3A>, Vec<u32>) {
+    let center = bbox.center();
+    let scale = extrusion.back_cap_scale;
+
+    let mut extruded = Vec::with_capacity(vertices.len() * 2);
+    extruded.extend(vertices.iter().map(|v| Vec3A::new(v.x, v.y, 0.5f32)));
+    extruded.extend(vertices.iter().map(|v| {
+        Vec3A::new(
+            center.x + (v.x - center.x) * scale,
+            center.y + (v.y - center.y) * scale,
+            -0.5f32,
+        )
+    }));
+    let flat_count = vertices.len() as u32;
+
+    let mut edges: HashMap<(u32, u32), (usize, usize, usize)> = HashMap::new();
+    let mut extruded_indices = Vec::with_capacity(indices.len() * 4);
+    for triangle in indices.chunks_exact(3) {
+        extruded_indices.extend_from_slice(triangle);
+        extruded_indices.extend(triangle.iter().rev().map(|i| i + flat_count));
+
+        // Like the contours of the font, the side walls are oriented
+        // so that the filled area lies to the right of each edge.
+        let [p0, p1, p2] = [0, 1, 2].map(|i| vertices[triangle[i] as usize]);
+        let counter_clockwise = (p1 - p0).cross(p2 - p0).z > 0f32;
+        for (a, b) in [
+            (triangle[0], triangle[1]),
+            (triangle[1], triangle[2]),
+            (triangle[2], triangle[0]),
+        ] {
+            let (a, b) = if counter_clockwise { (b, a) } else { (a, b) };
+            let edge = edges
+                .entry((a.min(b), a.max(b)))
+                .or_insert((a as usize, b as usize, 0));
+            edge.2 += 1;
+        }
+    }
+
+    let mut boundary: Vec<(usize, usize)> = edges
+        .into_values()
+        .filter(|edge| edge.2 == 1)
+        .map(|edge| (edge.0, edge.1))
+        .collect();
+    boundary.sort_unstable();
+    triangulate_between_edges_indexed(&mut extruded, &mut extruded_indices, &boundary);
+
+    (extruded, extruded_indices)
+}
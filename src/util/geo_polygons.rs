@@ -0,0 +1,55 @@
+use geo::{Coord, LineString, Polygon};
+use glam::Vec2;
+
+use crate::util::{contains, oriented_contours, signed_area};
+
+/// Converts the closed contours of a glyph into polygons with holes.
+///
+/// Arguments:
+///
+/// * `contours`: The points of each contour without repeating the first point.
+///
+/// Returns:
+///
+/// The counter-clockwise outer contours, each with the clockwise holes
+/// that are directly nested in it.
+pub(crate) fn contours_to_polygons(contours: &[Vec<Vec2>]) -> Vec<Polygon<f32>> {
+    let oriented = oriented_contours(contours);
+    let (outer, holes): (Vec<&Vec<Vec2>>, Vec<&Vec<Vec2>>) =
+        oriented.iter().partition(|c| signed_area(c) > 0f32);
+
+    let mut interiors: Vec<Vec<LineString<f32>>> = vec![Vec::new(); outer.len()];
+    for hole in holes {
+        // A hole belongs to the smallest outer contour that surrounds it.
+        let parent = outer
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| contains(c, hole[0]))
+            .min_by(|(_, a), (_, b)| signed_area(a).total_cmp(&signed_area(b)))
+            .map(|(i, _)| i);
+        if let Some(parent) = parent {
+            interiors[parent].push(line_string(hole));
+        }
+    }
+
+    outer
+        .into_iter()
+        .zip(interiors)
+        .map(|(exterior, interiors)| Polygon::new(line_string(exterior), interiors))
+        .collect()
+}
+
+/// Converts a contour into a closed [LineString].
+///
+/// Arguments:
+///
+/// * `contour`: The points of the contour.
+///
+/// Returns:
+///
+/// The [LineString], which repeats its first point.
+fn line_string(contour: &[Vec2]) -> LineString<f32> {
+    let mut line: LineString<f32> = contour.iter().map(|p| Coord { x: p.x, y: p.y }).collect();
+    line.close();
+    line
+}
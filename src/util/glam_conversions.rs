@@ -1,4 +1,6 @@
-use glam::{Vec2, Vec3A};
+use glam::{Mat3, Mat4, Vec2, Vec3, Vec3A};
+
+use crate::{BoundingBox, GlamVertex, IndexType};
 
 /// Converts a [Vec] of [Vec3A]s to a [Vec] of [f32].
 ///
@@ -11,7 +13,7 @@ use glam::{Vec2, Vec3A};
 /// The concatenated components of all [Vec3A]s combined in a single
 /// [Vec].
 pub(crate) fn glam_vecs_to_raw(vecs: &[Vec3A]) -> Vec<f32> {
-    let mut raw_vecs = Vec::new();
+    let mut raw_vecs = Vec::with_capacity(vecs.len() * 3);
 
     for vec in vecs.iter() {
         raw_vecs.push(vec.x);
@@ -33,7 +35,7 @@ pub(crate) fn glam_vecs_to_raw(vecs: &[Vec3A]) -> Vec<f32> {
 /// The concatenated components of all [Vec2]s combined in a single
 /// [Vec].
 pub(crate) fn glam_vecs_to_raw_2d(vecs: &[Vec2]) -> Vec<f32> {
-    let mut raw_vecs = Vec::new();
+    let mut raw_vecs = Vec::with_capacity(vecs.len() * 2);
 
     for vec in vecs.iter() {
         raw_vecs.push(vec.x);
@@ -42,3 +44,258 @@ pub(crate) fn glam_vecs_to_raw_2d(vecs: &[Vec2]) -> Vec<f32> {
 
     raw_vecs
 }
+
+/// Converts a [Vec] of [f32] to a [Vec] of [Vec3A]s.
+///
+/// Arguments:
+///
+/// * `raw`: The concatenated components of the vectors.
+///
+/// Returns:
+///
+/// The list of [Vec3A]s. Incomplete trailing components are ignored.
+pub(crate) fn raw_to_glam_vecs(raw: &[f32]) -> Vec<Vec3A> {
+    raw.chunks_exact(3).map(Vec3A::from_slice).collect()
+}
+
+/// Transforms a slice of points by an affine transformation.
+///
+/// The columns of the matrix are converted only once instead of once per point,
+/// which is what [Mat4::transform_point3a] does, while the result is the same.
+///
+/// Arguments:
+///
+/// * `vertices`: The points that will be transformed in place.
+/// * `transform`: The 4x4 homogenous transformation matrix.
+pub(crate) fn transform_points(vertices: &mut [Vec3A], transform: &Mat4) {
+    let x_axis = Vec3A::from(transform.x_axis.truncate());
+    let y_axis = Vec3A::from(transform.y_axis.truncate());
+    let z_axis = Vec3A::from(transform.z_axis.truncate());
+    let w_axis = Vec3A::from(transform.w_axis.truncate());
+
+    for v in vertices.iter_mut() {
+        *v = x_axis * v.x + y_axis * v.y + z_axis * v.z + w_axis;
+    }
+}
+
+/// Transforms a slice of two-dimensional points by an affine transformation.
+///
+/// Arguments:
+///
+/// * `vertices`: The points that will be transformed in place.
+/// * `transform`: The 3x3 homogenous transformation matrix.
+pub(crate) fn transform_points_2d(vertices: &mut [Vec2], transform: &Mat3) {
+    let x_axis = transform.x_axis.truncate();
+    let y_axis = transform.y_axis.truncate();
+    let z_axis = transform.z_axis.truncate();
+
+    for v in vertices.iter_mut() {
+        *v = x_axis * v.x + y_axis * v.y + z_axis;
+    }
+}
+
+/// Snaps the vertices of a mesh to a regular grid.
+///
+/// Since rounding preserves the order of the coordinates, the
+/// [BoundingBox] is snapped along with the vertices.
+///
+/// Arguments:
+///
+/// * `vertices`: The vertices that will be snapped in place.
+/// * `bbox`: The [BoundingBox] of the vertices.
+/// * `step`: The spacing of the grid.
+pub(crate) fn quantize_points(vertices: &mut [Vec3A], bbox: &mut BoundingBox, step: f32) {
+    let snap = |v: Vec3A| (v / step).round() * step;
+    for v in vertices.iter_mut() {
+        *v = snap(*v);
+    }
+    bbox.min = snap(bbox.min);
+    bbox.max = snap(bbox.max);
+}
+
+/// Snaps the vertices of a two-dimensional mesh to a regular grid.
+///
+/// Arguments:
+///
+/// * `vertices`: The vertices that will be snapped in place.
+/// * `bbox`: The [BoundingBox] of the vertices.
+/// * `step`: The spacing of the grid.
+pub(crate) fn quantize_points_2d(vertices: &mut [Vec2], bbox: &mut BoundingBox, step: f32) {
+    for v in vertices.iter_mut() {
+        *v = (*v / step).round() * step;
+    }
+    bbox.min = (bbox.min / step).round() * step;
+    bbox.max = (bbox.max / step).round() * step;
+}
+
+/// Checks wether a triangle lost its area when its corners were snapped to a grid.
+///
+/// Since the corners lie on the grid, the area of a triangle is either zero
+/// or at least half a cell, which makes the check robust against rounding errors.
+///
+/// Arguments:
+///
+/// * `corners`: The snapped corners of the triangle.
+/// * `step`: The spacing of the grid.
+///
+/// Returns:
+///
+/// `true` if the triangle has no area.
+pub(crate) fn is_degenerate_triangle(corners: [Vec3; 3], step: f32) -> bool {
+    let [a, b, c] = corners;
+    (b - a).cross(c - a).length() < step * step * 0.5f32
+}
+
+/// Removes the triangles of a mesh that lost their area when
+/// the vertices were snapped to a grid.
+///
+/// Arguments:
+///
+/// * `vertices`: The snapped vertices, where each consecutive three vertices form a triangle.
+/// * `step`: The spacing of the grid.
+pub(crate) fn remove_degenerate_triangles<V: GlamVertex>(vertices: &mut Vec<V>, step: f32) {
+    let triangles = vertices.len() / 3;
+    let mut kept = 0;
+    for t in 0..triangles {
+        let corners = [0, 1, 2].map(|i| vertices[t * 3 + i]);
+        if !is_degenerate_triangle(corners.map(|c| c.to_vec3()), step) {
+            vertices[kept * 3..kept * 3 + 3].copy_from_slice(&corners);
+            kept += 1;
+        }
+    }
+    vertices.truncate(kept * 3);
+}
+
+/// Removes the triangles of an indexed mesh that lost their area when
+/// the vertices were snapped to a grid.
+///
+/// The vertices are kept, so that the indices of the remaining triangles stay valid.
+///
+/// Arguments:
+///
+/// * `indices`: The indices of the mesh.
+/// * `vertices`: The snapped vertices of the mesh.
+/// * `step`: The spacing of the grid.
+pub(crate) fn remove_degenerate_triangles_indexed<V: GlamVertex, I: IndexType>(
+    indices: &mut Vec<I>,
+    vertices: &[V],
+    step: f32,
+) {
+    let triangles = indices.len() / 3;
+    let mut kept = 0;
+    for t in 0..triangles {
+        let triangle = [0, 1, 2].map(|i| indices[t * 3 + i]);
+        let corners = triangle.map(|i| vertices[i.to_usize()].to_vec3());
+        if !is_degenerate_triangle(corners, step) {
+            indices[kept * 3..kept * 3 + 3].copy_from_slice(&triangle);
+            kept += 1;
+        }
+    }
+    indices.truncate(kept * 3);
+}
+
+/// Transforms the vertices of a mesh together with its [BoundingBox].
+///
+/// Arguments:
+///
+/// * `vertices`: The vertices that will be transformed in place.
+/// * `bbox`: The [BoundingBox] of the vertices.
+/// * `transform`: The 4x4 homogenous transformation matrix.
+///
+/// Returns:
+///
+/// The transformed [BoundingBox].
+pub(crate) fn transform_vertices(
+    vertices: &mut [Vec3A],
+    mut bbox: BoundingBox,
+    transform: &Mat4,
+) -> BoundingBox {
+    transform_points(vertices, transform);
+    bbox.transform(transform);
+
+    bbox
+}
+
+/// Transforms the flattened vertices of a generated mesh in place.
+///
+/// Two-dimensional vertices are placed in the XY plane before they are
+/// transformed and the z-coordinates of the transformed vertices are dropped.
+///
+/// Arguments:
+///
+/// * `vertices`: The concatenated components of the vertices.
+/// * `components`: The number of components of each vertex (`2` or `3`).
+/// * `transform`: The 4x4 homogenous transformation matrix.
+///
+/// Returns:
+///
+/// The [BoundingBox] of the transformed vertices.
+pub(crate) fn transform_raw_vertices(
+    vertices: &mut [f32],
+    components: usize,
+    transform: &Mat4,
+) -> BoundingBox {
+    let mut min = Vec3A::splat(f32::INFINITY);
+    let mut max = Vec3A::splat(f32::NEG_INFINITY);
+    for vertex in vertices.chunks_exact_mut(components) {
+        let z = if components == 3 { vertex[2] } else { 0f32 };
+        let mut point = transform.transform_point3a(Vec3A::new(vertex[0], vertex[1], z));
+        if components == 2 {
+            point.z = 0f32;
+        }
+        vertex.copy_from_slice(&point.to_array()[..components]);
+        min = min.min(point);
+        max = max.max(point);
+    }
+
+    if min.x > max.x {
+        return BoundingBox::empty();
+    }
+    BoundingBox::new(min, max)
+}
+
+/// Mirrors the flattened vertices of a generated mesh in place.
+///
+/// Arguments:
+///
+/// * `vertices`: The concatenated components of the vertices.
+/// * `components`: The number of components of each vertex (`2` or `3`).
+/// * `component`: The index of the component that will be negated.
+/// * `bbox`: The [BoundingBox] of the vertices.
+///
+/// Returns:
+///
+/// The [BoundingBox] of the mirrored vertices.
+pub(crate) fn mirror_raw_vertices(
+    vertices: &mut [f32],
+    components: usize,
+    component: usize,
+    mut bbox: BoundingBox,
+) -> BoundingBox {
+    if component >= components {
+        return bbox;
+    }
+    for vertex in vertices.chunks_exact_mut(components) {
+        vertex[component] = -vertex[component];
+    }
+
+    let (min, max) = (bbox.min[component], bbox.max[component]);
+    bbox.min[component] = -max;
+    bbox.max[component] = -min;
+
+    bbox
+}
+
+/// Reverses the winding order of the triangles of a non-indexed mesh in place.
+///
+/// Arguments:
+///
+/// * `vertices`: The concatenated components of the vertices, where each
+///   consecutive three vertices form a triangle.
+/// * `components`: The number of components of each vertex (`2` or `3`).
+pub(crate) fn reverse_winding_raw(vertices: &mut [f32], components: usize) {
+    for triangle in vertices.chunks_exact_mut(3 * components) {
+        let (second, third) = triangle[components..].split_at_mut(components);
+        second.swap_with_slice(third);
+    }
+}
@@ -0,0 +1,694 @@
+use std::{collections::HashMap, f32::consts::PI, ops::Range};
+
+use glam::{Mat3, Mat4, Vec2, Vec3, Vec3A, Vec4};
+
+use crate::{
+    BoundingBox, InterleavedMeshText, MeshPart, Seam, SideWallMapping, UvProjection,
+    VertexAttribute, VertexAttributeDescriptor, VertexLayout,
+};
+
+use super::{
+    classify_triangle, is_degenerate_triangle, lightmap_uvs, quantize_points, quantize_points_2d,
+    remove_degenerate_triangles_indexed, transform_points_2d, transform_vertices,
+};
+
+type IndexedMesh = (Vec<u32>, Vec<Vec3A>, BoundingBox);
+type IndexedMesh2D = (Vec<u32>, Vec<Vec2>, BoundingBox);
+
+/// The source vertex, the rounded face normal and the rounded side wall
+/// and lightmap texture coordinates of an interleaved vertex.
+type VertexKey = (u32, [i32; 3], Option<[i32; 4]>);
+
+/// Face normals are rounded to this many steps per unit before they are compared,
+/// so that coplanar triangles end up sharing their vertices.
+const NORMAL_PRECISION: f32 = 1e4;
+
+/// The key under which all faces sharing a smooth vertex are grouped.
+const SMOOTH_KEY: [i32; 3] = [i32::MAX; 3];
+
+/// Texture coordinates of the side walls and the lightmap are rounded to this many
+/// steps per unit before they are compared, so that adjacent faces share their vertices.
+const UV_PRECISION: f32 = 1e4;
+
+/// Generates an [InterleavedMeshText] from the internal data representation.
+///
+/// If the layout contains normals, they are computed per face after the transformation
+/// has been applied and vertices are split wherever adjacent faces do not share the same normal.
+/// Vertices on a [Seam::Smooth] boundary between a cap and the side walls are shared instead
+/// and their normal is the angle weighted average of all adjacent faces.
+///
+/// Arguments:
+///
+/// * `data`: The undeformed and untransformed internal data from the cache or freshly generated.
+/// * `glyphs`: The range of vertices of each glyph.
+/// * `advance`: The horizontal advance of the text.
+/// * `deform`: Deforms the vertices of the mesh and returns their [BoundingBox].
+/// * `transform`: The transformation that will be applied to each vertex.
+/// * `layout`: The [VertexLayout] of the generated vertices.
+/// * `step`: The spacing of the grid to which the transformed positions are snapped
+///   or `None` if the positions are not quantized.
+///
+/// Returns:
+///
+/// The corresponding [InterleavedMeshText].
+pub(crate) fn interleaved_mesh_from_data(
+    data: IndexedMesh,
+    glyphs: &[Range<usize>],
+    advance: f32,
+    deform: impl FnOnce(&mut [Vec3A], BoundingBox) -> BoundingBox,
+    transform: &Mat4,
+    layout: &VertexLayout,
+    step: Option<f32>,
+) -> InterleavedMeshText {
+    let (indices, mut positions, bbox) = data;
+
+    // The texture coordinates are computed in the untransformed space,
+    // so that they stick to the text regardless of the transformation.
+    let mut uvs = match layout.uv_projection {
+        UvProjection::Planar | UvProjection::Cylindrical | UvProjection::Spherical => positions
+            .iter()
+            .map(|p| planar_uv(Vec2::new(p.x, p.y), &bbox))
+            .collect(),
+        UvProjection::PerGlyph => per_glyph_uvs(&positions, glyphs),
+    };
+    let flat: Vec<Vec2> =
+        if layout.contains(VertexAttribute::Color) || layout.contains(VertexAttribute::Character) {
+            positions.iter().map(|p| Vec2::new(p.x, p.y)).collect()
+        } else {
+            Vec::new()
+        };
+    let colors = if layout.contains(VertexAttribute::Color) {
+        vertex_colors(&flat, glyphs, &bbox, layout)
+    } else {
+        Vec::new()
+    };
+    let characters = if layout.contains(VertexAttribute::Character) {
+        glyph_coordinates(&flat, glyphs)
+    } else {
+        Vec::new()
+    };
+    let smooth = smooth_vertices(&indices, &positions, layout);
+    let with_lightmap = layout.contains(VertexAttribute::Uv2);
+    let walls = if with_lightmap || layout.side_wall_mapping == SideWallMapping::Unwrapped {
+        unwrap_side_walls(&indices, &positions)
+    } else {
+        Vec::new()
+    };
+    let lightmap = if with_lightmap {
+        lightmap_uvs(
+            &indices,
+            &positions,
+            glyphs,
+            &walls,
+            layout.lightmap_padding,
+        )
+    } else {
+        Vec::new()
+    };
+
+    let bbox = deform(&mut positions, bbox);
+    if matches!(
+        layout.uv_projection,
+        UvProjection::Cylindrical | UvProjection::Spherical
+    ) {
+        let spherical = layout.uv_projection == UvProjection::Spherical;
+        uvs = positions
+            .iter()
+            .map(|p| wrapped_uv(*p, &bbox, spherical))
+            .collect();
+    }
+    let mut bbox = transform_vertices(&mut positions, bbox, transform);
+    if let Some(step) = step {
+        quantize_points(&mut positions, &mut bbox, step);
+    }
+
+    let (attributes, stride) = describe_attributes(layout, false);
+    let with_normals = layout.contains(VertexAttribute::Normal);
+
+    // Find the distinct vertices and accumulate their normals first.
+    let mut sources: Vec<(u32, Vec2, Vec2)> = Vec::new();
+    let mut normals: Vec<Vec3A> = Vec::new();
+    let mut smooth_normals = vec![Vec3A::ZERO; positions.len()];
+    let mut interleaved_indices = Vec::with_capacity(indices.len());
+    let mut lookup: HashMap<VertexKey, u32> = HashMap::new();
+    for (t, triangle) in indices.chunks_exact(3).enumerate() {
+        let corners = [
+            positions[triangle[0] as usize],
+            positions[triangle[1] as usize],
+            positions[triangle[2] as usize],
+        ];
+        if step.is_some_and(|step| is_degenerate_triangle(corners.map(Vec3::from), step)) {
+            continue;
+        }
+        let normal = if with_normals {
+            (corners[1] - corners[0])
+                .cross(corners[2] - corners[0])
+                .normalize_or_zero()
+        } else {
+            Vec3A::ZERO
+        };
+
+        // Unwrapped side walls and lightmap charts need their own vertices,
+        // even along smooth seams.
+        let wall = match layout.side_wall_mapping {
+            SideWallMapping::Projected => None,
+            SideWallMapping::Unwrapped => walls.get(t).copied().flatten().map(|w| w.1),
+        };
+        let chart = lightmap.get(t).copied();
+        for (corner, i) in triangle.iter().enumerate() {
+            let normal_key = if smooth[*i as usize] {
+                SMOOTH_KEY
+            } else {
+                (normal * NORMAL_PRECISION).round().as_ivec3().to_array()
+            };
+            let uv = wall.map_or(uvs[*i as usize], |w| w[corner]);
+            let uv2 = chart.map_or(Vec2::ZERO, |c| c[corner]);
+            let uv_key = (wall.is_some() || chart.is_some()).then(|| {
+                (Vec4::new(uv.x, uv.y, uv2.x, uv2.y) * UV_PRECISION)
+                    .round()
+                    .as_ivec4()
+                    .to_array()
+            });
+
+            let index = *lookup.entry((*i, normal_key, uv_key)).or_insert_with(|| {
+                sources.push((*i, uv, uv2));
+                normals.push(Vec3A::ZERO);
+                sources.len() as u32 - 1
+            });
+            let weighted = normal * corner_angle(&corners, corner);
+            normals[index as usize] += weighted;
+            if smooth[*i as usize] {
+                smooth_normals[*i as usize] += weighted;
+            }
+            interleaved_indices.push(index);
+        }
+    }
+
+    let mut vertices = Vec::with_capacity(sources.len() * stride as usize);
+    for ((i, uv, uv2), normal) in sources.iter().zip(normals.iter()) {
+        let normal = if smooth[*i as usize] {
+            &smooth_normals[*i as usize]
+        } else {
+            normal
+        };
+        for attribute in layout.attributes.iter() {
+            match attribute {
+                VertexAttribute::Position => {
+                    push_components(&mut vertices, &positions[*i as usize].to_array())
+                }
+                VertexAttribute::Normal => {
+                    push_components(&mut vertices, &normal.normalize_or_zero().to_array())
+                }
+                VertexAttribute::Uv => push_components(&mut vertices, &uv.to_array()),
+                VertexAttribute::Uv2 => push_components(&mut vertices, &uv2.to_array()),
+                VertexAttribute::Color => push_components(&mut vertices, &colors[*i as usize]),
+                VertexAttribute::Character => {
+                    push_components(&mut vertices, &characters[*i as usize])
+                }
+            }
+        }
+    }
+
+    InterleavedMeshText {
+        advance,
+        attributes,
+        bbox,
+        indices: interleaved_indices,
+        stride,
+        vertices,
+    }
+}
+
+/// Generates a two-dimensional [InterleavedMeshText] from the internal data representation.
+///
+/// Two-dimensional meshes never contain normals, even if they are part of the layout.
+///
+/// Arguments:
+///
+/// * `data`: The untransformed internal data from the cache or freshly generated.
+/// * `glyphs`: The range of vertices of each glyph.
+/// * `advance`: The horizontal advance of the text.
+/// * `transform`: The transformation that will be applied to each vertex.
+/// * `layout`: The [VertexLayout] of the generated vertices.
+/// * `step`: The spacing of the grid to which the transformed positions are snapped
+///   or `None` if the positions are not quantized.
+///
+/// Returns:
+///
+/// The corresponding [InterleavedMeshText].
+pub(crate) fn interleaved_mesh_from_data_2d(
+    data: IndexedMesh2D,
+    glyphs: &[Range<usize>],
+    advance: f32,
+    transform: &Mat3,
+    layout: &VertexLayout,
+    step: Option<f32>,
+) -> InterleavedMeshText {
+    let (mut indices, positions, bbox) = data;
+
+    // The texture coordinates are computed from the untransformed positions.
+    let mut placed = positions.clone();
+    transform_points_2d(&mut placed, transform);
+    let mut placed_bbox = bbox;
+    placed_bbox.transform_2d(transform);
+    if let Some(step) = step {
+        quantize_points_2d(&mut placed, &mut placed_bbox, step);
+        remove_degenerate_triangles_indexed(&mut indices, &placed, step);
+    }
+
+    let (attributes, stride) = describe_attributes(layout, true);
+    let colors = if layout.contains(VertexAttribute::Color) {
+        vertex_colors(&positions, glyphs, &bbox, layout)
+    } else {
+        Vec::new()
+    };
+    let characters = if layout.contains(VertexAttribute::Character) {
+        glyph_coordinates(&positions, glyphs)
+    } else {
+        Vec::new()
+    };
+
+    let mut vertices = Vec::with_capacity(positions.len() * stride as usize);
+    for (i, p) in positions.iter().enumerate() {
+        for attribute in layout.attributes.iter() {
+            match attribute {
+                VertexAttribute::Position => push_components(&mut vertices, &placed[i].to_array()),
+                VertexAttribute::Normal => (),
+                VertexAttribute::Uv | VertexAttribute::Uv2 => {
+                    push_components(&mut vertices, &planar_uv(*p, &bbox).to_array())
+                }
+                VertexAttribute::Color => push_components(&mut vertices, &colors[i]),
+                VertexAttribute::Character => push_components(&mut vertices, &characters[i]),
+            }
+        }
+    }
+
+    InterleavedMeshText {
+        advance,
+        attributes,
+        bbox: placed_bbox,
+        indices,
+        stride,
+        vertices,
+    }
+}
+
+/// Computes the color of each vertex according to [VertexLayout::coloring].
+///
+/// Arguments:
+///
+/// * `positions`: The untransformed vertices projected onto the XY-plane.
+/// * `glyphs`: The range of vertices of each glyph.
+/// * `bbox`: The [BoundingBox] of the untransformed vertices.
+/// * `layout`: The [VertexLayout] of the generated vertices.
+///
+/// Returns:
+///
+/// The color (RGBA) of each vertex.
+fn vertex_colors(
+    positions: &[Vec2],
+    glyphs: &[Range<usize>],
+    bbox: &BoundingBox,
+    layout: &VertexLayout,
+) -> Vec<[f32; 4]> {
+    let mut glyph_of = vec![0usize; positions.len()];
+    for (glyph, range) in glyphs.iter().enumerate() {
+        for g in glyph_of[range.clone()].iter_mut() {
+            *g = glyph;
+        }
+    }
+
+    let height = bbox.max.y - bbox.min.y;
+    positions
+        .iter()
+        .zip(glyph_of)
+        .map(|(p, glyph)| {
+            let relative = if height > 0f32 {
+                ((p.y - bbox.min.y) / height).clamp(0f32, 1f32)
+            } else {
+                0f32
+            };
+            layout.coloring.color_of(layout.color, relative, glyph)
+        })
+        .collect()
+}
+
+/// Computes the [VertexAttribute::Character] of each vertex.
+///
+/// Arguments:
+///
+/// * `positions`: The untransformed vertices projected onto the XY-plane.
+/// * `glyphs`: The range of vertices of each glyph.
+///
+/// Returns:
+///
+/// The index of the glyph and the relative position within
+/// the bounding box of the glyph of each vertex.
+fn glyph_coordinates(positions: &[Vec2], glyphs: &[Range<usize>]) -> Vec<[f32; 3]> {
+    let mut coordinates = vec![[0f32; 3]; positions.len()];
+
+    for (glyph, range) in glyphs.iter().enumerate() {
+        let (min, max) = positions[range.clone()].iter().fold(
+            (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+            |(min, max), p| (min.min(*p), max.max(*p)),
+        );
+        let size = max - min;
+        for (c, p) in coordinates[range.clone()]
+            .iter_mut()
+            .zip(positions[range.clone()].iter())
+        {
+            let relative = Vec2::select(size.cmpgt(Vec2::ZERO), (*p - min) / size, Vec2::ZERO);
+            *c = [glyph as f32, relative.x, relative.y];
+        }
+    }
+
+    coordinates
+}
+
+/// Computes the position of each attribute within an interleaved vertex.
+///
+/// Arguments:
+///
+/// * `layout`: The [VertexLayout] of the vertices.
+/// * `two_dimensional`: Wether the vertices belong to a two-dimensional mesh.
+///
+/// Returns:
+///
+/// The [VertexAttributeDescriptor]s and the stride of a single vertex in bytes.
+fn describe_attributes(
+    layout: &VertexLayout,
+    two_dimensional: bool,
+) -> (Vec<VertexAttributeDescriptor>, u64) {
+    let mut descriptors = Vec::new();
+    let mut offset = 0;
+
+    for attribute in layout.attributes.iter() {
+        if two_dimensional && *attribute == VertexAttribute::Normal {
+            continue;
+        }
+
+        let descriptor = VertexAttributeDescriptor {
+            attribute: *attribute,
+            components: attribute.components(two_dimensional),
+            offset,
+        };
+        offset += descriptor.size();
+        descriptors.push(descriptor);
+    }
+
+    (descriptors, offset)
+}
+
+/// Finds the vertices that lie on a [Seam::Smooth] boundary between a cap and the side walls.
+///
+/// Arguments:
+///
+/// * `indices`: The indices of the untransformed mesh.
+/// * `positions`: The vertices of the untransformed mesh.
+/// * `layout`: The [VertexLayout] of the generated vertices.
+///
+/// Returns:
+///
+/// Wether each vertex should be shared by all of its adjacent faces.
+fn smooth_vertices(indices: &[u32], positions: &[Vec3A], layout: &VertexLayout) -> Vec<bool> {
+    let mut smooth = vec![false; positions.len()];
+    if !layout.contains(VertexAttribute::Normal)
+        || (layout.front_seam == Seam::Hard && layout.back_seam == Seam::Hard)
+    {
+        return smooth;
+    }
+
+    let depth = positions
+        .iter()
+        .fold((f32::MAX, f32::MIN), |d, v| (d.0.min(v.z), d.1.max(v.z)));
+
+    // Only vertices of the side walls can be part of a boundary.
+    for triangle in indices.chunks_exact(3) {
+        let corners = [
+            positions[triangle[0] as usize],
+            positions[triangle[1] as usize],
+            positions[triangle[2] as usize],
+        ];
+        if classify_triangle(corners, depth) != MeshPart::SideWall {
+            continue;
+        }
+
+        let middle = (depth.0 + depth.1) * 0.5f32;
+        for i in triangle.iter() {
+            let z = positions[*i as usize].z;
+            if (z > middle && layout.front_seam == Seam::Smooth)
+                || (z < middle && layout.back_seam == Seam::Smooth)
+            {
+                smooth[*i as usize] = true;
+            }
+        }
+    }
+
+    smooth
+}
+
+/// Computes the interior angle of a triangle at one of its corners.
+///
+/// Arguments:
+///
+/// * `corners`: The corners of the triangle.
+/// * `corner`: The index of the corner in question.
+///
+/// Returns:
+///
+/// The angle in radians.
+fn corner_angle(corners: &[Vec3A; 3], corner: usize) -> f32 {
+    let origin = corners[corner];
+    let a = corners[(corner + 1) % 3] - origin;
+    let b = corners[(corner + 2) % 3] - origin;
+
+    if a.length_squared() == 0f32 || b.length_squared() == 0f32 {
+        return 0f32;
+    }
+    a.angle_between(b)
+}
+
+/// Projects a point onto the given [BoundingBox] to obtain its texture coordinates.
+///
+/// Arguments:
+///
+/// * `position`: The position of the vertex in the XY-plane.
+/// * `bbox`: The [BoundingBox] that spans the texture.
+///
+/// Returns:
+///
+/// The texture coordinates with the origin in the upper left corner.
+fn planar_uv(position: Vec2, bbox: &BoundingBox) -> Vec2 {
+    let size = bbox.size();
+
+    let u = if size.x > f32::EPSILON {
+        (position.x - bbox.min.x) / size.x
+    } else {
+        0f32
+    };
+    let v = if size.y > f32::EPSILON {
+        (bbox.max.y - position.y) / size.y
+    } else {
+        0f32
+    };
+
+    Vec2::new(u, v)
+}
+
+/// Unwraps the side walls of a three-dimensional mesh.
+///
+/// Each side wall quad consists of a triangle with two vertices on the front
+/// and a triangle with two vertices on the back. The front edges of the quads
+/// are chained into the contours of the glyphs, along which the distance is measured.
+///
+/// Arguments:
+///
+/// * `indices`: The indices of the undeformed and untransformed mesh.
+/// * `positions`: The vertices of the undeformed and untransformed mesh.
+///
+/// Returns:
+///
+/// The first vertex of the contour and the texture coordinates of the corners of each
+/// triangle, which are [None] for triangles that are not part of the side walls.
+fn unwrap_side_walls(indices: &[u32], positions: &[Vec3A]) -> Vec<Option<(u32, [Vec2; 3])>> {
+    let depth = positions
+        .iter()
+        .fold((f32::MAX, f32::MIN), |d, v| (d.0.min(v.z), d.1.max(v.z)));
+    if depth.0 >= depth.1 {
+        return Vec::new();
+    }
+    let middle = (depth.0 + depth.1) * 0.5f32;
+    let is_front = |i: u32| positions[i as usize].z > middle;
+
+    // The front edge of each quad points to the next vertex along the contour.
+    let mut next: HashMap<u32, u32> = HashMap::new();
+    for (triangle, corners) in indices
+        .chunks_exact(3)
+        .zip(triangles_of(indices, positions))
+    {
+        if classify_triangle(corners, depth) != MeshPart::SideWall {
+            continue;
+        }
+        let fronts: Vec<usize> = (0..3).filter(|c| is_front(triangle[*c])).collect();
+        if let [_, _] = fronts[..] {
+            let back = (0..3).find(|c| !fronts.contains(c)).unwrap_or(0);
+            next.insert(triangle[(back + 1) % 3], triangle[(back + 2) % 3]);
+        }
+    }
+
+    let length = |a: u32, b: u32| {
+        let (a, b) = (positions[a as usize], positions[b as usize]);
+        Vec2::new(b.x - a.x, b.y - a.y).length()
+    };
+
+    // The distance along the contour of each front vertex.
+    let mut starts: Vec<u32> = next.keys().copied().collect();
+    starts.sort_unstable();
+    let mut distances: HashMap<u32, (f32, u32)> = HashMap::new();
+    for start in starts {
+        let (mut current, mut distance) = (start, 0f32);
+        while !distances.contains_key(&current) {
+            distances.insert(current, (distance, start));
+            let Some(following) = next.get(&current) else {
+                break;
+            };
+            distance += length(current, *following);
+            current = *following;
+        }
+    }
+
+    let v = |i: u32| depth.1 - positions[i as usize].z;
+    indices
+        .chunks_exact(3)
+        .zip(triangles_of(indices, positions))
+        .map(|(triangle, corners)| {
+            if classify_triangle(corners, depth) != MeshPart::SideWall {
+                return None;
+            }
+
+            // Both triangles of a quad are measured from the first front vertex.
+            let fronts: Vec<usize> = (0..3).filter(|c| is_front(triangle[*c])).collect();
+            let (first, end) = match fronts[..] {
+                [_, _] => {
+                    let back = (0..3).find(|c| !fronts.contains(c))?;
+                    ((back + 1) % 3, [(back + 2) % 3, back])
+                }
+                [front] => (front, [(front + 1) % 3, (front + 1) % 3]),
+                _ => return None,
+            };
+            let front = triangle[first];
+            let (start, contour) = *distances.get(&front)?;
+            let end_distance = start + length(front, *next.get(&front)?);
+
+            let mut uvs = [Vec2::ZERO; 3];
+            for (corner, uv) in uvs.iter_mut().enumerate() {
+                let u = if end.contains(&corner) {
+                    end_distance
+                } else {
+                    start
+                };
+                *uv = Vec2::new(u, v(triangle[corner]));
+            }
+            Some((contour, uvs))
+        })
+        .collect()
+}
+
+/// Collects the corners of each triangle of a mesh.
+///
+/// Arguments:
+///
+/// * `indices`: The indices of the mesh.
+/// * `positions`: The vertices of the mesh.
+///
+/// Returns:
+///
+/// An [Iterator] over the corners of each triangle.
+fn triangles_of<'a>(
+    indices: &'a [u32],
+    positions: &'a [Vec3A],
+) -> impl Iterator<Item = [Vec3A; 3]> + 'a {
+    indices.chunks_exact(3).map(|t| {
+        [
+            positions[t[0] as usize],
+            positions[t[1] as usize],
+            positions[t[2] as usize],
+        ]
+    })
+}
+
+/// Projects the vertices of each glyph onto the bounding box of the glyph.
+///
+/// Arguments:
+///
+/// * `positions`: The untransformed vertices of the text.
+/// * `glyphs`: The range of vertices of each glyph.
+///
+/// Returns:
+///
+/// The texture coordinates of each vertex.
+fn per_glyph_uvs(positions: &[Vec3A], glyphs: &[Range<usize>]) -> Vec<Vec2> {
+    let mut uvs = vec![Vec2::ZERO; positions.len()];
+
+    for range in glyphs.iter() {
+        let glyph = &positions[range.clone()];
+        if glyph.is_empty() {
+            continue;
+        }
+
+        let (min, max) = glyph.iter().fold(
+            (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY)),
+            |(min, max), v| (min.min(*v), max.max(*v)),
+        );
+        let bbox = BoundingBox::new(min, max);
+        for (uv, p) in uvs[range.clone()].iter_mut().zip(glyph.iter()) {
+            *uv = planar_uv(Vec2::new(p.x, p.y), &bbox);
+        }
+    }
+
+    uvs
+}
+
+/// Wraps a texture around the center of the given [BoundingBox] to obtain
+/// the texture coordinates of a point.
+///
+/// Arguments:
+///
+/// * `position`: The position of the vertex.
+/// * `bbox`: The [BoundingBox] whose center the texture is wrapped around.
+/// * `spherical`: Wether the texture is wrapped around a sphere instead of
+///   around the vertical axis of a cylinder.
+///
+/// Returns:
+///
+/// The texture coordinates with the origin in the upper left corner.
+fn wrapped_uv(position: Vec3A, bbox: &BoundingBox, spherical: bool) -> Vec2 {
+    let offset = position - bbox.center();
+
+    // The front of the text faces the center of the texture.
+    let u = offset.x.atan2(offset.z) / (2f32 * PI) + 0.5f32;
+    let v = if spherical {
+        let length = offset.length();
+        if length > f32::EPSILON {
+            (offset.y / length).clamp(-1f32, 1f32).acos() / PI
+        } else {
+            0.5f32
+        }
+    } else {
+        planar_uv(Vec2::new(position.x, position.y), bbox).y
+    };
+
+    Vec2::new(u, v)
+}
+
+/// Appends the given components to a byte buffer.
+///
+/// Arguments:
+///
+/// * `buffer`: The buffer to which the components will be appended.
+/// * `components`: The components that will be written in native byte order.
+fn push_components(buffer: &mut Vec<u8>, components: &[f32]) {
+    for c in components.iter() {
+        buffer.extend_from_slice(&c.to_ne_bytes());
+    }
+}
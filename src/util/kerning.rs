@@ -0,0 +1,95 @@
+use ttf_parser::{
+    gpos::{PairAdjustment, PositioningSubtable},
+    opentype_layout::LayoutTable,
+    Face, GlyphId, Tag,
+};
+
+/// The feature of the `GPOS` table that contains the kerning.
+const KERNING_FEATURE: Tag = Tag::from_bytes(b"kern");
+
+/// Finds the kerning between two glyphs.
+///
+/// The pair adjustments of the `kern` feature of the `GPOS` table are used
+/// if the font has such a feature, otherwise the legacy `kern` table is used.
+///
+/// Arguments:
+///
+/// * `face`: The font.
+/// * `left`: The glyph on the left side.
+/// * `right`: The glyph on the right side.
+///
+/// Returns:
+///
+/// The adjustment of the advance of the left glyph in font units
+/// or `None` if the font has no kerning for this pair.
+pub(crate) fn find_kerning(face: &Face, left: GlyphId, right: GlyphId) -> Option<i16> {
+    if let Some(table) = face.tables().gpos {
+        if table.features.into_iter().any(|f| f.tag == KERNING_FEATURE) {
+            return gpos_kerning(&table, left, right);
+        }
+    }
+
+    let mut kerning = None;
+    for subtable in face.tables().kern?.subtables {
+        if !subtable.horizontal || subtable.variable || subtable.has_cross_stream {
+            continue;
+        }
+        if let Some(value) = subtable.glyphs_kerning(left, right) {
+            kerning = Some(kerning.unwrap_or(0i16).saturating_add(value));
+        }
+    }
+
+    kerning
+}
+
+/// Finds the kerning between two glyphs in the pair adjustments of the `GPOS` table.
+///
+/// Arguments:
+///
+/// * `table`: The `GPOS` table.
+/// * `left`: The glyph on the left side.
+/// * `right`: The glyph on the right side.
+///
+/// Returns:
+///
+/// The adjustment of the advance of the left glyph in font units
+/// or `None` if no pair adjustment matches.
+fn gpos_kerning(table: &LayoutTable, left: GlyphId, right: GlyphId) -> Option<i16> {
+    let mut lookups: Vec<u16> = table
+        .features
+        .into_iter()
+        .filter(|f| f.tag == KERNING_FEATURE)
+        .flat_map(|f| f.lookup_indices)
+        .collect();
+    lookups.sort_unstable();
+    lookups.dedup();
+
+    let mut kerning = None;
+    for lookup in lookups.iter().filter_map(|l| table.lookups.get(*l)) {
+        // Only the first matching subtable of each lookup is applied.
+        let adjustment = lookup
+            .subtables
+            .into_iter::<PositioningSubtable>()
+            .find_map(|subtable| match subtable {
+                PositioningSubtable::Pair(PairAdjustment::Format1 { coverage, sets }) => {
+                    let (first, _) = sets.get(coverage.get(left)?)?.get(right)?;
+                    Some(first.x_advance)
+                }
+                PositioningSubtable::Pair(PairAdjustment::Format2 {
+                    coverage,
+                    classes,
+                    matrix,
+                }) => {
+                    coverage.get(left)?;
+                    let (first, _) = matrix.get((classes.0.get(left), classes.1.get(right)))?;
+                    Some(first.x_advance)
+                }
+                _ => None,
+            });
+        if let Some(value) = adjustment {
+            kerning = Some(kerning.unwrap_or(0i16).saturating_add(value));
+        }
+    }
+
+    kerning
+}
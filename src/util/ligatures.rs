@@ -0,0 +1,100 @@
+use ttf_parser::{
+    gsub::SubstitutionSubtable, opentype_layout::LayoutTable, Face, GlyphId, LazyArray16, Tag,
+};
+
+/// The zero-width joiner, which combines the surrounding characters
+/// into a single glyph if the font supports it.
+pub(crate) const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// The features whose ligatures are applied to joined sequences.
+const LIGATURE_FEATURES: [Tag; 3] = [
+    Tag::from_bytes(b"ccmp"),
+    Tag::from_bytes(b"liga"),
+    Tag::from_bytes(b"rlig"),
+];
+
+/// Finds the longest ligature of the font that starts a sequence of glyphs.
+///
+/// Only the ligature substitutions of the `ccmp`, `liga` and `rlig`
+/// features of the `GSUB` table are considered. The features of all scripts
+/// and languages are used and the flags of the lookups are ignored, which
+/// is sufficient for the sequences of emoji that are joined explicitly.
+///
+/// Arguments:
+///
+/// * `face`: The font.
+/// * `glyphs`: The glyphs of the sequence and wether each glyph is a joiner,
+///   which may be skipped if the ligature does not list it as a component.
+///
+/// Returns:
+///
+/// The ligature and the number of glyphs of the sequence it replaces
+/// or `None` if no ligature matches.
+pub(crate) fn find_ligature(face: &Face, glyphs: &[(GlyphId, bool)]) -> Option<(GlyphId, usize)> {
+    let table: LayoutTable = face.tables().gsub?;
+    let (first, _) = *glyphs.first()?;
+
+    let mut lookups: Vec<u16> = table
+        .features
+        .into_iter()
+        .filter(|f| LIGATURE_FEATURES.contains(&f.tag))
+        .flat_map(|f| f.lookup_indices)
+        .collect();
+    lookups.sort_unstable();
+    lookups.dedup();
+
+    let mut longest: Option<(GlyphId, usize)> = None;
+    for lookup in lookups.iter().filter_map(|l| table.lookups.get(*l)) {
+        for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+            let SubstitutionSubtable::Ligature(ligatures) = subtable else {
+                continue;
+            };
+            let Some(set) = ligatures
+                .coverage
+                .get(first)
+                .and_then(|i| ligatures.ligature_sets.get(i))
+            else {
+                continue;
+            };
+
+            for ligature in set {
+                let Some(length) = match_components(glyphs, ligature.components) else {
+                    continue;
+                };
+                if !matches!(longest, Some((_, l)) if l >= length) {
+                    longest = Some((ligature.glyph, length));
+                }
+            }
+        }
+    }
+
+    longest
+}
+
+/// Matches the components of a ligature after the first glyph of a sequence.
+///
+/// Arguments:
+///
+/// * `glyphs`: The glyphs of the sequence and wether each glyph is a joiner.
+/// * `components`: The components of the ligature after the first one.
+///
+/// Returns:
+///
+/// The number of glyphs of the sequence covered by the ligature
+/// or `None` if the components do not match.
+fn match_components(glyphs: &[(GlyphId, bool)], components: LazyArray16<GlyphId>) -> Option<usize> {
+    let mut position = 1;
+    for component in components {
+        let (glyph, joiner) = *glyphs.get(position)?;
+        if glyph != component {
+            if !joiner || glyphs.get(position + 1)?.0 != component {
+                return None;
+            }
+            position += 1;
+        }
+        position += 1;
+    }
+
+    // A single glyph is no ligature.
+    (position > 1).then_some(position)
+}
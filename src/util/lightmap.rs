@@ -0,0 +1,146 @@
+use std::{collections::HashMap, ops::Range};
+
+use glam::{Vec2, Vec3A};
+
+use crate::MeshPart;
+
+use super::classify_triangle;
+
+/// The packing is repeated at most this many times while the padding,
+/// which depends on the size of the packed area, grows.
+const PACKING_ITERATIONS: usize = 8;
+
+/// A connected region of the mesh that is mapped into the lightmap as a whole.
+struct Chart {
+    max: Vec2,
+    min: Vec2,
+}
+
+/// Computes a second set of texture coordinates, in which no two triangles overlap.
+///
+/// The caps of each glyph and the side walls along each contour form separate charts,
+/// which are packed into the unit square while keeping their relative size.
+///
+/// Arguments:
+///
+/// * `indices`: The indices of the undeformed and untransformed mesh.
+/// * `positions`: The vertices of the undeformed and untransformed mesh.
+/// * `glyphs`: The range of vertices of each glyph.
+/// * `walls`: The first vertex of the contour and the unwrapped texture
+///   coordinates of each side wall triangle.
+/// * `padding`: The minimum distance between two charts relative to the size of the texture.
+///
+/// Returns:
+///
+/// The texture coordinates of the corners of each triangle with the origin in the upper left corner.
+pub(crate) fn lightmap_uvs(
+    indices: &[u32],
+    positions: &[Vec3A],
+    glyphs: &[Range<usize>],
+    walls: &[Option<(u32, [Vec2; 3])>],
+    padding: f32,
+) -> Vec<[Vec2; 3]> {
+    let depth = positions
+        .iter()
+        .fold((f32::MAX, f32::MIN), |d, v| (d.0.min(v.z), d.1.max(v.z)));
+
+    let mut glyph_of = vec![0usize; positions.len()];
+    for (glyph, range) in glyphs.iter().enumerate() {
+        for g in glyph_of[range.clone()].iter_mut() {
+            *g = glyph;
+        }
+    }
+
+    // Assign each triangle to a chart in the local coordinates of that chart.
+    let mut charts: Vec<Chart> = Vec::new();
+    let mut lookup: HashMap<(usize, MeshPart, u32), usize> = HashMap::new();
+    let mut triangles: Vec<(usize, [Vec2; 3])> = Vec::with_capacity(indices.len() / 3);
+    for (t, triangle) in indices.chunks_exact(3).enumerate() {
+        let corners = [
+            positions[triangle[0] as usize],
+            positions[triangle[1] as usize],
+            positions[triangle[2] as usize],
+        ];
+        let part = classify_triangle(corners, depth);
+        let wall = match part {
+            MeshPart::SideWall => walls.get(t).copied().flatten(),
+            _ => None,
+        };
+
+        let (contour, local) = match wall {
+            Some((contour, uvs)) => (contour, uvs),
+            None => (0, corners.map(|c| Vec2::new(c.x, -c.y))),
+        };
+        let key = (glyph_of[triangle[0] as usize], part, contour);
+        let chart = *lookup.entry(key).or_insert_with(|| {
+            charts.push(Chart {
+                max: Vec2::splat(f32::MIN),
+                min: Vec2::splat(f32::MAX),
+            });
+            charts.len() - 1
+        });
+        for l in local.iter() {
+            charts[chart].min = charts[chart].min.min(*l);
+            charts[chart].max = charts[chart].max.max(*l);
+        }
+        triangles.push((chart, local));
+    }
+
+    let sizes: Vec<Vec2> = charts.iter().map(|c| c.max - c.min).collect();
+    let (offsets, side) = pack_charts(&sizes, padding);
+
+    triangles
+        .iter()
+        .map(|(chart, local)| local.map(|l| (offsets[*chart] + l - charts[*chart].min) / side))
+        .collect()
+}
+
+/// Packs rectangles into a square area, so that they do not overlap.
+///
+/// The rectangles are placed on shelves in the order of decreasing height.
+/// Since the padding is relative to the size of the area, the packing is
+/// repeated until the area no longer grows.
+///
+/// Arguments:
+///
+/// * `sizes`: The size of each rectangle.
+/// * `padding`: The minimum distance between two rectangles relative to the size of the area.
+///
+/// Returns:
+///
+/// The offset of each rectangle and the size of the square area.
+fn pack_charts(sizes: &[Vec2], padding: f32) -> (Vec<Vec2>, f32) {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by(|a, b| sizes[*b].y.total_cmp(&sizes[*a].y));
+
+    let area: f32 = sizes.iter().map(|s| s.x * s.y).sum();
+    let mut side = area.sqrt().max(f32::EPSILON);
+    let mut offsets = vec![Vec2::ZERO; sizes.len()];
+    for _ in 0..PACKING_ITERATIONS {
+        let gap = padding * side;
+        let padded: f32 = sizes.iter().map(|s| (s.x + gap) * (s.y + gap)).sum();
+        let widest = sizes.iter().fold(0f32, |w, s| w.max(s.x));
+        let width = padded.sqrt().max(widest + 2f32 * gap);
+
+        let mut cursor = Vec2::splat(gap);
+        let mut shelf = 0f32;
+        for i in order.iter() {
+            if cursor.x + sizes[*i].x + gap > width && cursor.x > gap {
+                cursor = Vec2::new(gap, cursor.y + shelf + gap);
+                shelf = 0f32;
+            }
+            offsets[*i] = cursor;
+            cursor.x += sizes[*i].x + gap;
+            shelf = shelf.max(sizes[*i].y);
+        }
+        let height = cursor.y + shelf + gap;
+
+        let packed = width.max(height);
+        if packed <= side {
+            break;
+        }
+        side = packed;
+    }
+
+    (offsets, side)
+}
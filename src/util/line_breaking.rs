@@ -0,0 +1,253 @@
+use std::ops::Range;
+
+#[cfg(feature = "linebreak")]
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+
+use crate::WhitespaceMode;
+
+/// The soft hyphen, which marks where a word may be hyphenated.
+pub(crate) const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// Finds the positions at which a line of text may be broken.
+///
+/// The break opportunities are determined by the Unicode line breaking
+/// algorithm ([UAX #14](https://www.unicode.org/reports/tr14/)).
+///
+/// Arguments:
+///
+/// * `text`: The text of a single line.
+///
+/// Returns:
+///
+/// The indices of the characters in front of which the line may be broken
+/// together with wether the line must be broken there. The end of the text is
+/// always included as a mandatory break.
+#[cfg(feature = "linebreak")]
+pub(crate) fn break_opportunities(text: &str) -> Vec<(usize, bool)> {
+    let mut characters = text.char_indices().map(|(i, _)| i).enumerate().peekable();
+
+    linebreaks(text)
+        .map(|(byte, opportunity)| {
+            let mut index = 0;
+            while let Some((i, _)) = characters.next_if(|(_, b)| *b < byte) {
+                index = i + 1;
+            }
+
+            (index, opportunity == BreakOpportunity::Mandatory)
+        })
+        .collect()
+}
+
+/// Finds the positions at which a line of text may be broken.
+///
+/// A line may be broken after each run of whitespace except non-breaking
+/// spaces and after hyphens. Line and paragraph separators force a break.
+///
+/// Arguments:
+///
+/// * `text`: The text of a single line.
+///
+/// Returns:
+///
+/// The indices of the characters in front of which the line may be broken
+/// together with wether the line must be broken there. The end of the text is
+/// always included as a mandatory break.
+#[cfg(not(feature = "linebreak"))]
+pub(crate) fn break_opportunities(text: &str) -> Vec<(usize, bool)> {
+    let characters: Vec<char> = text.chars().collect();
+
+    let mut opportunities: Vec<(usize, bool)> = (1..characters.len())
+        .filter_map(|i| {
+            let (previous, current) = (characters[i - 1], characters[i]);
+            match previous {
+                '\n' | '\u{2028}' | '\u{2029}' => Some((i, true)),
+                '\r' if current != '\n' => Some((i, true)),
+                '-' | SOFT_HYPHEN if !current.is_whitespace() => Some((i, false)),
+                _ if is_collapsible_whitespace(previous) && !is_collapsible_whitespace(current) => {
+                    Some((i, false))
+                }
+                _ => None,
+            }
+        })
+        .collect();
+    opportunities.push((characters.len(), true));
+
+    opportunities
+}
+
+/// Wraps a single line of text so that it does not exceed a certain width.
+///
+/// Words that do not fit are split at their hyphenation points if possible,
+/// otherwise words that are wider than the maximum width on their own are
+/// not broken. Lines that are broken at a soft hyphen end with a hyphen.
+/// Mandatory breaks (e.g. a line separator) always start a new line.
+///
+/// Arguments:
+///
+/// * `text`: The text of a single line without line feeds.
+/// * `positions`: The horizontal position in front of each character followed
+///   by the position after the last character.
+/// * `max_width`: The maximum advance of a line.
+/// * `hyphenation_points`: Finds the indices of the characters of a word in front
+///   of which a hyphen may be inserted.
+/// * `hyphen_advance`: The advance of the hyphen that is inserted.
+///
+/// Returns:
+///
+/// The ranges of the characters of each line together with wether a
+/// hyphen has to be inserted at the end of the line.
+pub(crate) fn wrap_line(
+    text: &str,
+    positions: &[f32],
+    max_width: Option<f32>,
+    hyphenation_points: impl Fn(&str) -> Vec<usize>,
+    hyphen_advance: f32,
+) -> Vec<(Range<usize>, bool)> {
+    let count = positions.len() - 1;
+    let max_width = max_width.unwrap_or(f32::INFINITY);
+
+    let characters: Vec<char> = text.chars().collect();
+    let trimmed_end = |range: &Range<usize>| {
+        // Trailing whitespace does not count towards the width of a line.
+        let mut end = range.end;
+        while end > range.start && is_collapsible_whitespace(characters[end - 1]) {
+            end -= 1;
+        }
+        end
+    };
+    let width = |range: &Range<usize>| positions[trimmed_end(range)] - positions[range.start];
+    let ends_with_soft_hyphen = |end: usize| end > 0 && characters[end - 1] == SOFT_HYPHEN;
+
+    // A line that is broken at a soft hyphen also has to fit the inserted hyphen.
+    let line_width = |range: &Range<usize>| match ends_with_soft_hyphen(range.end) {
+        true => width(range) + hyphen_advance,
+        false => width(range),
+    };
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut last_break = None;
+    for (end, mandatory) in break_opportunities(text) {
+        while line_width(&(start..end)) > max_width {
+            // Try to fit a part of the overflowing word into the current line.
+            let word_start = last_break.unwrap_or(start);
+            let word: String = characters[word_start..trimmed_end(&(word_start..end))]
+                .iter()
+                .collect();
+            let split = hyphenation_points(&word)
+                .into_iter()
+                .map(|i| word_start + i)
+                .filter(|i| *i > start)
+                .rev()
+                .find(|i| width(&(start..*i)) + hyphen_advance <= max_width);
+
+            if let Some(split) = split {
+                lines.push((start..split, true));
+                start = split;
+            } else if let Some(previous) = last_break {
+                lines.push((start..previous, ends_with_soft_hyphen(previous)));
+                start = previous;
+            } else {
+                break;
+            }
+            last_break = None;
+        }
+
+        if mandatory {
+            lines.push((start..end, false));
+            start = end;
+            last_break = None;
+        } else {
+            last_break = Some(end);
+        }
+    }
+    if lines.is_empty() {
+        lines.push((0..count, false));
+    }
+
+    lines
+}
+
+/// Finds the hyphenation points of a word using a hyphenation dictionary.
+///
+/// Arguments:
+///
+/// * `dictionary`: The dictionary of the language of the word.
+/// * `word`: The word that should be hyphenated.
+///
+/// Returns:
+///
+/// The indices of the characters of the word in front of which a hyphen may be inserted.
+#[cfg(feature = "hyphenation")]
+pub(crate) fn dictionary_hyphenation_points(
+    dictionary: &hyphenation::Standard,
+    word: &str,
+) -> Vec<usize> {
+    use hyphenation::Hyphenator;
+
+    dictionary
+        .hyphenate(word)
+        .breaks
+        .into_iter()
+        .map(|byte| word[..byte].chars().count())
+        .collect()
+}
+
+/// Checks wether a character is whitespace that may be collapsed or trimmed.
+///
+/// Arguments:
+///
+/// * `c`: The character in question.
+///
+/// Returns:
+///
+/// `true` if the character is whitespace, but not a non-breaking space.
+pub(crate) fn is_collapsible_whitespace(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, '\u{00A0}' | '\u{2007}' | '\u{202F}')
+}
+
+/// Removes the whitespace of a single line of text according to a [WhitespaceMode].
+///
+/// The whitespace at the end of the line is kept, because it is trimmed
+/// after the line has been wrapped.
+///
+/// Arguments:
+///
+/// * `text`: The text of a single line without line feeds.
+/// * `mode`: The [WhitespaceMode] of the text.
+///
+/// Returns:
+///
+/// The remaining text together with the index of the original character of each
+/// remaining character, followed by the number of original characters.
+pub(crate) fn apply_whitespace_mode(text: &str, mode: WhitespaceMode) -> (String, Vec<usize>) {
+    let mut result = String::with_capacity(text.len());
+    let mut indices = Vec::with_capacity(text.len() + 1);
+    let mut previous: Option<char> = None;
+
+    for (i, c) in text.chars().enumerate() {
+        let whitespace = is_collapsible_whitespace(c);
+        let skip = match mode {
+            WhitespaceMode::Preserve => false,
+            WhitespaceMode::Trim => whitespace && previous.is_none(),
+            WhitespaceMode::Collapse => {
+                whitespace && previous.is_none_or(is_collapsible_whitespace)
+            }
+        };
+        if skip {
+            continue;
+        }
+
+        let c = if whitespace && mode == WhitespaceMode::Collapse {
+            ' '
+        } else {
+            c
+        };
+        result.push(c);
+        indices.push(i);
+        previous = Some(c);
+    }
+    indices.push(text.chars().count());
+
+    (result, indices)
+}
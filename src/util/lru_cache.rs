@@ -0,0 +1,168 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// A map that keeps track of the order in which its entries were used,
+/// so that the least recently used entries can be evicted.
+///
+/// Looking up, inserting and evicting an entry takes logarithmic time.
+#[derive(Debug)]
+pub(crate) struct LruCache<K, V> {
+    /// The entries of the cache.
+    entries: HashMap<K, LruEntry<V>>,
+
+    /// The keys of the entries ordered by their last use.
+    order: BTreeMap<u64, K>,
+
+    /// The counter that orders the uses of the entries.
+    tick: u64,
+}
+
+/// An entry of a [LruCache].
+#[derive(Debug)]
+struct LruEntry<V> {
+    /// The cached value.
+    value: V,
+
+    /// The position of the last use of the entry in the order of the cache.
+    tick: u64,
+
+    /// The time of the last use of the entry, which is only
+    /// recorded if entries of the cache expire.
+    used: Option<Instant>,
+}
+
+impl<K, V> Default for LruCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> LruCache<K, V> {
+    /// Creates a new empty [LruCache].
+    ///
+    /// Returns:
+    ///
+    /// The new [LruCache].
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the value of an entry without marking it as used.
+    pub(crate) fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Gets an iterator over the entries of the cache in arbitrary order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, entry)| (key, &entry.value))
+    }
+
+    /// Gets the value of an entry and marks it as the most recently used one.
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The key of the entry.
+    /// * `now`: The current time if entries expire, otherwise [None].
+    ///
+    /// Returns:
+    ///
+    /// The value of the entry or [None] if the cache does not contain the key.
+    pub(crate) fn get(&mut self, key: &K, now: Option<Instant>) -> Option<&V> {
+        let entry = self.entries.get_mut(key)?;
+        if let Some(key) = self.order.remove(&entry.tick) {
+            self.tick += 1;
+            entry.tick = self.tick;
+            self.order.insert(self.tick, key);
+        }
+        entry.used = now.or(entry.used);
+
+        Some(&entry.value)
+    }
+
+    /// Inserts an entry as the most recently used one, replacing
+    /// any previous entry with the same key.
+    ///
+    /// Arguments:
+    ///
+    /// * `key`: The key of the entry.
+    /// * `value`: The value of the entry.
+    /// * `now`: The current time if entries expire, otherwise [None].
+    pub(crate) fn insert(&mut self, key: K, value: V, now: Option<Instant>) {
+        self.tick += 1;
+        let entry = LruEntry {
+            value,
+            tick: self.tick,
+            used: now,
+        };
+        if let Some(previous) = self.entries.insert(key.clone(), entry) {
+            self.order.remove(&previous.tick);
+        }
+        self.order.insert(self.tick, key);
+    }
+
+    /// Keeps only the entries for which the predicate returns `true`.
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(&K, &V) -> bool) {
+        let order = &mut self.order;
+        self.entries.retain(|key, entry| {
+            let kept = keep(key, &entry.value);
+            if !kept {
+                order.remove(&entry.tick);
+            }
+            kept
+        });
+    }
+
+    /// Removes all entries from the cache.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// Evicts the least recently used entries until the cache
+    /// holds at most `capacity` entries.
+    pub(crate) fn trim(&mut self, capacity: usize) {
+        while self.entries.len() > capacity {
+            let Some((_, key)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&key);
+        }
+    }
+
+    /// Evicts the entries that have not been used within the given duration.
+    ///
+    /// Entries whose time of use has not been recorded do not expire.
+    ///
+    /// Arguments:
+    ///
+    /// * `now`: The current time.
+    /// * `ttl`: The duration after which unused entries expire.
+    pub(crate) fn expire(&mut self, now: Instant, ttl: Duration) {
+        while let Some((_, key)) = self.order.first_key_value() {
+            let expired = self.entries[key]
+                .used
+                .is_some_and(|used| now.duration_since(used) >= ttl);
+            if !expired {
+                break;
+            }
+            if let Some((_, key)) = self.order.pop_first() {
+                self.entries.remove(&key);
+            }
+        }
+    }
+
+    /// Records the given time as the last use of every entry
+    /// without changing the order of the entries.
+    pub(crate) fn mark_all_used(&mut self, now: Instant) {
+        for entry in self.entries.values_mut() {
+            entry.used = Some(now);
+        }
+    }
+}
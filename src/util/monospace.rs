@@ -0,0 +1,52 @@
+use ttf_parser::{Face, Tag};
+
+/// The offset of the average advance within the `OS/2` table.
+const AVG_CHAR_WIDTH_OFFSET: usize = 2;
+
+/// The offset of the PANOSE classification within the `OS/2` table.
+const PANOSE_OFFSET: usize = 32;
+
+/// Checks wether all glyphs of a font have the same advance.
+///
+/// Arguments:
+///
+/// * `face`: The font.
+///
+/// Returns:
+///
+/// `true` if the `post` table marks the font as fixed pitch or the
+/// PANOSE classification of the `OS/2` table marks it as monospaced.
+pub(crate) fn is_monospaced_face(face: &Face) -> bool {
+    // The PANOSE proportion of latin text faces is `9` if they are monospaced.
+    let panose = face
+        .raw_face()
+        .table(Tag::from_bytes(b"OS/2"))
+        .and_then(|os2| os2.get(PANOSE_OFFSET..PANOSE_OFFSET + 4));
+
+    face.is_monospaced() || matches!(panose, Some([2, _, _, 9]))
+}
+
+/// Reads the average horizontal advance of all glyphs of a font.
+///
+/// This is the `xAvgCharWidth` of the `OS/2` table, which is
+/// not exposed by [ttf_parser::os2::Table].
+///
+/// Arguments:
+///
+/// * `face`: The font.
+///
+/// Returns:
+///
+/// The average advance or `None` if the font does not specify it.
+pub(crate) fn average_advance_of_face(face: &Face) -> Option<u16> {
+    // Only read the field if the table is valid.
+    face.tables().os2?;
+    let width = face
+        .raw_face()
+        .table(Tag::from_bytes(b"OS/2"))
+        .and_then(|os2| os2.get(AVG_CHAR_WIDTH_OFFSET..AVG_CHAR_WIDTH_OFFSET + 2))?;
+
+    u16::try_from(i16::from_be_bytes([width[0], width[1]]))
+        .ok()
+        .filter(|width| *width > 0)
+}
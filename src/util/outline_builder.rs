@@ -4,12 +4,19 @@ use crate::{GlyphOutline, QualitySettings};
 
 type Point = (f32, f32);
 
+#[derive(Default)]
 pub(crate) struct GlyphOutlineBuilder {
-    contours: Vec<Vec<u32>>,
+    close_contours: bool,
     current_point: (f32, f32),
+    discarded_contours: usize,
     font_height: f32,
     index: u32,
-    points: Vec<Point>,
+    max_segment_length: u32,
+    min_contour_area: u32,
+    non_finite: bool,
+    non_finite_contours: usize,
+    open: bool,
+    outline: GlyphOutline,
     quality: QualitySettings,
     start_index: u32,
 }
@@ -17,28 +24,166 @@ pub(crate) struct GlyphOutlineBuilder {
 impl GlyphOutlineBuilder {
     pub(crate) fn new(font_height: f32, quality: QualitySettings) -> Self {
         Self {
-            contours: Vec::new(),
+            close_contours: true,
             current_point: (0f32, 0f32),
+            discarded_contours: 0,
             font_height,
             index: 0,
-            points: Vec::new(),
+            max_segment_length: 0,
+            min_contour_area: 0,
+            non_finite: false,
+            non_finite_contours: 0,
+            open: false,
+            outline: GlyphOutline::default(),
             quality,
             start_index: 0,
         }
     }
 
-    pub(crate) fn get_glyph_outline(&mut self) -> GlyphOutline {
-        GlyphOutline {
-            contours: self.contours.clone(),
-            points: self.points.clone(),
+    /// Prepares this builder for the next glyph, while keeping the
+    /// allocated buffers of the previous glyph.
+    ///
+    /// Arguments:
+    ///
+    /// * `font_height`: The font metric by which the coordinates are normalized.
+    /// * `quality`: The [QualitySettings] used for interpolating curves.
+    pub(crate) fn reset(&mut self, font_height: f32, quality: QualitySettings) {
+        self.current_point = (0f32, 0f32);
+        self.discarded_contours = 0;
+        self.font_height = font_height;
+        self.index = 0;
+        self.non_finite = false;
+        self.non_finite_contours = 0;
+        self.open = false;
+        self.outline.contours.clear();
+        self.outline.points.clear();
+        self.quality = quality;
+        self.start_index = 0;
+    }
+
+    /// Controls wether contours that are not closed by the font are closed
+    /// by connecting their last point back to the first one.
+    ///
+    /// Otherwise the last point of such a contour is dropped when it is closed
+    /// and contours that are never closed remain open.
+    ///
+    /// Arguments:
+    ///
+    /// * `close_contours`: Wether open contours are closed.
+    pub(crate) fn set_close_contours(&mut self, close_contours: bool) {
+        self.close_contours = close_contours;
+    }
+
+    /// Sets the minimum area of a contour in square font units.
+    ///
+    /// Smaller contours are discarded once they are closed.
+    ///
+    /// Arguments:
+    ///
+    /// * `area`: The minimum area or `0` to keep all contours.
+    pub(crate) fn set_min_contour_area(&mut self, area: u32) {
+        self.min_contour_area = area;
+    }
+
+    /// Sets the maximum length of a contour segment in font units.
+    ///
+    /// Longer segments, including interpolated curve segments and the segment
+    /// that closes the contour, are split evenly once the contour is closed.
+    ///
+    /// Arguments:
+    ///
+    /// * `length`: The maximum length or `0` to keep all segments.
+    pub(crate) fn set_max_segment_length(&mut self, length: u32) {
+        self.max_segment_length = length;
+    }
+
+    /// Closes the last contour if the font did not close it.
+    pub(crate) fn close_open_contour(&mut self) {
+        if self.open && self.close_contours {
+            self.close();
         }
     }
 
+    /// Gets the outline of the glyph.
+    ///
+    /// Contours with fewer than three distinct points are not part
+    /// of the outline, since they are discarded once they are closed.
+    ///
+    /// Returns:
+    ///
+    /// The [GlyphOutline] of the glyph.
+    pub(crate) fn get_glyph_outline(&self) -> &GlyphOutline {
+        &self.outline
+    }
+
+    /// Gets the number of degenerate contours that were discarded.
+    ///
+    /// Returns:
+    ///
+    /// The number of contours with fewer than three distinct points.
+    pub(crate) fn discarded_contours(&self) -> usize {
+        self.discarded_contours
+    }
+
+    /// Gets the number of contours that were discarded because of non-finite points.
+    ///
+    /// Returns:
+    ///
+    /// The number of contours with a coordinate that is `NaN` or infinite.
+    pub(crate) fn non_finite_contours(&self) -> usize {
+        self.non_finite_contours
+    }
+
+    /// Removes the current contour together with its points.
+    fn discard_contour(&mut self) {
+        self.outline.contours.pop();
+        self.outline.points.truncate(self.start_index as usize);
+        self.index = self.start_index;
+    }
+
+    /// Splits the segments of the closed current contour that are longer
+    /// than the maximum segment length evenly, so that the side walls
+    /// are evenly tessellated.
+    fn resample_contour(&mut self) {
+        let start = self.start_index as usize;
+        let max_length = self.max_segment_length as f32 / self.font_height;
+        let points: Vec<Point> = self.outline.points.drain(start..).collect();
+
+        let count = points.len();
+        for (i, a) in points.iter().enumerate() {
+            let b = &points[(i + 1) % count];
+            self.outline.points.push(*a);
+            let length = ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+            let segments = (length / max_length).ceil() as u32;
+            for step in 1..segments {
+                let t = step as f32 / segments as f32;
+                self.outline.points.push(point_on_line(a, b, t));
+            }
+        }
+
+        self.index = self.outline.points.len() as u32;
+        let contour = self
+            .outline
+            .contours
+            .last_mut()
+            .expect("Contour has no start point.");
+        contour.clear();
+        contour.extend(self.start_index..self.index);
+        contour.push(self.start_index);
+    }
+
     fn add_point(&mut self, point: (f32, f32)) {
+        // Broken fonts may produce non-finite coordinates, which would poison the
+        // triangulation and the bounding box. The point is kept, so that the indices
+        // stay consistent, but its contour is discarded once it is closed.
+        if !point.0.is_finite() || !point.1.is_finite() {
+            self.non_finite = true;
+        }
         self.current_point = point;
 
         // Normalize the coordinates of each glyph into the range `0..=1`.
-        self.points
+        self.outline
+            .points
             .push((point.0 / self.font_height, point.1 / self.font_height));
         self.index += 1;
     }
@@ -46,13 +191,16 @@ impl GlyphOutlineBuilder {
 
 impl OutlineBuilder for GlyphOutlineBuilder {
     fn move_to(&mut self, x: f32, y: f32) {
+        self.close_open_contour();
+        self.non_finite = false;
+        self.open = true;
         self.start_index = self.index;
-        self.contours.push(vec![self.start_index]);
+        self.outline.contours.push(vec![self.start_index]);
         self.add_point((x, y));
     }
 
     fn line_to(&mut self, x: f32, y: f32) {
-        self.contours.last_mut().unwrap().push(self.index);
+        self.outline.contours.last_mut().unwrap().push(self.index);
         self.add_point((x, y));
     }
 
@@ -84,20 +232,88 @@ impl OutlineBuilder for GlyphOutlineBuilder {
     }
 
     fn close(&mut self) {
-        // The last point is a duplicate so we remove it.
-        // At this point there should be at least two points in the contour.
+        self.open = false;
+
+        // The last point is usually a duplicate of the first one, so we remove it.
+        // Fonts that omit the closing segment (e.g. most CFF fonts) keep their last
+        // point, since the contour is closed by connecting it back to the first one.
+        // Interpolated curves may end slightly off the first point, while distinct
+        // points of a font are at least one font unit apart.
+        let start = self.start_index as usize;
+        let duplicate = match (self.outline.points.get(start), self.outline.points.last()) {
+            (Some(first), Some(last)) if self.outline.points.len() > start + 1 => {
+                (first.0 - last.0).abs() * self.font_height < 0.01
+                    && (first.1 - last.1).abs() * self.font_height < 0.01
+            }
+            _ => false,
+        };
         let current_contour = self
+            .outline
             .contours
             .last_mut()
             .expect("Contour has no start point.");
 
-        current_contour.pop();
+        if duplicate || !self.close_contours {
+            current_contour.pop();
+            self.outline.points.pop();
+            self.index -= 1;
+        }
         current_contour.push(self.start_index);
-        self.points.pop();
-        self.index -= 1;
+
+        if self.non_finite {
+            self.discard_contour();
+            self.non_finite = false;
+            self.non_finite_contours += 1;
+            return;
+        }
+
+        // Contours without an area (present in some broken fonts) can not be triangulated.
+        let points = &self.outline.points[self.start_index as usize..];
+        let mut distinct: Vec<&Point> = Vec::with_capacity(3);
+        for point in points.iter() {
+            if distinct.len() < 3 && !distinct.contains(&point) {
+                distinct.push(point);
+            }
+        }
+        if distinct.len() < 3 {
+            self.discard_contour();
+            self.discarded_contours += 1;
+            return;
+        }
+
+        let area = contour_area(&self.outline.points[self.start_index as usize..]);
+        if area * self.font_height * self.font_height < self.min_contour_area as f32 {
+            self.discard_contour();
+            return;
+        }
+
+        if self.max_segment_length > 0 {
+            self.resample_contour();
+        }
     }
 }
 
+/// Computes the area enclosed by a closed contour.
+///
+/// Arguments:
+///
+/// * `points`: The points of the contour without repeating the first point.
+///
+/// Returns:
+///
+/// The area regardless of the orientation of the contour.
+fn contour_area(points: &[Point]) -> f32 {
+    let count = points.len();
+    let doubled: f32 = (0..count)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % count]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum();
+
+    doubled.abs() * 0.5f32
+}
+
 /// Returns the 2D coordinates of a point on a straight line
 /// with the normalized distance `t` from the start.
 ///
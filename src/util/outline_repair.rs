@@ -0,0 +1,258 @@
+use std::collections::{HashMap, HashSet};
+
+use glam::DVec2;
+
+use crate::GlyphOutline;
+
+/// Points closer than this distance are merged.
+const WELD_DISTANCE: f64 = 1e-6;
+
+/// Rebuilds a [GlyphOutline] from the boundary of the area it covers.
+///
+/// The area of an outline is determined with the nonzero rule that is used
+/// by TrueType and OpenType fonts. Overlapping contours are merged, degenerate
+/// contours are removed and all contours are oriented like TrueType contours,
+/// so that outer contours are clockwise and holes are counter-clockwise.
+/// This guarantees that an extruded glyph forms a closed solid without
+/// internal faces.
+///
+/// Arguments:
+///
+/// * `outline`: The outline that is repaired.
+///
+/// Returns:
+///
+/// The repaired outline, which has no contours if the outline does not cover any area.
+pub(crate) fn repair_outline(outline: &GlyphOutline) -> GlyphOutline {
+    let contours = outline_contours(outline);
+    let segments: Vec<(DVec2, DVec2)> = contours
+        .iter()
+        .flat_map(|c| {
+            c.iter()
+                .zip(c.iter().cycle().skip(1))
+                .map(|(a, b)| (*a, *b))
+        })
+        .collect();
+
+    // Split all segments where they touch or intersect other segments.
+    let mut splits: Vec<Vec<f64>> = vec![vec![0f64, 1f64]; segments.len()];
+    for i in 0..segments.len() {
+        for j in (i + 1)..segments.len() {
+            for (t, u) in intersections(segments[i], segments[j]) {
+                splits[i].push(t);
+                splits[j].push(u);
+            }
+        }
+    }
+
+    let mut points = Vec::new();
+    let mut welded = HashMap::new();
+    let mut pieces = HashSet::new();
+    for ((a, b), mut split) in segments.iter().zip(splits) {
+        split.sort_by(|x, y| x.total_cmp(y));
+        let indices: Vec<usize> = split
+            .iter()
+            .map(|t| weld(*a + (*b - *a) * *t, &mut points, &mut welded))
+            .collect();
+        for (start, end) in indices.iter().zip(indices.iter().skip(1)) {
+            if start != end {
+                pieces.insert((*start.min(end), *start.max(end)));
+            }
+        }
+    }
+
+    // Keep the pieces that separate the filled from the empty area and
+    // direct them, so that the filled area is on their right side.
+    let mut boundary: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (a, b) in pieces {
+        let (start, end) = (points[a], points[b]);
+        let direction = end - start;
+        let offset = direction.perp().normalize() * (direction.length() * 0.25).min(1e-5);
+        let middle = (start + end) * 0.5;
+        let left = winding_number(&segments, middle + offset) != 0;
+        let right = winding_number(&segments, middle - offset) != 0;
+        match (left, right) {
+            (false, true) => boundary.entry(a).or_default().push(b),
+            (true, false) => boundary.entry(b).or_default().push(a),
+            _ => {}
+        }
+    }
+
+    // Chain the directed pieces into closed contours.
+    let mut repaired = GlyphOutline {
+        contours: Vec::new(),
+        points: Vec::new(),
+    };
+    let mut starts: Vec<usize> = boundary.keys().copied().collect();
+    starts.sort_unstable();
+    for start in starts {
+        while let Some(next) = boundary.get_mut(&start).and_then(|n| n.pop()) {
+            let mut contour = vec![start];
+            let mut current = next;
+            while current != start {
+                contour.push(current);
+                match boundary.get_mut(&current).and_then(|n| n.pop()) {
+                    Some(next) => current = next,
+                    None => break,
+                }
+            }
+            if current != start || contour.len() < 3 {
+                continue;
+            }
+
+            let mut indices = Vec::with_capacity(contour.len() + 1);
+            for point in contour {
+                indices.push(repaired.points.len() as u32);
+                repaired
+                    .points
+                    .push((points[point].x as f32, points[point].y as f32));
+            }
+            indices.push(indices[0]);
+            repaired.contours.push(indices);
+        }
+    }
+
+    repaired
+}
+
+/// Extracts the contours of a [GlyphOutline] without duplicate points.
+///
+/// Arguments:
+///
+/// * `outline`: The outline of which the contours are extracted.
+///
+/// Returns:
+///
+/// The points of each contour that encloses an area. The last point is
+/// implicitly connected to the first point.
+fn outline_contours(outline: &GlyphOutline) -> Vec<Vec<DVec2>> {
+    let mut contours = Vec::new();
+    for contour in outline.contours.iter() {
+        let mut points: Vec<DVec2> = Vec::with_capacity(contour.len());
+        for index in contour.iter() {
+            let (x, y) = outline.points[*index as usize];
+            let point = DVec2::new(x as f64, y as f64);
+            if points
+                .last()
+                .is_none_or(|p| p.distance(point) > WELD_DISTANCE)
+            {
+                points.push(point);
+            }
+        }
+        while points.len() > 1 && points[0].distance(points[points.len() - 1]) <= WELD_DISTANCE {
+            points.pop();
+        }
+
+        if points.len() >= 3 {
+            contours.push(points);
+        }
+    }
+
+    contours
+}
+
+/// Finds the points where two segments touch or intersect.
+///
+/// Arguments:
+///
+/// * `first`: The start and end point of the first segment.
+/// * `second`: The start and end point of the second segment.
+///
+/// Returns:
+///
+/// The positions of the common points on the first and the
+/// second segment in the range `0..=1`.
+fn intersections(first: (DVec2, DVec2), second: (DVec2, DVec2)) -> Vec<(f64, f64)> {
+    let (p, r) = (first.0, first.1 - first.0);
+    let (q, s) = (second.0, second.1 - second.0);
+    let denominator = r.perp_dot(s);
+    let range = 0f64..=1f64;
+
+    if denominator.abs() > f64::EPSILON * r.length() * s.length() {
+        let t = (q - p).perp_dot(s) / denominator;
+        let u = (q - p).perp_dot(r) / denominator;
+        return match range.contains(&t) && range.contains(&u) {
+            true => vec![(t, u)],
+            false => Vec::new(),
+        };
+    }
+
+    // Parallel segments only have common points if they are collinear.
+    if (q - p).perp_dot(r).abs() > WELD_DISTANCE * r.length() {
+        return Vec::new();
+    }
+    let project = |point: DVec2, origin: DVec2, direction: DVec2| {
+        (point - origin).dot(direction) / direction.length_squared()
+    };
+    let mut common = Vec::new();
+    for (point, u) in [(q, 0f64), (q + s, 1f64)] {
+        let t = project(point, p, r);
+        if range.contains(&t) {
+            common.push((t, u));
+        }
+    }
+    for (point, t) in [(p, 0f64), (p + r, 1f64)] {
+        let u = project(point, q, s);
+        if range.contains(&u) {
+            common.push((t, u));
+        }
+    }
+
+    common
+}
+
+/// Merges a point with previously added points that are closer than [WELD_DISTANCE].
+///
+/// Arguments:
+///
+/// * `point`: The point that is added.
+/// * `points`: The previously added points.
+/// * `welded`: The indices of the previously added points by their grid cell.
+///
+/// Returns:
+///
+/// The index of the point.
+fn weld(point: DVec2, points: &mut Vec<DVec2>, welded: &mut HashMap<(i64, i64), usize>) -> usize {
+    let cell = (point / WELD_DISTANCE).floor();
+    let cell = (cell.x as i64, cell.y as i64);
+
+    for x in (cell.0 - 1)..=(cell.0 + 1) {
+        for y in (cell.1 - 1)..=(cell.1 + 1) {
+            if let Some(index) = welded.get(&(x, y)) {
+                if points[*index].distance(point) <= WELD_DISTANCE {
+                    return *index;
+                }
+            }
+        }
+    }
+
+    points.push(point);
+    welded.insert(cell, points.len() - 1);
+    points.len() - 1
+}
+
+/// Computes how often the segments wind around a point.
+///
+/// Arguments:
+///
+/// * `segments`: The segments of closed contours.
+/// * `point`: The point for which the winding number is computed.
+///
+/// Returns:
+///
+/// The number of counter-clockwise minus the number of clockwise turns.
+fn winding_number(segments: &[(DVec2, DVec2)], point: DVec2) -> i32 {
+    let mut winding = 0;
+    for (a, b) in segments.iter() {
+        let side = (*b - *a).perp_dot(point - *a);
+        if a.y <= point.y {
+            if b.y > point.y && side > 0f64 {
+                winding += 1;
+            }
+        } else if b.y <= point.y && side < 0f64 {
+            winding -= 1;
+        }
+    }
+
+    winding
+}
@@ -0,0 +1,150 @@
+use glam::{Mat3, Vec2, Vec3A};
+
+use crate::{BoundingBox, MeshPart, PartitionedMeshText};
+
+use super::{
+    glam_vecs_to_raw, glam_vecs_to_raw_2d, quantize_points, quantize_points_2d,
+    remove_degenerate_triangles_indexed, transform_points_2d,
+};
+
+type IndexedMesh = (Vec<u32>, Vec<Vec3A>, BoundingBox);
+type IndexedMesh2D = (Vec<u32>, Vec<Vec2>, BoundingBox);
+
+/// Determines the [MeshPart] a triangle of an untransformed glyph mesh belongs to.
+///
+/// Arguments:
+///
+/// * `corners`: The corners of the triangle.
+/// * `depth`: The minimum and maximum z-coordinate of the mesh.
+///
+/// Returns:
+///
+/// The [MeshPart] of the triangle.
+pub(crate) fn classify_triangle(corners: [Vec3A; 3], depth: (f32, f32)) -> MeshPart {
+    // Flat meshes only have a front cap.
+    if depth.0 == depth.1 {
+        return MeshPart::FrontCap;
+    }
+
+    // The glyphs of a section may differ in depth, but the side walls
+    // always connect the front cap with the back cap.
+    let middle = (depth.0 + depth.1) * 0.5f32;
+    if corners.iter().all(|c| c.z > middle) {
+        MeshPart::FrontCap
+    } else if corners.iter().all(|c| c.z < middle) {
+        MeshPart::BackCap
+    } else {
+        MeshPart::SideWall
+    }
+}
+
+/// Generates a [PartitionedMeshText] from the internal data representation.
+///
+/// Arguments:
+///
+/// * `data`: The untransformed internal data from the cache or freshly generated.
+/// * `advance`: The horizontal advance of the text.
+/// * `place`: Places the vertices of the mesh after the triangles have been
+///   classified and returns their [BoundingBox].
+/// * `step`: The spacing of the grid to which the placed vertices are snapped
+///   or `None` if the vertices are not quantized.
+///
+/// Returns:
+///
+/// The corresponding [PartitionedMeshText].
+pub(crate) fn partitioned_mesh_from_data(
+    data: IndexedMesh,
+    advance: f32,
+    place: impl FnOnce(&mut [Vec3A], BoundingBox) -> BoundingBox,
+    step: Option<f32>,
+) -> PartitionedMeshText {
+    let (indices, mut vertices, bbox) = data;
+
+    let depth = vertices
+        .iter()
+        .fold((f32::MAX, f32::MIN), |d, v| (d.0.min(v.z), d.1.max(v.z)));
+
+    let mut parts = (Vec::new(), Vec::new(), Vec::new());
+    for triangle in indices.chunks_exact(3) {
+        let corners = [
+            vertices[triangle[0] as usize],
+            vertices[triangle[1] as usize],
+            vertices[triangle[2] as usize],
+        ];
+
+        match classify_triangle(corners, depth) {
+            MeshPart::FrontCap => parts.0.extend_from_slice(triangle),
+            MeshPart::BackCap => parts.1.extend_from_slice(triangle),
+            MeshPart::SideWall => parts.2.extend_from_slice(triangle),
+        }
+    }
+
+    let mut bbox = place(&mut vertices, bbox);
+    if let Some(step) = step {
+        quantize_points(&mut vertices, &mut bbox, step);
+        for part in [&mut parts.0, &mut parts.1, &mut parts.2] {
+            remove_degenerate_triangles_indexed(part, &vertices, step);
+        }
+    }
+
+    let front_cap = 0..parts.0.len();
+    let back_cap = front_cap.end..front_cap.end + parts.1.len();
+    let side_walls = back_cap.end..back_cap.end + parts.2.len();
+
+    let mut indices = parts.0;
+    indices.append(&mut parts.1);
+    indices.append(&mut parts.2);
+
+    PartitionedMeshText {
+        advance,
+        back_cap,
+        bbox,
+        front_cap,
+        indices,
+        side_walls,
+        two_dimensional: false,
+        vertices: glam_vecs_to_raw(&vertices),
+    }
+}
+
+/// Generates a two-dimensional [PartitionedMeshText] from the internal data representation.
+///
+/// Two-dimensional meshes only consist of a front cap.
+///
+/// Arguments:
+///
+/// * `data`: The untransformed internal data from the cache or freshly generated.
+/// * `advance`: The horizontal advance of the text.
+/// * `transform`: The transformation that will be applied to each vertex.
+/// * `step`: The spacing of the grid to which the transformed vertices are snapped
+///   or `None` if the vertices are not quantized.
+///
+/// Returns:
+///
+/// The corresponding [PartitionedMeshText].
+pub(crate) fn partitioned_mesh_from_data_2d(
+    data: IndexedMesh2D,
+    advance: f32,
+    transform: &Mat3,
+    step: Option<f32>,
+) -> PartitionedMeshText {
+    let (mut indices, mut vertices, mut bbox) = data;
+
+    transform_points_2d(&mut vertices, transform);
+    bbox.transform_2d(transform);
+    if let Some(step) = step {
+        quantize_points_2d(&mut vertices, &mut bbox, step);
+        remove_degenerate_triangles_indexed(&mut indices, &vertices, step);
+    }
+
+    PartitionedMeshText {
+        advance,
+        back_cap: indices.len()..indices.len(),
+        bbox,
+        front_cap: 0..indices.len(),
+        side_walls: indices.len()..indices.len(),
+        indices,
+        two_dimensional: true,
+        vertices: glam_vecs_to_raw_2d(&vertices),
+    }
+}
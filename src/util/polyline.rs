@@ -0,0 +1,92 @@
+use glam::{Mat4, Quat, Vec3, Vec4};
+
+/// The orientation of a segment of a polyline.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SegmentFrame {
+    /// The distance of the start of the segment along the polyline.
+    pub distance: f32,
+
+    /// The direction in which the text is oriented upwards.
+    pub normal: Vec3,
+
+    /// The start point of the segment.
+    pub start: Vec3,
+
+    /// The direction of the segment.
+    pub tangent: Vec3,
+}
+
+/// Computes the frames of the segments of a polyline by parallel transport,
+/// so that the frames twist as little as possible along the polyline.
+///
+/// Arguments:
+///
+/// * `points`: The points of the polyline.
+/// * `up`: The direction that is used as the normal of the first segment,
+///   after it has been made perpendicular to the segment.
+///
+/// Returns:
+///
+/// The frame of each segment, skipping segments without length,
+/// which is empty if the polyline has fewer than two distinct points.
+pub(crate) fn polyline_frames(points: &[Vec3], up: Vec3) -> Vec<SegmentFrame> {
+    let mut frames: Vec<SegmentFrame> = Vec::with_capacity(points.len());
+    let mut distance = 0f32;
+    for segment in points.windows(2) {
+        let length = segment[0].distance(segment[1]);
+        if length <= f32::EPSILON {
+            continue;
+        }
+
+        let tangent = (segment[1] - segment[0]) / length;
+        let normal = match frames.last() {
+            Some(previous) => Quat::from_rotation_arc(previous.tangent, tangent) * previous.normal,
+            None => {
+                let normal = up - tangent * up.dot(tangent);
+                match normal.try_normalize() {
+                    Some(normal) => normal,
+                    None => tangent.any_orthonormal_vector(),
+                }
+            }
+        };
+
+        frames.push(SegmentFrame {
+            distance,
+            normal: (normal - tangent * normal.dot(tangent)).normalize(),
+            start: segment[0],
+            tangent,
+        });
+        distance += length;
+    }
+
+    frames
+}
+
+/// Computes the transformation that places the untransformed text on a polyline.
+///
+/// Arguments:
+///
+/// * `frames`: The frames of the segments of the polyline, which must not be empty.
+/// * `distance`: The distance along the polyline at which the text is placed.
+///   Distances outside of the polyline extend its first or last segment.
+///
+/// Returns:
+///
+/// The transformation that maps the point at the given distance on the x-axis of
+/// the untransformed text onto the polyline, with the y-axis along the normal
+/// and the z-axis along the binormal of the segment.
+pub(crate) fn polyline_transform(frames: &[SegmentFrame], distance: f32) -> Mat4 {
+    let index = frames
+        .partition_point(|frame| frame.distance <= distance)
+        .saturating_sub(1);
+    let frame = &frames[index];
+    let binormal = frame.tangent.cross(frame.normal);
+    let origin = frame.start + frame.tangent * (distance - frame.distance);
+
+    Mat4::from_cols(
+        frame.tangent.extend(0f32),
+        frame.normal.extend(0f32),
+        binormal.extend(0f32),
+        Vec4::from((origin - frame.tangent * distance, 1f32)),
+    )
+}
@@ -0,0 +1,234 @@
+use glam::{Vec2, Vec3A};
+
+use crate::{BoundingBox, TriangleMesh};
+
+/// Projects the vertices of a mesh onto a surface along a direction.
+///
+/// Each vertex is moved along the line through the vertex in the given direction
+/// to the closest intersection of the line with the surface. The vertices of
+/// three-dimensional meshes keep their height above the surface, which is measured
+/// against the direction from the vertices that are closest to the surface.
+/// Vertices whose line does not intersect the surface remain unchanged.
+///
+/// Arguments:
+///
+/// * `vertices`: The raw vertices that will be projected.
+/// * `components`: The number of components of each vertex.
+/// * `surface`: The mesh onto which the vertices are projected.
+/// * `direction`: The direction of the projection.
+/// * `offset`: The distance by which each projected vertex is moved away from the
+///   surface along the normal of the hit triangle, which faces against the direction.
+///
+/// Returns:
+///
+/// The projected vertices together with their [BoundingBox].
+pub(crate) fn project_onto_surface(
+    vertices: &[f32],
+    components: usize,
+    surface: &impl TriangleMesh,
+    direction: Vec3A,
+    offset: f32,
+) -> (Vec<Vec3A>, BoundingBox) {
+    let points: Vec<Vec3A> = vertices
+        .chunks_exact(components)
+        .map(|v| Vec3A::new(v[0], v[1], if components == 3 { v[2] } else { 0f32 }))
+        .collect();
+    if points.is_empty() {
+        return (points, BoundingBox::empty());
+    }
+
+    let direction = direction.normalize_or_zero();
+    let projected: Vec<Vec3A> = if direction == Vec3A::ZERO {
+        points
+    } else {
+        let triangles: Vec<[Vec3A; 3]> = surface.triangles().map(|t| t.map(Vec3A::from)).collect();
+        let grid = SurfaceGrid::new(&triangles, direction);
+        let front = points
+            .iter()
+            .map(|p| p.dot(direction))
+            .fold(f32::NEG_INFINITY, f32::max);
+
+        points
+            .iter()
+            .map(|p| {
+                let height = front - p.dot(direction);
+                let base = *p + direction * height;
+
+                match grid.intersect(base, direction) {
+                    Some((t, normal)) => base + direction * t + normal * (offset + height),
+                    None => *p,
+                }
+            })
+            .collect()
+    };
+
+    let (min, max) = projected.iter().fold(
+        (Vec3A::splat(f32::INFINITY), Vec3A::splat(f32::NEG_INFINITY)),
+        |(min, max), v| (min.min(*v), max.max(*v)),
+    );
+
+    (projected, BoundingBox::new(min, max))
+}
+
+/// A uniform grid in the plane perpendicular to the direction of a projection,
+/// in which each cell lists the triangles of the surface that may be hit by
+/// a line through the cell.
+struct SurfaceGrid<'a> {
+    /// The triangles of the surface.
+    triangles: &'a [[Vec3A; 3]],
+
+    /// The axes that span the plane of the grid.
+    axes: (Vec3A, Vec3A),
+
+    /// The minimum corner of the grid.
+    min: Vec2,
+
+    /// The maximum corner of the grid.
+    max: Vec2,
+
+    /// The number of cells along each axis.
+    resolution: usize,
+
+    /// The indices of the triangles that overlap each cell.
+    cells: Vec<Vec<usize>>,
+}
+
+impl<'a> SurfaceGrid<'a> {
+    /// Sorts the triangles of a surface into a new [SurfaceGrid].
+    ///
+    /// Arguments:
+    ///
+    /// * `triangles`: The triangles of the surface.
+    /// * `direction`: The normalized direction of the projection.
+    ///
+    /// Returns:
+    ///
+    /// The new [SurfaceGrid].
+    fn new(triangles: &'a [[Vec3A; 3]], direction: Vec3A) -> Self {
+        let axes = direction.any_orthonormal_pair();
+        let bounds: Vec<(Vec2, Vec2)> = triangles
+            .iter()
+            .map(|triangle| {
+                let corners = triangle.map(|p| Vec2::new(p.dot(axes.0), p.dot(axes.1)));
+                (
+                    corners[0].min(corners[1]).min(corners[2]),
+                    corners[0].max(corners[1]).max(corners[2]),
+                )
+            })
+            .collect();
+        let (min, max) = bounds.iter().fold(
+            (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+            |(min, max), b| (min.min(b.0), max.max(b.1)),
+        );
+
+        let resolution = ((triangles.len() as f32).sqrt().ceil() as usize).max(1);
+        let mut grid = Self {
+            triangles,
+            axes,
+            min,
+            max,
+            resolution,
+            cells: vec![Vec::new(); resolution * resolution],
+        };
+        for (i, (lo, hi)) in bounds.iter().enumerate() {
+            let (Some(lo), Some(hi)) = (grid.cell(*lo), grid.cell(*hi)) else {
+                continue;
+            };
+            for row in lo.1..=hi.1 {
+                for column in lo.0..=hi.0 {
+                    grid.cells[row * resolution + column].push(i);
+                }
+            }
+        }
+
+        grid
+    }
+
+    /// Gets the cell that contains a point in the plane of the grid.
+    ///
+    /// Arguments:
+    ///
+    /// * `point`: The point in the plane of the grid.
+    ///
+    /// Returns:
+    ///
+    /// The column and row of the cell or [None] if the point lies outside of the grid.
+    fn cell(&self, point: Vec2) -> Option<(usize, usize)> {
+        if !(point.cmpge(self.min).all() && point.cmple(self.max).all()) {
+            return None;
+        }
+
+        let size = (self.max - self.min).max(Vec2::splat(f32::MIN_POSITIVE));
+        let cell = (point - self.min) / size * self.resolution as f32;
+        let last = self.resolution - 1;
+
+        Some(((cell.x as usize).min(last), (cell.y as usize).min(last)))
+    }
+
+    /// Intersects a line with the triangles of the surface.
+    ///
+    /// Arguments:
+    ///
+    /// * `origin`: A point on the line.
+    /// * `direction`: The normalized direction of the line.
+    ///
+    /// Returns:
+    ///
+    /// The signed distance from the origin to the closest intersection together with
+    /// the normal of the hit triangle, or [None] if the line misses the surface.
+    fn intersect(&self, origin: Vec3A, direction: Vec3A) -> Option<(f32, Vec3A)> {
+        let (column, row) =
+            self.cell(Vec2::new(origin.dot(self.axes.0), origin.dot(self.axes.1)))?;
+
+        self.cells[row * self.resolution + column]
+            .iter()
+            .filter_map(|i| intersect_line(origin, direction, &self.triangles[*i]))
+            .min_by(|a, b| a.0.abs().total_cmp(&b.0.abs()))
+    }
+}
+
+/// Intersects a line with a triangle using the Möller–Trumbore algorithm.
+///
+/// Arguments:
+///
+/// * `origin`: A point on the line.
+/// * `direction`: The normalized direction of the line.
+/// * `triangle`: The corners of the triangle.
+///
+/// Returns:
+///
+/// The signed distance from the origin to the intersection together with the
+/// normal of the triangle facing against the direction, or [None] if the line
+/// misses the triangle.
+fn intersect_line(origin: Vec3A, direction: Vec3A, triangle: &[Vec3A; 3]) -> Option<(f32, Vec3A)> {
+    let edge_1 = triangle[1] - triangle[0];
+    let edge_2 = triangle[2] - triangle[0];
+
+    let p = direction.cross(edge_2);
+    let determinant = edge_1.dot(p);
+    if determinant.abs() < f32::EPSILON {
+        return None;
+    }
+    let inverse = 1f32 / determinant;
+
+    let s = origin - triangle[0];
+    let u = s.dot(p) * inverse;
+    if !(0f32..=1f32).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(edge_1);
+    let v = direction.dot(q) * inverse;
+    if v < 0f32 || u + v > 1f32 {
+        return None;
+    }
+
+    let normal = edge_1.cross(edge_2).normalize_or_zero();
+    let normal = if normal.dot(direction) > 0f32 {
+        -normal
+    } else {
+        normal
+    };
+
+    Some((edge_2.dot(q) * inverse, normal))
+}
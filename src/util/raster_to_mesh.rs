@@ -1,8 +1,8 @@
-use glam::Vec3A;
+use glam::{Vec2, Vec3A};
 
 use crate::{
     error::{GlyphTriangulationError, MeshTextError},
-    GlyphOutline,
+    ExtrusionSettings, GlyphOutline,
 };
 
 use super::{triangulate_between_edges, triangulate_between_edges_indexed};
@@ -10,6 +10,20 @@ use super::{triangulate_between_edges, triangulate_between_edges_indexed};
 type EdgeIndices = (usize, usize);
 type TriangleIndices = (usize, usize, usize);
 
+/// Buffers that are reused between the triangulations of consecutive glyphs,
+/// so that the triangulation does not allocate them for every glyph.
+#[derive(Default)]
+pub(crate) struct TriangulationScratch {
+    /// The points of the back cap.
+    back_points: Vec<(f32, f32)>,
+
+    /// The edges of all contours.
+    edges: Vec<EdgeIndices>,
+
+    /// The points of the outline in double precision.
+    points: Vec<(f64, f64)>,
+}
+
 /// Generates a triangle mesh from a discrete [GlyphOutline].
 ///
 /// Arguments:
@@ -17,6 +31,9 @@ type TriangleIndices = (usize, usize, usize);
 /// * `outline`: The outline of the desired glyph.
 /// * `flat`: Generates a two dimensional mesh if `true`, otherwise
 /// a three dimensional mesh with depth `1.0` units is generated.
+/// * `extrusion`: The [ExtrusionSettings] used for three dimensional meshes.
+/// * `center`: The center of the glyph about which the back cap is scaled.
+/// * `scratch`: The [TriangulationScratch] whose buffers are reused.
 ///
 /// Returns:
 ///
@@ -25,12 +42,15 @@ type TriangleIndices = (usize, usize, usize);
 pub(crate) fn raster_to_mesh(
     outline: &GlyphOutline,
     flat: bool,
+    extrusion: &ExtrusionSettings,
+    center: Vec2,
+    scratch: &mut TriangulationScratch,
 ) -> Result<Vec<Vec3A>, Box<dyn MeshTextError>> {
     let points = &outline.points;
-    let (triangles, edges) = get_glyph_area_triangulation(outline)?;
+    let triangles = get_glyph_area_triangulation(outline, scratch)?;
 
     if flat {
-        let mut vertices = Vec::new();
+        let mut vertices = Vec::with_capacity(triangles.len() * 3);
         for i in triangles {
             vertices.push(Vec3A::new(points[i.0].0, points[i.0].1, 0f32));
             vertices.push(Vec3A::new(points[i.1].0, points[i.1].1, 0f32));
@@ -39,7 +59,10 @@ pub(crate) fn raster_to_mesh(
 
         Ok(vertices)
     } else {
-        let mut vertices = Vec::new();
+        back_cap_points(points, extrusion, center, &mut scratch.back_points);
+        let back_points = &scratch.back_points;
+
+        let mut vertices = Vec::with_capacity(triangles.len() * 6 + scratch.edges.len() * 6);
         for i in triangles {
             // The first triangle.
             vertices.push(Vec3A::new(points[i.0].0, points[i.0].1, 0.5f32));
@@ -48,13 +71,14 @@ pub(crate) fn raster_to_mesh(
 
             // The second triangle.
             // The order of vertices is changed, so that the triangle faces outward.
-            vertices.push(Vec3A::new(points[i.2].0, points[i.2].1, -0.5f32));
-            vertices.push(Vec3A::new(points[i.1].0, points[i.1].1, -0.5f32));
-            vertices.push(Vec3A::new(points[i.0].0, points[i.0].1, -0.5f32));
+            let (b0, b1, b2) = (back_points[i.0], back_points[i.1], back_points[i.2]);
+            vertices.push(Vec3A::new(b2.0, b2.1, -0.5f32));
+            vertices.push(Vec3A::new(b1.0, b1.1, -0.5f32));
+            vertices.push(Vec3A::new(b0.0, b0.1, -0.5f32));
         }
 
         // Finally add the triangles in between the contours (e.g. in the z-axis).
-        triangulate_between_edges(&mut vertices, &outline.points, &edges);
+        triangulate_between_edges(&mut vertices, &outline.points, back_points, &scratch.edges);
 
         Ok(vertices)
     }
@@ -67,6 +91,9 @@ pub(crate) fn raster_to_mesh(
 /// * `outline`: The outline of the desired glyph.
 /// * `flat`: Generates a two dimensional mesh if `true`, otherwise
 /// a three dimensional mesh with depth `1.0` units is generated.
+/// * `extrusion`: The [ExtrusionSettings] used for three dimensional meshes.
+/// * `center`: The center of the glyph about which the back cap is scaled.
+/// * `scratch`: The [TriangulationScratch] whose buffers are reused.
 ///
 /// Returns:
 ///
@@ -76,17 +103,20 @@ pub(crate) fn raster_to_mesh(
 pub(crate) fn raster_to_mesh_indexed(
     outline: &GlyphOutline,
     flat: bool,
+    extrusion: &ExtrusionSettings,
+    center: Vec2,
+    scratch: &mut TriangulationScratch,
 ) -> Result<(Vec<Vec3A>, Vec<u32>), Box<dyn MeshTextError>> {
     let points = &outline.points;
-    let (triangles, edges) = get_glyph_area_triangulation(outline)?;
+    let triangles = get_glyph_area_triangulation(outline, scratch)?;
 
     if flat {
-        let mut vertices = Vec::new();
+        let mut vertices = Vec::with_capacity(points.len());
         for p in points {
             vertices.push(Vec3A::new(p.0, p.1, 0f32));
         }
 
-        let mut indices = Vec::new();
+        let mut indices = Vec::with_capacity(triangles.len() * 3);
         for i in triangles {
             indices.push(i.0 as u32);
             indices.push(i.1 as u32);
@@ -95,17 +125,18 @@ pub(crate) fn raster_to_mesh_indexed(
 
         Ok((vertices, indices))
     } else {
-        let mut vertices = Vec::new();
+        let mut vertices = Vec::with_capacity(points.len() * 2);
         for p in points {
             vertices.push(Vec3A::new(p.0, p.1, 0.5f32));
         }
         let flat_count = vertices.len() as u32;
 
-        for p in points {
+        back_cap_points(points, extrusion, center, &mut scratch.back_points);
+        for p in scratch.back_points.iter() {
             vertices.push(Vec3A::new(p.0, p.1, -0.5f32));
         }
 
-        let mut indices = Vec::new();
+        let mut indices = Vec::with_capacity(triangles.len() * 6 + scratch.edges.len() * 6);
         for i in triangles {
             indices.push(i.0 as u32);
             indices.push(i.1 as u32);
@@ -117,33 +148,70 @@ pub(crate) fn raster_to_mesh_indexed(
         }
 
         // Add the vertices and indices in between the contours (e.g. in the z-axis).
-        triangulate_between_edges_indexed(&mut vertices, &mut indices, &edges);
+        triangulate_between_edges_indexed(&mut vertices, &mut indices, &scratch.edges);
 
         Ok((vertices, indices))
     }
 }
 
+/// Computes the points of the back cap of a three dimensional glyph.
+///
+/// Arguments:
+///
+/// * `points`: The points of the front cap.
+/// * `extrusion`: The [ExtrusionSettings] that control the scale of the back cap.
+/// * `center`: The center of the glyph about which the back cap is scaled.
+/// * `back_points`: The buffer that receives the points of the back cap.
+fn back_cap_points(
+    points: &[(f32, f32)],
+    extrusion: &ExtrusionSettings,
+    center: Vec2,
+    back_points: &mut Vec<(f32, f32)>,
+) {
+    let scale = extrusion.back_cap_scale;
+
+    back_points.clear();
+    back_points.extend(points.iter().map(|p| {
+        (
+            center.x + (p.0 - center.x) * scale,
+            center.y + (p.1 - center.y) * scale,
+        )
+    }));
+}
+
+/// Triangulates the area enclosed by the contours of a [GlyphOutline].
+///
+/// Arguments:
+///
+/// * `outline`: The outline of the desired glyph.
+/// * `scratch`: The [TriangulationScratch] that receives the edges of the contours.
+///
+/// Returns:
+///
+/// A [Result] containing the triangles or an [MeshTextError] if
+/// anything went wrong in the process.
 fn get_glyph_area_triangulation(
     outline: &GlyphOutline,
-) -> Result<(Vec<TriangleIndices>, Vec<EdgeIndices>), Box<dyn MeshTextError>> {
-    // TODO: Implement a custom triangulation algorithm to get rid of these conversions.
-    let points: Vec<(f64, f64)> = outline
-        .points
-        .iter()
-        .map(|p| (p.0 as f64, p.1 as f64))
-        .collect();
-    let mut contours = Vec::new();
-    for c in outline.contours.iter() {
-        let path_indices: Vec<usize> = c.iter().map(|i| *i as usize).collect();
-        contours.push(path_indices);
+    scratch: &mut TriangulationScratch,
+) -> Result<Vec<TriangleIndices>, Box<dyn MeshTextError>> {
+    scratch.edges.clear();
+
+    // Repaired outlines may not have any contours left.
+    if outline.contours.is_empty() {
+        return Ok(Vec::new());
     }
 
+    // TODO: Implement a custom triangulation algorithm to get rid of these conversions.
+    let points = &mut scratch.points;
+    points.clear();
+    points.extend(outline.points.iter().map(|p| (p.0 as f64, p.1 as f64)));
+
     // We might need access to the edges later, so we compute them here once.
-    let mut edges = Vec::new();
-    for c in contours.iter() {
+    let edges = &mut scratch.edges;
+    for c in outline.contours.iter() {
         let next = edges.len();
         for (a, b) in c.iter().zip(c.iter().skip(1)) {
-            edges.push((*a, *b));
+            edges.push((*a as usize, *b as usize));
         }
         if let Some(start) = edges.get(next) {
             if start.0 != edges.last().unwrap().1 {
@@ -155,9 +223,8 @@ fn get_glyph_area_triangulation(
     }
 
     // Triangulate the contours.
-    let triangles = match cdt::triangulate_with_edges(&points, &edges) {
-        Ok(result) => result,
-        Err(err) => return Err(Box::new(GlyphTriangulationError(err))),
-    };
-    Ok((triangles, edges))
+    match cdt::triangulate_with_edges(points, &*edges) {
+        Ok(triangles) => Ok(triangles),
+        Err(err) => Err(Box::new(GlyphTriangulationError(err))),
+    }
 }
@@ -0,0 +1,134 @@
+use glam::Vec2;
+use ttf_parser::{GlyphId, Tag};
+
+use crate::{util::is_combining_mark, GlyphKey, PositionedGlyph, ShapingSettings};
+
+/// Shapes a text using `rustybuzz`.
+///
+/// Arguments:
+///
+/// * `face`: The font that is used for shaping.
+/// * `text`: The text that should be shaped.
+/// * `settings`: The [ShapingSettings] that control which features are applied.
+///
+/// Returns:
+///
+/// The shaped glyphs in visual order. The advances and offsets are in font units.
+/// Each glyph is accompanied by wether it is a combining mark, which the font
+/// did not attach to its base glyph.
+pub(crate) fn shape_text(
+    face: &rustybuzz::Face,
+    text: &str,
+    settings: &ShapingSettings,
+) -> Vec<(PositionedGlyph, bool)> {
+    let mut buffer = rustybuzz::UnicodeBuffer::new();
+    buffer.push_str(text);
+
+    if let Some(script) = settings
+        .script
+        .and_then(|tag| rustybuzz::Script::from_iso15924_tag(Tag::from_bytes(&tag)))
+    {
+        buffer.set_script(script);
+    }
+    if let Some(Ok(language)) = settings.language.as_ref().map(|l| l.parse()) {
+        buffer.set_language(language);
+    }
+    buffer.guess_segment_properties();
+    let rtl = buffer.direction() == rustybuzz::Direction::RightToLeft;
+    let output = rustybuzz::shape(face, &features(settings), buffer);
+    let characters = glyph_characters(text, output.glyph_infos(), rtl);
+
+    let mut glyphs = Vec::with_capacity(output.len());
+    let mut pen = Vec2::ZERO;
+    for ((info, position), character) in output
+        .glyph_infos()
+        .iter()
+        .zip(output.glyph_positions().iter())
+        .zip(characters)
+    {
+        let attached = position.x_offset != 0 || position.y_offset != 0;
+        glyphs.push((
+            PositionedGlyph {
+                advance: position.x_advance as f32,
+                cluster: info.cluster as usize,
+                depth: 1f32,
+                glyph_id: GlyphKey::Font(GlyphId(info.glyph_id as u16)),
+                offset: pen + Vec2::new(position.x_offset as f32, position.y_offset as f32),
+            },
+            !attached && character.is_some_and(is_combining_mark),
+        ));
+        pen += Vec2::new(position.x_advance as f32, position.y_advance as f32);
+    }
+
+    glyphs
+}
+
+/// Finds the character from which each shaped glyph originates.
+///
+/// The shaper merges combining marks into the cluster of their base character,
+/// so the characters of a cluster are assigned to its glyphs in order.
+///
+/// Arguments:
+///
+/// * `text`: The text that was shaped.
+/// * `infos`: The shaped glyphs in visual order.
+/// * `rtl`: Wether the glyphs of each cluster are in reverse order.
+///
+/// Returns:
+///
+/// The character of each glyph or `None` if the glyphs of its cluster
+/// do not correspond to its characters one by one, e.g. for ligatures.
+fn glyph_characters(text: &str, infos: &[rustybuzz::GlyphInfo], rtl: bool) -> Vec<Option<char>> {
+    let mut starts: Vec<usize> = infos.iter().map(|info| info.cluster as usize).collect();
+    starts.sort_unstable();
+    starts.dedup();
+
+    let mut characters = Vec::with_capacity(infos.len());
+    for group in infos.chunk_by(|a, b| a.cluster == b.cluster) {
+        let start = group[0].cluster as usize;
+        let end = match starts.binary_search(&start) {
+            Ok(i) => starts.get(i + 1).copied().unwrap_or(text.len()),
+            Err(_) => text.len(),
+        };
+        let cluster: Vec<char> = text[start..end].chars().collect();
+
+        if cluster.len() != group.len() {
+            characters.extend(group.iter().map(|_| None));
+        } else if rtl {
+            characters.extend(cluster.into_iter().rev().map(Some));
+        } else {
+            characters.extend(cluster.into_iter().map(Some));
+        }
+    }
+
+    characters
+}
+
+/// Collects the OpenType features that are toggled by the [ShapingSettings].
+///
+/// Arguments:
+///
+/// * `settings`: The [ShapingSettings] of the text.
+///
+/// Returns:
+///
+/// The features that should be passed to the shaper.
+fn features(settings: &ShapingSettings) -> Vec<rustybuzz::Feature> {
+    let mut features = Vec::new();
+
+    if !settings.ligatures {
+        for tag in [b"liga", b"clig"] {
+            features.push(rustybuzz::Feature::new(Tag::from_bytes(tag), 0, ..));
+        }
+    }
+
+    for feature in settings.features.iter() {
+        features.push(rustybuzz::Feature::new(
+            Tag::from_bytes(&feature.tag),
+            feature.value,
+            ..,
+        ));
+    }
+
+    features
+}
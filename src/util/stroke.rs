@@ -0,0 +1,188 @@
+use glam::Vec2;
+
+use crate::{BoundingBox, DashPattern};
+
+type IndexedMesh2D = (Vec<u32>, Vec<Vec2>, BoundingBox);
+
+/// Miter joins are limited to this many times the half width of the stroke,
+/// so that sharp corners do not produce long spikes.
+const MITER_LIMIT: f32 = 4f32;
+
+/// Generates a mesh along the closed contours of a glyph outline.
+///
+/// Arguments:
+///
+/// * `contours`: The closed contours, which do not repeat their first point.
+/// * `width`: The width of the stroke, which is centered on the contours.
+/// * `dash`: The optional [DashPattern] along each contour.
+///
+/// Returns:
+///
+/// The two-dimensional indexed mesh of the stroke.
+pub(crate) fn stroke_contours(
+    contours: &[Vec<Vec2>],
+    width: f32,
+    dash: Option<&DashPattern>,
+) -> IndexedMesh2D {
+    let mut indices = Vec::new();
+    let mut vertices = Vec::new();
+
+    for contour in contours.iter().filter(|c| c.len() >= 2) {
+        match dash {
+            Some(pattern) => {
+                for segment in dash_contour(contour, pattern) {
+                    stroke_polyline(&segment, false, width, &mut indices, &mut vertices);
+                }
+            }
+            None => stroke_polyline(contour, true, width, &mut indices, &mut vertices),
+        }
+    }
+
+    let bbox = if vertices.is_empty() {
+        BoundingBox::empty()
+    } else {
+        let (min, max) = vertices.iter().fold(
+            (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+            |(min, max), v| (min.min(*v), max.max(*v)),
+        );
+        BoundingBox::new(min.extend(0f32).into(), max.extend(0f32).into())
+    };
+
+    (indices, vertices, bbox)
+}
+
+/// Splits a closed contour into the open polylines of its dashes.
+///
+/// Arguments:
+///
+/// * `contour`: The closed contour, which does not repeat its first point.
+/// * `pattern`: The [DashPattern] that is applied along the arc length of the contour.
+///
+/// Returns:
+///
+/// The points of each dash.
+fn dash_contour(contour: &[Vec2], pattern: &DashPattern) -> Vec<Vec<Vec2>> {
+    let period = pattern.dash + pattern.gap;
+    if pattern.dash <= 0f32 {
+        return Vec::new();
+    }
+    if pattern.gap <= 0f32 || period <= 0f32 {
+        let mut closed = contour.to_vec();
+        closed.push(contour[0]);
+        return vec![closed];
+    }
+
+    let mut dashes = Vec::new();
+    let mut current: Vec<Vec2> = Vec::new();
+
+    // The position within the pattern at the start of the current edge.
+    let mut phase = pattern.offset.rem_euclid(period);
+    let edges = contour.iter().zip(contour.iter().cycle().skip(1));
+    for (start, end) in edges {
+        let length = start.distance(*end);
+        let mut travelled = 0f32;
+
+        while travelled < length {
+            let drawing = phase < pattern.dash;
+            let boundary = if drawing { pattern.dash } else { period };
+            let step = (boundary - phase).min(length - travelled);
+            let point = start.lerp(*end, (travelled + step) / length);
+
+            if drawing {
+                if current.is_empty() {
+                    current.push(start.lerp(*end, travelled / length));
+                }
+                current.push(point);
+            }
+
+            travelled += step;
+            phase += step;
+            if phase >= period {
+                phase -= period;
+            }
+            if drawing && phase >= pattern.dash && current.len() >= 2 {
+                dashes.push(std::mem::take(&mut current));
+            }
+        }
+    }
+
+    // A dash that reaches the end of the contour continues at its start.
+    if !current.is_empty() {
+        match dashes.first_mut() {
+            Some(first) if pattern.offset.rem_euclid(period) < pattern.dash => {
+                current.extend(first.iter().skip(1));
+                *first = current;
+            }
+            _ if current.len() >= 2 => dashes.push(current),
+            _ => (),
+        }
+    }
+
+    dashes
+}
+
+/// Appends the triangles of a stroke along a polyline to a mesh.
+///
+/// Arguments:
+///
+/// * `points`: The points of the polyline.
+/// * `closed`: Wether the last point is connected to the first point.
+/// * `width`: The width of the stroke.
+/// * `indices`: The indices to which the triangles are appended.
+/// * `vertices`: The vertices to which the triangles are appended.
+fn stroke_polyline(
+    points: &[Vec2],
+    closed: bool,
+    width: f32,
+    indices: &mut Vec<u32>,
+    vertices: &mut Vec<Vec2>,
+) {
+    let count = points.len();
+    if count < 2 {
+        return;
+    }
+    let half_width = width * 0.5f32;
+    let first = vertices.len() as u32;
+
+    // The normal of the edge starting at each point.
+    let normal = |i: usize| {
+        let direction = (points[(i + 1) % count] - points[i]).normalize_or_zero();
+        direction.perp()
+    };
+
+    for (i, point) in points.iter().enumerate() {
+        let previous = if i > 0 {
+            Some(normal(i - 1))
+        } else if closed {
+            Some(normal(count - 1))
+        } else {
+            None
+        };
+        let next = if i + 1 < count || closed {
+            Some(normal(i))
+        } else {
+            None
+        };
+
+        // Consecutive edges meet at a miter.
+        let offset = match (previous, next) {
+            (Some(a), Some(b)) => {
+                let miter = (a + b).normalize_or_zero();
+                let cosine = miter.dot(b).max(1f32 / MITER_LIMIT);
+                miter * half_width / cosine
+            }
+            (Some(n), None) | (None, Some(n)) => n * half_width,
+            (None, None) => Vec2::ZERO,
+        };
+        vertices.push(*point + offset);
+        vertices.push(*point - offset);
+    }
+
+    let segments = if closed { count } else { count - 1 };
+    for i in 0..segments as u32 {
+        let j = (i + 1) % count as u32;
+        let (left, right) = (first + 2 * i, first + 2 * i + 1);
+        let (next_left, next_right) = (first + 2 * j, first + 2 * j + 1);
+        indices.extend_from_slice(&[right, next_right, left, left, next_right, next_left]);
+    }
+}
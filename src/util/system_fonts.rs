@@ -0,0 +1,38 @@
+use fontdb::{Database, Family, Query, Stretch, Style, Weight};
+
+use crate::FontQuery;
+
+/// Loads the data of a font that is installed on the system.
+///
+/// Arguments:
+///
+/// * `query`: The [FontQuery] that describes the font.
+///
+/// Returns:
+///
+/// The data of the font file together with the index of the face
+/// in the file or `None` if no installed font matches the query.
+pub(crate) fn load_system_font(query: &FontQuery) -> Option<(Vec<u8>, u32)> {
+    let mut database = Database::new();
+    database.load_system_fonts();
+
+    let family = match query.family.as_str() {
+        "serif" => Family::Serif,
+        "sans-serif" => Family::SansSerif,
+        "cursive" => Family::Cursive,
+        "fantasy" => Family::Fantasy,
+        "monospace" => Family::Monospace,
+        name => Family::Name(name),
+    };
+    let id = database.query(&Query {
+        families: &[family],
+        weight: Weight(query.weight),
+        stretch: Stretch::Normal,
+        style: match query.italic {
+            true => Style::Italic,
+            false => Style::Normal,
+        },
+    })?;
+
+    database.with_face_data(id, |data, index| (data.to_vec(), index))
+}
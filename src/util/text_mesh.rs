@@ -1,9 +1,123 @@
 use glam::{Vec2, Vec3A};
 
-use crate::{BoundingBox, IndexedMeshText, MeshText};
+use crate::{
+    BoundingBox, GlamVertex, IndexedMeshText, IndexedMeshTextGlam, MeshText, MeshTextGlam,
+};
 
 use super::{glam_vecs_to_raw, glam_vecs_to_raw_2d};
 
+/// A mesh type that can be generated from the internal data representation.
+pub(crate) trait FromMeshData {
+    /// Generates the mesh from three-dimensional data.
+    ///
+    /// Arguments:
+    ///
+    /// * `data`: The internal data from the cache or freshly generated.
+    /// * `advance`: The horizontal advance of the text.
+    ///
+    /// Returns:
+    ///
+    /// The corresponding mesh.
+    fn from_data(data: (Vec<Vec3A>, BoundingBox), advance: f32) -> Self;
+
+    /// Generates the mesh from two-dimensional data.
+    ///
+    /// Arguments:
+    ///
+    /// * `data`: The internal data from the cache or freshly generated.
+    /// * `advance`: The horizontal advance of the text.
+    ///
+    /// Returns:
+    ///
+    /// The corresponding mesh.
+    fn from_data_2d(data: (Vec<Vec2>, BoundingBox), advance: f32) -> Self;
+}
+
+/// An indexed mesh type that can be generated from the internal data representation.
+pub(crate) trait FromIndexedMeshData {
+    /// Generates the mesh from three-dimensional data.
+    ///
+    /// Arguments:
+    ///
+    /// * `data`: The internal data from the cache or freshly generated.
+    /// * `advance`: The horizontal advance of the text.
+    ///
+    /// Returns:
+    ///
+    /// The corresponding mesh.
+    fn from_data(data: (Vec<u32>, Vec<Vec3A>, BoundingBox), advance: f32) -> Self;
+
+    /// Generates the mesh from two-dimensional data.
+    ///
+    /// Arguments:
+    ///
+    /// * `data`: The internal data from the cache or freshly generated.
+    /// * `advance`: The horizontal advance of the text.
+    ///
+    /// Returns:
+    ///
+    /// The corresponding mesh.
+    fn from_data_2d(data: (Vec<u32>, Vec<Vec2>, BoundingBox), advance: f32) -> Self;
+}
+
+impl FromMeshData for MeshText {
+    fn from_data(data: (Vec<Vec3A>, BoundingBox), advance: f32) -> Self {
+        text_mesh_from_data(data, advance)
+    }
+
+    fn from_data_2d(data: (Vec<Vec2>, BoundingBox), advance: f32) -> Self {
+        text_mesh_from_data_2d(data, advance)
+    }
+}
+
+impl<V: GlamVertex> FromMeshData for MeshTextGlam<V> {
+    fn from_data(data: (Vec<Vec3A>, BoundingBox), advance: f32) -> Self {
+        MeshTextGlam {
+            advance,
+            bbox: data.1,
+            vertices: V::from_vecs(data.0),
+        }
+    }
+
+    fn from_data_2d(data: (Vec<Vec2>, BoundingBox), advance: f32) -> Self {
+        MeshTextGlam {
+            advance,
+            bbox: data.1,
+            vertices: V::from_vecs_2d(data.0),
+        }
+    }
+}
+
+impl FromIndexedMeshData for IndexedMeshText {
+    fn from_data(data: (Vec<u32>, Vec<Vec3A>, BoundingBox), advance: f32) -> Self {
+        text_mesh_from_data_indexed(data, advance)
+    }
+
+    fn from_data_2d(data: (Vec<u32>, Vec<Vec2>, BoundingBox), advance: f32) -> Self {
+        text_mesh_from_data_indexed_2d(data, advance)
+    }
+}
+
+impl<V: GlamVertex> FromIndexedMeshData for IndexedMeshTextGlam<V> {
+    fn from_data(data: (Vec<u32>, Vec<Vec3A>, BoundingBox), advance: f32) -> Self {
+        IndexedMeshTextGlam {
+            advance,
+            bbox: data.2,
+            indices: data.0,
+            vertices: V::from_vecs(data.1),
+        }
+    }
+
+    fn from_data_2d(data: (Vec<u32>, Vec<Vec2>, BoundingBox), advance: f32) -> Self {
+        IndexedMeshTextGlam {
+            advance,
+            bbox: data.2,
+            indices: data.0,
+            vertices: V::from_vecs_2d(data.1),
+        }
+    }
+}
+
 /// Generates a [MeshText] from the internal data representation.
 ///
 /// It is a bit unfortunate, that this is needed, because it adds the
@@ -12,13 +126,16 @@ use super::{glam_vecs_to_raw, glam_vecs_to_raw_2d};
 /// Arguments:
 ///
 /// * `data`: The internal data from the cache or freshly generated.
+/// * `advance`: The horizontal advance of the text.
 ///
 /// Returns:
 ///
 /// The corresponding [MeshText].
-pub(crate) fn text_mesh_from_data(data: (Vec<Vec3A>, BoundingBox)) -> MeshText {
+pub(crate) fn text_mesh_from_data(data: (Vec<Vec3A>, BoundingBox), advance: f32) -> MeshText {
     MeshText {
+        advance,
         bbox: data.1,
+        two_dimensional: false,
         vertices: glam_vecs_to_raw(&data.0),
     }
 }
@@ -31,13 +148,16 @@ pub(crate) fn text_mesh_from_data(data: (Vec<Vec3A>, BoundingBox)) -> MeshText {
 /// Arguments:
 ///
 /// * `data`: The internal data from the cache or freshly generated.
+/// * `advance`: The horizontal advance of the text.
 ///
 /// Returns:
 ///
 /// The corresponding [MeshText].
-pub(crate) fn text_mesh_from_data_2d(data: (Vec<Vec2>, BoundingBox)) -> MeshText {
+pub(crate) fn text_mesh_from_data_2d(data: (Vec<Vec2>, BoundingBox), advance: f32) -> MeshText {
     MeshText {
+        advance,
         bbox: data.1,
+        two_dimensional: true,
         vertices: glam_vecs_to_raw_2d(&data.0),
     }
 }
@@ -52,16 +172,20 @@ pub(crate) fn text_mesh_from_data_2d(data: (Vec<Vec2>, BoundingBox)) -> MeshText
 /// Arguments:
 ///
 /// * `data`: The internal data from the cache or freshly generated.
+/// * `advance`: The horizontal advance of the text.
 ///
 /// Returns:
 ///
 /// The corresponding [IndexedMeshText].
 pub(crate) fn text_mesh_from_data_indexed(
     data: (Vec<u32>, Vec<Vec3A>, BoundingBox),
+    advance: f32,
 ) -> IndexedMeshText {
     IndexedMeshText {
+        advance,
         bbox: data.2,
         indices: data.0,
+        two_dimensional: false,
         vertices: glam_vecs_to_raw(&data.1),
     }
 }
@@ -77,16 +201,20 @@ pub(crate) fn text_mesh_from_data_indexed(
 /// Arguments:
 ///
 /// * `data`: The internal data from the cache or freshly generated.
+/// * `advance`: The horizontal advance of the text.
 ///
 /// Returns:
 ///
 /// The corresponding [IndexedMeshText].
 pub(crate) fn text_mesh_from_data_indexed_2d(
     data: (Vec<u32>, Vec<Vec2>, BoundingBox),
+    advance: f32,
 ) -> IndexedMeshText {
     IndexedMeshText {
+        advance,
         bbox: data.2,
         indices: data.0,
+        two_dimensional: true,
         vertices: glam_vecs_to_raw_2d(&data.1),
     }
 }
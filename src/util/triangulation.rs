@@ -12,22 +12,36 @@ const LOWER_Z: f32 = -0.5;
 /// Arguments:
 ///
 /// * `vertices`: The [Vec] of vertices to which the vertices will be appended.
-/// * `points`: The points on which the edges are defined.
+/// * `points`: The points on which the edges of the front cap are defined.
+/// * `back_points`: The points on which the edges of the back cap are defined.
 /// * `edges`: The indices of points that form closed paths.
 pub(crate) fn triangulate_between_edges(
     vertices: &mut Vec<Vec3A>,
     points: &[(f32, f32)],
+    back_points: &[(f32, f32)],
     edges: &[(usize, usize)],
 ) {
     for edge in edges.iter() {
         // First triangle.
         vertices.push(Vec3A::new(points[edge.0].0, points[edge.0].1, UPPER_Z));
         vertices.push(Vec3A::new(points[edge.1].0, points[edge.1].1, UPPER_Z));
-        vertices.push(Vec3A::new(points[edge.0].0, points[edge.0].1, LOWER_Z));
+        vertices.push(Vec3A::new(
+            back_points[edge.0].0,
+            back_points[edge.0].1,
+            LOWER_Z,
+        ));
 
         // Second triangle.
-        vertices.push(Vec3A::new(points[edge.1].0, points[edge.1].1, LOWER_Z));
-        vertices.push(Vec3A::new(points[edge.0].0, points[edge.0].1, LOWER_Z));
+        vertices.push(Vec3A::new(
+            back_points[edge.1].0,
+            back_points[edge.1].1,
+            LOWER_Z,
+        ));
+        vertices.push(Vec3A::new(
+            back_points[edge.0].0,
+            back_points[edge.0].1,
+            LOWER_Z,
+        ));
         vertices.push(Vec3A::new(points[edge.1].0, points[edge.1].1, UPPER_Z));
     }
 }
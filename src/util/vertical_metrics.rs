@@ -0,0 +1,38 @@
+use ttf_parser::Face;
+
+use crate::MetricsSource;
+
+/// Reads the vertical metrics of a font from the given source.
+///
+/// Arguments:
+///
+/// * `face`: The font.
+/// * `source`: The table from which the metrics are read.
+///
+/// Returns:
+///
+/// The ascender, descender and line gap in font units or `None`
+/// if the font does not contain the required table.
+pub(crate) fn vertical_metrics_of_face(
+    face: &Face,
+    source: MetricsSource,
+) -> Option<(i16, i16, i16)> {
+    match source {
+        MetricsSource::Auto => Some((face.ascender(), face.descender(), face.line_gap())),
+        MetricsSource::Hhea => {
+            let hhea = face.tables().hhea;
+            Some((hhea.ascender, hhea.descender, hhea.line_gap))
+        }
+        MetricsSource::Typographic => face.tables().os2.map(|os2| {
+            (
+                os2.typographic_ascender(),
+                os2.typographic_descender(),
+                os2.typographic_line_gap(),
+            )
+        }),
+        MetricsSource::Windows => face
+            .tables()
+            .os2
+            .map(|os2| (os2.windows_ascender(), os2.windows_descender(), 0)),
+    }
+}
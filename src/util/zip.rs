@@ -0,0 +1,134 @@
+use std::io::{self, Write};
+
+/// The size of a local file header without the file name and the extra field.
+const LOCAL_HEADER_SIZE: usize = 30;
+
+/// The id of the extra field that is used to align the file data.
+const PADDING_EXTRA_ID: u16 = 0x1986;
+
+/// The date of all files, which is the 1st of January 1980 in the MS-DOS format.
+const DOS_DATE: u16 = 0x0021;
+
+/// Writes an uncompressed zip archive.
+///
+/// Arguments:
+///
+/// * `writer`: The writer to which the archive is written.
+/// * `files`: The name and the content of each file in the archive.
+/// * `alignment`: The alignment of the content of each file relative to the
+///   start of the archive in bytes, or `1` if the content is not aligned.
+///
+/// Returns:
+///
+/// An [io::Error] if the archive could not be written.
+pub(crate) fn write_stored_zip<W: Write>(
+    mut writer: W,
+    files: &[(&str, &[u8])],
+    alignment: usize,
+) -> io::Result<()> {
+    let mut offset = 0usize;
+    let mut central_directory = Vec::new();
+
+    for (name, data) in files.iter() {
+        let crc = crc32(data);
+        let size = u32::try_from(data.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "file too large for zip"))?;
+
+        // Pad the extra field, so that the data starts at the desired alignment.
+        let unaligned = offset + LOCAL_HEADER_SIZE + name.len();
+        let mut padding = (alignment - unaligned % alignment) % alignment;
+        if padding > 0 && padding < 4 {
+            padding += alignment;
+        }
+        let mut extra = Vec::new();
+        if padding > 0 {
+            extra.extend_from_slice(&PADDING_EXTRA_ID.to_le_bytes());
+            extra.extend_from_slice(&(padding as u16 - 4).to_le_bytes());
+            extra.resize(padding, 0);
+        }
+
+        let mut header = Vec::with_capacity(LOCAL_HEADER_SIZE + name.len() + extra.len());
+        header.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        header.extend_from_slice(&file_fields(
+            crc,
+            size,
+            name.len() as u16,
+            extra.len() as u16,
+        ));
+        header.extend_from_slice(name.as_bytes());
+        header.extend_from_slice(&extra);
+        writer.write_all(&header)?;
+        writer.write_all(data)?;
+
+        central_directory.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        central_directory.extend_from_slice(&file_fields(crc, size, name.len() as u16, 0));
+        // Comment length, disk number and internal and external attributes.
+        central_directory.extend_from_slice(&[0u8; 10]);
+        central_directory.extend_from_slice(&(offset as u32).to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+
+        offset += header.len() + data.len();
+    }
+    writer.write_all(&central_directory)?;
+
+    let mut end = Vec::with_capacity(22);
+    end.extend_from_slice(&0x06054b50u32.to_le_bytes());
+    end.extend_from_slice(&[0u8; 4]);
+    end.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    end.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    end.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+    end.extend_from_slice(&(offset as u32).to_le_bytes());
+    end.extend_from_slice(&0u16.to_le_bytes());
+    writer.write_all(&end)
+}
+
+/// Encodes the fields that are shared by the local file header and
+/// the central directory header.
+///
+/// Arguments:
+///
+/// * `crc`: The CRC-32 checksum of the file.
+/// * `size`: The size of the file.
+/// * `name_length`: The length of the file name in bytes.
+/// * `extra_length`: The length of the extra field in bytes.
+///
+/// Returns:
+///
+/// The encoded fields starting with the version needed to extract the file.
+fn file_fields(crc: u32, size: u32, name_length: u16, extra_length: u16) -> [u8; 26] {
+    let mut fields = [0u8; 26];
+    fields[0..2].copy_from_slice(&20u16.to_le_bytes());
+    // The flags, the compression method and the modification time are zero.
+    fields[8..10].copy_from_slice(&DOS_DATE.to_le_bytes());
+    fields[10..14].copy_from_slice(&crc.to_le_bytes());
+    fields[14..18].copy_from_slice(&size.to_le_bytes());
+    fields[18..22].copy_from_slice(&size.to_le_bytes());
+    fields[22..24].copy_from_slice(&name_length.to_le_bytes());
+    fields[24..26].copy_from_slice(&extra_length.to_le_bytes());
+    fields
+}
+
+/// Computes the CRC-32 checksum used by zip archives.
+///
+/// Arguments:
+///
+/// * `data`: The data of which the checksum is computed.
+///
+/// Returns:
+///
+/// The checksum of the data.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFFFFFFu32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,75 @@
+#![cfg(any(feature = "kurbo", feature = "lyon"))]
+
+use meshtext::{Glyph, MeshGenerator, MeshText};
+
+/// Test if the outline of a glyph exported as a [kurbo::BezPath]
+/// spans the same area as the generated mesh.
+#[test]
+#[cfg(feature = "kurbo")]
+fn test_glyph_bez_path() {
+    use kurbo::{PathEl, Shape};
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let path = generator
+        .glyph_bez_path('O')
+        .expect("The glyph has an outline.");
+    let mesh: MeshText = generator
+        .generate_glyph('O', true, None)
+        .expect("Failed to generate text mesh for character O.");
+
+    let contours = path
+        .elements()
+        .iter()
+        .filter(|e| matches!(e, PathEl::MoveTo(_)))
+        .count();
+    assert_eq!(contours, 2);
+    assert!(path
+        .elements()
+        .iter()
+        .any(|e| matches!(e, PathEl::QuadTo(..) | PathEl::CurveTo(..))));
+
+    let bbox = path.bounding_box();
+    assert!((bbox.x0 as f32 - mesh.bbox.min.x).abs() < 1e-3);
+    assert!((bbox.y0 as f32 - mesh.bbox.min.y).abs() < 1e-3);
+    assert!((bbox.x1 as f32 - mesh.bbox.max.x).abs() < 1e-3);
+    assert!((bbox.y1 as f32 - mesh.bbox.max.y).abs() < 1e-3);
+}
+
+/// Test if the outline of a glyph exported as a [lyon_path::Path]
+/// spans the same area as the generated mesh.
+#[test]
+#[cfg(feature = "lyon")]
+fn test_glyph_lyon_path() {
+    use lyon_path::{Event, PathEvent};
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let path = generator
+        .glyph_lyon_path('H')
+        .expect("The glyph has an outline.");
+    let mesh: MeshText = generator
+        .generate_glyph('H', true, None)
+        .expect("Failed to generate text mesh for character H.");
+
+    let mut contours = 0;
+    let (mut min, mut max) = (glam::Vec2::MAX, glam::Vec2::MIN);
+    for event in path.iter() {
+        match event {
+            PathEvent::Begin { .. } => contours += 1,
+            PathEvent::End { close, .. } => assert!(close),
+            _ => {}
+        }
+        if let Event::Line { to, .. } = event {
+            min = min.min(glam::Vec2::new(to.x, to.y));
+            max = max.max(glam::Vec2::new(to.x, to.y));
+        }
+    }
+    assert_eq!(contours, 1);
+    assert!((min.x - mesh.bbox.min.x).abs() < 1e-3);
+    assert!((min.y - mesh.bbox.min.y).abs() < 1e-3);
+    assert!((max.x - mesh.bbox.max.x).abs() < 1e-3);
+    assert!((max.y - mesh.bbox.max.y).abs() < 1e-3);
+}
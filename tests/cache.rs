@@ -0,0 +1,418 @@
+use glam::Vec2;
+use meshtext::{
+    Glyph, IndexedMeshText, IndexedMeshTextGlam, MeshGenerator, MeshText, MeshTextGlam,
+    QualitySettings, TextSection,
+};
+
+/// Test if changing the quality takes effect for glyphs that
+/// have already been cached with a different quality.
+#[test]
+fn test_cache_quality() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let default: MeshText = generator
+        .generate_glyph('O', true, None)
+        .expect("Failed to generate text mesh for character O.");
+
+    generator.set_quality(QualitySettings {
+        quad_interpolation_steps: 10,
+        cubic_interpolation_steps: 6,
+    });
+    let detailed: MeshText = generator
+        .generate_glyph('O', true, None)
+        .expect("Failed to generate text mesh for character O.");
+    assert!(detailed.vertices.len() > default.vertices.len());
+
+    generator.set_quality(QualitySettings::default());
+    let restored: MeshText = generator
+        .generate_glyph('O', true, None)
+        .expect("Failed to generate text mesh for character O.");
+    assert_eq!(restored.vertices, default.vertices);
+}
+
+/// Test if imported glyphs are used instead of
+/// triangulating the glyphs again.
+#[test]
+fn test_cache_export_import() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut baker = MeshGenerator::new(font_data);
+    baker
+        .precache_glyphs("A", true, None)
+        .expect("Failed to precache glyphs.");
+
+    let mut cache = baker.export_cache();
+    assert_eq!(cache.normal.len(), 1);
+    assert_eq!(cache.indexed.len(), 1);
+    assert!(cache.indexed[0].flat);
+    assert_eq!(cache.indexed[0].mesh.advance, 0.5);
+
+    // Mark the imported glyph, so that it can be recognized.
+    cache.indexed[0].mesh.vertices[0] = 42.0;
+
+    let mut generator = MeshGenerator::new(font_data);
+    generator.import_cache(&cache);
+    let glyph: IndexedMeshText = generator
+        .generate_glyph('A', true, None)
+        .expect("Failed to generate text mesh for character A.");
+    assert_eq!(glyph.vertices[0], 42.0);
+}
+
+/// Test if an archived cache can be accessed without deserialization.
+#[test]
+#[cfg(feature = "rkyv")]
+fn test_cache_archive() {
+    use meshtext::GlyphCache;
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut baker = MeshGenerator::new(font_data);
+    baker
+        .precache_glyphs("AB", false, None)
+        .expect("Failed to precache glyphs.");
+    let cache = baker.export_cache();
+
+    let bytes = rkyv::to_bytes::<_, 1024>(&cache).expect("Failed to archive cache.");
+    let archived = unsafe { rkyv::archived_root::<GlyphCache>(&bytes) };
+    assert_eq!(archived.indexed.len(), 2);
+    for (glyph, archived_glyph) in cache.indexed.iter().zip(archived.indexed.iter()) {
+        assert_eq!(
+            glyph.mesh.vertices.as_slice(),
+            archived_glyph.mesh.vertices.as_slice()
+        );
+        assert_eq!(glyph.mesh.bbox.max, archived_glyph.mesh.bbox.max);
+    }
+
+    let restored: GlyphCache = rkyv::Deserialize::deserialize(archived, &mut rkyv::Infallible)
+        .expect("Failed to deserialize cache.");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.import_cache(&restored);
+}
+
+/// Test if triangulating the glyphs of a section in parallel
+/// yields the same meshes as the sequential triangulation.
+#[test]
+#[cfg(feature = "rayon")]
+fn test_cache_parallel_triangulation() {
+    use meshtext::TextSection;
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let text = "The quick brown fox jumps over the lazy dog.";
+    let mut parallel = MeshGenerator::new(font_data);
+    let mut sequential = MeshGenerator::new_without_cache(font_data, QualitySettings::default());
+
+    for flat in [true, false] {
+        let expected: IndexedMeshText = sequential
+            .generate_section(text, flat, None)
+            .expect("Failed to generate text section.");
+        let actual: IndexedMeshText = parallel
+            .generate_section(text, flat, None)
+            .expect("Failed to generate text section.");
+        assert_eq!(actual.indices, expected.indices);
+        assert_eq!(actual.vertices, expected.vertices);
+
+        let expected: MeshText = sequential
+            .generate_section(text, flat, None)
+            .expect("Failed to generate text section.");
+        let actual: MeshText = parallel
+            .generate_section(text, flat, None)
+            .expect("Failed to generate text section.");
+        assert_eq!(actual.vertices, expected.vertices);
+    }
+}
+
+/// Test if cached glyphs can be read without generating missing glyphs.
+#[test]
+fn test_cache_peek() {
+    use meshtext::CacheType;
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    assert!(generator
+        .peek_glyph('A', false, CacheType::Normal)
+        .is_none());
+
+    let generated: MeshText = generator
+        .generate_glyph('A', false, None)
+        .expect("Failed to generate text mesh for character A.");
+    let peeked = generator
+        .peek_glyph('A', false, CacheType::Normal)
+        .expect("Failed to find the cached glyph.");
+    assert!(peeked.indices.is_none());
+    assert_eq!(peeked.bbox, generated.bbox);
+    let vertices: Vec<f32> = peeked.vertices.iter().flat_map(|v| v.to_array()).collect();
+    assert_eq!(vertices, generated.vertices);
+
+    // Peeking must neither generate the glyph nor read the other caches.
+    assert!(generator.peek_glyph('A', true, CacheType::Normal).is_none());
+    assert!(generator
+        .peek_glyph('A', false, CacheType::Indexed)
+        .is_none());
+    assert!(generator
+        .peek_glyph('B', false, CacheType::Normal)
+        .is_none());
+}
+
+/// Test if evicted glyphs are removed from both caches,
+/// while the remaining glyphs stay cached.
+#[test]
+fn test_cache_evict() {
+    use meshtext::CacheType;
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator
+        .precache_glyphs("abc", true, None)
+        .expect("Failed to precache glyphs.");
+    generator
+        .precache_glyphs("abc", false, None)
+        .expect("Failed to precache glyphs.");
+
+    generator.evict_glyph('a');
+    for cache_type in [CacheType::Normal, CacheType::Indexed] {
+        for flat in [true, false] {
+            assert!(generator.peek_glyph('a', flat, cache_type).is_none());
+            assert!(generator.peek_glyph('b', flat, cache_type).is_some());
+        }
+    }
+
+    generator.evict_glyphs("bc");
+    let cache = generator.export_cache();
+    assert!(cache.normal.is_empty());
+    assert!(cache.indexed.is_empty());
+
+    // Evicted glyphs are generated again on demand.
+    let _: IndexedMeshText = generator
+        .generate_glyph('a', true, None)
+        .expect("Failed to generate text mesh for character a.");
+    assert!(generator
+        .peek_glyph('a', true, CacheType::Indexed)
+        .is_some());
+
+    generator.clear_cache();
+    assert!(generator
+        .peek_glyph('a', true, CacheType::Indexed)
+        .is_none());
+}
+
+/// Test if glyphs that have not been used within the
+/// time to live are evicted on the next generation call.
+#[test]
+fn test_cache_ttl() {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use meshtext::CacheType;
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let time = Arc::new(Mutex::new(Instant::now()));
+    let clock = time.clone();
+    generator.set_cache_clock(move || *clock.lock().unwrap());
+    let advance = |seconds: u64| *time.lock().unwrap() += Duration::from_secs(seconds);
+
+    generator
+        .precache_glyphs("a", true, None)
+        .expect("Failed to precache glyphs.");
+    generator.set_cache_ttl(Some(Duration::from_secs(2)));
+
+    advance(1);
+    let _: IndexedMeshText = generator
+        .generate_glyph('b', true, None)
+        .expect("Failed to generate text mesh for character b.");
+    assert!(generator
+        .peek_glyph('a', true, CacheType::Indexed)
+        .is_some());
+
+    advance(1);
+    let _: IndexedMeshText = generator
+        .generate_glyph('c', true, None)
+        .expect("Failed to generate text mesh for character c.");
+
+    // Only the glyph that was cached before the first step expired.
+    assert!(generator
+        .peek_glyph('a', true, CacheType::Indexed)
+        .is_none());
+    assert!(generator.peek_glyph('a', true, CacheType::Normal).is_none());
+    assert!(generator
+        .peek_glyph('b', true, CacheType::Indexed)
+        .is_some());
+    assert!(generator
+        .peek_glyph('c', true, CacheType::Indexed)
+        .is_some());
+
+    // Without a time to live the glyphs are kept.
+    generator.set_cache_ttl(None);
+    advance(10);
+    let _: IndexedMeshText = generator
+        .generate_glyph('d', true, None)
+        .expect("Failed to generate text mesh for character d.");
+    assert!(generator
+        .peek_glyph('b', true, CacheType::Indexed)
+        .is_some());
+}
+
+/// Test if glyphs expire with the system clock if there is no custom clock.
+#[test]
+fn test_cache_ttl_system_clock() {
+    use std::time::Duration;
+
+    use meshtext::CacheType;
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_cache_ttl(Some(Duration::from_millis(20)));
+    generator
+        .precache_glyphs("a", true, None)
+        .expect("Failed to precache glyphs.");
+
+    std::thread::sleep(Duration::from_millis(50));
+    let _: IndexedMeshText = generator
+        .generate_glyph('b', true, None)
+        .expect("Failed to generate text mesh for character b.");
+    assert!(generator
+        .peek_glyph('a', true, CacheType::Indexed)
+        .is_none());
+}
+
+/// Test if the policies of both caches are applied independently.
+#[test]
+fn test_cache_policy() {
+    use meshtext::{CachePolicy, CacheType};
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_cache_policy(CacheType::Normal, CachePolicy::Disabled);
+    generator.set_cache_policy(CacheType::Indexed, CachePolicy::Limited(2));
+
+    for c in ['a', 'b', 'c'] {
+        let _: MeshText = generator
+            .generate_glyph(c, true, None)
+            .expect("Failed to generate text mesh.");
+        let _: IndexedMeshText = generator
+            .generate_glyph(c, true, None)
+            .expect("Failed to generate text mesh.");
+    }
+    assert!(generator.export_cache().normal.is_empty());
+    assert!(generator
+        .peek_glyph('a', true, CacheType::Indexed)
+        .is_none());
+    assert!(generator
+        .peek_glyph('b', true, CacheType::Indexed)
+        .is_some());
+    assert!(generator
+        .peek_glyph('c', true, CacheType::Indexed)
+        .is_some());
+
+    // The least recently used glyph is evicted.
+    let _: IndexedMeshText = generator
+        .generate_glyph('b', true, None)
+        .expect("Failed to generate text mesh.");
+    let _: IndexedMeshText = generator
+        .generate_glyph('d', true, None)
+        .expect("Failed to generate text mesh.");
+    assert!(generator
+        .peek_glyph('b', true, CacheType::Indexed)
+        .is_some());
+    assert!(generator
+        .peek_glyph('c', true, CacheType::Indexed)
+        .is_none());
+    assert!(generator
+        .peek_glyph('d', true, CacheType::Indexed)
+        .is_some());
+
+    generator.set_cache_policy(CacheType::Indexed, CachePolicy::Limited(1));
+    assert_eq!(generator.export_cache().indexed.len(), 1);
+
+    let mut uncached = MeshGenerator::new_without_cache(font_data, QualitySettings::default());
+    uncached
+        .precache_glyphs("abc", true, None)
+        .expect("Failed to precache glyphs.");
+    let cache = uncached.export_cache();
+    assert!(cache.normal.is_empty());
+    assert!(cache.indexed.is_empty());
+
+    // Limited caches share the triangulations of identical outlines as well.
+    let limited: IndexedMeshText = generator
+        .generate_glyph('a', true, None)
+        .expect("Failed to generate text mesh.");
+    let unshared: IndexedMeshText = uncached
+        .generate_glyph('a', true, None)
+        .expect("Failed to generate text mesh.");
+    assert_eq!(limited.vertices, unshared.vertices);
+    assert_eq!(limited.indices, unshared.indices);
+}
+
+/// Test if the glam output types contain the same vertices
+/// as the flattened output types.
+#[test]
+fn test_cache_glam_output() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let flattened: MeshText = generator
+        .generate_section("Glam", false, None)
+        .expect("Failed to generate mesh.");
+    let glam: MeshTextGlam = generator
+        .generate_section("Glam", false, None)
+        .expect("Failed to generate mesh.");
+    let vertices: Vec<f32> = glam.vertices.iter().flat_map(|v| v.to_array()).collect();
+    assert_eq!(vertices, flattened.vertices);
+    assert_eq!(glam.bbox, flattened.bbox);
+    assert_eq!(glam.advance, flattened.advance);
+
+    let flattened: IndexedMeshText = generator
+        .generate_glyph_2d('g', None)
+        .expect("Failed to generate mesh.");
+    let glam: IndexedMeshTextGlam<Vec2> = generator
+        .generate_glyph_2d('g', None)
+        .expect("Failed to generate mesh.");
+    let vertices: Vec<f32> = glam.vertices.iter().flat_map(|v| v.to_array()).collect();
+    assert_eq!(vertices, flattened.vertices);
+    assert_eq!(glam.indices, flattened.indices);
+
+    let glam: IndexedMeshTextGlam<Vec2, u16> = generator
+        .generate_glyph_2d('g', None)
+        .expect("Failed to generate mesh.");
+    let indices: Vec<u32> = glam.indices.iter().map(|i| *i as u32).collect();
+    assert_eq!(indices, flattened.indices);
+
+    // Two-dimensional vertices can not hold extruded text.
+    let result: Result<MeshTextGlam<Vec2>, _> = generator.generate_section("Glam", true, None);
+    assert!(result.is_err());
+    let result: Result<IndexedMeshTextGlam<Vec2>, _> = generator.generate_glyph('g', false, None);
+    assert!(result.is_err());
+}
+
+/// Test if statistics are only collected for glyphs that are not cached yet.
+#[test]
+fn test_generation_stats() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    assert_eq!(generator.take_stats(), None);
+
+    generator.set_collect_stats(true);
+    let result: MeshText = generator
+        .generate_glyph('O', true, None)
+        .expect("Failed to generate text mesh for character O.");
+    let stats = generator
+        .take_stats()
+        .expect("Statistics are not collected.");
+    assert_eq!(stats.glyphs, 1);
+    assert_eq!(stats.contours, 2);
+    assert!(stats.points > 0);
+    assert_eq!(stats.triangles * 9, result.vertices.len());
+
+    let _: MeshText = generator
+        .generate_glyph('O', true, None)
+        .expect("Failed to generate text mesh for character O.");
+    let stats = generator
+        .take_stats()
+        .expect("Statistics are not collected.");
+    assert_eq!(stats.glyphs, 0);
+    assert_eq!(stats.triangles, 0);
+
+    generator.set_collect_stats(false);
+    let _: MeshText = generator
+        .generate_glyph('P', true, None)
+        .expect("Failed to generate text mesh for character P.");
+    assert_eq!(generator.take_stats(), None);
+}
@@ -0,0 +1,50 @@
+use meshtext::{Glyph, MeshGenerator, MeshText};
+
+/// Checks wether a point lies within any triangle of a two-dimensional mesh.
+fn covers(mesh: &MeshText, x: f32, y: f32) -> bool {
+    mesh.vertices.chunks(6).any(|t| {
+        let side = |i: usize, j: usize| {
+            (t[2 * j] - t[2 * i]) * (y - t[2 * i + 1])
+                - (t[2 * j + 1] - t[2 * i + 1]) * (x - t[2 * i])
+        };
+        let (a, b, c) = (side(0, 1), side(1, 2), side(2, 0));
+        (a >= 0.0 && b >= 0.0 && c >= 0.0) || (a <= 0.0 && b <= 0.0 && c <= 0.0)
+    })
+}
+
+/// Test if the median of the distance field agrees with the
+/// triangulated glyph, except close to the outline.
+#[test]
+fn test_msdf_matches_mesh() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    for character in ['A', 'g', '8'] {
+        let mesh: MeshText = generator
+            .generate_glyph_2d(character, None)
+            .expect("Failed to generate mesh.");
+        let field = generator.generate_glyph_msdf(character, 64.0, 0.05);
+        assert!(field.width > 0 && field.height > 0);
+        assert_eq!(field.data.len(), field.width * field.height * 3);
+
+        let (mut checked, mut mismatched) = (0, 0);
+        for row in 0..field.height {
+            for column in 0..field.width {
+                let distance = field.distance(column, row).unwrap();
+                if distance.abs() < 2.0 * field.pixel_size {
+                    continue;
+                }
+
+                let x = field.origin.x + (column as f32 + 0.5) * field.pixel_size;
+                let y = field.origin.y + ((field.height - row) as f32 - 0.5) * field.pixel_size;
+                checked += 1;
+                if (distance > 0.0) != covers(&mesh, x, y) {
+                    mismatched += 1;
+                }
+            }
+        }
+
+        assert!(checked > 100);
+        assert!(mismatched * 100 < checked, "{} of {}", mismatched, checked);
+    }
+}
@@ -0,0 +1,117 @@
+#![cfg(any(feature = "3mf", feature = "usdz"))]
+
+#[cfg(feature = "usdz")]
+use meshtext::export::write_usdz;
+#[cfg(feature = "3mf")]
+use meshtext::export::{write_3mf, write_3mf_colored, ModelUnit};
+use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+
+/// Reads the stored files of an uncompressed zip archive.
+#[cfg(feature = "3mf")]
+fn stored_files(package: &[u8]) -> Vec<(String, String)> {
+    let mut files = Vec::new();
+    let mut offset = 0;
+    while package[offset..offset + 4] == *b"PK\x03\x04" {
+        let read_u16 = |at: usize| u16::from_le_bytes([package[at], package[at + 1]]) as usize;
+        let size =
+            u32::from_le_bytes(package[offset + 18..offset + 22].try_into().unwrap()) as usize;
+        let (name_length, extra_length) = (read_u16(offset + 26), read_u16(offset + 28));
+        let start = offset + 30 + name_length + extra_length;
+        let name = std::str::from_utf8(&package[offset + 30..offset + 30 + name_length]).unwrap();
+        let content = std::str::from_utf8(&package[start..start + size]).unwrap();
+        files.push((name.to_owned(), content.to_owned()));
+        offset = start + size;
+    }
+    files
+}
+
+/// Test if a 3MF package contains the parts
+/// required by the specification and the mesh.
+#[test]
+#[cfg(feature = "3mf")]
+fn test_3mf_package() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: IndexedMeshText = generator
+        .generate_section("Hi!", false, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    let mut package = Vec::new();
+    write_3mf(&mesh, ModelUnit::Centimeter, &mut package).expect("Failed to write 3MF package.");
+
+    let files = stored_files(&package);
+    let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["[Content_Types].xml", "_rels/.rels", "3D/3dmodel.model"]
+    );
+
+    let model = &files[2].1;
+    assert!(model.contains(r#"unit="centimeter""#));
+    assert!(!model.contains("basematerials"));
+    assert_eq!(model.matches("<vertex ").count(), mesh.vertices.len() / 3);
+    assert_eq!(model.matches("<triangle ").count(), mesh.indices.len() / 3);
+}
+
+/// Test if each mesh of a colored 3MF package
+/// becomes an object with its own color.
+#[test]
+#[cfg(feature = "3mf")]
+fn test_3mf_colors() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let first: IndexedMeshText = generator
+        .generate_section("H", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let second: IndexedMeshText = generator
+        .generate_section("i", false, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    let mut package = Vec::new();
+    write_3mf_colored(
+        &[(&first, [1.0, 0.0, 0.0]), (&second, [0.0, 0.0, 1.0])],
+        ModelUnit::default(),
+        &mut package,
+    )
+    .expect("Failed to write 3MF package.");
+
+    let model = &stored_files(&package)[2].1;
+    assert!(model.contains(r#"unit="millimeter""#));
+    assert!(model.contains(r##"displaycolor="#FF0000""##));
+    assert!(model.contains(r##"displaycolor="#0000FF""##));
+    assert!(model.contains(r#"<object id="3" type="model" pid="1" pindex="1">"#));
+    assert_eq!(model.matches("<item ").count(), 2);
+}
+
+/// Test if an USDZ package is an uncompressed zip archive
+/// with the stage aligned to 64 bytes.
+#[test]
+#[cfg(feature = "usdz")]
+fn test_usdz_package() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: IndexedMeshText = generator
+        .generate_section("Hi!", false, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    let mut package = Vec::new();
+    write_usdz(&mesh, [1.0, 0.5, 0.0], &mut package).expect("Failed to write USDZ package.");
+
+    assert_eq!(&package[0..4], b"PK\x03\x04");
+    let name_length = u16::from_le_bytes([package[26], package[27]]) as usize;
+    let extra_length = u16::from_le_bytes([package[28], package[29]]) as usize;
+    let start = 30 + name_length + extra_length;
+    assert_eq!(start % 64, 0);
+
+    let size = u32::from_le_bytes(package[18..22].try_into().unwrap()) as usize;
+    let stage = std::str::from_utf8(&package[start..start + size]).expect("Invalid stage.");
+    assert!(stage.starts_with("#usda 1.0"));
+    assert!(stage.contains("UsdPreviewSurface"));
+    assert!(stage.contains(&format!(
+        "[{}]",
+        vec!["3"; mesh.indices.len() / 3].join(", ")
+    )));
+
+    let end = package.len() - 22;
+    assert_eq!(&package[end..end + 4], b"PK\x05\x06");
+}
@@ -0,0 +1,265 @@
+use meshtext::{
+    Bend, ExtrusionSettings, Glyph, IndexedMeshText, MeshGenerator, MeshPart, MeshText, Paragraph,
+    PartitionedMeshText, TextSection, TriangleMesh,
+};
+
+/// Test if scaling the back cap produces a frustum-like glyph whose
+/// volume matches the analytical volume of a frustum.
+#[test]
+fn test_back_cap_scale_volume() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let flat: MeshText = generator
+        .generate_glyph('H', true, None)
+        .expect("Failed to generate text mesh for character H.");
+
+    generator.set_extrusion_settings(ExtrusionSettings {
+        back_cap_scale: 0.5,
+    });
+    let mesh: MeshText = generator
+        .generate_glyph('H', false, None)
+        .expect("Failed to generate text mesh for character H.");
+    let indexed_mesh: IndexedMeshText = generator
+        .generate_glyph('H', false, None)
+        .expect("Failed to generate indexed text mesh for character H.");
+
+    // The volume of a frustum is `h / 3 * (A1 + A2 + sqrt(A1 * A2))`.
+    let area = flat.surface_area();
+    let expected = (area + area * 0.25 + area * 0.5) / 3f32;
+
+    assert!((mesh.volume().unwrap() - expected).abs() < 1e-4);
+    assert!((indexed_mesh.volume().unwrap() - expected).abs() < 1e-4);
+}
+
+/// Test if the triangles of a partitioned section are grouped into
+/// caps facing the right direction and the side walls.
+#[test]
+fn test_partitioned_section() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let indexed_mesh: IndexedMeshText = generator
+        .generate_section("Hi!", false, None)
+        .expect("Failed to generate indexed text mesh for text section.");
+    let mesh: PartitionedMeshText = generator
+        .generate_section("Hi!", false, None)
+        .expect("Failed to generate partitioned text mesh for text section.");
+
+    assert_eq!(mesh.indices.len(), indexed_mesh.indices.len());
+    assert_eq!(mesh.side_walls.end, mesh.indices.len());
+    assert_eq!(
+        mesh.part(MeshPart::FrontCap).len(),
+        mesh.part(MeshPart::BackCap).len()
+    );
+    assert!(!mesh.part(MeshPart::SideWall).is_empty());
+
+    let z = |i: &u32| mesh.vertices[*i as usize * 3 + 2];
+    assert!(mesh.part(MeshPart::FrontCap).iter().all(|i| z(i) == 0.5));
+    assert!(mesh.part(MeshPart::BackCap).iter().all(|i| z(i) == -0.5));
+
+    let flat: PartitionedMeshText = generator
+        .generate_section("Hi!", true, None)
+        .expect("Failed to generate partitioned text mesh for text section.");
+    assert_eq!(flat.front_cap, 0..flat.indices.len());
+    assert!(flat.part(MeshPart::SideWall).is_empty());
+}
+
+/// Test if the depth callback scales each glyph of a section
+/// and of a paragraph individually.
+#[test]
+fn test_depth_for() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_depth_for(|index, c| if c == 'i' { 3.0 } else { index as f32 + 1.0 });
+
+    let mesh: MeshText = generator
+        .generate_section("Hi", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let indexed_mesh: IndexedMeshText = generator
+        .generate_section("HH", false, None)
+        .expect("Failed to generate indexed text mesh for text section.");
+    assert_eq!(mesh.bbox.max.z, 1.5);
+    assert_eq!(mesh.bbox.min.z, -1.5);
+    assert_eq!(indexed_mesh.bbox.max.z, 1.0);
+
+    // Only the first glyph keeps the default depth.
+    let near_origin = indexed_mesh
+        .vertices
+        .chunks(3)
+        .filter(|v| v[0] < 0.5)
+        .all(|v| v[2].abs() <= 0.5);
+    assert!(near_origin);
+
+    generator.clear_depth_for();
+    let mesh: MeshText = generator
+        .generate_section("Hi", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(mesh.bbox.max.z, 0.5);
+}
+
+/// Test if the depth gradient interpolates the depth along the width
+/// of a section while the caps remain partitioned correctly.
+#[test]
+fn test_depth_gradient() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_depth_gradient(1.0, 3.0);
+
+    let mesh: IndexedMeshText = generator
+        .generate_section("HHHH", false, None)
+        .expect("Failed to generate indexed text mesh for text section.");
+    let advance = mesh.advance;
+    for v in mesh.vertices.chunks(3) {
+        let depth = 1.0 + 2.0 * (v[0] / advance).clamp(0.0, 1.0);
+        assert!((v[2].abs() - depth * 0.5).abs() < 1e-5);
+    }
+    assert!(mesh.bbox.max.z > 1.25 && mesh.bbox.max.z <= 1.5);
+    assert_eq!(mesh.bbox.min.z, -mesh.bbox.max.z);
+
+    let partitioned: PartitionedMeshText = generator
+        .generate_section("HHHH", false, None)
+        .expect("Failed to generate partitioned text mesh for text section.");
+    let z = |i: &u32| partitioned.vertices[*i as usize * 3 + 2];
+    assert!(partitioned
+        .part(MeshPart::FrontCap)
+        .iter()
+        .all(|i| z(i) > 0.0));
+    assert!(partitioned
+        .part(MeshPart::BackCap)
+        .iter()
+        .all(|i| z(i) < 0.0));
+    assert_eq!(
+        partitioned.part(MeshPart::FrontCap).len(),
+        partitioned.part(MeshPart::BackCap).len()
+    );
+
+    generator.clear_depth_gradient();
+    let mesh: MeshText = generator
+        .generate_section("HHHH", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(mesh.bbox.max.z, 0.5);
+}
+
+/// Test if the depth gradient of a paragraph spans its widest line.
+#[test]
+fn test_depth_gradient_paragraph() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_depth_gradient(1.0, 3.0);
+
+    let paragraph = Paragraph::new().with_run("HHHH\nHHHH");
+    let (mesh, _) = generator
+        .generate_paragraph(&paragraph, false, None)
+        .expect("Failed to generate indexed text mesh for paragraph.");
+    assert!(mesh.bbox.max.z > 1.25 && mesh.bbox.max.z <= 1.5);
+}
+
+/// Test if the displacement only offsets the front cap of a section.
+#[test]
+fn test_displacement() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_displacement(|x, y| 0.1 * (x + y));
+
+    let mesh: IndexedMeshText = generator
+        .generate_section("Hi", false, None)
+        .expect("Failed to generate indexed text mesh for text section.");
+    for v in mesh.vertices.chunks(3) {
+        if v[2] < 0.0 {
+            assert_eq!(v[2], -0.5);
+        } else {
+            assert!((v[2] - 0.5 - 0.1 * (v[0] + v[1])).abs() < 1e-5);
+        }
+    }
+
+    let flat: MeshText = generator
+        .generate_section("Hi", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert!(flat.vertices.chunks(3).any(|v| v[2] != 0.0));
+
+    generator.clear_displacement();
+    let mesh: MeshText = generator
+        .generate_section("Hi", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(mesh.bbox.max.z, 0.5);
+}
+
+/// Test if bent sections lie on the surface of the cylinder or sphere
+/// and remain partitioned into the correct caps.
+#[test]
+fn test_bend() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let radius = 2.0f32;
+
+    generator.set_bend(Bend::Cylinder { radius });
+    let flat: IndexedMeshText = generator
+        .generate_section("Label", true, None)
+        .expect("Failed to generate indexed text mesh for text section.");
+    let center = flat.advance * 0.5;
+    for v in flat.vertices.chunks(3) {
+        let distance = ((v[0] - center).powi(2) + (v[2] + radius).powi(2)).sqrt();
+        assert!((distance - radius).abs() < 1e-4);
+    }
+
+    generator.set_bend(Bend::Sphere { radius });
+    let flat: IndexedMeshText = generator
+        .generate_section("Label", true, None)
+        .expect("Failed to generate indexed text mesh for text section.");
+    for v in flat.vertices.chunks(3) {
+        let distance = ((v[0] - center).powi(2) + v[1].powi(2) + (v[2] + radius).powi(2)).sqrt();
+        assert!((distance - radius).abs() < 1e-4);
+    }
+
+    // Strongly bent text would be misclassified by its z-coordinates.
+    generator.set_bend(Bend::Cylinder { radius: 0.5 });
+    let mesh: PartitionedMeshText = generator
+        .generate_section("Label", false, None)
+        .expect("Failed to generate partitioned text mesh for text section.");
+    assert_eq!(
+        mesh.part(MeshPart::FrontCap).len(),
+        mesh.part(MeshPart::BackCap).len()
+    );
+    let distance = |i: &u32| {
+        let v = &mesh.vertices[*i as usize * 3..*i as usize * 3 + 3];
+        ((v[0] - center).powi(2) + (v[2] + 0.5).powi(2)).sqrt()
+    };
+    assert!(mesh
+        .part(MeshPart::FrontCap)
+        .iter()
+        .all(|i| (distance(i) - 1.0).abs() < 1e-4));
+
+    generator.clear_bend();
+    let mesh: MeshText = generator
+        .generate_section("Label", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(mesh.bbox.max.z, 0.5);
+}
+
+/// Test if the provenance of each triangle matches the partitioned mesh
+/// and points to the character that produced it.
+#[test]
+fn test_triangle_provenance() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let mesh: IndexedMeshText = generator
+        .generate_section("a b", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let partitioned: PartitionedMeshText = generator
+        .generate_section("a b", false, None)
+        .expect("Failed to generate partitioned mesh for text section.");
+    let provenance = generator
+        .triangle_provenance("a b", false)
+        .expect("Failed to classify triangles of text section.");
+    assert_eq!(provenance.len(), mesh.indices.len() / 3);
+
+    for part in [MeshPart::FrontCap, MeshPart::BackCap, MeshPart::SideWall] {
+        let count = provenance.iter().filter(|p| p.part == part).count();
+        assert_eq!(count, partitioned.part(part).len() / 3);
+    }
+
+    // The whitespace does not produce any triangles.
+    assert_eq!(provenance.first().unwrap().character, 0);
+    assert_eq!(provenance.last().unwrap().character, 2);
+    assert!(provenance.iter().all(|p| p.character != 1));
+}
@@ -0,0 +1,100 @@
+use glam::Vec2;
+use meshtext::{FontSet, GlyphProvider, IndexedMeshText, MeshGenerator, Script, TextSection};
+
+/// Provides a square for the character `■`.
+struct Squares;
+
+impl GlyphProvider for Squares {
+    fn advance(&self, character: char) -> Option<f32> {
+        (character == '■').then_some(0.8)
+    }
+
+    fn outline(&self, _character: char) -> Vec<Vec<Vec2>> {
+        vec![vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 0.6),
+            Vec2::new(0.6, 0.6),
+            Vec2::new(0.6, 0.0),
+        ]]
+    }
+}
+
+/// Test if each character is generated with a face that supports
+/// it and if the runs of the faces are merged into a single mesh.
+#[test]
+fn test_font_set_routing() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut squares = MeshGenerator::new(font_data);
+    squares.set_glyph_provider(Squares);
+    let mut expected = MeshGenerator::new(font_data);
+    expected.set_glyph_provider(Squares);
+
+    let mut font_set = FontSet::new(MeshGenerator::new(font_data));
+    let index = font_set.add_face(squares);
+    assert_eq!(font_set.face_count(), 2);
+    assert_eq!(font_set.face_of_char('a'), 0);
+    assert_eq!(font_set.face_of_char('■'), index);
+
+    let merged: IndexedMeshText = font_set
+        .generate_section("a■ b", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let whole: IndexedMeshText = expected
+        .generate_section("a■ b", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(merged.advance, whole.advance);
+    assert_eq!(merged.indices, whole.indices);
+    assert_eq!(merged.vertices.len(), whole.vertices.len());
+    for (a, b) in merged.vertices.iter().zip(whole.vertices.iter()) {
+        assert!((a - b).abs() < 1e-5);
+    }
+
+    let merged: IndexedMeshText = font_set
+        .generate_section_2d("a■b", None)
+        .expect("Failed to generate text mesh for text section.");
+    let whole: IndexedMeshText = expected
+        .generate_section_2d("a■b", None)
+        .expect("Failed to generate text mesh for text section.");
+    assert!(merged.two_dimensional);
+    assert_eq!(merged.advance, whole.advance);
+    assert_eq!(merged.indices, whole.indices);
+
+    // Routes only apply to faces that support the character.
+    font_set.add_route('a'..='z', index);
+    font_set.add_route('0'..='9', 5);
+    assert_eq!(font_set.face_of_char('a'), index);
+    assert_eq!(font_set.face_of_char('5'), 0);
+    font_set.clear_routes();
+    assert_eq!(font_set.face_of_char('a'), 0);
+}
+
+/// Test if runs of text are routed by their script and if
+/// unsupported characters use the fallback face.
+#[test]
+fn test_font_set_scripts() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut squares = MeshGenerator::new(font_data);
+    squares.set_glyph_provider(Squares);
+
+    let mut font_set = FontSet::new(MeshGenerator::new(font_data));
+    let index = font_set.add_face(squares);
+    font_set.add_script(Script::Latin, index);
+    assert_eq!(font_set.face_of_char('a'), index);
+    assert_eq!(font_set.face_of_char('1'), 0);
+
+    // Digits stay in the face of the preceding Latin text.
+    let merged: IndexedMeshText = font_set
+        .generate_section("a1", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let whole: IndexedMeshText = font_set
+        .face_mut(index)
+        .expect("Failed to get face.")
+        .generate_section("a1", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(merged.indices, whole.indices);
+
+    assert_eq!(font_set.face_of_char('中'), 0);
+    font_set.set_fallback_face(index);
+    assert_eq!(font_set.face_of_char('中'), index);
+    font_set.set_fallback_face(5);
+    assert_eq!(font_set.face_of_char('中'), 0);
+}
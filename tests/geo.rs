@@ -0,0 +1,49 @@
+#![cfg(feature = "geo")]
+
+use geo::{Area, BoundingRect};
+use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+
+/// Test if the holes of the glyphs are assigned to the contours surrounding them.
+#[test]
+fn test_section_polygons_holes() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let generator = MeshGenerator::new(font_data);
+
+    let polygons = generator.section_polygons("Bi", None);
+    let mut holes: Vec<usize> = polygons.0.iter().map(|p| p.interiors().len()).collect();
+    holes.sort_unstable();
+    assert_eq!(holes, vec![0, 0, 2]);
+}
+
+/// Test if the polygons of a section cover the same area as its 2D mesh.
+#[test]
+fn test_section_polygons_area() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let transform = [2f32, 0f32, 0f32, 0f32, 2f32, 0f32, 1f32, 0f32, 1f32];
+    let polygons = generator.section_polygons("Ag", Some(&transform));
+    let mesh: IndexedMeshText = generator
+        .generate_section_2d("Ag", Some(&transform))
+        .expect("Failed to generate text mesh for text section.");
+
+    let vertex = |i: u32| {
+        let i = i as usize * 2;
+        glam::Vec2::new(mesh.vertices[i], mesh.vertices[i + 1])
+    };
+    let area: f32 = mesh
+        .indices
+        .chunks(3)
+        .map(|t| {
+            (vertex(t[1]) - vertex(t[0]))
+                .perp_dot(vertex(t[2]) - vertex(t[0]))
+                .abs()
+                * 0.5
+        })
+        .sum();
+    assert!((polygons.unsigned_area() - area).abs() < 1e-3);
+
+    let rect = polygons.bounding_rect().expect("The section has outlines.");
+    assert!((rect.min().x - mesh.bbox.min.x).abs() < 1e-4);
+    assert!((rect.max().y - mesh.bbox.max.y).abs() < 1e-4);
+}
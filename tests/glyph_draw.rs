@@ -0,0 +1,67 @@
+use glam::{Mat4, Vec3};
+use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+
+/// Test if drawing the shared glyph meshes with their transformations
+/// reproduces the mesh of the text section.
+#[test]
+fn test_section_draws() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let transform = Mat4::from_scale_rotation_translation(
+        Vec3::splat(2f32),
+        glam::Quat::from_rotation_z(0.3f32),
+        Vec3::new(1f32, -2f32, 3f32),
+    )
+    .to_cols_array();
+    let list = generator
+        .generate_section_draws("a ba b", false, Some(&transform))
+        .expect("Failed to generate draw list.");
+    let mesh: IndexedMeshText = generator
+        .generate_section("a ba b", false, Some(&transform))
+        .expect("Failed to generate text mesh for text section.");
+
+    // The spaces have no mesh and the repeated glyphs are stored once.
+    assert_eq!(list.draws.len(), 4);
+    assert_eq!(
+        list.draws.iter().map(|d| d.character).collect::<Vec<_>>(),
+        vec![0, 2, 3, 5]
+    );
+    assert_eq!(list.draws[0].first_index, list.draws[2].first_index);
+    assert_eq!(list.draws[1].glyph_id, list.draws[3].glyph_id);
+    assert_eq!(
+        list.indices.len() * 2,
+        list.draws
+            .iter()
+            .map(|d| d.index_count as usize)
+            .sum::<usize>()
+    );
+
+    let mut drawn = Vec::new();
+    for draw in list.draws.iter() {
+        let range = draw.first_index as usize..(draw.first_index + draw.index_count) as usize;
+        for i in list.indices[range].iter() {
+            let i = *i as usize * 3;
+            let v = Vec3::new(list.vertices[i], list.vertices[i + 1], list.vertices[i + 2]);
+            drawn.push(draw.transform.transform_point3(v));
+        }
+    }
+    let expected: Vec<Vec3> = mesh
+        .indices
+        .iter()
+        .map(|i| {
+            let i = *i as usize * 3;
+            Vec3::new(mesh.vertices[i], mesh.vertices[i + 1], mesh.vertices[i + 2])
+        })
+        .collect();
+    assert_eq!(drawn.len(), expected.len());
+    for (a, b) in drawn.iter().zip(expected.iter()) {
+        assert!(a.distance(*b) < 1e-4);
+    }
+
+    let (min, max) = drawn.iter().fold((Vec3::MAX, Vec3::MIN), |(min, max), v| {
+        (min.min(*v), max.max(*v))
+    });
+    assert!(Vec3::from(list.bbox.min).distance(min) < 1e-4);
+    assert!(Vec3::from(list.bbox.max).distance(max) < 1e-4);
+}
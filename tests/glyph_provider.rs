@@ -0,0 +1,353 @@
+use std::sync::{Arc, Mutex};
+
+use glam::{Vec2, Vec3A};
+use meshtext::{
+    GenerationWarning, Glyph, GlyphProvider, GlyphSource, IndexedMeshText, MeshGenerator,
+    MeshPostProcessor, MeshText, TextSection, TriangleMesh,
+};
+
+/// Provides a frame with a square hole for the character `▣`.
+struct Frames;
+
+impl GlyphProvider for Frames {
+    fn advance(&self, character: char) -> Option<f32> {
+        (character == '▣').then_some(0.8)
+    }
+
+    fn outline(&self, _character: char) -> Vec<Vec<Vec2>> {
+        vec![
+            // The outer contour is clockwise.
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.0, 0.6),
+                Vec2::new(0.6, 0.6),
+                Vec2::new(0.6, 0.0),
+            ],
+            // The hole is counter-clockwise.
+            vec![
+                Vec2::new(0.2, 0.2),
+                Vec2::new(0.4, 0.2),
+                Vec2::new(0.4, 0.4),
+                Vec2::new(0.2, 0.4),
+            ],
+        ]
+    }
+}
+
+/// Test if custom glyphs replace the glyphs of the font
+/// both in single glyphs and in text sections.
+#[test]
+fn test_custom_glyph() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_glyph_provider(Frames);
+
+    let frame: IndexedMeshText = generator
+        .generate_glyph('▣', false, None)
+        .expect("Failed to generate text mesh for character ▣.");
+    let volume = frame.volume().unwrap();
+    assert!((volume - 0.32).abs() < 1e-4);
+    assert!((frame.bbox.max.x - 0.6).abs() < 1e-6);
+    assert_eq!(frame.advance, 0.8);
+
+    let section: MeshText = generator
+        .generate_section("a▣b", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(section.advance, 1.8);
+    assert_eq!(generator.caret_positions("a▣b")[2].position.x, 1.3);
+
+    // Without the provider the missing glyph of the font is used.
+    generator.remove_glyph_provider();
+    let section: MeshText = generator
+        .generate_section("a▣b", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_ne!(section.advance, 1.8);
+}
+
+/// Test if characters missing from the font are
+/// replaced by the custom fallback mesh.
+#[test]
+fn test_fallback_mesh() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mut marker: IndexedMeshText = generator
+        .generate_glyph('?', false, None)
+        .expect("Failed to generate text mesh for character ?.");
+    marker.advance = 0.7;
+    generator.set_fallback_mesh(&marker);
+
+    let section: IndexedMeshText = generator
+        .generate_section("a中b", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let reference: IndexedMeshText = generator
+        .generate_section("a?b", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(section.advance, 1.7);
+    assert_eq!(section.indices, reference.indices);
+
+    let missing: MeshText = generator
+        .generate_glyph('中', false, None)
+        .expect("Failed to generate text mesh for character 中.");
+    assert_eq!(missing.vertices.len(), marker.indices.len() * 3);
+
+    generator.remove_fallback_mesh();
+    let section: IndexedMeshText = generator
+        .generate_section("a中b", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_ne!(section.indices, reference.indices);
+}
+
+/// Test if the fallback mesh is converted for flat and three-dimensional text.
+#[test]
+fn test_fallback_mesh_dimensions() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let flat_marker: IndexedMeshText = generator
+        .generate_glyph('?', true, None)
+        .expect("Failed to generate text mesh for character ?.");
+    let marker: IndexedMeshText = generator
+        .generate_glyph('?', false, None)
+        .expect("Failed to generate text mesh for character ?.");
+
+    // Only the front cap of a three-dimensional fallback mesh is used for flat text.
+    generator.set_fallback_mesh(&marker);
+    let missing: IndexedMeshText = generator
+        .generate_glyph('中', true, None)
+        .expect("Failed to generate text mesh for character 中.");
+    assert_eq!(missing.indices.len(), flat_marker.indices.len());
+    assert_eq!(missing.bbox, flat_marker.bbox);
+
+    // A flat fallback mesh is extruded for three-dimensional text.
+    generator.set_fallback_mesh(&flat_marker);
+    let missing: IndexedMeshText = generator
+        .generate_glyph('中', false, None)
+        .expect("Failed to generate text mesh for character 中.");
+    assert!((missing.volume().unwrap() - marker.volume().unwrap()).abs() < 1e-4);
+    assert_eq!(missing.bbox, marker.bbox);
+}
+
+/// Records the source of each processed glyph.
+struct Record(Arc<Mutex<Vec<GlyphSource>>>);
+
+impl MeshPostProcessor for Record {
+    fn process(
+        &self,
+        glyph: GlyphSource,
+        _flat: bool,
+        _vertices: &mut Vec<Vec3A>,
+        _indices: Option<&mut Vec<u32>>,
+    ) {
+        self.0.lock().unwrap().push(glyph);
+    }
+}
+
+/// Test if post-processors can tell glyphs of the font,
+/// custom glyphs and the fallback mesh apart.
+#[test]
+fn test_post_processed_glyph_sources() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let marker: IndexedMeshText = generator
+        .generate_glyph('?', true, None)
+        .expect("Failed to generate text mesh for character ?.");
+    generator.set_fallback_mesh(&marker);
+    generator.set_glyph_provider(Frames);
+
+    let sources = Arc::new(Mutex::new(Vec::new()));
+    generator.add_post_processor(Record(sources.clone()));
+    let _: IndexedMeshText = generator
+        .generate_section("中▣", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let _: IndexedMeshText = generator
+        .generate_glyph('A', true, None)
+        .expect("Failed to generate text mesh for character A.");
+
+    let sources = sources.lock().unwrap();
+    assert_eq!(
+        *sources,
+        vec![
+            GlyphSource::Fallback,
+            GlyphSource::Custom('▣'),
+            GlyphSource::Font(36)
+        ]
+    );
+}
+
+/// Provides the same frame for `▣` and `▢`, but a different size for `▦`.
+struct Twins;
+
+impl GlyphProvider for Twins {
+    fn advance(&self, character: char) -> Option<f32> {
+        matches!(character, '▣' | '▢' | '▦').then_some(0.8)
+    }
+
+    fn outline(&self, character: char) -> Vec<Vec<Vec2>> {
+        let scale = if character == '▦' { 1.2 } else { 1.0 };
+        Frames
+            .outline(character)
+            .into_iter()
+            .map(|c| c.into_iter().map(|p| p * scale).collect())
+            .collect()
+    }
+}
+
+/// Test if glyphs with identical outlines share their triangulation,
+/// while glyphs with different outlines are triangulated separately.
+#[test]
+fn test_identical_outlines() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_glyph_provider(Twins);
+
+    for flat in [true, false] {
+        let first: IndexedMeshText = generator
+            .generate_glyph('▣', flat, None)
+            .expect("Failed to generate text mesh for character ▣.");
+        let second: IndexedMeshText = generator
+            .generate_glyph('▢', flat, None)
+            .expect("Failed to generate text mesh for character ▢.");
+        let third: IndexedMeshText = generator
+            .generate_glyph('▦', flat, None)
+            .expect("Failed to generate text mesh for character ▦.");
+        assert_eq!(first.indices, second.indices);
+        assert_eq!(first.vertices, second.vertices);
+        assert_ne!(first.vertices, third.vertices);
+
+        let first: MeshText = generator
+            .generate_glyph('▣', flat, None)
+            .expect("Failed to generate text mesh for character ▣.");
+        let second: MeshText = generator
+            .generate_glyph('▢', flat, None)
+            .expect("Failed to generate text mesh for character ▢.");
+        assert_eq!(first.vertices, second.vertices);
+    }
+}
+
+/// Test if glyphs can be looked up and generated by their PostScript name.
+#[test]
+fn test_glyph_by_name() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let glyph_id = generator
+        .glyph_by_name("A")
+        .expect("Failed to find glyph A.");
+    assert!(generator.glyph_by_name("doesNotExist").is_none());
+
+    let by_id = generator
+        .generate_glyph_by_id(glyph_id, false, None)
+        .expect("Failed to generate mesh by glyph id.");
+    let by_char: IndexedMeshText = generator
+        .generate_glyph('A', false, None)
+        .expect("Failed to generate mesh for character A.");
+    assert_eq!(by_id.indices, by_char.indices);
+    assert_eq!(by_id.vertices, by_char.vertices);
+    assert_eq!(by_id.advance, by_char.advance);
+}
+
+/// Provides a square with an additional contour that has no area for the character `■`.
+struct Broken;
+
+impl GlyphProvider for Broken {
+    fn advance(&self, character: char) -> Option<f32> {
+        (character == '■').then_some(0.8)
+    }
+
+    fn outline(&self, _character: char) -> Vec<Vec<Vec2>> {
+        vec![
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.0, 0.6),
+                Vec2::new(0.6, 0.6),
+                Vec2::new(0.6, 0.0),
+            ],
+            // Only two of the points are distinct.
+            vec![
+                Vec2::new(0.2, 0.2),
+                Vec2::new(0.2, 0.2),
+                Vec2::new(0.4, 0.4),
+            ],
+        ]
+    }
+}
+
+/// Test if degenerate contours are discarded and reported as a warning.
+#[test]
+fn test_degenerate_contours() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_glyph_provider(Broken);
+
+    let square: IndexedMeshText = generator
+        .generate_glyph('■', false, None)
+        .expect("Failed to generate text mesh for character ■.");
+    assert!((square.volume().unwrap() - 0.36).abs() < 1e-4);
+
+    let warnings = generator.take_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        warnings[0],
+        GenerationWarning::DegenerateContours { count: 1, .. }
+    ));
+    assert!(generator.take_warnings().is_empty());
+}
+
+/// Provides a square with additional contours that have non-finite
+/// coordinates for the character `■`.
+struct NonFinite;
+
+impl GlyphProvider for NonFinite {
+    fn advance(&self, character: char) -> Option<f32> {
+        (character == '■').then_some(0.8)
+    }
+
+    fn outline(&self, _character: char) -> Vec<Vec<Vec2>> {
+        vec![
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.0, 0.6),
+                Vec2::new(0.6, 0.6),
+                Vec2::new(0.6, 0.0),
+            ],
+            vec![
+                Vec2::new(0.2, 0.2),
+                Vec2::new(f32::NAN, 0.3),
+                Vec2::new(0.4, 0.4),
+            ],
+            vec![
+                Vec2::new(0.2, 0.2),
+                Vec2::new(0.3, f32::INFINITY),
+                Vec2::new(0.4, 0.2),
+            ],
+        ]
+    }
+}
+
+/// Test if contours with non-finite coordinates are discarded and reported
+/// as a warning, without corrupting the rest of the section.
+#[test]
+fn test_non_finite_contours() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_glyph_provider(NonFinite);
+
+    let square: IndexedMeshText = generator
+        .generate_glyph('■', false, None)
+        .expect("Failed to generate text mesh for character ■.");
+    assert!((square.volume().unwrap() - 0.36).abs() < 1e-4);
+    assert!(square.vertices.iter().all(|v| v.is_finite()));
+    assert!((square.bbox.min - Vec3A::new(0.0, 0.0, -0.5)).length() < 1e-4);
+    assert!((square.bbox.max - Vec3A::new(0.6, 0.6, 0.5)).length() < 1e-4);
+
+    let warnings = generator.take_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        warnings[0],
+        GenerationWarning::NonFiniteContours { count: 2, .. }
+    ));
+
+    let section: IndexedMeshText = generator
+        .generate_section("a■b", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert!(section.bbox.min.is_finite() && section.bbox.max.is_finite());
+}
@@ -0,0 +1,54 @@
+use glam::Vec3;
+use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+
+/// Test if placing the shared glyph meshes at their nodes
+/// reproduces the mesh of the text section.
+#[test]
+fn test_section_nodes() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let scene = generator
+        .generate_section_nodes("a ba b", false)
+        .expect("Failed to generate nodes.");
+    let mesh: IndexedMeshText = generator
+        .generate_section("a ba b", false, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    // The spaces have no mesh and the repeated glyphs share their meshes.
+    assert_eq!(scene.meshes.len(), 2);
+    assert_eq!(
+        scene.nodes.iter().map(|n| n.character).collect::<String>(),
+        "abab"
+    );
+    assert_eq!(scene.nodes[0].mesh, scene.nodes[2].mesh);
+    assert_eq!(scene.advance, mesh.advance);
+
+    let mut placed = Vec::new();
+    for node in scene.nodes.iter() {
+        let glyph = &scene.meshes[node.mesh];
+        for i in glyph.indices.iter() {
+            let i = *i as usize * 3;
+            let v = Vec3::new(
+                glyph.vertices[i],
+                glyph.vertices[i + 1],
+                glyph.vertices[i + 2],
+            );
+            placed.push(node.transform.transform_point3(v));
+        }
+    }
+    let expected: Vec<Vec3> = mesh
+        .indices
+        .iter()
+        .map(|i| {
+            let i = *i as usize * 3;
+            Vec3::new(mesh.vertices[i], mesh.vertices[i + 1], mesh.vertices[i + 2])
+        })
+        .collect();
+    assert_eq!(placed.len(), expected.len());
+    for (a, b) in placed.iter().zip(expected.iter()) {
+        assert!(a.distance(*b) < 1e-4);
+    }
+    assert!((scene.bbox.min - mesh.bbox.min).length() < 1e-4);
+    assert!((scene.bbox.max - mesh.bbox.max).length() < 1e-4);
+}
@@ -0,0 +1,372 @@
+use meshtext::{
+    Bend, Glyph, InterleavedMeshText, MeshGenerator, Seam, SideWallMapping, TextSection,
+    UvProjection, VertexAttribute, VertexColoring, VertexLayout,
+};
+
+/// Reads the `f32` component at the given byte offset of an interleaved buffer.
+fn read_f32(data: &[u8], offset: usize) -> f32 {
+    f32::from_ne_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+/// Test if a three-dimensional glyph is interleaved with position, normal
+/// and texture coordinates and that all normals are normalized.
+#[test]
+fn test_interleaved_glyph_layout() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: InterleavedMeshText = generator
+        .generate_glyph('A', false, None)
+        .expect("Failed to generate interleaved mesh for character A.");
+
+    assert_eq!(mesh.stride, 32);
+    assert_eq!(mesh.attribute(VertexAttribute::Position).unwrap().offset, 0);
+    assert_eq!(mesh.attribute(VertexAttribute::Normal).unwrap().offset, 12);
+    assert_eq!(mesh.attribute(VertexAttribute::Uv).unwrap().offset, 24);
+    assert_eq!(mesh.vertices.len() % mesh.stride as usize, 0);
+    assert!(mesh
+        .indices
+        .iter()
+        .all(|i| (*i as usize) < mesh.vertex_count()));
+
+    for vertex in mesh.vertices.chunks(mesh.stride as usize) {
+        let normal = (
+            read_f32(vertex, 12),
+            read_f32(vertex, 16),
+            read_f32(vertex, 20),
+        );
+        let length = (normal.0 * normal.0 + normal.1 * normal.1 + normal.2 * normal.2).sqrt();
+        assert!((length - 1f32).abs() < 1e-4);
+
+        // Vertices on the caps must point along the z-axis.
+        let z = read_f32(vertex, 8);
+        if normal.2.abs() > 0.5 {
+            assert_eq!(z.signum(), normal.2.signum());
+        }
+    }
+}
+
+/// Test if two-dimensional sections omit the normals.
+#[test]
+fn test_interleaved_section_2d() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: InterleavedMeshText = generator
+        .generate_section_2d("Hi", None)
+        .expect("Failed to generate interleaved mesh for text section.");
+
+    assert_eq!(mesh.stride, 16);
+    assert!(mesh.attribute(VertexAttribute::Normal).is_none());
+
+    for vertex in mesh.vertices.chunks(mesh.stride as usize) {
+        let (u, v) = (read_f32(vertex, 8), read_f32(vertex, 12));
+        assert!((0f32..=1f32).contains(&u));
+        assert!((0f32..=1f32).contains(&v));
+    }
+}
+
+/// Test if smooth seams share the vertices between the front cap and
+/// the side walls while the back cap stays separated.
+#[test]
+fn test_interleaved_smooth_seam() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let hard: InterleavedMeshText = generator
+        .generate_glyph('O', false, None)
+        .expect("Failed to generate interleaved mesh for character O.");
+
+    generator.set_vertex_layout(VertexLayout {
+        front_seam: Seam::Smooth,
+        ..Default::default()
+    });
+    let smooth: InterleavedMeshText = generator
+        .generate_glyph('O', false, None)
+        .expect("Failed to generate interleaved mesh for character O.");
+
+    assert_eq!(hard.indices.len(), smooth.indices.len());
+    assert!(smooth.vertex_count() < hard.vertex_count());
+
+    for vertex in smooth.vertices.chunks(smooth.stride as usize) {
+        let z = read_f32(vertex, 8);
+        let normal_z = read_f32(vertex, 20);
+
+        // The normals along the front boundary are tilted towards the front.
+        if z > 0f32 && normal_z < 0.99 {
+            assert!(normal_z > 0.1);
+        }
+        if z < 0f32 {
+            assert!(normal_z.abs() < 1e-4 || (normal_z + 1f32).abs() < 1e-4);
+        }
+    }
+}
+
+/// Test if the normals of a bent section follow the surface of the cylinder.
+#[test]
+fn test_interleaved_bent_normals() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let radius = 5f32;
+    generator.set_bend(Bend::Cylinder { radius });
+
+    let mesh: InterleavedMeshText = generator
+        .generate_section("Hello", true, None)
+        .expect("Failed to generate interleaved mesh for text section.");
+    let center = mesh.advance * 0.5;
+
+    for vertex in mesh.vertices.chunks(mesh.stride as usize) {
+        let position = (read_f32(vertex, 0), read_f32(vertex, 8) + radius);
+        let normal = (read_f32(vertex, 12), read_f32(vertex, 20));
+        let length = ((position.0 - center).powi(2) + position.1.powi(2)).sqrt();
+        let radial = ((position.0 - center) / length, position.1 / length);
+        assert!(normal.0 * radial.0 + normal.1 * radial.1 > 0.99);
+    }
+}
+
+/// Test if the texture coordinates follow the selected projection.
+#[test]
+fn test_interleaved_uv_projection() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let uv = |vertex: &[u8]| (read_f32(vertex, 24), read_f32(vertex, 28));
+
+    // Each glyph spans the whole texture.
+    generator.set_vertex_layout(VertexLayout {
+        uv_projection: UvProjection::PerGlyph,
+        ..Default::default()
+    });
+    let mesh: InterleavedMeshText = generator
+        .generate_section("HH", false, None)
+        .expect("Failed to generate interleaved mesh for text section.");
+    let second_glyph: Vec<(f32, f32)> = mesh
+        .vertices
+        .chunks(mesh.stride as usize)
+        .filter(|v| read_f32(v, 0) > mesh.advance * 0.5)
+        .map(uv)
+        .collect();
+    assert!(second_glyph.iter().any(|(u, _)| *u == 0.0));
+    assert!(second_glyph.iter().any(|(u, _)| *u == 1.0));
+
+    // The texture is wrapped around the bent text.
+    generator.set_bend(Bend::Cylinder { radius: 1.0 });
+    for projection in [UvProjection::Cylindrical, UvProjection::Spherical] {
+        generator.set_vertex_layout(VertexLayout {
+            uv_projection: projection,
+            ..Default::default()
+        });
+        let mesh: InterleavedMeshText = generator
+            .generate_section("Hello", true, None)
+            .expect("Failed to generate interleaved mesh for text section.");
+
+        let vertices: Vec<&[u8]> = mesh.vertices.chunks(mesh.stride as usize).collect();
+        let leftmost = vertices
+            .iter()
+            .min_by(|a, b| read_f32(a, 0).total_cmp(&read_f32(b, 0)))
+            .unwrap();
+        let rightmost = vertices
+            .iter()
+            .max_by(|a, b| read_f32(a, 0).total_cmp(&read_f32(b, 0)))
+            .unwrap();
+        assert!(uv(leftmost).0 < uv(rightmost).0);
+        assert!(vertices
+            .iter()
+            .map(|v| uv(v))
+            .all(|(u, v)| (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v)));
+    }
+}
+
+/// Test if unwrapped side walls are textured along the contour and the depth.
+#[test]
+fn test_interleaved_unwrapped_side_walls() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_vertex_layout(VertexLayout {
+        side_wall_mapping: SideWallMapping::Unwrapped,
+        ..Default::default()
+    });
+
+    let mesh: InterleavedMeshText = generator
+        .generate_glyph('O', false, None)
+        .expect("Failed to generate interleaved mesh for character O.");
+
+    let mut perimeter = 0f32;
+    for vertex in mesh.vertices.chunks(mesh.stride as usize) {
+        let z = read_f32(vertex, 8);
+        let normal_z = read_f32(vertex, 20);
+        let (u, v) = (read_f32(vertex, 24), read_f32(vertex, 28));
+
+        // The caps keep their planar texture coordinates.
+        if normal_z.abs() > 0.99 {
+            continue;
+        }
+        assert!((v - (0.5 - z)).abs() < 1e-5);
+        perimeter = perimeter.max(u);
+    }
+
+    // The outer contour of the glyph is longer than its height.
+    assert!(perimeter > 1.0);
+}
+
+/// Test if the lightmap texture coordinates lie within the unit square
+/// and the front and back caps of a glyph do not overlap.
+#[test]
+fn test_interleaved_lightmap_uvs() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_vertex_layout(VertexLayout::new(&[
+        VertexAttribute::Position,
+        VertexAttribute::Uv2,
+    ]));
+
+    let mesh: InterleavedMeshText = generator
+        .generate_glyph('O', false, None)
+        .expect("Failed to generate interleaved mesh for character O.");
+    assert_eq!(mesh.stride, 20);
+
+    // The bounds of the front and the back cap in the lightmap.
+    let mut caps = [(f32::MAX, f32::MIN, f32::MAX, f32::MIN); 2];
+    for triangle in mesh.indices.chunks(3) {
+        let vertices: Vec<&[u8]> = triangle
+            .iter()
+            .map(|i| &mesh.vertices[(*i * mesh.stride as u32) as usize..])
+            .collect();
+        let z: Vec<f32> = vertices.iter().map(|v| read_f32(v, 8)).collect();
+        for vertex in vertices.iter() {
+            let (u, v) = (read_f32(vertex, 12), read_f32(vertex, 16));
+            assert!((0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v));
+
+            // Side walls have corners on both caps.
+            if z.iter().all(|c| *c == z[0]) {
+                let cap = &mut caps[(z[0] < 0.0) as usize];
+                *cap = (cap.0.min(u), cap.1.max(u), cap.2.min(v), cap.3.max(v));
+            }
+        }
+    }
+
+    let [front, back] = caps;
+    let overlap_u = front.0 < back.1 && back.0 < front.1;
+    let overlap_v = front.2 < back.3 && back.2 < front.3;
+    assert!(!(overlap_u && overlap_v));
+}
+
+/// Test if vertex colors follow a vertical gradient and rotate their hue per glyph.
+#[test]
+fn test_interleaved_vertex_coloring() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_vertex_layout(VertexLayout {
+        coloring: VertexColoring::VerticalGradient {
+            top: [1.0, 0.0, 0.0, 1.0],
+            bottom: [0.0, 0.0, 1.0, 1.0],
+        },
+        ..VertexLayout::new(&[VertexAttribute::Position, VertexAttribute::Color])
+    });
+
+    let mesh: InterleavedMeshText = generator
+        .generate_glyph_2d('I', None)
+        .expect("Failed to generate interleaved mesh for character I.");
+    assert_eq!(mesh.stride, 24);
+    for vertex in mesh.vertices.chunks(mesh.stride as usize) {
+        let y = read_f32(vertex, 4);
+        let (red, blue) = (read_f32(vertex, 8), read_f32(vertex, 16));
+        let height = (y - mesh.bbox.min.y) / (mesh.bbox.max.y - mesh.bbox.min.y);
+        assert!((red - height).abs() < 1e-4);
+        assert!((blue - (1.0 - height)).abs() < 1e-4);
+    }
+
+    generator.set_vertex_layout(VertexLayout {
+        color: [1.0, 0.0, 0.0, 1.0],
+        coloring: VertexColoring::HueRotation { step: 1.0 / 3.0 },
+        ..VertexLayout::new(&[VertexAttribute::Position, VertexAttribute::Color])
+    });
+    let mesh: InterleavedMeshText = generator
+        .generate_section("II", false, None)
+        .expect("Failed to generate interleaved mesh for text section.");
+
+    // The second glyph is shifted from red to green.
+    for vertex in mesh.vertices.chunks(mesh.stride as usize) {
+        let x = read_f32(vertex, 0);
+        let (red, green) = (read_f32(vertex, 12), read_f32(vertex, 16));
+        if x < 0.5 {
+            assert!(red > 0.99 && green < 0.01);
+        } else {
+            assert!(red < 0.01 && green > 0.99);
+        }
+    }
+}
+
+/// Test if flipping a mesh negates the normals and keeps the other attributes.
+#[test]
+fn test_interleaved_flip() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let original: InterleavedMeshText = generator
+        .generate_glyph('A', false, None)
+        .expect("Failed to generate interleaved mesh for character A.");
+    let mut flipped: InterleavedMeshText = generator
+        .generate_glyph('A', false, None)
+        .expect("Failed to generate interleaved mesh for character A.");
+    flipped.flip();
+
+    for (a, b) in original
+        .indices
+        .chunks_exact(3)
+        .zip(flipped.indices.chunks_exact(3))
+    {
+        assert_eq!([a[0], a[2], a[1]], b);
+    }
+    let stride = original.stride as usize;
+    for (a, b) in original
+        .vertices
+        .chunks(stride)
+        .zip(flipped.vertices.chunks(stride))
+    {
+        for offset in (0..stride).step_by(4) {
+            let sign = if (12..24).contains(&offset) {
+                -1f32
+            } else {
+                1f32
+            };
+            assert_eq!(read_f32(a, offset) * sign, read_f32(b, offset));
+        }
+    }
+}
+
+/// Test if each vertex knows the glyph it belongs to and its relative position within it.
+#[test]
+fn test_interleaved_character_attribute() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_vertex_layout(VertexLayout::new(&[
+        VertexAttribute::Position,
+        VertexAttribute::Character,
+    ]));
+
+    for two_dimensional in [false, true] {
+        let mesh: InterleavedMeshText = if two_dimensional {
+            generator.generate_section_2d("I I", None)
+        } else {
+            generator.generate_section("I I", false, None)
+        }
+        .expect("Failed to generate interleaved mesh for text section.");
+        let offset = mesh.attribute(VertexAttribute::Character).unwrap().offset as usize;
+
+        let mut glyphs = Vec::new();
+        for vertex in mesh.vertices.chunks(mesh.stride as usize) {
+            let (glyph, u, v) = (
+                read_f32(vertex, offset),
+                read_f32(vertex, offset + 4),
+                read_f32(vertex, offset + 8),
+            );
+            assert!((0f32..=1f32).contains(&u) && (0f32..=1f32).contains(&v));
+            assert_eq!(
+                glyph,
+                if read_f32(vertex, 0) < 1f32 {
+                    0f32
+                } else {
+                    2f32
+                }
+            );
+            glyphs.push(glyph);
+        }
+        assert!(glyphs.contains(&0f32) && glyphs.contains(&2f32));
+    }
+}
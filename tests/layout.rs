@@ -0,0 +1,713 @@
+use glam::{Mat3, Mat4, Vec2};
+use meshtext::{
+    AdvanceFallback, Glyph, IndexedMeshText, InterleavedMeshText, MeshGenerator, MeshText,
+    MetricsSource, Paragraph, PartitionedMeshText, TabSettings, TabSize, TextSection, TriangleMesh,
+};
+
+/// Test if combining marks do not advance and are centered above
+/// the preceding glyph.
+#[test]
+fn test_combining_mark_layout() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let base: IndexedMeshText = generator
+        .generate_section("e", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let combined: IndexedMeshText = generator
+        .generate_section("e\u{301}", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let plain: IndexedMeshText = generator
+        .generate_section("ex", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let marked: IndexedMeshText = generator
+        .generate_section("e\u{301}x", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    let center = |mesh: &IndexedMeshText| (mesh.bbox.min.x + mesh.bbox.max.x) * 0.5;
+    assert!((center(&base) - center(&combined)).abs() < 1e-4);
+    assert!((plain.bbox.max.x - marked.bbox.max.x).abs() < 1e-4);
+}
+
+/// Test if the advance of a section can be used to continue
+/// the layout where the section ended.
+#[test]
+fn test_section_advance() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let glyph: MeshText = generator
+        .generate_glyph('a', true, None)
+        .expect("Failed to generate text mesh for character a.");
+    let first: IndexedMeshText = generator
+        .generate_section("ab", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let second: IndexedMeshText = generator
+        .generate_section("cd", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let combined: IndexedMeshText = generator
+        .generate_section("abcd", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    assert!((glyph.advance * 2f32 - first.advance).abs() < 1e-4);
+    assert!((first.advance + second.advance - combined.advance).abs() < 1e-4);
+}
+
+/// Test if the carets are placed between the characters
+/// of a section and span the height of the font.
+#[test]
+fn test_caret_positions() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let section: IndexedMeshText = generator
+        .generate_section("abc", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let prefix: IndexedMeshText = generator
+        .generate_section("ab", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let carets = generator.caret_positions("abc");
+
+    assert_eq!(carets.len(), 4);
+    assert_eq!(carets[0].position.x, 0f32);
+    assert!((carets[2].position.x - prefix.advance).abs() < 1e-4);
+    assert!((carets[3].position.x - section.advance).abs() < 1e-4);
+    assert!(carets.iter().enumerate().all(|(i, c)| c.index == i));
+
+    let caret = carets[1];
+    assert!(caret.position.y <= section.bbox.min.y);
+    assert!(caret.position.y + caret.height >= section.bbox.max.y);
+}
+
+/// Test if points on a section are mapped to the character below them.
+#[test]
+fn test_hit_test() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let generator = MeshGenerator::new(font_data);
+
+    let layout = generator.section_layout("abc");
+    let carets = &layout.carets;
+    let y = carets[0].position.y + carets[0].height * 0.5;
+
+    for i in 0..3 {
+        let x = (carets[i].position.x + carets[i + 1].position.x) * 0.5;
+        assert_eq!(layout.hit_test(Vec2::new(x, y)), Some(i));
+    }
+    assert_eq!(layout.hit_test(Vec2::new(layout.advance + 0.1, y)), None);
+    assert_eq!(layout.hit_test(Vec2::new(0.1, y + carets[0].height)), None);
+}
+
+/// Test if a selection covers exactly the selected characters.
+#[test]
+fn test_selection() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let generator = MeshGenerator::new(font_data);
+
+    let layout = generator.section_layout("abcd");
+    let carets = &layout.carets;
+
+    let selection = layout.generate_selection(1..3, false, None);
+    assert_eq!(selection.bbox.min.x, carets[1].position.x);
+    assert_eq!(selection.bbox.max.x, carets[3].position.x);
+    assert_eq!(selection.bbox.min.y, carets[1].position.y);
+    assert_eq!(selection.bbox.min.z, -0.5);
+    assert_eq!(selection.indices.len(), 36);
+
+    assert!(layout
+        .generate_selection(2..2, true, None)
+        .indices
+        .is_empty());
+    assert!(layout
+        .generate_selection(5..9, true, None)
+        .indices
+        .is_empty());
+}
+
+/// Test if a caret mesh is slanted by the italic angle.
+#[test]
+fn test_caret_mesh() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let generator = MeshGenerator::new(font_data);
+
+    let mut layout = generator.section_layout("abc");
+    let caret = layout.carets[1];
+
+    let upright = layout.generate_caret(1, 0.1, true, None);
+    assert!((upright.bbox.min.x - (caret.position.x - 0.05)).abs() < 1e-4);
+    assert!((upright.bbox.max.x - (caret.position.x + 0.05)).abs() < 1e-4);
+    assert!((upright.bbox.max.y - (caret.position.y + caret.height)).abs() < 1e-4);
+
+    layout.italic_angle = -45f32;
+    let slanted = layout.generate_caret(1, 0.1, true, None);
+    let top = caret.position.y + caret.height;
+    assert!((slanted.bbox.max.x - (caret.position.x + 0.05 + top)).abs() < 1e-4);
+}
+
+/// Test if a truncated section is shortened to fit the maximum width.
+#[test]
+fn test_section_truncated() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let full: IndexedMeshText = generator
+        .generate_section("abcdef", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let (untouched, kept): (IndexedMeshText, usize) = generator
+        .generate_section_truncated("abcdef", full.advance, '.', true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(kept, 6);
+    assert_eq!(untouched.indices.len(), full.indices.len());
+
+    let (truncated, kept): (MeshText, usize) = generator
+        .generate_section_truncated("abcdef", full.advance * 0.6, '.', true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let expected: MeshText = generator
+        .generate_section("ab.", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(kept, 2);
+    assert_eq!(truncated.vertices, expected.vertices);
+    assert!(truncated.advance <= full.advance * 0.6);
+}
+
+/// Test if truncated sections are kerned with the ellipsis
+/// and are empty if not even the ellipsis fits.
+#[test]
+fn test_section_truncated_kerning() {
+    let font_data = include_bytes!("../assets/font/LayoutTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    // `A` is kerned with the `V` that is used as the ellipsis.
+    let (truncated, kept): (IndexedMeshText, usize) = generator
+        .generate_section_truncated("AAAA", 1.15, 'V', true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(kept, 1);
+    assert!((truncated.advance - 1.1).abs() < 1e-4);
+
+    let (empty, kept): (IndexedMeshText, usize) = generator
+        .generate_section_truncated("AAAA", 0.5, 'V', true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(kept, 0);
+    assert!(empty.indices.is_empty());
+    assert_eq!(empty.advance, 0f32);
+}
+
+/// Test if tabs advance to the next tab stop.
+#[test]
+fn test_tab_stops() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    // By default tab stops are four spaces apart.
+    let tab_position = |generator: &MeshGenerator<_>, text: &str| {
+        let layout = generator.section_layout(text);
+        layout.carets[text.chars().count()].position.x
+    };
+    assert_eq!(tab_position(&generator, "a\t"), 2.0);
+    assert_eq!(tab_position(&generator, "abcde\t"), 4.0);
+
+    generator.set_tab_settings(TabSettings {
+        tab_size: TabSize::Advance(1.5),
+        stops: vec![1.0],
+    });
+    assert_eq!(tab_position(&generator, "\t"), 1.0);
+    assert_eq!(tab_position(&generator, "\t\t"), 1.5);
+    assert_eq!(tab_position(&generator, "abcd\t"), 3.0);
+}
+
+/// Test if appending text to a mesh matches generating the whole text at once.
+#[test]
+fn test_append_section() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let transform = [
+        2f32, 0f32, 0f32, 0f32, 0f32, 2f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 1f32, 0f32, 0f32,
+        1f32,
+    ];
+
+    let mut mesh: IndexedMeshText = generator
+        .generate_section("ab", false, Some(&transform))
+        .expect("Failed to generate text mesh for text section.");
+    generator
+        .append_section(&mut mesh, "ab", "cd", false, Some(&transform))
+        .expect("Failed to append text section.");
+    let whole: IndexedMeshText = generator
+        .generate_section("abcd", false, Some(&transform))
+        .expect("Failed to generate text mesh for text section.");
+
+    assert_eq!(mesh.advance, whole.advance);
+    assert_eq!(mesh.indices, whole.indices);
+    assert_eq!(mesh.vertices.len(), whole.vertices.len());
+    assert!(mesh
+        .vertices
+        .iter()
+        .zip(whole.vertices.iter())
+        .all(|(a, b)| (a - b).abs() < 1e-5));
+    assert!((mesh.bbox.max - whole.bbox.max).abs().max_element() < 1e-5);
+
+    let mut mesh: IndexedMeshText = generator
+        .generate_section_2d("", None)
+        .expect("Failed to generate text mesh for text section.");
+    generator
+        .append_section(&mut mesh, "", "ab", true, None)
+        .expect("Failed to append text section.");
+    let whole: IndexedMeshText = generator
+        .generate_section_2d("ab", None)
+        .expect("Failed to generate text mesh for text section.");
+    assert!(mesh.two_dimensional);
+    assert_eq!(mesh.vertices, whole.vertices);
+    assert_eq!(mesh.bbox, whole.bbox);
+}
+
+/// Test if fitted sections fill the width unless they are limited by the height.
+#[test]
+fn test_section_fitted() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let (wide, scale): (IndexedMeshText, f32) = generator
+        .generate_section_fitted("Label", 10f32, None, false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert!((wide.advance * scale - 10f32).abs() < 1e-4);
+    assert!(wide.bbox.max.x <= 10f32);
+    assert!((wide.bbox.size().z - 1f32).abs() < 1e-4);
+
+    let (limited, limited_scale): (IndexedMeshText, f32) = generator
+        .generate_section_fitted("Label", 10f32, Some(0.5f32), false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert!(limited_scale < scale);
+    assert!(limited.bbox.size().y <= 0.5f32);
+}
+
+/// Test if variation selectors do not produce a glyph of their own.
+#[test]
+fn test_variation_selector_layout() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let plain: IndexedMeshText = generator
+        .generate_section("ax", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let selected: IndexedMeshText = generator
+        .generate_section("a\u{FE0F}x", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    assert_eq!(plain.indices.len(), selected.indices.len());
+    assert!((plain.advance - selected.advance).abs() < 1e-4);
+    assert!((plain.bbox.max.x - selected.bbox.max.x).abs() < 1e-4);
+}
+
+/// Test if zero-width joiners, for which the font has no ligature,
+/// do not produce a glyph of their own.
+#[test]
+fn test_zero_width_joiner_layout() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let plain: IndexedMeshText = generator
+        .generate_section("ax", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let joined: IndexedMeshText = generator
+        .generate_section("a\u{200D}x", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    assert_eq!(plain.indices.len(), joined.indices.len());
+    assert!((plain.advance - joined.advance).abs() < 1e-4);
+    assert!((plain.bbox.max.x - joined.bbox.max.x).abs() < 1e-4);
+}
+
+/// Test if characters that are combined by a zero-width joiner are
+/// replaced by the ligature of the font.
+#[test]
+#[cfg(not(feature = "shaping"))]
+fn test_zero_width_joiner_ligature() {
+    let font_data = include_bytes!("../assets/font/LayoutTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let joined: IndexedMeshText = generator
+        .generate_section("f\u{200D}i", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let ligature: MeshText = generator
+        .generate_section("f\u{200D}i", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    // The ligature is a single square that replaces both glyphs.
+    assert_eq!(joined.indices.len(), 6);
+    assert_eq!(ligature.vertices.len(), 18);
+    assert!((joined.advance - 0.7).abs() < 1e-4);
+    assert!((joined.bbox.min.x - 0.05).abs() < 1e-4);
+    assert!((joined.bbox.max.x - 0.65).abs() < 1e-4);
+
+    // Without the joiner, the glyphs are not combined.
+    let separate: IndexedMeshText = generator
+        .generate_section("fi", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(separate.indices.len(), 12);
+    assert!((separate.advance - 1.0).abs() < 1e-4);
+}
+
+/// Test if all glyphs of a monospaced font advance by the same amount.
+#[test]
+fn test_monospaced_layout() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    assert!(generator.is_monospaced());
+
+    let glyph: MeshText = generator
+        .generate_glyph('m', true, None)
+        .expect("Failed to generate glyph.");
+    let section: IndexedMeshText = generator
+        .generate_section("il Wm", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    assert!((section.advance - 5f32 * glyph.advance).abs() < 1e-4);
+}
+
+/// Test if glyphs of a monospaced font that span several cells keep their own advance.
+#[test]
+fn test_monospaced_wide_glyph_layout() {
+    let font_data = include_bytes!("../assets/font/MonospaceTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    assert!(generator.is_monospaced());
+
+    let section: IndexedMeshText = generator
+        .generate_section("iW i", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    // The height of the font is 1000 font units.
+    assert!((section.advance - 2.5).abs() < 1e-4);
+    assert!((section.bbox.min.x - 0.2).abs() < 1e-4);
+    assert!((section.bbox.max.x - 2.3).abs() < 1e-4);
+}
+
+/// Test if the vertical metrics are read from the selected source.
+///
+/// The metrics of the `hhea` and `OS/2` tables of the font disagree,
+/// so each source scales the text differently.
+#[test]
+fn test_metrics_source() {
+    let font_data = include_bytes!("../assets/font/MetricsTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let reference: IndexedMeshText = generator
+        .generate_section("H", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    for (source, height) in [
+        (MetricsSource::Hhea, 1000f32),
+        (MetricsSource::Typographic, 2000f32),
+        (MetricsSource::Windows, 1500f32),
+    ] {
+        generator.set_metrics_source(source);
+        let section: IndexedMeshText = generator
+            .generate_section("H", true, None)
+            .expect("Failed to generate text mesh for text section.");
+
+        assert!((section.advance - 500f32 / height).abs() < 1e-4);
+        assert!((section.bbox.max.x - 500f32 / height).abs() < 1e-4);
+        assert!((section.bbox.max.y - 1000f32 / height).abs() < 1e-4);
+    }
+
+    // Without the `USE_TYPO_METRICS` flag, the metrics of the `hhea` table are used.
+    generator.set_metrics_source(MetricsSource::Auto);
+    let section: IndexedMeshText = generator
+        .generate_section("H", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert!((reference.advance - 0.5).abs() < 1e-4);
+    assert_eq!(section.bbox, reference.bbox);
+}
+
+/// Test if the vertices are snapped to the quantization grid.
+#[test]
+fn test_quantization() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_quantization(Some(1f32 / 64f32));
+    let step = generator.font_units_to_normalized(generator.units_per_em() as f32 / 64f32);
+
+    let section: IndexedMeshText = generator
+        .generate_section("Grid", false, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    assert!(!section.vertices.is_empty());
+    for coordinate in section.vertices.iter() {
+        let cells = coordinate / step;
+        assert!((cells - cells.round()).abs() < 1e-3);
+    }
+}
+
+/// Checks that all coordinates lie on the quantization grid.
+fn assert_on_grid(coordinates: impl IntoIterator<Item = f32>, step: f32) {
+    for coordinate in coordinates {
+        let cells = coordinate / step;
+        assert!((cells - cells.round()).abs() < 1e-3);
+    }
+}
+
+/// Test if the vertices of all kinds of generated meshes are snapped to the quantization grid.
+#[test]
+fn test_quantization_of_all_outputs() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_quantization(Some(1f32 / 64f32));
+    let step = generator.font_units_to_normalized(generator.units_per_em() as f32 / 64f32);
+    let transform = Mat4::from_rotation_z(0.3).to_cols_array();
+
+    let interleaved: InterleavedMeshText = generator
+        .generate_section("Grid", false, Some(&transform))
+        .expect("Failed to generate interleaved mesh.");
+    let stride = interleaved.stride as usize;
+    assert_on_grid(
+        interleaved.vertices.chunks(stride).flat_map(|v| {
+            (0..3).map(|i| f32::from_ne_bytes(v[i * 4..i * 4 + 4].try_into().unwrap()))
+        }),
+        step,
+    );
+
+    let partitioned: PartitionedMeshText = generator
+        .generate_section("Grid", false, Some(&transform))
+        .expect("Failed to generate partitioned mesh.");
+    assert_on_grid(partitioned.vertices.iter().copied(), step);
+
+    let mut appended: IndexedMeshText = generator
+        .generate_section("Gr", false, Some(&transform))
+        .expect("Failed to generate text mesh for text section.");
+    generator
+        .append_section(&mut appended, "Gr", "id", false, Some(&transform))
+        .expect("Failed to append text.");
+    assert_on_grid(appended.vertices.iter().copied(), step);
+
+    let (paragraph, _) = generator
+        .generate_paragraph(
+            &Paragraph::new().with_run("Gr\nid"),
+            false,
+            Some(&transform),
+        )
+        .expect("Failed to generate paragraph.");
+    assert_on_grid(paragraph.vertices.iter().copied(), step);
+
+    let rotation = Mat3::from_angle(0.3).to_cols_array();
+    let outline = generator
+        .generate_section_outline("Grid", 0.02, None, Some(&rotation))
+        .expect("Failed to generate outline.");
+    assert_on_grid(outline.vertices.iter().copied(), step);
+}
+
+/// Test if triangles that lose their area on a coarse quantization grid are removed.
+#[test]
+fn test_quantization_removes_degenerate_triangles() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let exact: IndexedMeshText = generator
+        .generate_section("@", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    generator.set_quantization(Some(1f32 / 16f32));
+    let indexed: IndexedMeshText = generator
+        .generate_section("@", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let mesh: MeshText = generator
+        .generate_section("@", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    assert!(indexed.indices.len() < exact.indices.len());
+    assert_eq!(indexed.indices.len(), mesh.vertices.len() / 3);
+    for [a, b, c] in indexed.triangles().chain(mesh.triangles()) {
+        assert!((b - a).cross(c - a).length() > 1e-6);
+    }
+}
+
+/// Test if the kerning of a pair of characters accounts for the difference
+/// between the advance of a section and the advances of its glyphs.
+#[test]
+fn test_kerning() {
+    // The pairs are kerned by the GPOS pair adjustment formats 1 and 2
+    // as well as by the legacy `kern` table.
+    let layout_data = include_bytes!("../assets/font/LayoutTest.ttf");
+    let kern_data = include_bytes!("../assets/font/KernTest.ttf");
+    let cases = [
+        (&layout_data[..], "AV", -0.1f32, 1.1f32),
+        (&layout_data[..], "To", -0.05f32, 1.05f32),
+        (&kern_data[..], "AV", -0.08f32, 1.12f32),
+    ];
+
+    for (font_data, pair, kerning, advance) in cases {
+        let mut generator = MeshGenerator::new(font_data);
+        let mut characters = pair.chars();
+        let (left, right) = (characters.next().unwrap(), characters.next().unwrap());
+        assert!((generator.kerning(left, right) - kerning).abs() < 1e-4);
+
+        let left_glyph: MeshText = generator
+            .generate_glyph(left, true, None)
+            .expect("Failed to generate text mesh for character.");
+        let right_glyph: MeshText = generator
+            .generate_glyph(right, true, None)
+            .expect("Failed to generate text mesh for character.");
+        let section: IndexedMeshText = generator
+            .generate_section(pair, true, None)
+            .expect("Failed to generate text mesh for text section.");
+
+        assert!((section.advance - advance).abs() < 1e-4);
+        let expected = left_glyph.advance + right_glyph.advance + kerning;
+        assert!((section.advance - expected).abs() < 1e-4);
+    }
+}
+
+/// Test if appended text is kerned with the previous text and
+/// advances its tabs to the tab stops of the whole line.
+#[test]
+fn test_append_section_seam() {
+    let font_data = include_bytes!("../assets/font/KernTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    // `A` is kerned with `V` across the seam.
+    let mut mesh: IndexedMeshText = generator
+        .generate_section("A", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    generator
+        .append_section(&mut mesh, "A", "V\tA", false, None)
+        .expect("Failed to append text section.");
+    let whole: IndexedMeshText = generator
+        .generate_section("AV\tA", false, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    assert!((mesh.advance - whole.advance).abs() < 1e-5);
+    assert_eq!(mesh.indices, whole.indices);
+    assert_eq!(mesh.vertices.len(), whole.vertices.len());
+    assert!(mesh
+        .vertices
+        .iter()
+        .zip(whole.vertices.iter())
+        .all(|(a, b)| (a - b).abs() < 1e-5));
+
+    // A tab at the start of the appended text continues from the pen.
+    let mut mesh: IndexedMeshText = generator
+        .generate_section("AV", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    generator
+        .append_section(&mut mesh, "AV", "\t", false, None)
+        .expect("Failed to append text section.");
+    let whole: IndexedMeshText = generator
+        .generate_section("AV\t", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert!((mesh.advance - whole.advance).abs() < 1e-5);
+}
+
+/// Test if updating a section reuses the unchanged glyphs and
+/// produces the same mesh as generating the new text.
+#[test]
+fn test_update_section() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let transform = glam::Mat4::from_translation(glam::Vec3::new(1f32, 2f32, 3f32)).to_cols_array();
+
+    let texts = ["12:34", "12:54", "12:54", "9", "", "10:00"];
+    let mut mesh: IndexedMeshText<u16> = generator
+        .generate_section(texts[0], false, Some(&transform))
+        .expect("Failed to generate text mesh for text section.");
+    let mut mesh_2d: IndexedMeshText = generator
+        .generate_section_2d(texts[0], None)
+        .expect("Failed to generate text mesh for text section.");
+    for pair in texts.windows(2) {
+        let changed = generator
+            .update_section(&mut mesh, pair[0], pair[1], false, Some(&transform))
+            .expect("Failed to update text section.");
+        generator
+            .update_section(&mut mesh_2d, pair[0], pair[1], true, None)
+            .expect("Failed to update text section.");
+
+        let whole: IndexedMeshText<u16> = generator
+            .generate_section(pair[1], false, Some(&transform))
+            .expect("Failed to generate text mesh for text section.");
+        let whole_2d: IndexedMeshText = generator
+            .generate_section_2d(pair[1], None)
+            .expect("Failed to generate text mesh for text section.");
+        assert_eq!(mesh.indices, whole.indices);
+        assert_eq!(mesh.vertices, whole.vertices);
+        assert_eq!(mesh.advance, whole.advance);
+        assert_eq!(mesh_2d.indices, whole_2d.indices);
+        assert_eq!(mesh_2d.vertices, whole_2d.vertices);
+        if !whole.vertices.is_empty() {
+            assert!((mesh.bbox.min - whole.bbox.min).length() < 1e-4);
+            assert!((mesh.bbox.max - whole.bbox.max).length() < 1e-4);
+        }
+
+        // Only the changed digit is regenerated.
+        if pair == ["12:34", "12:54"] {
+            assert!(changed.start > 0);
+            assert!(changed.end < mesh.vertices.len() / 3);
+        }
+        if pair[0] == pair[1] {
+            assert!(changed.is_empty());
+        }
+    }
+}
+
+/// Test if updating a section that was generated with different settings
+/// regenerates it completely instead of reusing outdated glyphs.
+#[test]
+fn test_update_section_after_settings_change() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let mut mesh: IndexedMeshText = generator
+        .generate_section("12:34", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    generator.set_extrusion_settings(meshtext::ExtrusionSettings {
+        back_cap_scale: 0.5,
+    });
+    let changed = generator
+        .update_section(&mut mesh, "12:34", "12:54", false, None)
+        .expect("Failed to update text section.");
+    let whole: IndexedMeshText = generator
+        .generate_section("12:54", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(changed, 0..whole.vertices.len() / 3);
+    assert_eq!(mesh.vertices, whole.vertices);
+
+    // The quality is part of the cache key, so the cached glyphs are kept.
+    let mut mesh_2d: IndexedMeshText = generator
+        .generate_section_2d("12:34", None)
+        .expect("Failed to generate text mesh for text section.");
+    generator.set_quality(meshtext::QualitySettings {
+        quad_interpolation_steps: 2,
+        cubic_interpolation_steps: 2,
+    });
+    let changed = generator
+        .update_section(&mut mesh_2d, "12:34", "12:54", true, None)
+        .expect("Failed to update text section.");
+    let whole_2d: IndexedMeshText = generator
+        .generate_section_2d("12:54", None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(changed.start, 0);
+    assert_eq!(mesh_2d.indices, whole_2d.indices);
+    assert_eq!(mesh_2d.vertices, whole_2d.vertices);
+
+    // Once the section was updated with the current settings, its glyphs are reused again.
+    let changed = generator
+        .update_section(&mut mesh_2d, "12:54", "12:34", true, None)
+        .expect("Failed to update text section.");
+    assert!(changed.start > 0);
+}
+
+/// Test if glyphs without an advance use the [AdvanceFallback],
+/// which keeps them in place by default.
+#[test]
+fn test_advance_fallback() {
+    let font_data = include_bytes!("../assets/font/AdvanceTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let advance = |generator: &mut MeshGenerator<_>| {
+        let mesh: IndexedMeshText = generator
+            .generate_section("AC", true, None)
+            .expect("Failed to generate text mesh for text section.");
+        mesh.advance
+    };
+
+    assert_eq!(AdvanceFallback::default(), AdvanceFallback::Zero);
+    assert_eq!(advance(&mut generator), 0f32);
+
+    generator.set_advance_fallback(AdvanceFallback::GlyphWidth { side_bearing: 0.05 });
+    assert!((advance(&mut generator) - 0.6).abs() < 1e-4);
+
+    generator.set_advance_fallback(AdvanceFallback::AverageAdvance);
+    assert!((advance(&mut generator) - 1.2).abs() < 1e-4);
+}
@@ -1,6 +1,6 @@
 mod util;
 
-use meshtext::{Glyph, MeshGenerator, MeshText};
+use meshtext::{DashPattern, Glyph, IndexedMeshText, MeshGenerator, MeshText, TriangleMesh};
 
 /// Use this test to plot a triangulation diagram for the specified letter.
 //#[test]
@@ -430,3 +430,128 @@ fn test_flat_glyph_quads() {
 
     assert_eq!(expected, mesh.vertices);
 }
+
+/// Computes the total area of a two-dimensional indexed mesh.
+fn area_2d(mesh: &IndexedMeshText) -> f32 {
+    let point = |i: u32| {
+        (
+            mesh.vertices[2 * i as usize],
+            mesh.vertices[2 * i as usize + 1],
+        )
+    };
+    mesh.indices
+        .chunks(3)
+        .map(|t| {
+            let (a, b, c) = (point(t[0]), point(t[1]), point(t[2]));
+            ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)) * 0.5
+        })
+        .sum()
+}
+
+/// Test if a dashed outline covers about half of the area of a solid
+/// outline when the dashes are as long as the gaps.
+#[test]
+fn test_dashed_outline() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let solid = generator
+        .generate_section_outline("O", 0.02, None, None)
+        .expect("Failed to generate outline for character O.");
+    let dashed = generator
+        .generate_section_outline("O", 0.02, Some(DashPattern::new(0.04, 0.04, 0.0)), None)
+        .expect("Failed to generate dashed outline for character O.");
+    let shifted = generator
+        .generate_section_outline("O", 0.02, Some(DashPattern::new(0.04, 0.04, 0.03)), None)
+        .expect("Failed to generate dashed outline for character O.");
+
+    let glyph: MeshText = generator
+        .generate_glyph_2d('O', None)
+        .expect("Failed to generate mesh for character O.");
+    assert!(solid.bbox.min.x < glyph.bbox.min.x);
+    assert!(solid.bbox.max.y > glyph.bbox.max.y);
+
+    // The triangles face the viewer, so the area is positive.
+    assert!(area_2d(&solid) > 0.0);
+    let ratio = area_2d(&dashed) / area_2d(&solid);
+    assert!(ratio > 0.4 && ratio < 0.6, "ratio {}", ratio);
+    assert_ne!(dashed.vertices, shifted.vertices);
+}
+
+/// Test if contours with an area below the threshold are dropped.
+#[test]
+fn test_min_contour_area() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let dotted: IndexedMeshText = generator
+        .generate_glyph('i', true, None)
+        .expect("Failed to generate text mesh for character i.");
+
+    // The dot of the `i` is far smaller than its stem.
+    generator.set_min_contour_area(30_000);
+    let dotless: IndexedMeshText = generator
+        .generate_glyph('i', true, None)
+        .expect("Failed to generate text mesh for character i.");
+
+    assert!(dotless.indices.len() < dotted.indices.len());
+    assert!(!dotless.indices.is_empty());
+}
+
+/// Test if long segments, including the interpolated segments of curves,
+/// are split without changing the shape of the glyph.
+#[test]
+fn test_max_segment_length() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let segment = generator.font_units_to_normalized(50f32) + 1e-4;
+
+    for character in ['I', 'O'] {
+        generator.set_max_segment_length(0);
+        let coarse: IndexedMeshText = generator
+            .generate_glyph(character, false, None)
+            .expect("Failed to generate text mesh for glyph.");
+
+        generator.set_max_segment_length(50);
+        let even: IndexedMeshText = generator
+            .generate_glyph(character, false, None)
+            .expect("Failed to generate text mesh for glyph.");
+
+        assert!(even.vertices.len() > coarse.vertices.len());
+        let (coarse_volume, even_volume) = (coarse.volume().unwrap(), even.volume().unwrap());
+        assert!((coarse_volume - even_volume).abs() < 1e-4);
+
+        // No side wall spans more than a single segment along the contour.
+        for triangle in even.triangles() {
+            for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+                let (a, b) = (triangle[a], triangle[b]);
+                if (a.z - b.z).abs() > 0.5 {
+                    assert!(a.truncate().distance(b.truncate()) <= segment);
+                }
+            }
+        }
+    }
+}
+
+/// Test if contours that the font does not close are closed automatically
+/// instead of losing their last point.
+#[test]
+fn test_close_open_contours() {
+    // The square of `A` omits the closing segment, while the square of `B` has one.
+    let font_data = include_bytes!("../assets/font/CffTest.otf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let open: IndexedMeshText = generator
+        .generate_glyph_2d('A', None)
+        .expect("Failed to generate text mesh for character A.");
+    let closed: IndexedMeshText = generator
+        .generate_glyph_2d('B', None)
+        .expect("Failed to generate text mesh for character B.");
+    assert!((area_2d(&open).abs() - 0.16).abs() < 1e-4);
+    assert!((area_2d(&closed).abs() - 0.16).abs() < 1e-4);
+
+    generator.set_close_contours(false);
+    let truncated: IndexedMeshText = generator
+        .generate_glyph_2d('A', None)
+        .expect("Failed to generate text mesh for character A.");
+    assert!((area_2d(&truncated).abs() - 0.08).abs() < 1e-4);
+}
@@ -0,0 +1,159 @@
+use meshtext::{
+    IndexedMeshText, MeshGenerator, Paragraph, TextAlignment, TextSection, WhitespaceMode,
+};
+
+/// Test if a paragraph is wrapped at spaces and line feeds.
+#[test]
+fn test_paragraph_wrapping() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let paragraph = Paragraph::new()
+        .with_run("aaa bbb ")
+        .with_run("ccc\ndd")
+        .with_max_width(3.5);
+    let (mesh, lines) = generator
+        .generate_paragraph(&paragraph, true, None)
+        .expect("Failed to generate text mesh for paragraph.");
+
+    let characters: Vec<_> = lines.iter().map(|l| l.characters.clone()).collect();
+    assert_eq!(characters, vec![0..8, 8..11, 12..14]);
+    assert!((lines[0].advance - 3.5).abs() < 1e-4);
+    assert!(lines.windows(2).all(|l| l[1].baseline < l[0].baseline));
+
+    let section: IndexedMeshText = generator
+        .generate_section("aaabbbcccdd", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(mesh.indices.len(), section.indices.len());
+}
+
+/// Test if the lines of a paragraph are aligned within the maximum width.
+#[test]
+fn test_paragraph_alignment() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let offsets = |generator: &mut MeshGenerator<_>, alignment: TextAlignment| {
+        let paragraph = Paragraph::new()
+            .with_run("aaa bbb ccc")
+            .with_max_width(4.0)
+            .with_alignment(alignment);
+        let (_, lines) = generator
+            .generate_paragraph(&paragraph, true, None)
+            .expect("Failed to generate text mesh for paragraph.");
+
+        lines.iter().map(|l| l.offset).collect::<Vec<f32>>()
+    };
+
+    assert_eq!(offsets(&mut generator, TextAlignment::Left), vec![0.0, 0.0]);
+    assert_eq!(
+        offsets(&mut generator, TextAlignment::Center),
+        vec![0.25, 1.25]
+    );
+    assert_eq!(
+        offsets(&mut generator, TextAlignment::Right),
+        vec![0.5, 2.5]
+    );
+}
+
+/// Test if lines are only broken at legal break opportunities.
+#[test]
+fn test_paragraph_break_opportunities() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let lines = |generator: &mut MeshGenerator<_>, text: &str| {
+        let paragraph = Paragraph::new().with_run(text).with_max_width(2.0);
+        let (_, lines) = generator
+            .generate_paragraph(&paragraph, true, None)
+            .expect("Failed to generate text mesh for paragraph.");
+
+        lines
+            .iter()
+            .map(|l| l.characters.clone())
+            .collect::<Vec<_>>()
+    };
+
+    // Non-breaking spaces keep the words together.
+    assert_eq!(lines(&mut generator, "aa\u{a0}bb cc"), vec![0..6, 6..8]);
+
+    // A line may be broken after a hyphen.
+    assert_eq!(lines(&mut generator, "aa-bb"), vec![0..3, 3..5]);
+}
+
+/// Test if words that do not fit are split at their hyphenation points.
+#[test]
+#[cfg(feature = "hyphenation")]
+fn test_paragraph_hyphenation() {
+    use hyphenation::{Language, Load, Standard};
+
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let dictionary =
+        Standard::from_embedded(Language::EnglishUS).expect("Failed to load dictionary.");
+    let paragraph = Paragraph::new()
+        .with_run("hyphenation")
+        .with_max_width(3.0)
+        .with_hyphenation(dictionary);
+    let (_, lines) = generator
+        .generate_paragraph(&paragraph, true, None)
+        .expect("Failed to generate text mesh for paragraph.");
+
+    let characters: Vec<_> = lines.iter().map(|l| l.characters.clone()).collect();
+    assert_eq!(characters, vec![0..2, 2..6, 6..11]);
+    assert!(lines[0].hyphenated && lines[1].hyphenated && !lines[2].hyphenated);
+    assert!(lines.iter().all(|l| l.advance <= 3.0));
+}
+
+/// Test if whitespace is preserved, trimmed or collapsed.
+#[test]
+fn test_paragraph_whitespace() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let advances = |generator: &mut MeshGenerator<_>, whitespace: WhitespaceMode| {
+        let paragraph = Paragraph::new()
+            .with_run("  a   b  \nc")
+            .with_whitespace(whitespace);
+        let (_, lines) = generator
+            .generate_paragraph(&paragraph, true, None)
+            .expect("Failed to generate text mesh for paragraph.");
+
+        assert_eq!(lines[0].characters, 0..9);
+        assert_eq!(lines[1].characters, 10..11);
+        lines[0].advance
+    };
+
+    assert_eq!(advances(&mut generator, WhitespaceMode::Preserve), 4.5);
+    assert_eq!(advances(&mut generator, WhitespaceMode::Trim), 2.5);
+    assert_eq!(advances(&mut generator, WhitespaceMode::Collapse), 1.5);
+}
+
+/// Test if soft hyphens are invisible unless a line is broken at them.
+#[test]
+fn test_paragraph_soft_hyphen() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let plain: IndexedMeshText = generator
+        .generate_section("aaaabbbb", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    let soft: IndexedMeshText = generator
+        .generate_section("aaaa\u{ad}bbbb", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(plain.advance, soft.advance);
+    assert_eq!(plain.indices.len(), soft.indices.len());
+
+    let paragraph = Paragraph::new()
+        .with_run("aaaa\u{ad}bbbb")
+        .with_max_width(2.5);
+    let (_, lines) = generator
+        .generate_paragraph(&paragraph, true, None)
+        .expect("Failed to generate text mesh for paragraph.");
+
+    let characters: Vec<_> = lines.iter().map(|l| l.characters.clone()).collect();
+    assert_eq!(characters, vec![0..5, 5..9]);
+    assert!(lines[0].hyphenated && !lines[1].hyphenated);
+    assert_eq!(lines[0].advance, 2.5);
+}
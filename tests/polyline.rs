@@ -0,0 +1,68 @@
+use glam::Vec3;
+use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+
+/// Test if text on a straight polyline along the x-axis
+/// matches the text section without a polyline.
+#[test]
+fn test_polyline_straight() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let section: IndexedMeshText = generator
+        .generate_section("Hello", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let path = [Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0)];
+    let result = generator
+        .generate_section_on_polyline("Hello", &path, Vec3::Y, false)
+        .expect("Failed to generate text mesh on polyline.");
+
+    assert_eq!(result.indices, section.indices);
+    assert_eq!(result.advance, section.advance);
+    for (a, b) in result.vertices.iter().zip(section.vertices.iter()) {
+        assert!((a - b).abs() < 1e-5);
+    }
+}
+
+/// Test if glyphs are rotated into the frames of the segments and
+/// if the bounding box encloses the rotated glyphs.
+#[test]
+fn test_polyline_frames() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    // The text runs along the negative z-axis after the first glyph.
+    let path = [
+        Vec3::ZERO,
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, -5.0),
+    ];
+    let result = generator
+        .generate_section_on_polyline("IIII", &path, Vec3::Y, true)
+        .expect("Failed to generate text mesh on polyline.");
+
+    let vertices: Vec<Vec3> = result
+        .vertices
+        .chunks_exact(3)
+        .map(|v| Vec3::new(v[0], v[1], v[2]))
+        .collect();
+    let (min, max) = vertices.iter().fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), v| (min.min(*v), max.max(*v)),
+    );
+    assert_eq!(Vec3::from(result.bbox.min), min);
+    assert_eq!(Vec3::from(result.bbox.max), max);
+    assert!(min.z < -0.5);
+
+    // The glyphs after the corner face along the negative x-axis
+    // and stay upright, since the normal is transported.
+    for v in vertices.iter().filter(|v| v.z < -0.1) {
+        assert!((v.x - 1.0).abs() < 1e-5);
+    }
+    assert!(max.y > 0.5);
+
+    // A polyline needs two distinct points.
+    let degenerate = [Vec3::ONE, Vec3::ONE];
+    assert!(generator
+        .generate_section_on_polyline("I", &degenerate, Vec3::Y, true)
+        .is_err());
+}
@@ -0,0 +1,162 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use glam::{Vec2, Vec3A};
+use meshtext::{
+    Glyph, GlyphSource, IndexedMeshText, MeshGenerator, MeshPostProcessor, MeshText,
+    OutlineProcessor, TextSection,
+};
+
+/// Moves all glyphs up and counts the processed glyphs.
+struct Lift {
+    calls: Arc<AtomicUsize>,
+}
+
+impl MeshPostProcessor for Lift {
+    fn process(
+        &self,
+        _glyph: GlyphSource,
+        _flat: bool,
+        vertices: &mut Vec<Vec3A>,
+        _indices: Option<&mut Vec<u32>>,
+    ) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        for v in vertices.iter_mut() {
+            v.y += 1.0;
+        }
+    }
+}
+
+/// Snaps all outline points to a grid and counts the processed glyphs.
+struct Snap {
+    calls: Arc<AtomicUsize>,
+    step: f32,
+}
+
+impl OutlineProcessor for Snap {
+    fn process(&self, _glyph: GlyphSource, _contours: &[Vec<u32>], points: &mut [Vec2]) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        for p in points.iter_mut() {
+            *p = (*p / self.step).round() * self.step;
+        }
+    }
+}
+
+/// Test if post-processors run once per glyph before the
+/// glyph is cached and if the bounding box follows the vertices.
+#[test]
+fn test_post_processor_runs_before_caching() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let original: IndexedMeshText = generator
+        .generate_section("aa", true, None)
+        .expect("Failed to generate text mesh for text section.");
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    generator.add_post_processor(Lift {
+        calls: calls.clone(),
+    });
+
+    let lifted: IndexedMeshText = generator
+        .generate_section("aa", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(lifted.bbox.min.y, original.bbox.min.y + 1.0);
+    assert_eq!(lifted.vertices[1], original.vertices[1] + 1.0);
+
+    let _: IndexedMeshText = generator
+        .generate_section("aaa", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    // The non-indexed cache is processed separately.
+    let _: MeshText = generator
+        .generate_section("a", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+    generator.clear_post_processors();
+    let restored: IndexedMeshText = generator
+        .generate_section("aa", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(restored.vertices, original.vertices);
+}
+
+/// Test if outline processors adjust the outline before
+/// triangulation and run once per glyph before the glyph is cached.
+#[test]
+fn test_outline_processor_runs_before_caching() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    // Snapped points may coincide, so the outlines are repaired.
+    generator.set_watertight(true);
+    let original: MeshText = generator
+        .generate_glyph('o', true, None)
+        .expect("Failed to generate text mesh for character o.");
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let step = 0.125;
+    generator.add_outline_processor(Snap {
+        calls: calls.clone(),
+        step,
+    });
+
+    let snapped: MeshText = generator
+        .generate_glyph('o', true, None)
+        .expect("Failed to generate text mesh for character o.");
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert!(snapped.vertices.len() < original.vertices.len());
+    for v in snapped.vertices.chunks_exact(3) {
+        assert!((v[0] / step - (v[0] / step).round()).abs() < 1e-4);
+        assert!((v[1] / step - (v[1] / step).round()).abs() < 1e-4);
+    }
+
+    let _: MeshText = generator
+        .generate_section("oo", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    generator.clear_outline_processors();
+    let restored: MeshText = generator
+        .generate_glyph('o', true, None)
+        .expect("Failed to generate text mesh for character o.");
+    assert_eq!(restored.vertices, original.vertices);
+}
+
+/// Test if both kinds of processors run once per distinct glyph
+/// if the glyphs are generated for the first time by a text section.
+#[test]
+fn test_processors_run_once_per_section_glyph() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_watertight(true);
+    generator.set_collect_stats(true);
+
+    let outlines = Arc::new(AtomicUsize::new(0));
+    let meshes = Arc::new(AtomicUsize::new(0));
+    generator.add_outline_processor(Snap {
+        calls: outlines.clone(),
+        step: 0.125,
+    });
+    generator.add_post_processor(Lift {
+        calls: meshes.clone(),
+    });
+
+    let _: IndexedMeshText = generator
+        .generate_section("banana", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(outlines.load(Ordering::Relaxed), 3);
+    assert_eq!(meshes.load(Ordering::Relaxed), 3);
+    let stats = generator
+        .take_stats()
+        .expect("Statistics are not collected.");
+    assert_eq!(stats.glyphs, 3);
+
+    let _: MeshText = generator
+        .generate_section("banana", true, None)
+        .expect("Failed to generate text mesh for text section.");
+    assert_eq!(outlines.load(Ordering::Relaxed), 6);
+    assert_eq!(meshes.load(Ordering::Relaxed), 6);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "rapier")]
+
+use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+
+/// Test if a triangle mesh collider keeps the triangles and extent of the mesh.
+#[test]
+fn test_trimesh_collider() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let mesh: IndexedMeshText = generator
+        .generate_section("Hi", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let collider = mesh.to_trimesh_collider().build();
+
+    let trimesh = collider
+        .shape()
+        .as_trimesh()
+        .expect("The collider has a triangle mesh shape.");
+    assert_eq!(trimesh.indices().len(), mesh.indices.len() / 3);
+    assert_eq!(trimesh.vertices().len(), mesh.vertices.len() / 3);
+
+    let aabb = collider.compute_aabb();
+    assert!((aabb.mins.x - mesh.bbox.min.x).abs() < 1e-4);
+    assert!((aabb.mins.y - mesh.bbox.min.y).abs() < 1e-4);
+    assert!((aabb.mins.z - mesh.bbox.min.z).abs() < 1e-4);
+    assert!((aabb.maxs.x - mesh.bbox.max.x).abs() < 1e-4);
+    assert!((aabb.maxs.y - mesh.bbox.max.y).abs() < 1e-4);
+    assert!((aabb.maxs.z - mesh.bbox.max.z).abs() < 1e-4);
+}
+
+/// Test if the vertices of two-dimensional meshes are placed in the XY-plane.
+#[test]
+fn test_trimesh_collider_2d() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let mesh: IndexedMeshText = generator
+        .generate_section_2d("Hi", None)
+        .expect("Failed to generate text mesh for text section.");
+    let collider = mesh.to_trimesh_collider().build();
+
+    let trimesh = collider
+        .shape()
+        .as_trimesh()
+        .expect("The collider has a triangle mesh shape.");
+    assert_eq!(trimesh.vertices().len(), mesh.vertices.len() / 2);
+    assert!(trimesh.vertices().iter().all(|v| v.z == 0f32));
+}
+
+/// Test if a convex decomposition collider covers the mesh.
+#[test]
+fn test_convex_decomposition_collider() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let mesh: IndexedMeshText = generator
+        .generate_section("I", false, None)
+        .expect("Failed to generate text mesh for text section.");
+    let collider = mesh.to_convex_decomposition_collider().build();
+
+    let compound = collider
+        .shape()
+        .as_compound()
+        .expect("The collider has a compound shape.");
+    assert!(!compound.shapes().is_empty());
+
+    let aabb = collider.compute_aabb();
+    assert!((aabb.mins.x - mesh.bbox.min.x).abs() < 1e-3);
+    assert!((aabb.maxs.y - mesh.bbox.max.y).abs() < 1e-3);
+}
@@ -0,0 +1,30 @@
+use meshtext::{Glyph, IndexedMeshText, MeshGenerator, MeshText, RasterImageFormat};
+
+/// Test if glyphs that only have a raster image are generated as
+/// a rectangle that matches the area of the extracted image.
+#[test]
+fn test_raster_image_quad() {
+    // A minimal font whose glyph for `A` is a PNG image in the `sbix` table.
+    let font_data = include_bytes!("../assets/font/SbixTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let image = generator.glyph_image('A').expect("Failed to get image.");
+    assert_eq!(image.format, RasterImageFormat::PNG);
+    assert_eq!((image.width, image.height), (50, 60));
+    assert_eq!(image.pixels_per_em, 100);
+    assert!((image.min.x - 0.1).abs() < 1e-5 && (image.min.y + 0.2).abs() < 1e-5);
+    assert!((image.max.x - 0.6).abs() < 1e-5 && (image.max.y - 0.4).abs() < 1e-5);
+
+    let quad: IndexedMeshText = generator
+        .generate_glyph('A', true, None)
+        .expect("Failed to generate text mesh for character A.");
+    assert_eq!(quad.indices.len(), 6);
+    assert_eq!(quad.bbox.min.truncate(), image.min);
+    assert_eq!(quad.bbox.max.truncate(), image.max);
+
+    let extruded: MeshText = generator
+        .generate_glyph('A', false, None)
+        .expect("Failed to generate text mesh for character A.");
+    assert!(!extruded.vertices.is_empty());
+    assert_eq!(extruded.bbox.max.z, 0.5);
+}
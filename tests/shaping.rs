@@ -0,0 +1,98 @@
+#![cfg(feature = "shaping")]
+
+use meshtext::{FontFeature, IndexedMeshText, MeshGenerator, ShapingSettings, TextSection};
+
+/// Generates a flat text section and returns its mesh.
+fn section(
+    generator: &mut MeshGenerator<ttf_parser::Face<'static>>,
+    text: &str,
+) -> IndexedMeshText {
+    generator
+        .generate_section(text, true, None)
+        .expect("Failed to generate text mesh for text section.")
+}
+
+/// Test if combining marks that the font does not attach
+/// do not advance and are centered above their base glyph.
+#[test]
+fn test_shaped_combining_marks() {
+    let font_data = include_bytes!("../assets/font/LayoutTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let base = section(&mut generator, "o");
+    let marked = section(&mut generator, "o\u{301}");
+    assert_eq!(base.advance, marked.advance);
+    assert_eq!(section(&mut generator, "o\u{301}o").advance, 1.0);
+
+    // Only the accent reaches above the base glyph.
+    let accent: Vec<f32> = marked
+        .vertices
+        .chunks(3)
+        .filter(|v| v[1] > base.bbox.max.y)
+        .map(|v| v[0])
+        .collect();
+    let min = accent.iter().copied().fold(f32::MAX, f32::min);
+    let max = accent.iter().copied().fold(f32::MIN, f32::max);
+    let center = (base.bbox.min.x + base.bbox.max.x) * 0.5;
+    assert!(((min + max) * 0.5 - center).abs() < 1e-4);
+}
+
+/// Test if ligatures are used unless they are disabled.
+#[test]
+fn test_shaped_ligatures() {
+    let font_data = include_bytes!("../assets/font/LayoutTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    assert!((section(&mut generator, "fi").advance - 0.7).abs() < 1e-4);
+
+    generator.set_shaping_settings(ShapingSettings {
+        ligatures: false,
+        ..Default::default()
+    });
+    assert!((section(&mut generator, "fi").advance - 1.0).abs() < 1e-4);
+}
+
+/// Test if optional features can be enabled.
+#[test]
+fn test_shaped_features() {
+    let font_data = include_bytes!("../assets/font/LayoutTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    assert!((section(&mut generator, "a").advance - 0.5).abs() < 1e-4);
+
+    generator.set_shaping_settings(ShapingSettings {
+        features: vec![FontFeature::enable(b"smcp")],
+        ..Default::default()
+    });
+    assert!((section(&mut generator, "a").advance - 0.55).abs() < 1e-4);
+}
+
+/// Test if the language selects the localized forms of the font.
+#[test]
+fn test_shaped_language() {
+    let font_data = include_bytes!("../assets/font/LayoutTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    assert!((section(&mut generator, "i").advance - 0.5).abs() < 1e-4);
+
+    generator.set_shaping_settings(ShapingSettings {
+        script: Some(*b"Latn"),
+        language: Some("tr".to_string()),
+        ..Default::default()
+    });
+    assert!((section(&mut generator, "i").advance - 0.3).abs() < 1e-4);
+}
+
+/// Test if the pairs of the `GPOS` table and the legacy `kern` table are kerned.
+#[test]
+fn test_shaped_kerning() {
+    let font_data = include_bytes!("../assets/font/LayoutTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    assert!((section(&mut generator, "AV").advance - 1.1).abs() < 1e-4);
+    assert!((section(&mut generator, "To").advance - 1.05).abs() < 1e-4);
+
+    let font_data = include_bytes!("../assets/font/KernTest.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    assert!((section(&mut generator, "AV").advance - 1.12).abs() < 1e-4);
+}
@@ -0,0 +1,12 @@
+#![cfg(feature = "system-fonts")]
+
+use meshtext::{FontQuery, MeshGenerator};
+
+/// Test if no generator is created for a font that is not installed.
+#[test]
+fn test_missing_system_font() {
+    let query = FontQuery::new("This Font Does Not Exist")
+        .with_weight(700)
+        .with_italic(true);
+    assert!(MeshGenerator::from_system_font(&query).is_none());
+}
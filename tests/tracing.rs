@@ -0,0 +1,63 @@
+#![cfg(feature = "tracing")]
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+use meshtext::{IndexedMeshText, MeshGenerator, TextSection};
+use tracing::{span, subscriber::with_default, Event, Metadata, Subscriber};
+
+/// Records the names of all spans that are created.
+#[derive(Default)]
+struct SpanNames {
+    names: Arc<Mutex<Vec<&'static str>>>,
+    next_id: AtomicU64,
+}
+
+impl Subscriber for SpanNames {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        self.names.lock().unwrap().push(span.metadata().name());
+        span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+/// Test if the generation of a text section is instrumented.
+#[test]
+fn test_generation_spans() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let subscriber = SpanNames::default();
+    let names = subscriber.names.clone();
+    with_default(subscriber, || {
+        let _: IndexedMeshText = generator
+            .generate_section("Hi", false, None)
+            .expect("Failed to generate text mesh for text section.");
+    });
+
+    let names = names.lock().unwrap();
+    for name in [
+        "layout_section",
+        "load_from_cache_indexed",
+        "insert_into_cache_indexed",
+        "outline_glyph",
+        "triangulate_outline_indexed",
+    ] {
+        assert!(names.contains(&name), "Missing span {}.", name);
+    }
+}
@@ -0,0 +1,428 @@
+use glam::{Mat4, Vec2, Vec3, Vec3A};
+use meshtext::{
+    Anchor, BoundingBox, Glyph, IndexedMeshText, IndexedMeshTextGlam, MeshGenerator, MeshText,
+    MeshTextGlam, MirrorAxis, TextSection, TriangleMesh,
+};
+
+/// Test if indexed and non-indexed meshes of the same glyph yield
+/// the same triangles.
+#[test]
+fn test_triangles_indexed_and_non_indexed() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: MeshText = generator
+        .generate_glyph('A', true, None)
+        .expect("Failed to generate text mesh for character A.");
+    let indexed_mesh: IndexedMeshText = generator
+        .generate_glyph('A', true, None)
+        .expect("Failed to generate indexed text mesh for character A.");
+
+    let triangles: Vec<_> = mesh.triangles().collect();
+    let indexed_triangles: Vec<_> = indexed_mesh.triangles().collect();
+
+    assert_eq!(triangles.len(), 11);
+    assert_eq!(triangles, indexed_triangles);
+}
+
+/// Test if indexed and non-indexed extruded glyphs enclose the same volume.
+#[test]
+fn test_volume_indexed_and_non_indexed() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: MeshText = generator
+        .generate_glyph('B', false, None)
+        .expect("Failed to generate text mesh for character B.");
+    let indexed_mesh: IndexedMeshText = generator
+        .generate_glyph('B', false, None)
+        .expect("Failed to generate indexed text mesh for character B.");
+
+    let volume = mesh.volume().unwrap();
+    assert!(volume > 0f32);
+    assert!((volume - indexed_mesh.volume().unwrap()).abs() < 1e-4);
+    assert!((mesh.surface_area() - indexed_mesh.surface_area()).abs() < 1e-4);
+}
+
+/// Test if meshes with [glam] vertices yield the same triangles as flattened meshes.
+#[test]
+fn test_triangles_glam() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: MeshText = generator
+        .generate_glyph('B', false, None)
+        .expect("Failed to generate text mesh for character B.");
+    let glam_mesh: MeshTextGlam = generator
+        .generate_glyph('B', false, None)
+        .expect("Failed to generate glam text mesh for character B.");
+    let indexed_mesh: IndexedMeshTextGlam = generator
+        .generate_glyph('B', false, None)
+        .expect("Failed to generate indexed glam text mesh for character B.");
+
+    assert!(glam_mesh.vertices_ref().is_none());
+    assert_eq!(glam_mesh.vertices(), mesh.vertices);
+    assert_eq!(
+        glam_mesh.triangles().collect::<Vec<_>>(),
+        mesh.triangles().collect::<Vec<_>>()
+    );
+    assert!((indexed_mesh.volume().unwrap() - mesh.volume().unwrap()).abs() < 1e-4);
+
+    let flat: IndexedMeshText = generator
+        .generate_glyph_2d('B', None)
+        .expect("Failed to generate text mesh for character B.");
+    let glam_flat: IndexedMeshTextGlam<Vec2> = generator
+        .generate_glyph_2d('B', None)
+        .expect("Failed to generate glam text mesh for character B.");
+    assert_eq!(glam_flat.vertex_components(), 2);
+    assert_eq!(glam_flat.vertices(), flat.vertices);
+    assert!(glam_flat.volume().is_none());
+    assert!((glam_flat.surface_area() - flat.surface_area()).abs() < 1e-4);
+}
+
+/// Test if the convex hull of a glyph encloses the glyph itself.
+#[test]
+fn test_glyph_hull_encloses_glyph() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: MeshText = generator
+        .generate_glyph('B', false, None)
+        .expect("Failed to generate text mesh for character B.");
+    let hulls = generator
+        .generate_glyph_hulls("B", false, None)
+        .expect("Failed to generate hull for character B.");
+
+    assert_eq!(hulls.len(), 1);
+    assert!(hulls[0].volume().unwrap() >= mesh.volume().unwrap());
+    assert!(hulls[0].bbox.size().abs_diff_eq(mesh.bbox.size(), 1e-5));
+}
+
+/// A sloped plane given by `z = x / 2`.
+struct SlopedPlane {
+    indices: Vec<u32>,
+    vertices: Vec<f32>,
+}
+
+impl TriangleMesh for SlopedPlane {
+    fn vertices_ref(&self) -> Option<&[f32]> {
+        Some(&self.vertices)
+    }
+
+    fn indices_ref(&self) -> Option<&[u32]> {
+        Some(&self.indices)
+    }
+
+    fn vertex_components(&self) -> usize {
+        3
+    }
+}
+
+/// Test if flat text conforms to a surface after the projection.
+#[test]
+fn test_project_onto_surface() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let text: IndexedMeshText = generator
+        .generate_section_2d("Hi", None)
+        .expect("Failed to generate indexed text mesh for text section.");
+
+    let plane = SlopedPlane {
+        indices: vec![0, 1, 2, 0, 2, 3],
+        vertices: vec![
+            -10.0, -10.0, -5.0, 10.0, -10.0, 5.0, 10.0, 10.0, 5.0, -10.0, 10.0, -5.0,
+        ],
+    };
+    let decal = text.project_onto(&plane, Vec3::Z, 0.0);
+
+    assert!(!decal.two_dimensional);
+    assert_eq!(decal.indices, text.indices);
+    for v in decal.vertices.chunks(3) {
+        assert!((v[2] - v[0] * 0.5).abs() < 1e-5);
+    }
+
+    // Vertices that miss the surface keep their position.
+    let missed = text.project_onto(&plane, Vec3::Y, 0.0);
+    assert!(missed.vertices.chunks(3).all(|v| v[2] == 0.0));
+}
+
+/// Test if three-dimensional text keeps its depth after the projection.
+#[test]
+fn test_project_onto_surface_depth() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let text: IndexedMeshText = generator
+        .generate_section("Hi", false, None)
+        .expect("Failed to generate indexed text mesh for text section.");
+
+    let floor = SlopedPlane {
+        indices: vec![0, 1, 2, 0, 2, 3],
+        vertices: vec![
+            -10.0, -10.0, -2.0, 10.0, -10.0, -2.0, 10.0, 10.0, -2.0, -10.0, 10.0, -2.0,
+        ],
+    };
+    let decal = text.project_onto(&floor, Vec3::NEG_Z, 0.1);
+
+    assert!((decal.bbox.min.z + 1.9).abs() < 1e-5);
+    assert!((decal.bbox.max.z + 0.9).abs() < 1e-5);
+    for (projected, original) in decal.vertices.chunks(3).zip(text.vertices.chunks(3)) {
+        assert_eq!(projected[..2], original[..2]);
+        assert!((projected[2] - (original[2] - 1.4)).abs() < 1e-5);
+    }
+}
+
+/// Test if the coverage of a rasterized section matches its surface area
+/// and that a single sample per pixel yields a binary mask.
+#[test]
+fn test_rasterize_coverage() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: IndexedMeshText = generator
+        .generate_section_2d("Hi!", None)
+        .expect("Failed to generate text mesh for text section.");
+
+    let (min, max) = (Vec2::new(-0.1, -0.2), Vec2::new(1.9, 1.0));
+    let (width, height) = (200, 120);
+    let pixel_area = (max - min).x / width as f32 * (max - min).y / height as f32;
+
+    let mask = mesh.rasterize(min, max, width, height, 4);
+    let area: f32 = mask.data.iter().map(|c| *c as f32 / 255.0).sum::<f32>() * pixel_area;
+    assert!((area - mesh.surface_area()).abs() / mesh.surface_area() < 0.03);
+
+    let binary = mesh.rasterize(min, max, width, height, 1);
+    assert!(binary.data.iter().all(|c| *c == 0 || *c == 255));
+    assert!(binary.get(width, 0).is_none());
+}
+
+/// Test if indexed meshes can be generated with other index types.
+#[test]
+fn test_index_types() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    let default: IndexedMeshText = generator
+        .generate_section("Index", false, None)
+        .expect("Failed to generate mesh.");
+    let short: IndexedMeshText<u16> = generator
+        .generate_section("Index", false, None)
+        .expect("Failed to generate mesh.");
+    let wide: IndexedMeshText<usize> = generator
+        .generate_glyph('I', false, None)
+        .expect("Failed to generate mesh.");
+
+    assert_eq!(short.vertices, default.vertices);
+    assert!(short
+        .indices
+        .iter()
+        .zip(default.indices.iter())
+        .all(|(s, d)| *s as u32 == *d));
+    assert!(!wide.indices.is_empty());
+
+    // Only meshes with the default index type are triangle meshes.
+    let widened: IndexedMeshText = short.with_index_type().expect("Failed to convert indices.");
+    assert!((widened.surface_area() - default.surface_area()).abs() < 1e-5);
+
+    // Indices that exceed the range of the index type can not be converted.
+    let large = IndexedMeshText {
+        advance: 0f32,
+        bbox: default.bbox,
+        indices: vec![0u32, 1, 70_000],
+        two_dimensional: false,
+        vertices: Vec::new(),
+    };
+    assert!(large.with_index_type::<u16>().is_err());
+}
+
+/// Test if the array accessors match the glam based accessors.
+#[test]
+#[cfg(feature = "arrays")]
+fn test_array_accessors() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: IndexedMeshText = generator
+        .generate_section("Array", false, None)
+        .expect("Failed to generate mesh.");
+
+    assert!(mesh
+        .triangle_arrays()
+        .zip(mesh.triangles())
+        .all(|(a, t)| a == t.map(|c| c.to_array())));
+    assert_eq!(mesh.bbox.min_array(), mesh.bbox.min.to_array());
+    assert_eq!(mesh.bbox.max_array(), mesh.bbox.max.to_array());
+
+    let mut bbox = meshtext::BoundingBox::from_arrays(mesh.bbox.min_array(), mesh.bbox.max_array());
+    assert_eq!(bbox, mesh.bbox);
+    #[rustfmt::skip]
+    bbox.transform_array(&[
+        2f32, 0f32, 0f32, 0f32,
+        0f32, 2f32, 0f32, 0f32,
+        0f32, 0f32, 2f32, 0f32,
+        0f32, 0f32, 0f32, 1f32,
+    ]);
+    assert_eq!(bbox.size_array(), (mesh.bbox.size() * 2f32).to_array());
+}
+
+/// Test if merged meshes keep their triangles and offset the indices correctly.
+#[test]
+fn test_merge() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let first: IndexedMeshText = generator
+        .generate_section("ab", false, None)
+        .expect("Failed to generate mesh.");
+    let second: IndexedMeshText = generator
+        .generate_section("cd", false, None)
+        .expect("Failed to generate mesh.");
+    let translation = Mat4::from_translation(Vec3::new(0f32, -2f32, 0f32));
+    let first_triangles: Vec<[Vec3; 3]> = first.triangles().collect();
+    let second_triangles: Vec<[Vec3; 3]> = second.triangles().collect();
+    let (first_bbox, second_bbox) = (first.bbox, second.bbox);
+
+    let merged = IndexedMeshText::merge(&[(first, Mat4::IDENTITY), (second, translation)])
+        .expect("Failed to merge meshes.");
+    assert!(!merged.two_dimensional);
+    let triangles: Vec<[Vec3; 3]> = merged.triangles().collect();
+    assert_eq!(
+        triangles.len(),
+        first_triangles.len() + second_triangles.len()
+    );
+    assert_eq!(triangles[..first_triangles.len()], first_triangles[..]);
+    assert!(triangles[first_triangles.len()..]
+        .iter()
+        .zip(second_triangles.iter())
+        .all(|(m, s)| (0..3).all(|c| m[c] == s[c] + Vec3::new(0f32, -2f32, 0f32))));
+    assert_eq!(merged.bbox.max, first_bbox.max.max(second_bbox.max));
+    assert_eq!(merged.bbox.min.y, second_bbox.min.y - 2f32);
+
+    let flat: IndexedMeshText = generator
+        .generate_section_2d("ab", None)
+        .expect("Failed to generate mesh.");
+    let merged = IndexedMeshText::merge(&[(flat, translation)]).expect("Failed to merge meshes.");
+    assert!(merged.two_dimensional);
+    assert_eq!(merged.bbox.max.z, 0f32);
+}
+
+/// Test if mirrored meshes keep facing outwards when they are merged.
+#[test]
+fn test_merge_mirrored() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mesh: IndexedMeshText = generator
+        .generate_section("ab", false, None)
+        .expect("Failed to generate mesh.");
+    let volume = mesh.volume().unwrap();
+
+    let mirror = Mat4::from_scale(Vec3::new(-1f32, 1f32, 1f32));
+    let merged = IndexedMeshText::merge(&[(mesh, mirror)]).expect("Failed to merge meshes.");
+    assert!((merged.volume().unwrap() - volume).abs() < 1e-4);
+
+    let flat: IndexedMeshText = generator
+        .generate_section_2d("ab", None)
+        .expect("Failed to generate mesh.");
+    let merged = IndexedMeshText::merge(&[(flat, mirror)]).expect("Failed to merge meshes.");
+    for triangle in merged.triangles() {
+        let normal = (triangle[1] - triangle[0]).cross(triangle[2] - triangle[0]);
+        assert!(normal.z >= 0f32);
+    }
+}
+
+/// Test if transforming a mesh computes a tight bounding box.
+#[test]
+fn test_transform() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mut mesh: MeshText = generator
+        .generate_section("Spin", false, None)
+        .expect("Failed to generate mesh.");
+    let triangles = mesh.triangles().count();
+
+    mesh.transform(&Mat4::from_rotation_z(0.5f32));
+    assert_eq!(mesh.triangles().count(), triangles);
+    let (min, max) = mesh.triangles().flatten().fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), v| (min.min(v), max.max(v)),
+    );
+    assert_eq!(Vec3::from(mesh.bbox.min), min);
+    assert_eq!(Vec3::from(mesh.bbox.max), max);
+
+    let mut flat: IndexedMeshText = generator
+        .generate_section_2d("Spin", None)
+        .expect("Failed to generate mesh.");
+    flat.transform(&Mat4::from_translation(Vec3::new(1f32, 2f32, 3f32)));
+    assert!(flat.two_dimensional);
+    assert_eq!(flat.bbox.max.z, 0f32);
+    assert!(flat.bbox.min.y > 1f32);
+}
+
+/// Test if mirrored meshes keep their orientation.
+#[test]
+fn test_mirror() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+
+    for axis in [MirrorAxis::X, MirrorAxis::Y, MirrorAxis::Z] {
+        let mut mesh: MeshText = generator
+            .generate_section("Stamp", false, None)
+            .expect("Failed to generate mesh.");
+        let mut indexed: IndexedMeshText = generator
+            .generate_section("Stamp", false, None)
+            .expect("Failed to generate mesh.");
+        let volume = mesh.volume().unwrap();
+        let (min, max) = (mesh.bbox.min, mesh.bbox.max);
+
+        mesh.mirror(axis);
+        indexed.mirror(axis);
+        assert!((mesh.volume().unwrap() - volume).abs() < 1e-3);
+        assert!((indexed.volume().unwrap() - volume).abs() < 1e-3);
+
+        let c = axis as usize;
+        assert_eq!(mesh.bbox.min[c], -max[c]);
+        assert_eq!(mesh.bbox.max[c], -min[c]);
+        assert_eq!(indexed.bbox, mesh.bbox);
+    }
+}
+
+/// Test if aligned meshes move their anchor to the origin.
+#[test]
+fn test_align() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mut mesh: IndexedMeshText = generator
+        .generate_section("Align", false, None)
+        .expect("Failed to generate mesh.");
+    let size = mesh.bbox.size();
+
+    mesh.align(Anchor::MinCorner);
+    assert!(mesh.bbox.min.abs().max_element() < 1e-5);
+    mesh.align(Anchor::MaxCorner);
+    assert!(mesh.bbox.max.abs().max_element() < 1e-5);
+    assert!((mesh.bbox.size() - size).abs().max_element() < 1e-5);
+
+    let mut shifted: MeshText = generator
+        .generate_section("Align", false, None)
+        .expect("Failed to generate mesh.");
+    let original = shifted.bbox;
+    shifted.transform(&Mat4::from_translation(Vec3::new(3f32, 0f32, 0f32)));
+    let translation = shifted.align(Anchor::BaselineStart);
+    assert!((translation.x + 3f32 + original.min.x).abs() < 1e-5);
+    assert_eq!(translation.y, 0f32);
+    assert!((shifted.bbox.min.y - original.min.y).abs() < 1e-5);
+}
+
+/// Test if meshes are scaled and centered to fit into a box.
+#[test]
+fn test_fit_into() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    let mut mesh: IndexedMeshText = generator
+        .generate_section("Plaque", false, None)
+        .expect("Failed to generate mesh.");
+    let plaque = BoundingBox::new(Vec3A::new(1f32, 1f32, -0.1), Vec3A::new(3f32, 5f32, 0.1));
+
+    let ratio = mesh.bbox.size().x / mesh.bbox.size().y;
+    let scale = mesh.fit_into(&plaque);
+    assert!(scale > 0f32);
+    assert!((mesh.bbox.center() - plaque.center()).abs().max_element() < 1e-4);
+    assert!(mesh.bbox.min.cmpge(plaque.min - 1e-4).all());
+    assert!(mesh.bbox.max.cmple(plaque.max + 1e-4).all());
+    assert!((mesh.bbox.size().x / mesh.bbox.size().y - ratio).abs() < 1e-3);
+
+    // The depth limits the scale of the extruded text.
+    assert!((mesh.bbox.size().z - 0.2).abs() < 1e-4);
+}
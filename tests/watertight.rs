@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use glam::Vec2;
+use meshtext::{Glyph, GlyphProvider, IndexedMeshText, MeshGenerator, TriangleMesh};
+
+/// Provides two overlapping squares for the character `⧉`.
+struct Overlapping;
+
+impl GlyphProvider for Overlapping {
+    fn advance(&self, character: char) -> Option<f32> {
+        (character == '⧉').then_some(0.6)
+    }
+
+    fn outline(&self, _character: char) -> Vec<Vec<Vec2>> {
+        let square = |offset: f32| {
+            vec![
+                Vec2::new(offset, offset),
+                Vec2::new(offset, offset + 0.3),
+                Vec2::new(offset + 0.3, offset + 0.3),
+                Vec2::new(offset + 0.3, offset),
+            ]
+        };
+        vec![square(0.0), square(0.1)]
+    }
+}
+
+/// Checks that each directed edge of a mesh is matched by
+/// exactly one edge in the opposite direction.
+fn assert_closed_manifold(mesh: &IndexedMeshText) {
+    let mut edges: HashMap<(u32, u32), i32> = HashMap::new();
+    for triangle in mesh.indices.chunks_exact(3) {
+        for (a, b) in [
+            (triangle[0], triangle[1]),
+            (triangle[1], triangle[2]),
+            (triangle[2], triangle[0]),
+        ] {
+            *edges.entry((a, b)).or_default() += 1;
+        }
+    }
+
+    for ((a, b), count) in edges.iter() {
+        assert_eq!(*count, 1, "The edge {a}-{b} is used more than once.");
+        assert_eq!(edges.get(&(*b, *a)), Some(&1), "The edge {a}-{b} is open.");
+    }
+}
+
+/// Test if watertight glyphs are closed manifold solids.
+#[test]
+fn test_watertight_glyphs() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_watertight(true);
+
+    for c in "ABO8&%@gij".chars() {
+        let mesh: IndexedMeshText = generator
+            .generate_glyph(c, false, None)
+            .expect("Failed to generate text mesh.");
+        assert_closed_manifold(&mesh);
+        assert!(mesh.volume().unwrap() > 0f32);
+    }
+}
+
+/// Test if overlapping contours are merged into a single solid.
+#[test]
+fn test_watertight_overlapping_contours() {
+    let font_data = include_bytes!("../assets/font/FiraMono-Regular.ttf");
+    let mut generator = MeshGenerator::new(font_data);
+    generator.set_glyph_provider(Overlapping);
+    generator.set_watertight(true);
+
+    let mesh: IndexedMeshText = generator
+        .generate_glyph('⧉', false, None)
+        .expect("Failed to generate text mesh for character ⧉.");
+    assert_closed_manifold(&mesh);
+
+    // The squares cover 0.09 each and overlap by 0.04.
+    let volume = mesh.volume().unwrap();
+    assert!((volume - 0.14).abs() < 1e-4);
+}
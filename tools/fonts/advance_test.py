@@ -0,0 +1,24 @@
+"""Generates `AdvanceTest.ttf`, whose glyphs have no advances.
+
+The font has no `hmtx` table, but its `OS/2` table specifies an average
+advance of 600 font units. The letters `A` and `C` are squares from 100
+to 300 font units.
+"""
+
+from sfnt import cmap, glyf, head, hhea, maxp, os2, write_font
+
+square = [[(100, 0), (300, 0), (300, 200), (100, 200)]]
+glyph_data, loca = glyf([[], square, square])
+
+write_font(
+    "AdvanceTest.ttf",
+    {
+        b"head": head(),
+        b"hhea": hhea(3, number_of_metrics=0),
+        b"maxp": maxp(3),
+        b"OS/2": os2(avg_char_width=600),
+        b"cmap": cmap({"A": 1, "C": 2}),
+        b"glyf": glyph_data,
+        b"loca": loca,
+    },
+)
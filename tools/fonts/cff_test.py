@@ -0,0 +1,76 @@
+"""Generates `CffTest.otf`, whose outlines are stored in a `CFF` table.
+
+The letter `A` is a square from (100, 100) to (500, 500) that is not
+closed explicitly, as CFF charstrings usually end. The letter `B` is
+the same square with an explicit closing segment.
+"""
+
+from sfnt import be, cmap, head, hhea, hmtx, maxp, write_font
+
+RMOVETO, RLINETO, ENDCHAR = 21, 5, 14
+
+
+def index(items):
+    """Builds a CFF INDEX with 32 bit offsets."""
+    if not items:
+        return be("H", 0)
+    offsets = [1]
+    for item in items:
+        offsets.append(offsets[-1] + len(item))
+    return be("HB", len(items), 4) + b"".join(be("I", o) for o in offsets) + b"".join(items)
+
+
+def charstring(*operations):
+    """Builds a Type 2 charstring from operators and their operands."""
+    out = b""
+    for operator, operands in operations:
+        out += b"".join(bytes([28]) + be("h", v) for v in operands) + bytes([operator])
+    return out + bytes([ENDCHAR])
+
+
+def integer(value):
+    """Encodes a 32 bit integer operand of a DICT."""
+    return bytes([29]) + be("i", value)
+
+
+square = [(RMOVETO, (100, 100)), (RLINETO, (400, 0)), (RLINETO, (0, 400)), (RLINETO, (-400, 0))]
+charstrings = index([
+    bytes([ENDCHAR]),
+    charstring(*square),
+    charstring(*square, (RLINETO, (0, -400))),
+])
+
+header = bytes([1, 0, 4, 4])
+name = index([b"CffTest"])
+strings = index([])
+global_subrs = index([])
+private = b""
+
+
+def top_dict(charstrings_offset, private_offset):
+    return (
+        integer(charstrings_offset) + bytes([17])
+        + integer(len(private)) + integer(private_offset) + bytes([18])
+    )
+
+
+top_length = len(index([top_dict(0, 0)]))
+charstrings_offset = len(header) + len(name) + top_length + len(strings) + len(global_subrs)
+private_offset = charstrings_offset + len(charstrings)
+cff = (
+    header + name + index([top_dict(charstrings_offset, private_offset)])
+    + strings + global_subrs + charstrings + private
+)
+
+write_font(
+    "CffTest.otf",
+    {
+        b"head": head(),
+        b"hhea": hhea(3),
+        b"maxp": maxp(3),
+        b"hmtx": hmtx([500, 500, 500]),
+        b"cmap": cmap({"A": 1, "B": 2}),
+        b"CFF ": cff,
+    },
+    cff=True,
+)
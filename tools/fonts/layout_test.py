@@ -0,0 +1,107 @@
+"""Generates `LayoutTest.ttf` and `KernTest.ttf`, which exercise the
+substitutions and positioning of the text layout.
+
+Both fonts have the same glyphs, whose advances are listed below. The
+`GSUB` table of `LayoutTest.ttf` has these features:
+
+* `liga` replaces `f` and `i` with the ligature `f_i`.
+* `smcp` replaces `a` with the small capital `a.sc`.
+* `locl` replaces `i` with the dotless `ı` for Turkish (`latn`/`TRK`).
+
+Its `GPOS` table kerns `A` and `V` by -100 font units with a pair
+adjustment of format 1 and `T` and `o` by -50 font units with a pair
+adjustment of format 2. The combining acute accent is not attached to
+any base glyph.
+
+`KernTest.ttf` has no `GSUB` and `GPOS` tables, instead its legacy `kern`
+table kerns `A` and `V` by -80 font units.
+"""
+
+from sfnt import be, class_def, cmap, coverage, glyf, head, hhea, hmtx, layout_table, maxp, write_font
+
+NOTDEF, A, V, T, O, F, I, F_I, LOWER_A, A_SC, DOTLESS_I, ACUTE = range(12)
+
+glyphs = [
+    (".notdef", None, 500, []),
+    ("A", "A", 600, [[(50, 0), (550, 0), (550, 700), (50, 700)]]),
+    ("V", "V", 600, [[(50, 0), (550, 0), (550, 700), (50, 700)]]),
+    ("T", "T", 600, [[(50, 0), (550, 0), (550, 700), (50, 700)]]),
+    ("o", "o", 500, [[(50, 0), (450, 0), (450, 500), (50, 500)]]),
+    ("f", "f", 500, [[(50, 0), (450, 0), (450, 700), (50, 700)]]),
+    ("i", "i", 500, [[(200, 0), (300, 0), (300, 700), (200, 700)]]),
+    ("f_i", None, 700, [[(50, 0), (650, 0), (650, 700), (50, 700)]]),
+    ("a", "a", 500, [[(50, 0), (450, 0), (450, 500), (50, 500)]]),
+    ("a.sc", None, 550, [[(50, 0), (500, 0), (500, 550), (50, 550)]]),
+    ("dotlessi", "\u0131", 300, [[(100, 0), (200, 0), (200, 500), (100, 500)]]),
+    ("acutecomb", "\u0301", 300, [[(0, 600), (200, 600), (200, 700)]]),
+]
+num_glyphs = len(glyphs)
+glyph_data, loca = glyf([g[3] for g in glyphs])
+common = {
+    b"head": head(),
+    b"hhea": hhea(num_glyphs, advance_max=700),
+    b"maxp": maxp(num_glyphs),
+    b"hmtx": hmtx([g[2] for g in glyphs]),
+    b"cmap": cmap({g[1]: i for i, g in enumerate(glyphs) if g[1]}),
+    b"glyf": glyph_data,
+    b"loca": loca,
+}
+
+
+def ligature_substitution(first, components, ligature):
+    """Builds a ligature substitution subtable of format 1."""
+    ligature_table = be("HH", ligature, len(components) + 1) + b"".join(be("H", c) for c in components)
+    ligature_set = be("HH", 1, 4) + ligature_table
+    cover = coverage([first])
+    return be("HHHH", 1, 8, 1, 8 + len(cover)) + cover + ligature_set
+
+
+def single_substitution(glyph, substitute):
+    """Builds a single substitution subtable of format 2."""
+    return be("HHHH", 2, 8, 1, substitute) + coverage([glyph])
+
+
+def pair_adjustment_format1(left, right, value):
+    """Builds a pair adjustment subtable of format 1, which adjusts the x advance."""
+    cover = coverage([left])
+    pair_set = be("H", 1) + be("Hh", right, value)
+    return be("HHHHHH", 1, 12, 4, 0, 1, 12 + len(cover)) + cover + pair_set
+
+
+def pair_adjustment_format2(left, right, value):
+    """Builds a pair adjustment subtable of format 2 with one class on each side."""
+    cover = coverage([left])
+    first = class_def({left: 1})
+    second = class_def({right: 1})
+    matrix = be("hhhh", 0, 0, 0, value)
+    header = 16 + len(matrix)
+    return (
+        be("HHHH", 2, header, 4, 0)
+        + be("HHHH", header + len(cover), header + len(cover) + len(first), 2, 2)
+        + matrix + cover + first + second
+    )
+
+
+gsub = layout_table(
+    [
+        (4, [ligature_substitution(F, [I], F_I)]),
+        (1, [single_substitution(LOWER_A, A_SC)]),
+        (1, [single_substitution(I, DOTLESS_I)]),
+    ],
+    [(b"liga", [0]), (b"locl", [2]), (b"smcp", [1])],
+    {b"DFLT": ([0, 2], {}), b"latn": ([0, 2], {b"TRK ": [0, 1, 2]})},
+)
+gpos = layout_table(
+    [
+        (2, [pair_adjustment_format1(A, V, -100)]),
+        (2, [pair_adjustment_format2(T, O, -50)]),
+    ],
+    [(b"kern", [0, 1])],
+    {b"DFLT": ([0], {}), b"latn": ([0], {})},
+)
+write_font("LayoutTest.ttf", {**common, b"GSUB": gsub, b"GPOS": gpos})
+
+pairs = [(A, V, -80)]
+kern = be("HH", 0, 1) + be("HHH", 0, 14 + 6 * len(pairs), 1) + be("HHHH", len(pairs), 6, 0, 0)
+kern += b"".join(be("HHh", *pair) for pair in pairs)
+write_font("KernTest.ttf", {**common, b"kern": kern})
@@ -0,0 +1,27 @@
+"""Generates `MetricsTest.ttf`, whose vertical metrics disagree.
+
+The ascender and descender of the `hhea` table span 1000 font units, the
+typographic metrics of the `OS/2` table span 2000 font units and its Windows
+metrics span 1500 font units. The `USE_TYPO_METRICS` flag is not set. The
+letter `H` is a rectangle of 500 by 1000 font units, which advances by 500
+font units.
+"""
+
+from sfnt import cmap, glyf, head, hhea, hmtx, maxp, os2, write_font
+
+rectangle = [[(0, 0), (500, 0), (500, 1000), (0, 1000)]]
+glyph_data, loca = glyf([[], rectangle])
+
+write_font(
+    "MetricsTest.ttf",
+    {
+        b"head": head(bbox=(0, -500, 500, 1500)),
+        b"hhea": hhea(2, ascender=800, descender=-200, advance_max=500),
+        b"maxp": maxp(2),
+        b"hmtx": hmtx([500, 500]),
+        b"OS/2": os2(typo=(1500, -500, 100), win=(1200, 300)),
+        b"cmap": cmap({"H": 1}),
+        b"glyf": glyph_data,
+        b"loca": loca,
+    },
+)
@@ -0,0 +1,31 @@
+"""Generates `MonospaceTest.ttf`, a monospaced font with a double-width glyph.
+
+The `post` table marks the font as monospaced. The space and `i` advance by
+500 font units, while `W` spans two cells and advances by 1000 font units,
+like the wide glyphs of many monospaced fonts.
+"""
+
+from sfnt import cmap, glyf, head, hhea, hmtx, maxp, post, write_font
+
+glyphs = [
+    (None, 500, []),
+    (" ", 500, []),
+    ("i", 500, [[(200, 0), (300, 0), (300, 700), (200, 700)]]),
+    ("W", 1000, [[(50, 0), (950, 0), (950, 700), (50, 700)]]),
+]
+num_glyphs = len(glyphs)
+glyph_data, loca = glyf([g[2] for g in glyphs])
+
+write_font(
+    "MonospaceTest.ttf",
+    {
+        b"head": head(bbox=(0, -200, 1000, 800)),
+        b"hhea": hhea(num_glyphs, advance_max=1000),
+        b"maxp": maxp(num_glyphs),
+        b"hmtx": hmtx([g[1] for g in glyphs]),
+        b"post": post(fixed_pitch=True),
+        b"cmap": cmap({g[0]: i for i, g in enumerate(glyphs) if g[0]}),
+        b"glyf": glyph_data,
+        b"loca": loca,
+    },
+)
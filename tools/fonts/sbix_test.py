@@ -0,0 +1,27 @@
+"""Generates `SbixTest.ttf`, which only has a bitmap for the letter `A`.
+
+The glyph is a 50x60 PNG in a single `sbix` strike of 100 pixels per em,
+which is drawn at an offset of (10, -20) pixels.
+"""
+
+from sfnt import be, cmap, head, hhea, hmtx, maxp, write_font
+
+png = (
+    b"\x89PNG\r\n\x1a\n"
+    + be("I", 13) + b"IHDR" + be("II", 50, 60) + b"\x08\x06\x00\x00\x00" + b"\0\0\0\0"
+)
+glyph = be("hh", 10, -20) + b"png " + png
+strike = be("HH", 100, 72) + be("III", 16, 16, 16 + len(glyph)) + glyph
+sbix = be("HHI", 1, 1, 1) + be("I", 12) + strike
+
+write_font(
+    "SbixTest.ttf",
+    {
+        b"head": head(),
+        b"hhea": hhea(2),
+        b"maxp": maxp(2),
+        b"hmtx": hmtx([500, 700]),
+        b"cmap": cmap({"A": 1}),
+        b"sbix": sbix,
+    },
+)
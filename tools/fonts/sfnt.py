@@ -0,0 +1,209 @@
+"""Helpers for writing the minimal test fonts in `assets/font`.
+
+The fonts only contain the tables that the tests rely on, which keeps
+them small enough to review byte by byte. No third party packages are
+required, so that the fixtures can be regenerated with a plain Python 3.
+"""
+
+import os
+import struct
+
+
+def be(fmt, *values):
+    """Packs the values in big endian byte order."""
+    return struct.pack(">" + fmt, *values)
+
+
+def head(units_per_em=1000, bbox=(0, -200, 600, 800)):
+    """Builds a `head` table with short `loca` offsets."""
+    return be(
+        "IIIIHHqqhhhhHHhhh",
+        0x10000, 0x10000, 0, 0x5F0F3CF5, 0, units_per_em, 0, 0,
+        *bbox, 0, 8, 2, 0, 0,
+    )
+
+
+def hhea(num_glyphs, ascender=800, descender=-200, line_gap=0, advance_max=600,
+         number_of_metrics=None):
+    """Builds a `hhea` table.
+
+    `number_of_metrics` defaults to one metric per glyph.
+    """
+    if number_of_metrics is None:
+        number_of_metrics = num_glyphs
+    return be(
+        "Ihhh H hhh hhh hhhh hH",
+        0x10000, ascender, descender, line_gap, advance_max,
+        0, 0, advance_max, 1, 0, 0, 0, 0, 0, 0, 0, number_of_metrics,
+    )
+
+
+def maxp(num_glyphs):
+    """Builds a version 0.5 `maxp` table."""
+    return be("IH", 0x5000, num_glyphs)
+
+
+def hmtx(advances, num_glyphs=None):
+    """Builds a `hmtx` table with zero side bearings.
+
+    Glyphs after the last advance only get a side bearing.
+    """
+    if num_glyphs is None:
+        num_glyphs = len(advances)
+    metrics = b"".join(be("Hh", advance, 0) for advance in advances)
+    return metrics + be("h", 0) * (num_glyphs - len(advances))
+
+
+def os2(avg_char_width=500, typo=(800, -200, 0), win=(800, 200), use_typo_metrics=False):
+    """Builds a version 4 `OS/2` table."""
+    selection = 0x40 | (0x80 if use_typo_metrics else 0)
+    return (
+        be("HhHHh", 4, avg_char_width, 400, 5, 0)
+        + be("hhhhhhhhhh", 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+        + be("h", 0)
+        + bytes(10)
+        + be("IIII", 0, 0, 0, 0)
+        + b"TEST"
+        + be("HHH", selection, 0x20, 0xFFFF)
+        + be("hhh", *typo)
+        + be("HH", *win)
+        + be("II", 1, 0)
+        + be("hhHHH", 500, 700, 0, 0x20, 0)
+    )
+
+
+def post(fixed_pitch=False):
+    """Builds a version 3 `post` table without glyph names."""
+    return be("IIhhIIIII", 0x30000, 0, -100, 50, int(fixed_pitch), 0, 0, 0, 0)
+
+
+def cmap(mapping):
+    """Builds a `cmap` table with a single format 4 subtable.
+
+    `mapping` maps characters to glyph ids and every character is
+    stored in its own segment.
+    """
+    segments = [(ord(c), ord(c), (g - ord(c)) & 0xFFFF) for c, g in sorted(mapping.items())]
+    segments.append((0xFFFF, 0xFFFF, 1))
+    n = len(segments)
+    subtable = be("HHHHHHH", 4, 16 + n * 8, 0, n * 2, 2, 0, 0)
+    subtable += b"".join(be("H", s[1]) for s in segments) + be("H", 0)
+    subtable += b"".join(be("H", s[0]) for s in segments)
+    subtable += b"".join(be("H", s[2]) for s in segments)
+    subtable += b"".join(be("H", 0) for _ in segments)
+    return be("HHHHI", 0, 1, 3, 1, 12) + subtable
+
+
+def glyf(contours_per_glyph):
+    """Builds `glyf` and `loca` tables from the polygon contours of each glyph.
+
+    Every point is on the curve and glyphs without contours have no outline.
+    """
+    data = b""
+    loca = be("H", 0)
+    for contours in contours_per_glyph:
+        if contours:
+            points = [p for contour in contours for p in contour]
+            xs = [p[0] for p in points]
+            ys = [p[1] for p in points]
+            glyph = be("hhhhh", len(contours), min(xs), min(ys), max(xs), max(ys))
+            end = -1
+            for contour in contours:
+                end += len(contour)
+                glyph += be("H", end)
+            glyph += be("H", 0) + bytes([1]) * len(points)
+            for axis in (0, 1):
+                previous = 0
+                for point in points:
+                    glyph += be("h", point[axis] - previous)
+                    previous = point[axis]
+            glyph += b"\0" * (len(glyph) % 2)
+            data += glyph
+        loca += be("H", len(data) // 2)
+    return data, loca
+
+
+def coverage(glyphs):
+    """Builds a format 1 coverage table of sorted glyph ids."""
+    return be("HH", 1, len(glyphs)) + b"".join(be("H", g) for g in glyphs)
+
+
+def class_def(classes):
+    """Builds a format 2 class definition table from glyph ids to classes."""
+    ranges = sorted(classes.items())
+    return be("HH", 2, len(ranges)) + b"".join(be("HHH", g, g, c) for g, c in ranges)
+
+
+def lang_sys(features):
+    """Builds a language system table that uses the given feature indices."""
+    return be("HHH", 0, 0xFFFF, len(features)) + b"".join(be("H", f) for f in features)
+
+
+def layout_table(lookups, features, scripts):
+    """Builds a `GSUB` or `GPOS` table.
+
+    `lookups` is a list of lookup types and their subtables, `features` a list
+    of feature tags and the indices of their lookups, and `scripts` maps script
+    tags to the feature indices of their default language system and a map
+    from language tags to the feature indices of these languages.
+    """
+    script_records = b""
+    script_data = b""
+    scripts = sorted(scripts.items())
+    for tag, (default, languages) in scripts:
+        languages = sorted(languages.items())
+        offset = 4 + 6 * len(languages)
+        table = be("HH", offset, len(languages))
+        systems = lang_sys(default)
+        for language, indices in languages:
+            table += language + be("H", offset + len(systems))
+            systems += lang_sys(indices)
+        script_records += tag + be("H", 2 + 6 * len(scripts) + len(script_data))
+        script_data += table + systems
+    script_list = be("H", len(scripts)) + script_records + script_data
+
+    feature_records = b""
+    feature_data = b""
+    for tag, indices in features:
+        feature_records += tag + be("H", 2 + 6 * len(features) + len(feature_data))
+        feature_data += be("HH", 0, len(indices)) + b"".join(be("H", i) for i in indices)
+    feature_list = be("H", len(features)) + feature_records + feature_data
+
+    records = b""
+    lookup_data = b""
+    offset = 2 + 2 * len(lookups)
+    for lookup_type, subtables in lookups:
+        lookup = be("HHH", lookup_type, 0, len(subtables))
+        sub_offset = 6 + 2 * len(subtables)
+        for subtable in subtables:
+            lookup += be("H", sub_offset)
+            sub_offset += len(subtable)
+        lookup += b"".join(subtables)
+        records += be("H", offset + len(lookup_data))
+        lookup_data += lookup
+    lookup_list = be("H", len(lookups)) + records + lookup_data
+
+    script_offset = 10
+    feature_offset = script_offset + len(script_list)
+    lookup_offset = feature_offset + len(feature_list)
+    return (
+        be("IHHH", 0x10000, script_offset, feature_offset, lookup_offset)
+        + script_list + feature_list + lookup_list
+    )
+
+
+def write_font(name, tables, cff=False):
+    """Writes the tables into a font file in `assets/font`."""
+    tables = sorted(tables.items())
+    offset = 12 + 16 * len(tables)
+    version = 0x4F54544F if cff else 0x10000
+    out = be("IHHHH", version, len(tables), 0, 0, 0)
+    data = b""
+    for tag, table in tables:
+        table = table + b"\0" * ((-len(table)) % 4)
+        out += tag + be("III", 0, offset + len(data), len(table))
+        data += table
+
+    path = os.path.join(os.path.dirname(__file__), "..", "..", "assets", "font", name)
+    with open(path, "wb") as file:
+        file.write(out + data)